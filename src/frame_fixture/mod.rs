@@ -236,6 +236,12 @@ mod tests {
             "context_menu",
             "same as modal — nothing is recorded until a right-click",
         ),
+        (
+            "command_palette",
+            "same as modal — recorded only while a caller holds it open, and once open \
+             it focuses its field, whose blinking caret keeps `frame/cached_*` from \
+             settling to `Damage::Skip`",
+        ),
         (
             "gpu_view",
             "needs a `wgpu::Device` the deviceless CPU/alloc harnesses don't have",
@@ -25,7 +25,9 @@ pub(super) fn translate(event: &WindowEvent, scale_factor: f32, mut emit: impl F
                 MouseButton::Left => PointerButton::Left,
                 MouseButton::Right => PointerButton::Right,
                 MouseButton::Middle => PointerButton::Middle,
-                _ => return,
+                MouseButton::Back => PointerButton::Back,
+                MouseButton::Forward => PointerButton::Forward,
+                MouseButton::Other(code) => PointerButton::Other(*code),
             };
             emit(match state {
                 ElementState::Pressed => InputEvent::PointerPressed(button),
@@ -1,6 +1,8 @@
 use glam::Vec2;
 use winit::dpi::PhysicalPosition;
-use winit::event::{DeviceId, Ime, MouseScrollDelta, TouchPhase, WindowEvent};
+use winit::event::{
+    DeviceId, ElementState, Ime, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent,
+};
 use winit::keyboard::{
     Key as WinitKey, KeyCode, ModifiersState, NamedKey, NativeKeyCode, PhysicalKey,
 };
@@ -9,6 +11,7 @@ use crate::common::platform::{PLATFORM, Platform};
 use crate::host::winit::input::{logical_key, normalize_modifiers, physical_key, translate};
 use crate::input::input_event::InputEvent;
 use crate::input::keyboard::{Key, Modifiers};
+use crate::input::pointer::PointerButton;
 
 fn wheel(delta: MouseScrollDelta) -> WindowEvent {
     WindowEvent::MouseWheel {
@@ -158,6 +161,39 @@ fn wheel_deltas_are_logical_and_point_in_scroll_direction() {
     ));
 }
 
+#[test]
+fn every_mouse_button_translates_press_and_release() {
+    let cases = [
+        (MouseButton::Left, PointerButton::Left),
+        (MouseButton::Right, PointerButton::Right),
+        (MouseButton::Middle, PointerButton::Middle),
+        (MouseButton::Back, PointerButton::Back),
+        (MouseButton::Forward, PointerButton::Forward),
+        (MouseButton::Other(7), PointerButton::Other(7)),
+    ];
+    for (winit_button, expected) in cases {
+        for state in [ElementState::Pressed, ElementState::Released] {
+            let mut got = None;
+            translate(
+                &WindowEvent::MouseInput {
+                    device_id: DeviceId::dummy(),
+                    state,
+                    button: winit_button,
+                },
+                1.0,
+                |event| got = Some(event),
+            );
+            match (state, got) {
+                (ElementState::Pressed, Some(InputEvent::PointerPressed(b)))
+                | (ElementState::Released, Some(InputEvent::PointerReleased(b))) => {
+                    assert_eq!(b, expected, "{winit_button:?}");
+                }
+                (_, other) => panic!("{winit_button:?} {state:?} translated to {other:?}"),
+            }
+        }
+    }
+}
+
 #[test]
 fn pinch_translation_rejects_invalid_factors() {
     let mut emitted = None;
@@ -27,12 +27,17 @@ const COARSE_STEP_PX: u32 = 4;
 const MAX_RASTER_PX: u32 = 512;
 
 /// What one cached icon raster is keyed by: which icon, at what physical pixel
-/// size. Ten bytes, against the 24 of cosmic's glyph `CacheKey` — an icon
-/// needs no subpixel bins, because unlike a glyph it snaps to whole pixels.
+/// size, which way round. Twelve bytes, against the 24 of cosmic's glyph
+/// `CacheKey` — an icon needs no subpixel bins, because unlike a glyph it
+/// snaps to whole pixels.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct IconRasterKey {
     pub(crate) icon: IconRef,
     pub(crate) size: U16Vec2,
+    /// Rasterize flipped horizontally — a directional icon under a
+    /// right-to-left owner. A separate raster rather than a flipped quad, so
+    /// the atlas shader and its packed instance stay as they are.
+    pub(crate) mirror: bool,
 }
 
 impl IconRasterKey {
@@ -60,7 +65,16 @@ impl IconRasterKey {
         } else {
             U16Vec2::new(short as u16, target as u16)
         };
-        Self { icon, size }
+        Self {
+            icon,
+            size,
+            mirror: false,
+        }
+    }
+
+    /// The same raster, flipped horizontally when `mirror` is set.
+    pub(crate) const fn mirrored(self, mirror: bool) -> Self {
+        Self { mirror, ..self }
     }
 }
 
@@ -105,13 +119,17 @@ mod tests {
     ///
     /// Each is a sum of the one below it: an id is a slot plus a
     /// generation, a ref is an id plus an icon index, and a key is a ref
-    /// plus a `u16` box. Nothing here is padded, which is the property
-    /// worth keeping — a key is hashed per icon draw.
+    /// plus a `u16` box plus the mirror flag. Only the flag's byte pads,
+    /// which is the property worth keeping — a key is hashed per icon draw.
     #[test]
     fn the_key_chain_is_as_wide_as_its_docs_claim() {
         assert_eq!(size_of::<IconSetId>(), 4, "two u16s");
         assert_eq!(size_of::<IconRef>(), 6, "an id plus an icon index");
-        assert_eq!(size_of::<IconRasterKey>(), 10, "a ref plus a U16Vec2");
+        assert_eq!(
+            size_of::<IconRasterKey>(),
+            12,
+            "a ref plus a U16Vec2 plus a flag, aligned to 2"
+        );
         assert_eq!(
             size_of::<IconHandle>(),
             16,
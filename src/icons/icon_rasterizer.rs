@@ -147,8 +147,14 @@ impl IconRasterizer {
         // the space resvg actually renders in. Non-uniform when the caller
         // asked for a box off the icon's aspect ratio.
         let size = tree.size();
-        let transform =
-            tiny_skia::Transform::from_scale(w as f32 / size.width(), h as f32 / size.height());
+        let (sx, sy) = (w as f32 / size.width(), h as f32 / size.height());
+        // A mirrored raster is the same scale reflected about the box's
+        // vertical centre line: negate x, then shift it back into `0..w`.
+        let transform = if key.mirror {
+            tiny_skia::Transform::from_row(-sx, 0.0, 0.0, sy, w as f32, 0.0)
+        } else {
+            tiny_skia::Transform::from_scale(sx, sy)
+        };
         resvg::render(tree, transform, &mut pixmap);
 
         out.clear();
@@ -254,6 +260,7 @@ mod tests {
                 icon,
             },
             size: U16Vec2::new(w, h),
+            mirror: false,
         }
     }
 
@@ -346,6 +353,21 @@ mod tests {
         );
     }
 
+    /// A mirrored key is the same artwork reflected across the box: the red
+    /// half moves to the right, and it is a raster of its own rather than
+    /// the unmirrored one reused.
+    #[test]
+    fn mirrored_key_flips_the_artwork() {
+        let (mut r, atlas) = (IconRasterizer::default(), fixtures());
+        let mut out = Vec::new();
+        r.rasterize(&atlas, key(HALF_ID, 8, 2).mirrored(true), &mut out);
+        assert_eq!(out.len(), 8 * 2 * 4);
+        assert_eq!(out[0], 0, "no red on the left once mirrored");
+        assert_eq!(&out[16..20], &[255, 0, 0, 255], "right half is red");
+        assert_eq!(&out[28..32], &[255, 0, 0, 255]);
+        assert_eq!(r.parsed_count(), 1, "the flip reuses the parse");
+    }
+
     /// `leak_from_svgs` drops a source that will not parse, so reaching this
     /// path takes a hand-built set — which a baked one effectively is. The
     /// rasterizer still has to fail *once* rather than once per frame.
@@ -0,0 +1,290 @@
+//! Multi-step key bindings. A [`KeyBinding`] is one or more
+//! [`Shortcut`] steps pressed in sequence — `Ctrl+S` is one step,
+//! `Ctrl+K Ctrl+S` is two. It carries two text forms:
+//!
+//! - `Display` is the platform-native label (`"⌘K ⌘S"` / `"Ctrl+K Ctrl+S"`),
+//!   one [`Shortcut`] label per step. This is what menus and tooltips show.
+//! - The **portable** form is what a keymap file stores and
+//!   [`str::parse`] reads: steps separated by whitespace, each step
+//!   `Mod+Mod+Key`. `Ctrl` (alias `Cmd`) names the primary command
+//!   modifier, so one file binds ⌘ on macOS and Ctrl elsewhere, exactly
+//!   as [`Mods::ctrl`] does.
+
+use crate::input::keyboard::Key;
+use crate::input::shortcut::{Mods, Shortcut};
+use std::fmt;
+use std::str::FromStr;
+
+/// A sequence of one to [`KeyBinding::MAX_STEPS`] shortcuts. `Copy` and
+/// fixed-size so commands can declare their default binding in `const`
+/// items alongside the [`Shortcut`]s they are built from.
+#[derive(Copy, Clone, Debug)]
+pub struct KeyBinding {
+    steps: [Shortcut; Self::MAX_STEPS],
+    len: u8,
+}
+
+/// Placeholder for the unused tail of [`KeyBinding::steps`]. Never
+/// compared or shown: everything reads `steps[..len]`.
+const UNUSED: Shortcut = Shortcut::key(Key::Other);
+
+impl KeyBinding {
+    /// Longest chord a binding can hold. Editors settle on two; the rest
+    /// is headroom, not an invitation.
+    pub const MAX_STEPS: usize = 4;
+
+    /// A single-step binding — the plain shortcut case.
+    pub const fn single(step: Shortcut) -> Self {
+        Self {
+            steps: [step, UNUSED, UNUSED, UNUSED],
+            len: 1,
+        }
+    }
+
+    /// A two-step chord, like `Ctrl+K Ctrl+S`.
+    pub const fn chord(first: Shortcut, second: Shortcut) -> Self {
+        Self {
+            steps: [first, second, UNUSED, UNUSED],
+            len: 2,
+        }
+    }
+
+    /// Any number of steps. `None` if `steps` is empty or longer than
+    /// [`Self::MAX_STEPS`].
+    pub fn from_steps(steps: &[Shortcut]) -> Option<Self> {
+        if steps.is_empty() || steps.len() > Self::MAX_STEPS {
+            return None;
+        }
+        let mut binding = Self::single(steps[0]);
+        binding.steps[..steps.len()].copy_from_slice(steps);
+        binding.len = steps.len() as u8;
+        Some(binding)
+    }
+
+    /// The steps, in the order they are pressed.
+    pub fn steps(&self) -> &[Shortcut] {
+        &self.steps[..self.len as usize]
+    }
+
+    /// True iff `self`'s steps are exactly `other`'s first steps and
+    /// `other` has more of them — pressing `self` to completion fires it
+    /// before `other` can ever be reached.
+    pub fn is_strict_prefix_of(&self, other: &KeyBinding) -> bool {
+        self.len < other.len && steps_eq(self.steps(), &other.steps()[..self.len as usize])
+    }
+
+    /// The portable text form — see the module docs. Round-trips
+    /// through [`str::parse`].
+    pub fn portable(&self) -> impl fmt::Display + '_ {
+        Portable(self)
+    }
+}
+
+/// Step equality as matching sees it: exact modifiers, `Char` keys
+/// ignore-case — the same rule [`Shortcut::matches`] applies, so two
+/// bindings that compare equal here fire on the same presses.
+fn step_eq(a: Shortcut, b: Shortcut) -> bool {
+    a.mods == b.mods
+        && match (a.key, b.key) {
+            (Key::Char(x), Key::Char(y)) => x.eq_ignore_ascii_case(&y),
+            (x, y) => x == y,
+        }
+}
+
+pub(crate) fn steps_eq(a: &[Shortcut], b: &[Shortcut]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| step_eq(*x, *y))
+}
+
+/// Equal when they fire on the same presses: exact modifiers, `Char`
+/// keys ignore-case. The unused tail never takes part.
+impl PartialEq for KeyBinding {
+    fn eq(&self, other: &Self) -> bool {
+        steps_eq(self.steps(), other.steps())
+    }
+}
+
+impl Eq for KeyBinding {}
+
+impl From<Shortcut> for KeyBinding {
+    fn from(step: Shortcut) -> Self {
+        Self::single(step)
+    }
+}
+
+/// Platform-native label: each step's [`Shortcut`] label, space-separated.
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.steps().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+struct Portable<'a>(&'a KeyBinding);
+
+impl fmt::Display for Portable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.0.steps().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            if step.mods.ctrl {
+                f.write_str("Ctrl+")?;
+            }
+            if step.mods.shift {
+                f.write_str("Shift+")?;
+            }
+            if step.mods.alt {
+                f.write_str("Alt+")?;
+            }
+            match NAMED_KEYS.iter().find(|(_, key)| *key == step.key) {
+                Some((name, _)) => f.write_str(name)?,
+                None => match step.key {
+                    Key::Char(c) => write!(f, "{}", c.to_ascii_uppercase())?,
+                    // `Other` is not a bindable key and the parser has no
+                    // name for it, so a binding built around one in code
+                    // fails its round trip instead of rebinding a real key.
+                    _ => f.write_str("Other")?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Portable key names, first spelling wins on output. Letters, digits
+/// and punctuation are written as themselves; only the keys that would
+/// collide with the syntax (`Space`, `Plus`) or have no character get a
+/// name.
+const NAMED_KEYS: &[(&str, Key)] = &[
+    ("Space", Key::Char(' ')),
+    ("Plus", Key::Char('+')),
+    ("Left", Key::ArrowLeft),
+    ("Right", Key::ArrowRight),
+    ("Up", Key::ArrowUp),
+    ("Down", Key::ArrowDown),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("Enter", Key::Enter),
+    ("Tab", Key::Tab),
+    ("Escape", Key::Escape),
+    ("Esc", Key::Escape),
+    ("F1", Key::F1),
+    ("F2", Key::F2),
+    ("F3", Key::F3),
+    ("F4", Key::F4),
+    ("F5", Key::F5),
+    ("F6", Key::F6),
+    ("F7", Key::F7),
+    ("F8", Key::F8),
+    ("F9", Key::F9),
+    ("F10", Key::F10),
+    ("F11", Key::F11),
+    ("F12", Key::F12),
+];
+
+/// Why a portable binding string failed to parse. Carries the offending
+/// step so a keymap loader can point at it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindingParseError {
+    step: String,
+    reason: &'static str,
+}
+
+impl fmt::Display for KeyBindingParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid key binding step `{}`: {}",
+            self.step, self.reason
+        )
+    }
+}
+
+impl std::error::Error for KeyBindingParseError {}
+
+impl FromStr for KeyBinding {
+    type Err = KeyBindingParseError;
+
+    /// Parse the portable form. Modifier and key names are
+    /// case-insensitive; `Cmd` is accepted as a synonym for `Ctrl` and
+    /// `Option` for `Alt`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut steps = [UNUSED; Self::MAX_STEPS];
+        let mut len = 0;
+        for token in s.split_whitespace() {
+            let error = |reason| KeyBindingParseError {
+                step: token.to_owned(),
+                reason,
+            };
+            if len == Self::MAX_STEPS {
+                return Err(error("more steps than a binding can hold"));
+            }
+            steps[len] = parse_step(token).map_err(error)?;
+            len += 1;
+        }
+        KeyBinding::from_steps(&steps[..len]).ok_or(KeyBindingParseError {
+            step: String::new(),
+            reason: "a binding needs at least one step",
+        })
+    }
+}
+
+fn parse_step(token: &str) -> Result<Shortcut, &'static str> {
+    // `rsplit_once` keeps a bare `+` key working (`Ctrl++` is not valid
+    // portable syntax, but `+` alone is an unambiguous key).
+    let (mods_part, key_part) = match token.rsplit_once('+') {
+        Some((mods, key)) if !key.is_empty() => (Some(mods), key),
+        _ => (None, token),
+    };
+    let mut mods = Mods::NONE;
+    if let Some(mods_part) = mods_part {
+        for name in mods_part.split('+') {
+            let flag = match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" => &mut mods.ctrl,
+                "shift" => &mut mods.shift,
+                "alt" | "option" => &mut mods.alt,
+                _ => return Err("unknown modifier"),
+            };
+            if std::mem::replace(flag, true) {
+                return Err("modifier named twice");
+            }
+        }
+    }
+    let key = NAMED_KEYS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(key_part))
+        .map(|(_, key)| *key);
+    let key = match key {
+        Some(key) => key,
+        None => {
+            let mut chars = key_part.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Key::Char(c.to_ascii_uppercase()),
+                _ => return Err("unknown key"),
+            }
+        }
+    };
+    Ok(Shortcut::new(mods, key))
+}
+
+impl ::serde::Serialize for KeyBinding {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.portable())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyBinding {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        text.parse().map_err(::serde::de::Error::custom)
+    }
+}
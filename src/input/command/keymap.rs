@@ -0,0 +1,83 @@
+//! Serialized user rebinding: the overrides a [`CommandRegistry`]
+//! applies on top of its commands' defaults.
+//!
+//! [`CommandRegistry`]: crate::input::command::CommandRegistry
+
+use crate::input::command::binding::KeyBinding;
+use std::collections::BTreeMap;
+
+/// Command id → binding overrides, as a user edits and an app persists
+/// them. Serializes as a flat map in the portable binding syntax, with
+/// the empty string meaning "explicitly unbound":
+///
+/// ```toml
+/// "file.save" = "Ctrl+Alt+S"
+/// "view.zen" = "Ctrl+K Z"
+/// "file.print" = ""
+/// ```
+///
+/// Holds only overrides — a command it doesn't name keeps its default —
+/// so a keymap written against one version of an app still applies
+/// after the app adds commands. Ordered by id so the file diffs cleanly.
+#[derive(Clone, Debug, Default, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+#[serde(transparent)]
+pub struct Keymap {
+    overrides: BTreeMap<String, Override>,
+}
+
+/// One override. Its own type only to spell `None` as `""` — TOML has
+/// no null.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Override(Option<KeyBinding>);
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override `id`'s binding; `None` unbinds it.
+    pub fn set(&mut self, id: impl Into<String>, binding: Option<KeyBinding>) {
+        self.overrides.insert(id.into(), Override(binding));
+    }
+
+    /// Drop `id`'s override, so it falls back to its default again.
+    pub fn remove(&mut self, id: &str) {
+        self.overrides.remove(id);
+    }
+
+    /// `id`'s override: `None` when the keymap doesn't mention it,
+    /// `Some(None)` when it unbinds it.
+    pub fn get(&self, id: &str) -> Option<Option<KeyBinding>> {
+        self.overrides.get(id).map(|slot| slot.0)
+    }
+
+    /// Every overridden id, in order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.overrides.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+}
+
+impl ::serde::Serialize for Override {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(binding) => binding.serialize(serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Override {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        if text.trim().is_empty() {
+            return Ok(Self(None));
+        }
+        text.parse()
+            .map(|binding| Self(Some(binding)))
+            .map_err(::serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,504 @@
+//! Application commands and the chords that fire them.
+//!
+//! A [`Command`] is declared once — id, label, default [`KeyBinding`],
+//! enabled predicate — and registered with a [`CommandRegistry`] the app
+//! owns. Each frame, [`CommandRegistry::update`] reads the key presses
+//! granted to the recording position and returns the ids of the commands
+//! they fired, so dispatch lives in one `match` instead of a
+//! `ui.key_pressed(...)` at every call site that happens to record.
+//!
+//! ## Chords
+//!
+//! A binding may take several steps (`Ctrl+K Ctrl+S`). A press that
+//! completes no binding but starts one parks the registry in a *pending*
+//! state: [`CommandRegistry::pending_chord`] reports the steps so far for
+//! a status-bar indicator, and the chord lapses after
+//! [`CommandRegistry::chord_timeout`] or on any press that continues no
+//! binding. A press that completes one binding and begins another fires
+//! the complete one — which is exactly the [`ConflictKind::Prefix`]
+//! conflict [`CommandRegistry::conflicts`] reports.
+//!
+//! ## Rebinding
+//!
+//! User rebinding goes through [`CommandRegistry::rebind`], which refuses a
+//! binding that would conflict, or through a serialized [`Keymap`] of
+//! overrides ([`CommandRegistry::keymap`] / [`CommandRegistry::apply_keymap`]),
+//! which applies wholesale and reports what conflicts.
+
+pub(crate) mod binding;
+pub(crate) mod keymap;
+
+use crate::input::command::binding::{KeyBinding, steps_eq};
+use crate::input::command::keymap::Keymap;
+use crate::input::keyboard::{Key, KeyPress};
+use crate::input::watch::KeyboardWake;
+use crate::ui::Ui;
+use std::fmt;
+use std::time::Duration;
+
+/// One user-facing action. `C` is whatever context the enabled
+/// predicate reads — the app's state, usually — and is `()` for a
+/// registry whose commands are always available.
+///
+/// Built with `const` helpers so a command table can be a `const` array:
+///
+/// ```
+/// # use palantir::{Command, KeyBinding, Shortcut};
+/// struct Doc { dirty: bool }
+/// const SAVE: Command<Doc> = Command::new("file.save", "Save")
+///     .binding(KeyBinding::single(Shortcut::ctrl('S')))
+///     .enabled(|doc| doc.dirty);
+/// const SAVE_ALL: Command<Doc> = Command::new("file.save_all", "Save All")
+///     .binding(KeyBinding::chord(Shortcut::ctrl('K'), Shortcut::key(palantir::Key::Char('S'))));
+/// ```
+pub struct Command<C> {
+    /// Stable identifier. Keymaps store it, so renaming one orphans
+    /// every user override that names it.
+    pub id: &'static str,
+    /// Human-readable name, shown by menus and tooltips.
+    pub label: &'static str,
+    /// Binding before any user override. `None` leaves the command
+    /// reachable only from menus and code.
+    pub default_binding: Option<KeyBinding>,
+    /// Whether the command can run against the current context. A
+    /// disabled command's binding fires nothing and starts no chord.
+    pub enabled: fn(&C) -> bool,
+}
+
+impl<C> Command<C> {
+    /// An unbound, always-enabled command.
+    pub const fn new(id: &'static str, label: &'static str) -> Self {
+        Self {
+            id,
+            label,
+            default_binding: None,
+            enabled: |_| true,
+        }
+    }
+
+    /// Default binding. Users override it through the registry.
+    pub const fn binding(mut self, binding: KeyBinding) -> Self {
+        self.default_binding = Some(binding);
+        self
+    }
+
+    /// Enabled predicate, evaluated once per [`CommandRegistry::update`].
+    pub const fn enabled(mut self, enabled: fn(&C) -> bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+// Hand-written: a derive would demand `C: Clone` / `C: Debug` for a type
+// that only ever holds a `fn(&C)`.
+impl<C> Clone for Command<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Command<C> {}
+
+impl<C> fmt::Debug for Command<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("default_binding", &self.default_binding)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How two bindings collide.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// Both commands are bound to the same keys; only the first
+    /// registered ever fires.
+    Duplicate,
+    /// [`BindingConflict::command`]'s binding is the opening steps of
+    /// [`BindingConflict::other`]'s, so it fires before the longer chord
+    /// can complete and `other` is unreachable from the keyboard.
+    Prefix,
+}
+
+/// Two commands whose bindings collide. Reported by
+/// [`CommandRegistry::conflicts`] and refused by
+/// [`CommandRegistry::rebind`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BindingConflict {
+    pub kind: ConflictKind,
+    /// The command that wins: registered first for a
+    /// [`ConflictKind::Duplicate`], the shorter binding for a
+    /// [`ConflictKind::Prefix`].
+    pub command: &'static str,
+    /// The command that loses.
+    pub other: &'static str,
+    /// `command`'s binding.
+    pub binding: KeyBinding,
+}
+
+impl fmt::Display for BindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let binding = self.binding.portable();
+        match self.kind {
+            ConflictKind::Duplicate => write!(
+                f,
+                "`{}` and `{}` are both bound to {binding}",
+                self.command, self.other,
+            ),
+            ConflictKind::Prefix => write!(
+                f,
+                "`{}` ({binding}) fires before `{}`'s chord can complete",
+                self.command, self.other,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BindingConflict {}
+
+/// A chord in progress: the steps pressed so far and when the first
+/// landed.
+#[derive(Copy, Clone, Debug)]
+struct Pending {
+    prefix: KeyBinding,
+    started_at: Duration,
+}
+
+#[derive(Debug)]
+struct Entry<C> {
+    command: Command<C>,
+    /// Effective binding — the default until a rebind or keymap says
+    /// otherwise.
+    binding: Option<KeyBinding>,
+    /// `command.enabled` as of the last update; read by menus between
+    /// updates so they don't re-run the predicate per row.
+    enabled: bool,
+}
+
+/// The app's command table plus the chord state machine that drives it.
+/// Owned by the app, not the [`Ui`]: commands are app vocabulary, and the
+/// enabled predicates read app state the `Ui` never sees.
+///
+/// ```
+/// # use palantir::{Command, CommandRegistry, KeyBinding, Shortcut, Ui};
+/// # struct Doc { dirty: bool }
+/// # fn save(_: &mut Doc) {}
+/// # fn demo(ui: &mut Ui, registry: &mut CommandRegistry<Doc>, doc: &mut Doc) {
+/// for id in registry.update(ui, doc) {
+///     match *id {
+///         "file.save" => save(doc),
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CommandRegistry<C> {
+    entries: Vec<Entry<C>>,
+    chord_timeout: Duration,
+    pending: Option<Pending>,
+    /// Ids fired by the last update; the slice it returns.
+    fired: Vec<&'static str>,
+}
+
+impl<C> Default for CommandRegistry<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> CommandRegistry<C> {
+    /// How long a half-typed chord waits for its next step by default.
+    pub const DEFAULT_CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            chord_timeout: Self::DEFAULT_CHORD_TIMEOUT,
+            pending: None,
+            fired: Vec::new(),
+        }
+    }
+
+    /// Override [`Self::DEFAULT_CHORD_TIMEOUT`].
+    pub fn with_chord_timeout(mut self, timeout: Duration) -> Self {
+        self.chord_timeout = timeout;
+        self
+    }
+
+    pub fn chord_timeout(&self) -> Duration {
+        self.chord_timeout
+    }
+
+    /// Add a command. Panics if its id is already registered — two
+    /// commands sharing an id would share every keymap override.
+    /// Registration order breaks [`ConflictKind::Duplicate`] ties.
+    pub fn register(&mut self, command: Command<C>) -> &mut Self {
+        assert!(
+            self.index(command.id).is_none(),
+            "command `{}` registered twice",
+            command.id,
+        );
+        self.entries.push(Entry {
+            command,
+            binding: command.default_binding,
+            enabled: true,
+        });
+        self
+    }
+
+    /// Every registered command, in registration order.
+    pub fn commands(&self) -> impl Iterator<Item = &Command<C>> {
+        self.entries.iter().map(|entry| &entry.command)
+    }
+
+    pub fn command(&self, id: &str) -> Option<&Command<C>> {
+        self.index(id).map(|i| &self.entries[i].command)
+    }
+
+    /// The effective binding: the user's override if any, else the
+    /// default. `None` for an unbound or unknown command.
+    pub fn binding(&self, id: &str) -> Option<KeyBinding> {
+        self.index(id).and_then(|i| self.entries[i].binding)
+    }
+
+    /// The enabled predicate's answer as of the last [`Self::update`].
+    /// `true` before the first one; `false` for an unknown id.
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.index(id).is_some_and(|i| self.entries[i].enabled)
+    }
+
+    /// Bind `id` to `binding` (`None` unbinds it), refusing a binding that
+    /// would collide with another command's. Panics on an unknown id.
+    pub fn rebind(&mut self, id: &str, binding: Option<KeyBinding>) -> Result<(), BindingConflict> {
+        let i = self.expect_index(id);
+        if let Some(binding) = binding {
+            let this = self.entries[i].command.id;
+            for (j, entry) in self.entries.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                let Some(theirs) = entry.binding else {
+                    continue;
+                };
+                // Order the pair as `conflicts` would report it.
+                let (first, second) = if j < i {
+                    ((entry.command.id, theirs), (this, binding))
+                } else {
+                    ((this, binding), (entry.command.id, theirs))
+                };
+                if let Some(conflict) = conflict_between(first, second) {
+                    return Err(conflict);
+                }
+            }
+        }
+        self.entries[i].binding = binding;
+        self.cancel_chord();
+        Ok(())
+    }
+
+    /// Drop `id`'s override, restoring its default binding. Panics on an
+    /// unknown id.
+    pub fn reset(&mut self, id: &str) {
+        let i = self.expect_index(id);
+        self.entries[i].binding = self.entries[i].command.default_binding;
+        self.cancel_chord();
+    }
+
+    /// Every colliding pair among the effective bindings, in
+    /// registration order. Empty for a consistent table — worth asserting
+    /// on the defaults in a test, and worth surfacing after loading a
+    /// user keymap.
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut out = Vec::new();
+        for (i, a) in self.entries.iter().enumerate() {
+            let Some(ab) = a.binding else { continue };
+            for b in &self.entries[i + 1..] {
+                let Some(bb) = b.binding else { continue };
+                out.extend(conflict_between((a.command.id, ab), (b.command.id, bb)));
+            }
+        }
+        out
+    }
+
+    /// The user's overrides: every command whose effective binding
+    /// differs from its default. Serialize it to persist rebinding.
+    pub fn keymap(&self) -> Keymap {
+        let mut keymap = Keymap::new();
+        for entry in &self.entries {
+            if entry.binding != entry.command.default_binding {
+                keymap.set(entry.command.id, entry.binding);
+            }
+        }
+        keymap
+    }
+
+    /// Reset every command to its default, then apply `keymap`'s
+    /// overrides. Unlike [`Self::rebind`] nothing is refused — a loaded
+    /// file is applied as written — so the conflicts it leaves are
+    /// returned for the app to report. Ids the registry doesn't know
+    /// (a command since renamed or removed) are skipped.
+    pub fn apply_keymap(&mut self, keymap: &Keymap) -> Vec<BindingConflict> {
+        for entry in &mut self.entries {
+            entry.binding = match keymap.get(entry.command.id) {
+                Some(binding) => binding,
+                None => entry.command.default_binding,
+            };
+        }
+        for id in keymap.ids() {
+            if self.index(id).is_none() {
+                tracing::warn!(id, "keymap names an unregistered command, ignoring");
+            }
+        }
+        self.cancel_chord();
+        self.conflicts()
+    }
+
+    /// The steps of the chord in progress, for a "waiting for next key"
+    /// indicator. `None` when no chord is pending.
+    pub fn pending_chord(&self) -> Option<KeyBinding> {
+        self.pending.map(|pending| pending.prefix)
+    }
+
+    /// Abandon a pending chord.
+    pub fn cancel_chord(&mut self) {
+        self.pending = None;
+    }
+
+    /// Run one frame: evaluate every enabled predicate against `ctx`,
+    /// feed this frame's key presses through the chord matcher, and
+    /// return the ids of the commands they fired, in press order.
+    ///
+    /// Reads the presses granted to the recording position, exactly as
+    /// [`Ui::key_pressed`] would — so call it at the point in the tree
+    /// whose scope should own the shortcuts (usually the root, before
+    /// anything records). Call it every frame: it also watches the keys
+    /// that can start a binding — a press nobody watches is never
+    /// delivered — and, mid-chord, every key plus the wake that lapses
+    /// the chord.
+    pub fn update(&mut self, ui: &mut Ui, ctx: &C) -> &[&'static str] {
+        self.fired.clear();
+        for entry in &mut self.entries {
+            entry.enabled = (entry.command.enabled)(ctx);
+        }
+        let now = ui.now();
+        if self
+            .pending
+            .is_some_and(|pending| now.saturating_sub(pending.started_at) >= self.chord_timeout)
+        {
+            self.pending = None;
+        }
+        for press in ui.granted_key_presses() {
+            self.advance(press, now);
+        }
+
+        match self.pending {
+            // Mid-chord every press matters — the next step fires, any
+            // other key cancels — so wake on all of them, and once more
+            // at the deadline to drop the indicator.
+            Some(pending) => {
+                ui.watch_keyboard(KeyboardWake::KEY);
+                let deadline = pending.started_at + self.chord_timeout;
+                ui.request_repaint_after(deadline.saturating_sub(now));
+            }
+            None => {
+                for entry in self.live_entries(None) {
+                    if let Some(binding) = entry.binding {
+                        ui.watch_key(binding.steps()[0]);
+                    }
+                }
+            }
+        }
+        &self.fired
+    }
+
+    /// Feed one press through the matcher. A binding completed by it
+    /// fires; failing that, a binding it continues leaves it pending;
+    /// failing both, whatever was pending lapses.
+    fn advance(&mut self, press: KeyPress, now: Duration) {
+        // Bare modifier keys arrive as `Other`; pressing Shift between
+        // chord steps must not break the chord.
+        if press.key == Key::Other {
+            return;
+        }
+        let prefix = self.pending.map(|pending| pending.prefix);
+        // A held key auto-repeating mid-chord is still the step it was,
+        // not a second press of it.
+        if prefix.is_some() && press.repeat {
+            return;
+        }
+        let depth = prefix.map_or(0, |prefix| prefix.steps().len());
+        let mut complete = None;
+        let mut longer = None;
+        for entry in self.live_entries(prefix) {
+            let binding = entry.binding.expect("live entries are bound");
+            let steps = binding.steps();
+            if !steps[depth].matches(press) {
+                continue;
+            }
+            if steps.len() == depth + 1 {
+                complete.get_or_insert(entry.command.id);
+            } else {
+                longer.get_or_insert(binding);
+            }
+        }
+        let previous = self.pending.take();
+        if let Some(id) = complete {
+            self.fired.push(id);
+        } else if let Some(binding) = longer {
+            let prefix = KeyBinding::from_steps(&binding.steps()[..=depth])
+                .expect("a strict prefix of a binding is a binding");
+            // The timeout runs from the chord's first step.
+            let started_at = previous.map_or(now, |pending| pending.started_at);
+            self.pending = Some(Pending { prefix, started_at });
+        }
+    }
+
+    /// Enabled, bound entries that `prefix` (if any) opens and that have
+    /// at least one step after it.
+    fn live_entries(&self, prefix: Option<KeyBinding>) -> impl Iterator<Item = &Entry<C>> {
+        self.entries.iter().filter(move |entry| {
+            let prefix = prefix.as_ref().map_or(&[][..], KeyBinding::steps);
+            entry.enabled
+                && entry.binding.is_some_and(|binding| {
+                    let steps = binding.steps();
+                    steps.len() > prefix.len() && steps_eq(&steps[..prefix.len()], prefix)
+                })
+        })
+    }
+
+    fn index(&self, id: &str) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.command.id == id)
+    }
+
+    fn expect_index(&self, id: &str) -> usize {
+        self.index(id)
+            .unwrap_or_else(|| panic!("no command `{id}` is registered"))
+    }
+}
+
+/// The conflict between two bindings, `a` registered before `b`.
+fn conflict_between(
+    (a, ab): (&'static str, KeyBinding),
+    (b, bb): (&'static str, KeyBinding),
+) -> Option<BindingConflict> {
+    let (kind, command, other, binding) = if ab == bb {
+        (ConflictKind::Duplicate, a, b, ab)
+    } else if ab.is_strict_prefix_of(&bb) {
+        (ConflictKind::Prefix, a, b, ab)
+    } else if bb.is_strict_prefix_of(&ab) {
+        (ConflictKind::Prefix, b, a, bb)
+    } else {
+        return None;
+    };
+    Some(BindingConflict {
+        kind,
+        command,
+        other,
+        binding,
+    })
+}
+
+#[cfg(test)]
+mod tests;
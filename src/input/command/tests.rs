@@ -0,0 +1,237 @@
+use crate::input::command::binding::KeyBinding;
+use crate::input::command::keymap::Keymap;
+use crate::input::command::{BindingConflict, Command, CommandRegistry, ConflictKind};
+use crate::input::keyboard::{Key, Modifiers};
+use crate::input::shortcut::{Mods, Shortcut};
+use crate::ui::harness::UiHarness;
+use glam::UVec2;
+use std::time::Duration;
+
+const SURFACE: UVec2 = UVec2::new(200, 200);
+
+struct Doc {
+    dirty: bool,
+}
+
+const SAVE: Command<Doc> = Command::new("file.save", "Save")
+    .binding(KeyBinding::single(Shortcut::ctrl('S')))
+    .enabled(|doc| doc.dirty);
+const SAVE_ALL: Command<Doc> = Command::new("file.save_all", "Save All").binding(
+    KeyBinding::chord(Shortcut::ctrl('K'), Shortcut::key(Key::Char('S'))),
+);
+const ZEN: Command<Doc> = Command::new("view.zen", "Zen Mode").binding(KeyBinding::chord(
+    Shortcut::ctrl('K'),
+    Shortcut::key(Key::Char('Z')),
+));
+
+fn registry() -> CommandRegistry<Doc> {
+    let mut registry = CommandRegistry::new();
+    registry.register(SAVE).register(SAVE_ALL).register(ZEN);
+    registry
+}
+
+fn press(h: &mut UiHarness, ctrl: bool, key: Key) {
+    h.set_modifiers(Modifiers {
+        ctrl,
+        ..Modifiers::NONE
+    });
+    h.key(key);
+}
+
+/// Runs one frame and returns what the registry fired in it.
+fn fired(h: &mut UiHarness, registry: &mut CommandRegistry<Doc>, doc: &Doc) -> Vec<&'static str> {
+    h.frame_value(|ui| registry.update(ui, doc).to_vec())
+}
+
+#[test]
+fn portable_form_round_trips_and_parses_aliases() {
+    let cases = [
+        ("Ctrl+S", KeyBinding::single(Shortcut::ctrl('S'))),
+        (
+            "Ctrl+K Ctrl+Shift+S",
+            KeyBinding::chord(Shortcut::ctrl('K'), Shortcut::ctrl_shift('S')),
+        ),
+        (
+            "Alt+Space",
+            KeyBinding::single(Shortcut::new(
+                Mods {
+                    alt: true,
+                    ..Mods::NONE
+                },
+                Key::Char(' '),
+            )),
+        ),
+        ("F5", KeyBinding::single(Shortcut::key(Key::F5))),
+        ("+", KeyBinding::single(Shortcut::key(Key::Char('+')))),
+    ];
+    for (text, binding) in cases {
+        assert_eq!(text.parse::<KeyBinding>(), Ok(binding), "{text}");
+        let written = binding.portable().to_string();
+        assert_eq!(written.parse::<KeyBinding>(), Ok(binding), "{written}");
+    }
+    assert_eq!(
+        "cmd+option+esc".parse::<KeyBinding>(),
+        "Ctrl+Alt+Escape".parse::<KeyBinding>(),
+    );
+    for bad in [
+        "",
+        "Ctrl+",
+        "Hyper+S",
+        "Ctrl+Ctrl+S",
+        "Ctrl+Nope",
+        "A B C D E",
+    ] {
+        assert!(
+            bad.parse::<KeyBinding>().is_err(),
+            "{bad:?} should not parse"
+        );
+    }
+}
+
+#[test]
+fn chord_fires_on_second_step_and_single_step_fires_at_once() {
+    let mut h = UiHarness::new(SURFACE);
+    let mut registry = registry();
+    let doc = Doc { dirty: true };
+    // The first update watches every binding's opening step; presses
+    // nobody watches are not delivered.
+    assert!(fired(&mut h, &mut registry, &doc).is_empty());
+
+    press(&mut h, true, Key::Char('s'));
+    assert_eq!(fired(&mut h, &mut registry, &doc), ["file.save"]);
+
+    press(&mut h, true, Key::Char('k'));
+    assert!(fired(&mut h, &mut registry, &doc).is_empty());
+    assert_eq!(
+        registry.pending_chord(),
+        Some(KeyBinding::single(Shortcut::ctrl('K'))),
+        "first step parks the chord",
+    );
+    // A bare modifier press between the steps keeps it parked.
+    press(&mut h, false, Key::Other);
+    press(&mut h, false, Key::Char('z'));
+    assert_eq!(fired(&mut h, &mut registry, &doc), ["view.zen"]);
+    assert_eq!(registry.pending_chord(), None);
+}
+
+#[test]
+fn pending_chord_lapses_on_timeout_or_a_dead_end_press() {
+    let mut h = UiHarness::new(SURFACE);
+    let mut registry = registry().with_chord_timeout(Duration::from_millis(500));
+    let doc = Doc { dirty: true };
+    fired(&mut h, &mut registry, &doc);
+
+    press(&mut h, true, Key::Char('k'));
+    fired(&mut h, &mut registry, &doc);
+    assert!(registry.pending_chord().is_some());
+    h.advance(Duration::from_millis(600));
+    press(&mut h, false, Key::Char('s'));
+    assert!(
+        fired(&mut h, &mut registry, &doc).is_empty(),
+        "a second step after the timeout is a fresh press",
+    );
+    assert_eq!(registry.pending_chord(), None);
+
+    press(&mut h, true, Key::Char('k'));
+    fired(&mut h, &mut registry, &doc);
+    press(&mut h, false, Key::Char('q'));
+    assert!(fired(&mut h, &mut registry, &doc).is_empty());
+    assert_eq!(
+        registry.pending_chord(),
+        None,
+        "no binding continues with Q"
+    );
+}
+
+#[test]
+fn disabled_command_does_not_fire() {
+    let mut h = UiHarness::new(SURFACE);
+    let mut registry = registry();
+    let doc = Doc { dirty: false };
+    fired(&mut h, &mut registry, &doc);
+    press(&mut h, true, Key::Char('s'));
+    assert!(fired(&mut h, &mut registry, &doc).is_empty());
+    assert!(!registry.is_enabled("file.save"));
+    assert!(registry.is_enabled("file.save_all"));
+}
+
+#[test]
+fn conflicts_report_duplicates_and_shadowing_prefixes() {
+    let mut registry = registry();
+    assert!(registry.conflicts().is_empty(), "defaults are consistent");
+
+    registry.register(Command::new("edit.kill", "Kill").binding(Shortcut::ctrl('K').into()));
+    registry.register(Command::new("file.store", "Store").binding(Shortcut::ctrl('S').into()));
+    let kill = KeyBinding::single(Shortcut::ctrl('K'));
+    assert_eq!(
+        registry.conflicts(),
+        [
+            BindingConflict {
+                kind: ConflictKind::Duplicate,
+                command: "file.save",
+                other: "file.store",
+                binding: KeyBinding::single(Shortcut::ctrl('S')),
+            },
+            BindingConflict {
+                kind: ConflictKind::Prefix,
+                command: "edit.kill",
+                other: "file.save_all",
+                binding: kill,
+            },
+            BindingConflict {
+                kind: ConflictKind::Prefix,
+                command: "edit.kill",
+                other: "view.zen",
+                binding: kill,
+            },
+        ],
+    );
+}
+
+#[test]
+fn rebind_refuses_conflicts_and_keymap_persists_overrides() {
+    let mut registry = registry();
+    let err = registry
+        .rebind("file.save", Some(Shortcut::ctrl('K').into()))
+        .unwrap_err();
+    assert_eq!(err.kind, ConflictKind::Prefix);
+    assert_eq!(
+        registry.binding("file.save"),
+        Some(Shortcut::ctrl('S').into()),
+        "a refused rebind changes nothing",
+    );
+
+    let zen = "Ctrl+Alt+Z".parse::<KeyBinding>().unwrap();
+    registry.rebind("view.zen", Some(zen)).unwrap();
+    registry.rebind("file.save_all", None).unwrap();
+    let keymap = registry.keymap();
+    let text = toml::to_string(&keymap).unwrap();
+    assert_eq!(
+        text,
+        "\"file.save_all\" = \"\"\n\"view.zen\" = \"Ctrl+Alt+Z\"\n"
+    );
+
+    let mut fresh = self::registry();
+    let loaded: Keymap = toml::from_str(&text).unwrap();
+    assert!(fresh.apply_keymap(&loaded).is_empty());
+    assert_eq!(fresh.binding("view.zen"), Some(zen));
+    assert_eq!(fresh.binding("file.save_all"), None);
+    assert_eq!(fresh.keymap(), keymap);
+
+    fresh.reset("view.zen");
+    assert_eq!(fresh.binding("view.zen"), ZEN.default_binding);
+}
+
+#[test]
+fn keymap_application_reports_the_conflicts_it_leaves() {
+    let mut registry = registry();
+    let keymap: Keymap = toml::from_str("\"view.zen\" = \"Ctrl+S\"\n\"gone\" = \"F1\"").unwrap();
+    let conflicts = registry.apply_keymap(&keymap);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].kind, ConflictKind::Duplicate);
+    assert_eq!(
+        (conflicts[0].command, conflicts[0].other),
+        ("file.save", "view.zen")
+    );
+    assert!(toml::from_str::<Keymap>("\"view.zen\" = \"Ctrl+Bogus\"").is_err());
+}
@@ -58,6 +58,11 @@ pub(crate) struct InputState {
     /// [`PointerButton`] via [`PointerButton::idx`]. Independent per
     /// button — a left-drag in progress doesn't block a right-click.
     captures: [Capture; PointerButton::COUNT],
+    /// Code of the last [`PointerButton::Other`] press. Every code
+    /// shares one capture slot, so this is what lets
+    /// [`Self::pointer_actions`] name the button that slot's edges
+    /// came from rather than a placeholder.
+    other_code: u16,
     /// Frame-snapshot of "no widget can hold any non-default interaction
     /// state this frame" — no pointer on the surface, no routed
     /// scroll/pinch target or pending target delta, no live button
@@ -139,6 +144,7 @@ impl Default for InputState {
             pinch_target: None,
             frame_target_deltas: Vec::new(),
             captures: [Capture::default(); PointerButton::COUNT],
+            other_code: 0,
             // Recomputed each record pass before any `response_for`
             // call; `false` is the safe pre-frame default (forces the
             // full path).
@@ -256,6 +262,40 @@ impl InputState {
         })
     }
 
+    /// Every press this frame that [`Self::key_pressed`] would grant to
+    /// `reader`, in arrival order: the same layer gate and the same scope
+    /// test, with no shortcut to match against. For matchers that have to
+    /// see presses in sequence — a multi-step chord — rather than ask
+    /// about one chord at a time. Subscribes nothing; the caller watches
+    /// whatever it wants to be woken for.
+    pub(crate) fn granted_key_presses(
+        &mut self,
+        reader: Layer,
+        parent: Option<WidgetId>,
+        cascade: &Cascade,
+    ) -> impl Iterator<Item = KeyPress> + '_ {
+        // Same early-out as `key_pressed`: a keyless frame never resolves
+        // the reader's scope.
+        let scope = if self.frame_keyboard_events.is_empty() || self.silenced(reader) {
+            None
+        } else {
+            Some(self.scopes.reader(parent, cascade))
+        };
+        let this = &*self;
+        let events: &[KeyboardEvent] = match scope {
+            Some(_) => &this.frame_keyboard_events,
+            None => &[],
+        };
+        events.iter().filter_map(move |event| match event {
+            KeyboardEvent::Down(press)
+                if Some(this.scopes.grant(KeyClass::of(*press))) == scope =>
+            {
+                Some(*press)
+            }
+            _ => None,
+        })
+    }
+
     /// Withdraw `owner`'s scope from the next resolution — see
     /// [`Scopes::close`] for the span that covers.
     pub(crate) fn close_scope(&mut self, owner: WidgetId) {
@@ -304,6 +344,10 @@ impl InputState {
     pub(crate) fn pointer_actions(&self) -> impl Iterator<Item = PointerAction> + '_ {
         PointerButton::all().flat_map(move |button| {
             let cap = self.capture(button);
+            let button = match button {
+                PointerButton::Other(_) => PointerButton::Other(self.other_code),
+                named => named,
+            };
             // Each edge is built where its target is already in hand, rather
             // than recovered afterwards from which variant it turned out to be:
             // a new variant would have had to be remembered in that lookup or
@@ -463,6 +507,9 @@ impl InputState {
                     self.push_pointer_event(PointerWake::BUTTONS, pointer_pos, |pos| {
                         PointerEvent::Down { pos, button: btn }
                     });
+                if let PointerButton::Other(code) = btn {
+                    self.other_code = code;
+                }
                 // Frame clock for multi-press timing — read before the
                 // `capture_mut` borrow.
                 let now = self.frame_time;
@@ -603,3 +603,54 @@ fn pointer_actions_report_the_edges_the_response_reports() {
         "an edge outlived the frame it happened on"
     );
 }
+
+/// The thumb buttons and the `Other` catch-all get the same per-widget
+/// surface as the three classic buttons: their own slot, their own click
+/// edge, nothing leaking onto `left`. The collation names the actual
+/// `Other` code even though every code shares one slot.
+#[test]
+fn extra_buttons_click_into_their_own_slots() {
+    use crate::input::pointer::PointerButton;
+    use crate::input::response::{PointerAction, PointerEdge};
+
+    let id = WidgetId::from_hash("nav");
+    let mut h = UiHarness::new(UVec2::new(200, 80));
+    let build = |ui: &mut Ui| {
+        Panel::hstack().auto_id().show(ui, |ui| {
+            Button::new()
+                .id(id)
+                .label("nav")
+                .size((Sizing::fixed(100.0), Sizing::fixed(40.0)))
+                .show(ui);
+        });
+    };
+    h.frame(build);
+
+    for button in [
+        PointerButton::Back,
+        PointerButton::Forward,
+        PointerButton::Other(9),
+    ] {
+        h.click_button_at(button, Vec2::new(50.0, 20.0));
+        let mut edges = Vec::new();
+        let mut clicked = false;
+        let mut left = false;
+        h.frame(|ui| {
+            edges.extend(ui.pointer_actions());
+            let state = ui.response_for(id);
+            clicked |= state.button(button).clicked();
+            left |= state.left.clicked();
+            build(ui);
+        });
+        assert!(clicked, "{button:?} click must land in its own slot");
+        assert!(!left, "{button:?} click must not read as a left click");
+        assert_eq!(
+            edges,
+            [PointerAction {
+                id,
+                button,
+                edge: PointerEdge::Clicked { count: 1 },
+            }],
+        );
+    }
+}
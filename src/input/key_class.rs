@@ -21,7 +21,8 @@ pub enum KeyClass {
     /// Printable characters and bare Enter. Only a text field wants these.
     Text,
     /// The clipboard/undo family plus the destructive edit keys:
-    /// Ctrl+Z/X/C/V/A, Delete, Backspace. The contested class — a text
+    /// Ctrl+Z/X/C/V/A, Delete, Backspace — and a field's find chords,
+    /// Ctrl+F/H, and its add-a-caret Ctrl+D. The contested class — a text
     /// field and a canvas both want it, and deciding between them is what
     /// scopes exist for.
     Edit,
@@ -43,10 +44,14 @@ pub enum KeyClass {
 /// modifier.
 ///
 /// Kept in step with `EditAction::shortcut` by
-/// `widgets::text_edit::tests::every_edit_action_chord_is_edit_class`: a
-/// seventh edit action that forgets to extend this list fails that test
+/// `widgets::text_edit::tests::every_edit_action_chord_is_edit_class`: an
+/// edit action that forgets to extend this list fails that test
 /// rather than silently becoming an accelerator the app steals.
-const EDIT_CHORDS: [char; 5] = ['z', 'x', 'c', 'v', 'a'];
+///
+/// `f` and `h` are the find bar's: a focused multi-line field searches
+/// its own buffer, as an editor does, rather than the app's document.
+/// `d` adds a caret at the next occurrence of the selection.
+const EDIT_CHORDS: [char; 8] = ['z', 'x', 'c', 'v', 'a', 'f', 'h', 'd'];
 
 /// Whether `press` is one of [`EDIT_CHORDS`].
 ///
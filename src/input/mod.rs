@@ -5,6 +5,7 @@
 #[cfg(feature = "bench")]
 pub(crate) mod bench;
 pub(crate) mod capture;
+pub(crate) mod command;
 pub(crate) mod event_outcome;
 pub(crate) mod input_event;
 pub(crate) mod input_state;
@@ -12,19 +12,30 @@
 use glam::Vec2;
 use strum::{EnumCount, EnumIter, IntoEnumIterator};
 
-/// Which pointer button an event came from. The discriminants are the
-/// indices of the matching [`ButtonState`](crate::ButtonState) slots on
-/// [`ResponseState`](crate::ResponseState), so the three buttons get an
-/// identical query surface — middle-click is as queryable as left.
+/// Which pointer button an event came from. Each variant owns one
+/// [`ButtonState`](crate::ButtonState) slot on
+/// [`ResponseState`](crate::ResponseState), so every button gets an
+/// identical query surface — a thumb-button click is as queryable as
+/// left.
+///
+/// `Other` covers whatever a many-button mouse reports past the five
+/// named ones. Every code shares the one `other` slot: the per-widget
+/// surface says *that* an extra button was pressed, the raw
+/// [`PointerEvent`] stream says which one.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumCount, EnumIter)]
-#[repr(u8)]
 pub enum PointerButton {
     /// Primary button. Drives clicks, drags, and focus.
-    Left = 0,
+    Left,
     /// Secondary button. `right.clicked()` is the context-menu trigger.
-    Right = 1,
+    Right,
     /// Wheel button.
-    Middle = 2,
+    Middle,
+    /// Thumb button conventionally bound to "navigate back" (X1).
+    Back,
+    /// Thumb button conventionally bound to "navigate forward" (X2).
+    Forward,
+    /// Any further button, by the platform's raw code.
+    Other(u16),
 }
 
 impl PointerButton {
@@ -34,16 +45,26 @@ impl PointerButton {
     ///
     /// Widget code reaches for this when a rule is "any button" rather
     /// than a named one — `Popup`'s outside-click dismissal — so that
-    /// adding a fourth button doesn't leave a hand-written
-    /// `left || right || middle` silently short.
+    /// adding a button doesn't leave a hand-written
+    /// `left || right || middle` silently short. Yields one value per
+    /// slot — `Other(0)` stands in for every `Other` code.
     #[inline]
     pub(crate) fn all() -> impl Iterator<Item = Self> {
         <Self as IntoEnumIterator>::iter()
     }
 
+    /// The capture slot this button latches into — `0..COUNT`, with
+    /// every `Other` code folded onto the last one.
     #[inline]
     pub(super) fn idx(self) -> usize {
-        self as usize
+        match self {
+            PointerButton::Left => 0,
+            PointerButton::Right => 1,
+            PointerButton::Middle => 2,
+            PointerButton::Back => 3,
+            PointerButton::Forward => 4,
+            PointerButton::Other(_) => 5,
+        }
     }
 }
 
@@ -310,6 +310,14 @@ pub struct ResponseState {
     /// Middle / wheel-button state, with the same surface as
     /// [`Self::left`] — press runs and drags included.
     pub middle: ButtonState,
+    /// Back thumb-button state — `back.clicked()` is the "navigate back"
+    /// trigger.
+    pub back: ButtonState,
+    /// Forward thumb-button state, the peer of [`Self::back`].
+    pub forward: ButtonState,
+    /// State shared by every [`PointerButton::Other`] code. Which code
+    /// it was is on the [`PointerEvent`](crate::PointerEvent) stream.
+    pub other: ButtonState,
     /// Wheel / touchpad / pinch deltas routed to this widget.
     pub scroll: ScrollDelta,
 }
@@ -327,6 +335,9 @@ impl Default for ResponseState {
             left: ButtonState::default(),
             right: ButtonState::default(),
             middle: ButtonState::default(),
+            back: ButtonState::default(),
+            forward: ButtonState::default(),
+            other: ButtonState::default(),
             scroll: ScrollDelta::default(),
         }
     }
@@ -369,6 +380,9 @@ impl ResponseState {
             PointerButton::Left => &self.left,
             PointerButton::Right => &self.right,
             PointerButton::Middle => &self.middle,
+            PointerButton::Back => &self.back,
+            PointerButton::Forward => &self.forward,
+            PointerButton::Other(_) => &self.other,
         }
     }
 
@@ -389,6 +403,9 @@ impl ResponseState {
             PointerButton::Left => &mut self.left,
             PointerButton::Right => &mut self.right,
             PointerButton::Middle => &mut self.middle,
+            PointerButton::Back => &mut self.back,
+            PointerButton::Forward => &mut self.forward,
+            PointerButton::Other(_) => &mut self.other,
         }
     }
 
@@ -0,0 +1,88 @@
+//! Aspect-ratio resolution: which axis of a node drives, and the extent
+//! the other derives from it.
+//!
+//! The derived axis behaves as a Fixed axis whose value happens to be
+//! computed — measure, arrange and the intrinsic query each resolve it
+//! from the driving extent they already have, so nothing waits on a
+//! previous frame's rect and there is no second pass to oscillate.
+
+use crate::layout::axis::Axis;
+use crate::layout::types::sizing::Sizes;
+use crate::primitives::rect::Rect;
+use crate::primitives::size::Size;
+use crate::scene::node::bounds_extras::BoundsExtras;
+
+/// A node's resolved aspect-ratio constraint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct AspectRatio {
+    /// Width / height.
+    ratio: f32,
+    driver: Axis,
+}
+
+impl AspectRatio {
+    /// The constraint `bounds` asks for under `size`, or `None` when
+    /// there is no ratio or both axes are Fixed (or percent, which sizes
+    /// like Fixed once measure resolves it). A Fixed axis drives,
+    /// then a Fill axis, then width.
+    #[inline]
+    pub(crate) fn of(size: Sizes, bounds: &BoundsExtras) -> Option<Self> {
+        let ratio = bounds.aspect_ratio()?;
+        let (w, h) = (size.w(), size.h());
+        let driver = match (w.is_rigid(), h.is_rigid()) {
+            (true, true) => return None,
+            (true, false) => Axis::X,
+            (false, true) => Axis::Y,
+            (false, false) if w.fill_weight().is_none() && h.fill_weight().is_some() => Axis::Y,
+            (false, false) => Axis::X,
+        };
+        Some(Self { ratio, driver })
+    }
+
+    #[inline]
+    pub(crate) fn driver(self) -> Axis {
+        self.driver
+    }
+
+    /// The axis resolved from the driver.
+    #[inline]
+    pub(crate) fn dependent(self) -> Axis {
+        match self.driver {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
+    /// The dependent axis's border-box extent for a driving border-box
+    /// extent of `driving`, clamped to the dependent's own bounds.
+    #[inline]
+    pub(crate) fn derive(self, driving: f32, bounds: &BoundsExtras) -> f32 {
+        let raw = match self.driver {
+            Axis::X => driving / self.ratio,
+            Axis::Y => driving * self.ratio,
+        };
+        let axis = self.dependent();
+        raw.clamp(axis.main(bounds.min_size), axis.main(bounds.max_size))
+    }
+
+    /// `size` with its dependent axis re-derived from its driving one.
+    #[inline]
+    pub(crate) fn apply(self, size: Size, bounds: &BoundsExtras) -> Size {
+        let dependent = self.derive(self.driver.main(size), bounds);
+        match self.driver {
+            Axis::X => Size::new(size.w, dependent),
+            Axis::Y => Size::new(dependent, size.h),
+        }
+    }
+
+    /// `rect` resized to the ratio about its origin — arrange's half:
+    /// whatever slot the parent handed over, the driving extent it
+    /// granted decides the other.
+    #[inline]
+    pub(crate) fn apply_rect(self, rect: Rect, bounds: &BoundsExtras) -> Rect {
+        Rect {
+            min: rect.min,
+            size: self.apply(rect.size, bounds),
+        }
+    }
+}
@@ -15,6 +15,15 @@ pub(crate) enum Axis {
 }
 
 impl Axis {
+    /// The perpendicular axis.
+    #[inline]
+    pub(crate) fn other(self) -> Axis {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
     pub(crate) fn main(self, s: Size) -> f32 {
         match self {
             Axis::X => s.w,
@@ -32,6 +32,15 @@ impl AxisAlignPair {
         }
     }
 
+    /// A canvas child's anchor, taken as is: anchors don't cascade from
+    /// the canvas the way alignment does.
+    pub(super) const fn anchor(a: Align) -> Self {
+        Self {
+            h: a.halign().to_axis(),
+            v: a.valign().to_axis(),
+        }
+    }
+
     /// Both axes with `Auto` read as `Stretch` — Grid's default, where a
     /// child that named no alignment fills its cell.
     pub(super) const fn or_stretch_if_auto(self) -> Self {
@@ -1,8 +1,11 @@
 //! The per-axis inputs the measure pass resolves an outer extent from.
 
+use crate::layout::aspect_ratio::AspectRatio;
+use crate::layout::axis::Axis;
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::size::Size;
 use crate::primitives::spacing::Sums;
+use crate::scene::node::bounds_extras::BoundsExtras;
 use crate::scene::node::layout_core::LayoutCore;
 
 /// Per-axis inputs for [`Self::resolve`]. Bundles the seven
@@ -100,15 +103,23 @@ impl AxisCtx {
     /// every driver's content size is monotone in `available` and pass-1
     /// already saturated at the floor. Pinned by
     /// `cross_driver_tests::convergence`.
+    ///
+    /// Aspect ratio: the dependent axis is resolved from the driving one
+    /// on both sides of the dispatch, the way a Fixed axis would be.
+    /// Children see the extent derived from the driver's pre-dispatch
+    /// outer; `desired` takes the one derived from the driver's final
+    /// extent — which for a Fill driver with a finite `available` is that
+    /// same outer, since arrange will stretch it there.
     #[inline]
     pub(super) fn resolve_node(
         layout: LayoutCore,
         available: Size,
         intrinsic_min: Size,
-        min_size: Size,
-        max_size: Size,
+        bounds: &BoundsExtras,
         dispatch: impl FnOnce(Size) -> Size,
     ) -> Size {
+        let (min_size, max_size) = (bounds.min_size, bounds.max_size);
+        let aspect = AspectRatio::of(layout.size, bounds);
         let Sums {
             horiz: p_horiz,
             vert: p_vert,
@@ -141,14 +152,16 @@ impl AxisCtx {
             .fixed_value()
             .unwrap_or_else(|| (dispatch_avail.h - m_vert).max(0.0))
             .clamp(min_size.h, max_size.h);
-        let inner_avail = Size::new((outer_w - p_horiz).max(0.0), (outer_h - p_vert).max(0.0));
+        let outer = Size::new(outer_w, outer_h);
+        let outer = aspect.map_or(outer, |a| a.apply(outer, bounds));
+        let inner_avail = Size::new((outer.w - p_horiz).max(0.0), (outer.h - p_vert).max(0.0));
 
         let content = dispatch(inner_avail);
 
         // Fold content into margin-inclusive desired. Margin is added once
         // at the end inside `AxisCtx::resolve`; this function works in
         // margin-exclusive space (`content_plus_padding = content + p_*`).
-        Size::new(
+        let desired = Size::new(
             AxisCtx {
                 sizing: layout.size.w(),
                 content_plus_padding: content.w + p_horiz,
@@ -169,6 +182,22 @@ impl AxisCtx {
                 max: max_size.h,
             }
             .resolve(),
-        )
+        );
+        let Some(aspect) = aspect else {
+            return desired;
+        };
+        let margin = Size::new(m_horiz, m_vert);
+        let driver = aspect.driver();
+        let fills = driver.main_sizing(layout.size).fill_weight().is_some();
+        let driving = if fills && driver.main(dispatch_avail).is_finite() {
+            driver.main(outer)
+        } else {
+            driver.main(desired) - driver.main(margin)
+        };
+        let dependent = aspect.derive(driving, bounds) + aspect.dependent().main(margin);
+        match driver {
+            Axis::X => Size::new(desired.w, dependent),
+            Axis::Y => Size::new(dependent, desired.h),
+        }
     }
 }
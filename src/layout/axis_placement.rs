@@ -19,7 +19,7 @@ pub(super) struct AxisPlacement {
 
 impl AxisPlacement {
     /// Resolve the outer extent and alignment offset for one arranged axis.
-    /// `Fixed` always keeps its measured extent. `Fill` and explicit `Stretch`
+    /// `Fixed` and percent always keep their measured extent. `Fill` and explicit `Stretch`
     /// grow to their slot without shrinking below measured content, while the
     /// node's outer min/max bounds remain authoritative.
     pub(super) fn arrange(
@@ -36,7 +36,7 @@ impl AxisPlacement {
         let desired = clip_scroll_to_slot(child, axis.main(desired), slot).clamp(min, max);
         let sizing = axis.main_sizing(child.size);
         let stretch = sizing.fill_weight().is_some()
-            || matches!(align, AxisAlign::Stretch) && sizing.fixed_value().is_none();
+            || matches!(align, AxisAlign::Stretch) && !sizing.is_rigid();
         let size = if stretch {
             slot.max(desired).clamp(min, max)
         } else {
@@ -78,11 +78,9 @@ impl AxisPlacement {
     /// alignment — [`Self::arrange_rect`] under [`AxisAlignPair::AUTO`],
     /// keeping only the extents.
     ///
-    /// The two callers that place a node without needing its alignment offset:
-    /// `LayoutEngine::run` sizing a layer root against the surface, and
-    /// `canvas::arrange` sizing an absolutely-positioned child against its
-    /// slot. Both position by other means (the root's `Placement`, the child's
-    /// declared `pos`), so the offset the placement carries is dead to them.
+    /// For a caller that places the node without needing its alignment
+    /// offset: `LayoutEngine::run` sizing a layer root against the surface,
+    /// which positions it by the root's `Placement` instead.
     pub(super) fn arrange_size(
         child: &LayoutCore,
         bounds: &BoundsExtras,
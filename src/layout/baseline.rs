@@ -0,0 +1,168 @@
+//! First baselines, for the drivers that align children on them.
+//!
+//! A node's baseline is read off what `measure` already left behind — the
+//! desired sizes and the leaf text shapes — so it is a query rather than a
+//! column: nothing new to snapshot into the measure cache, because a cache
+//! hit restores both inputs. The answer is a distance below the node's
+//! margin-box top, the frame every driver's slot is expressed in.
+//!
+//! Sizes are the measured ones. A baseline child is never stretched on the
+//! axis it aligns on, and nothing above it in the subtree is either unless
+//! its own alignment asks, so desired is what arrange will hand it.
+//!
+//! The intrinsic query stays blind to baselines: it never shapes into the
+//! frame's text columns, so a Hug HStack's cross intrinsic is its tallest
+//! child's, and only `measure` grows it to the shared line's extent.
+
+use crate::layout::axis::Axis;
+use crate::layout::pass::LayoutPass;
+use crate::layout::types::align::{Align, VAlign};
+use crate::layout::types::layout_mode::LayoutMode;
+use crate::primitives::size::Size;
+use crate::scene::node::layout_core::LayoutCore;
+use crate::scene::shapes::record::{ShapeRecord, text_paint_bbox_local};
+use crate::scene::tree::node_id::NodeId;
+
+/// Whether `child` aligns on its baseline under a parent whose
+/// `child_align` is `parent_child_align` — the same child-first cascade
+/// [`AxisAlignPair::resolve`](crate::layout::axis_align_pair::AxisAlignPair::resolve)
+/// runs.
+#[inline]
+pub(super) fn aligns_on_baseline(child: &LayoutCore, parent_child_align: Align) -> bool {
+    child.meta.align().valign().or(parent_child_align.valign()) == VAlign::Baseline
+}
+
+/// The shared line a set of baseline-aligned siblings sit on: the deepest
+/// baseline among them, and the deepest extent below one.
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct BaselineGroup {
+    pub(super) above: f32,
+    pub(super) below: f32,
+}
+
+impl BaselineGroup {
+    /// Take in a member of margin-box height `height` whose baseline sits
+    /// `baseline` below its top.
+    #[inline]
+    pub(super) fn add(&mut self, baseline: f32, height: f32) {
+        self.above = self.above.max(baseline);
+        self.below = self.below.max(height - baseline);
+    }
+
+    /// The cross extent the group needs.
+    #[inline]
+    pub(super) fn extent(self) -> f32 {
+        self.above + self.below
+    }
+}
+
+impl LayoutPass<'_> {
+    /// `node`'s first baseline below its margin-box top. A node with no
+    /// text anywhere down its first-child chain answers with its bottom
+    /// margin edge, the baseline CSS synthesizes for an inline box.
+    pub(super) fn baseline(&self, node: NodeId) -> f32 {
+        self.first_baseline(node)
+            .unwrap_or_else(|| self.desired(node).h)
+    }
+
+    /// The line an HStack's baseline children share, or `None` when none
+    /// of them aligns on it. Read by the stack at measure, to size its
+    /// cross axis, and at arrange, to place them.
+    pub(super) fn baseline_group(&self, node: NodeId) -> Option<BaselineGroup> {
+        let tree = self.tree;
+        let parent_child_align = tree.panel(node).child_align;
+        let layouts = tree.records.layout();
+        let mut group = None;
+        for c in tree.active_children(node) {
+            if !aligns_on_baseline(&layouts[c.idx()], parent_child_align) {
+                continue;
+            }
+            group
+                .get_or_insert_with(BaselineGroup::default)
+                .add(self.baseline(c), self.desired(c).h);
+        }
+        group
+    }
+
+    fn first_baseline(&self, node: NodeId) -> Option<f32> {
+        let tree = self.tree;
+        let layout = &tree.records.layout()[node.idx()];
+        let [_, margin_top, _, _] = layout.margin.as_array();
+        let [_, padding_top, _, _] = layout.padding.as_array();
+        let mode = LayoutMode::from(layout.meta);
+        let inner_top = margin_top + padding_top;
+        let below_inner = match mode {
+            LayoutMode::Leaf => return self.text_baseline(node, layout).map(|b| margin_top + b),
+            // Children sit where their `pos` puts them, not in any flow
+            // a first child leads.
+            LayoutMode::Canvas => return None,
+            LayoutMode::HStack => match self.baseline_group(node) {
+                Some(group) => group.above,
+                None => self.first_child_baseline(node, layout, Axis::X)?,
+            },
+            LayoutMode::ZStack | LayoutMode::Scroll(_) | LayoutMode::Scrollbars(_) => {
+                self.first_child_baseline(node, layout, Axis::X)?
+            }
+            // The first child leads the main axis (or, for a grid, the
+            // first row) from the top.
+            LayoutMode::VStack
+            | LayoutMode::WrapHStack
+            | LayoutMode::WrapVStack
+            | LayoutMode::Grid(_) => self.first_child_baseline(node, layout, Axis::Y)?,
+        };
+        Some(inner_top + below_inner)
+    }
+
+    /// The first visible child's baseline below `node`'s inner top. On
+    /// `Axis::X` — a parent that aligns its children vertically — the
+    /// child is offset by its own alignment within the inner height; on
+    /// `Axis::Y` it leads from the top.
+    fn first_child_baseline(&self, node: NodeId, layout: &LayoutCore, axis: Axis) -> Option<f32> {
+        let tree = self.tree;
+        let c = tree.active_children(node).next()?;
+        let baseline = self.first_baseline(c)?;
+        if axis == Axis::Y {
+            return Some(baseline);
+        }
+        let child_h = self.desired(c).h;
+        let inner_h = self.desired(node).h - layout.margin.vert() - layout.padding.vert();
+        let valign = tree.records.layout()[c.idx()]
+            .meta
+            .align()
+            .valign()
+            .or(tree.panel(node).child_align.valign());
+        let offset = match valign {
+            VAlign::Center => (inner_h - child_h) * 0.5,
+            VAlign::Bottom => inner_h - child_h,
+            _ => 0.0,
+        };
+        Some(offset.max(0.0) + baseline)
+    }
+
+    /// A leaf's first text run's baseline below its border-box top, placed
+    /// the way the encoder places the run in the leaf's padded rect.
+    fn text_baseline(&self, node: NodeId, layout: &LayoutCore) -> Option<f32> {
+        let tree = self.tree;
+        let shaped = *self.text_shapes_of(node).first()?;
+        let span = tree.records.shape_span()[node.idx()];
+        let lo = span.start as usize;
+        let (local_origin, align) = tree.shapes.records[lo..lo + span.len as usize]
+            .iter()
+            .find_map(|shape| match shape {
+                ShapeRecord::Text {
+                    local_origin,
+                    align,
+                    ..
+                } => Some((*local_origin, *align)),
+                _ => None,
+            })?;
+        let desired = self.desired(node);
+        let owner = Size::new(
+            desired.w - layout.margin.horiz(),
+            desired.h - layout.margin.vert(),
+        );
+        let placed =
+            text_paint_bbox_local(local_origin, align, layout.padding, owner, shaped.measured);
+        Some(placed.min.y + shaped.baseline)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::layout::axis::Axis;
+use crate::layout::axis_align_pair::AxisAlignPair;
 use crate::layout::axis_placement::AxisPlacement;
 use crate::layout::engine::LayoutEngine;
 use crate::layout::intrinsic::{IntrinsicQuery, IntrinsicRange};
 use crate::layout::pass::LayoutPass;
+use crate::layout::types::align::AxisAlign;
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::interned_text::InternedText;
 use crate::primitives::{rect::Rect, size::Size};
@@ -34,6 +36,14 @@ use crate::scene::tree::node_id::NodeId;
 /// [`crate::widgets::scroll::Scroll`] (shift positions into positive
 /// space and auto-compensate the scroll's offset so visible state stays
 /// stable).
+///
+/// **Anchors.** A child whose `anchor` is `Center`, `End` or `Stretch` on
+/// an axis is pinned there: arrange places it against the canvas's edges
+/// the way a ZStack would, its margin is the inset from the edge, and
+/// `position` only nudges it from there. Its offset therefore never
+/// inflates a `Hug` canvas — the anchor is relative to the extent the
+/// canvas settles on, so counting it would feed back — and the child
+/// sizes the canvas by its own extent alone.
 pub(super) fn measure(pass: &mut LayoutPass<'_>, node: NodeId, inner_avail: Size) -> Size {
     let canvas_size = pass.tree.records.layout()[node.idx()].size;
     let pos_inflates_x = canvas_size.w().is_hug();
@@ -44,15 +54,26 @@ pub(super) fn measure(pass: &mut LayoutPass<'_>, node: NodeId, inner_avail: Size
     // their subtrees regardless.
     pass.measure_per_axis_hug(node, inner_avail, |tree, c, d| {
         let pos = tree.bounds(c).position;
-        let off_x = if pos_inflates_x { pos.x } else { 0.0 };
-        let off_y = if pos_inflates_y { pos.y } else { 0.0 };
+        let align = AxisAlignPair::anchor(tree.bounds(c).anchor);
+        let off_x = if pos_inflates_x && !anchored(align.h) {
+            pos.x
+        } else {
+            0.0
+        };
+        let off_y = if pos_inflates_y && !anchored(align.v) {
+            pos.y
+        } else {
+            0.0
+        };
         Size::new(off_x + d.w, off_y + d.h)
     })
 }
 
-/// Each child gets a slot at `inner.min + bounds.position`, sized per its
-/// desired (intrinsic) size. `Fill` falls back to intrinsic — same reason as
-/// `measure`.
+/// Each child gets a slot at `inner.min`, sized per its desired
+/// (intrinsic) size on a `Hug` axis — `Fill` falls back to intrinsic,
+/// same reason as `measure` — and to `inner` otherwise, then moves by
+/// `bounds.position`. An anchored axis always takes `inner`'s extent as
+/// its slot and aligns within it, so it tracks the canvas's edges.
 pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect) {
     let tree = pass.tree;
     let layouts = tree.records.layout();
@@ -66,32 +87,48 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect) {
         let d = pass.desired(c);
         let child_layout = layouts[c.idx()];
         let bounds = tree.bounds(c);
-        let pos = bounds.position;
-        let slot_w = if canvas_size.w().is_hug() {
+        let align = AxisAlignPair::anchor(bounds.anchor);
+        let slot_w = if canvas_size.w().is_hug() && !anchored(align.h) {
             d.w
         } else {
             inner.size.w
         };
-        let slot_h = if canvas_size.h().is_hug() {
+        let slot_h = if canvas_size.h().is_hug() && !anchored(align.v) {
             d.h
         } else {
             inner.size.h
         };
+        let slot = Rect {
+            min: inner.min,
+            size: Size::new(slot_w, slot_h),
+        };
+        let placed = AxisPlacement::arrange_rect(align, &child_layout, bounds, d, slot);
         let child_rect = Rect {
-            min: inner.min + pos,
-            size: AxisPlacement::arrange_size(&child_layout, bounds, d, Size::new(slot_w, slot_h)),
+            min: placed.min + bounds.position,
+            size: placed.size,
         };
         pass.arrange(c, child_rect);
     }
 }
 
+/// Whether an anchor axis pins a canvas child to the canvas's edges
+/// rather than to its `position` from the top-left.
+#[inline]
+fn anchored(align: AxisAlign) -> bool {
+    matches!(
+        align,
+        AxisAlign::Center | AxisAlign::End | AxisAlign::Stretch
+    )
+}
+
 /// Intrinsic size of a Canvas. Mirrors `measure`'s per-axis gating:
 /// when the canvas is `Hug` on the queried axis, returns
 /// `max(child.position + child.intrinsic)` so Hug-canvas wraps every
 /// positioned child; when `Fill` (or `Fixed`, though `Fixed` doesn't
 /// reach this branch — see `intrinsic.rs`), drops the positional offset
 /// so a `.position(...)` past `available` can't floor `Fill` above what
-/// the parent offered.
+/// the parent offered. An anchored child drops it on either, as in
+/// `measure`.
 pub(super) fn intrinsic(
     layout: &mut LayoutEngine,
     tree: &Tree,
@@ -105,7 +142,12 @@ pub(super) fn intrinsic(
         Sizing::HUG
     );
     query.children_max(layout, tree, node, axis, interned_text, |tree, c| {
-        if pos_inflates {
+        let align = AxisAlignPair::anchor(tree.bounds(c).anchor);
+        let along = match axis {
+            Axis::X => align.h,
+            Axis::Y => align.v,
+        };
+        if pos_inflates && !anchored(along) {
             axis.main_v(tree.bounds(c).position)
         } else {
             0.0
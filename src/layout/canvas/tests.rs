@@ -210,7 +210,8 @@ fn canvas_collapsed_child_does_not_grow_bbox() {
 /// driver). Stack/ZStack/Grid all consume align via shared axis resolution;
 /// Canvas does not. Adding align-cascade to Canvas would seem like a
 /// reasonable change but would break the contract that Canvas users
-/// rely on for free-form placement.
+/// rely on for free-form placement; pinning to edges goes through
+/// `.anchor(...)` instead.
 #[test]
 fn canvas_ignores_child_align() {
     let mut h = UiHarness::new(UVec2::new(400, 400));
@@ -241,3 +242,98 @@ fn canvas_ignores_child_align() {
     assert_eq!((r.min.x, r.min.y), (30.0, 40.0));
     assert_eq!((r.size.w, r.size.h), (50.0, 50.0));
 }
+
+fn rect_of(h: &UiHarness, name: &str) -> (f32, f32, f32, f32) {
+    let r = h.layout_rect(WidgetId::from_hash(name)).expect("arranged");
+    (r.min.x, r.min.y, r.size.w, r.size.h)
+}
+
+/// A corner anchor with a margin keeps the child the same distance from
+/// that corner through a resize, and `position` nudges it from there.
+#[test]
+fn canvas_anchor_tracks_corner_across_resize() {
+    let scene = |ui: &mut crate::Ui| {
+        Panel::canvas()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::FILL))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("minimap"))
+                    .size((50.0, 40.0))
+                    .anchor(Align::BOTTOM_RIGHT)
+                    .margin(16.0)
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("nudged"))
+                    .size((10.0, 10.0))
+                    .anchor(Align::BOTTOM_RIGHT)
+                    .position((-5.0, 5.0))
+                    .show(ui);
+            });
+    };
+    let mut h = UiHarness::new(UVec2::new(400, 300));
+    h.frame(scene);
+    assert_eq!(rect_of(&h, "minimap"), (334.0, 244.0, 50.0, 40.0));
+    assert_eq!(rect_of(&h, "nudged"), (385.0, 295.0, 10.0, 10.0));
+
+    h.resize(UVec2::new(600, 500));
+    h.frame(scene);
+    assert_eq!(rect_of(&h, "minimap"), (534.0, 444.0, 50.0, 40.0));
+}
+
+/// `Center` centres on its axis, `Stretch` spans edge to edge less the
+/// margins, and `Auto` on the other axis keeps the child at `position`.
+#[test]
+fn canvas_anchor_centres_and_stretches() {
+    let mut h = UiHarness::new(UVec2::new(400, 300));
+    h.frame(|ui| {
+        Panel::canvas()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::FILL))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("toolbar"))
+                    .size((120.0, 30.0))
+                    .anchor(Align::h(HAlign::Center))
+                    .position((0.0, 8.0))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("status"))
+                    .size((Sizing::HUG, Sizing::fixed(20.0)))
+                    .anchor(Align::new(HAlign::Stretch, VAlign::Bottom))
+                    .margin((10.0, 0.0, 10.0, 4.0))
+                    .show(ui);
+            });
+    });
+    assert_eq!(rect_of(&h, "toolbar"), (140.0, 8.0, 120.0, 30.0));
+    assert_eq!(rect_of(&h, "status"), (10.0, 276.0, 380.0, 20.0));
+}
+
+/// An anchored axis sizes a `Hug` canvas by the child's own extent: its
+/// `position` is a nudge from an edge the canvas hasn't settled yet, so
+/// it can't inflate the bounding box.
+#[test]
+fn canvas_anchored_child_does_not_inflate_hug() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    let panel = h.under_outer(|ui| {
+        Panel::canvas()
+            .auto_id()
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("free"))
+                    .position((30.0, 0.0))
+                    .size((20.0, 20.0))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("pinned"))
+                    .position((0.0, 100.0))
+                    .size((10.0, 10.0))
+                    .anchor(Align::v(VAlign::Bottom))
+                    .show(ui);
+            })
+            .response
+            .node()
+    });
+    let r = h.ui.arranged_rect(Layer::Main, panel);
+    assert_eq!((r.size.w, r.size.h), (50.0, 20.0));
+}
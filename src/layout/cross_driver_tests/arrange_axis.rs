@@ -18,15 +18,22 @@ enum Driver {
     Canvas,
     Stack,
     WrapStack,
+    /// The case's axis as a stack's main axis rather than its cross.
+    StackMain,
+    /// The case's axis as a wrap line's main axis: the child alone on its
+    /// line, sharing that line's leftover.
+    WrapStackMain,
     ZStack,
     Grid,
 }
 
-const DRIVERS: [Driver; 6] = [
+const DRIVERS: [Driver; 8] = [
     Driver::Root,
     Driver::Canvas,
     Driver::Stack,
     Driver::WrapStack,
+    Driver::StackMain,
+    Driver::WrapStackMain,
     Driver::ZStack,
     Driver::Grid,
 ];
@@ -108,6 +115,18 @@ fn arrange_with(driver: Driver, case: ArrangeCase) -> Rect {
                     add_child(ui, child, case);
                 });
         }
+        Driver::StackMain | Driver::WrapStackMain => {
+            let panel = match (driver, case.axis) {
+                (Driver::StackMain, Axis::X) => Panel::hstack(),
+                (Driver::StackMain, Axis::Y) => Panel::vstack(),
+                (_, Axis::X) => Panel::wrap_hstack(),
+                (_, Axis::Y) => Panel::wrap_vstack(),
+            };
+            panel
+                .auto_id()
+                .size(parent_size)
+                .show(ui, |ui| add_child(ui, child, case));
+        }
         Driver::ZStack => {
             Panel::zstack()
                 .auto_id()
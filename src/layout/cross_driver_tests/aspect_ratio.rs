@@ -0,0 +1,188 @@
+//! Pin: `Configure::aspect_ratio` resolves the dependent axis from the
+//! driving one inside a single measure/arrange, under every driver that
+//! hands a child its driving extent, and in the intrinsic query a Hug
+//! parent sizes itself by.
+use crate::layout::types::{sizing::Sizing, track::Track};
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{frame::Frame, grid::Grid, panel::Panel};
+use glam::UVec2;
+
+fn rect(h: &UiHarness, id: &'static str) -> Rect {
+    h.layout_rect(WidgetId::from_hash(id)).expect("arranged")
+}
+
+#[test]
+fn fill_width_derives_height_in_a_vstack() {
+    let mut h = UiHarness::new(UVec2::new(320, 600));
+    h.frame(|ui| {
+        Panel::vstack()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::HUG))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("thumb"))
+                    .size((Sizing::FILL, Sizing::HUG))
+                    .aspect_ratio(16.0 / 9.0)
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("below"))
+                    .size((Sizing::FILL, Sizing::fixed(10.0)))
+                    .show(ui);
+            });
+    });
+    let thumb = rect(&h, "thumb");
+    assert_eq!(thumb.size.w, 320.0);
+    assert_eq!(thumb.size.h, 180.0);
+    // Measure already knew the derived height: the next sibling sits
+    // right below it in the same frame.
+    assert_eq!(rect(&h, "below").min.y, 180.0);
+}
+
+#[test]
+fn fixed_height_drives_the_width() {
+    let mut h = UiHarness::new(UVec2::new(800, 600));
+    h.frame(|ui| {
+        Panel::hstack().auto_id().show(ui, |ui| {
+            Frame::new()
+                .id(WidgetId::from_hash("avatar"))
+                .size((Sizing::HUG, Sizing::fixed(40.0)))
+                .aspect_ratio(1.5)
+                .show(ui);
+            Frame::new()
+                .id(WidgetId::from_hash("next"))
+                .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+                .show(ui);
+        });
+    });
+    assert_eq!(rect(&h, "avatar").size.w, 60.0);
+    assert_eq!(rect(&h, "next").min.x, 60.0);
+}
+
+#[test]
+fn fill_siblings_in_an_hstack_derive_from_their_share() {
+    let mut h = UiHarness::new(UVec2::new(400, 600));
+    h.frame(|ui| {
+        Panel::hstack()
+            .id(WidgetId::from_hash("row"))
+            .size((Sizing::FILL, Sizing::HUG))
+            .show(ui, |ui| {
+                for id in ["a", "b"] {
+                    Frame::new()
+                        .id(WidgetId::from_hash(id))
+                        .size((Sizing::FILL, Sizing::HUG))
+                        .aspect_ratio(2.0)
+                        .show(ui);
+                }
+            });
+    });
+    assert_eq!(rect(&h, "a").size, rect(&h, "b").size);
+    assert_eq!(rect(&h, "a").size.w, 200.0);
+    assert_eq!(rect(&h, "a").size.h, 100.0);
+    assert_eq!(rect(&h, "row").size.h, 100.0);
+}
+
+#[test]
+fn grid_cell_sizes_its_hug_row_from_the_column_width() {
+    let mut h = UiHarness::new(UVec2::new(300, 600));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::HUG))
+            .cols([Track::fill(), Track::fill()])
+            .rows([Track::hug(), Track::hug()])
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("tile"))
+                    .size((Sizing::FILL, Sizing::HUG))
+                    .aspect_ratio(1.0)
+                    .grid_cell((0, 1))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("second-row"))
+                    .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+                    .grid_cell((1, 0))
+                    .show(ui);
+            });
+    });
+    let tile = rect(&h, "tile");
+    assert_eq!(
+        (tile.min.x, tile.size.w, tile.size.h),
+        (150.0, 150.0, 150.0)
+    );
+    assert_eq!(rect(&h, "second-row").min.y, 150.0);
+}
+
+#[test]
+fn dependent_axis_stays_within_its_bounds() {
+    let mut h = UiHarness::new(UVec2::new(800, 600));
+    h.frame(|ui| {
+        Panel::vstack()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::HUG))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("capped"))
+                    .size((Sizing::FILL, Sizing::HUG))
+                    .max_size((f32::INFINITY, 100.0))
+                    .aspect_ratio(1.0)
+                    .show(ui);
+            });
+    });
+    assert_eq!(rect(&h, "capped").size.h, 100.0);
+}
+
+/// A Hug grid column is sized by its cells' intrinsic widths: the
+/// derived axis has to answer from the driver there too, or the column
+/// would size against the cell's (empty) content.
+#[test]
+fn intrinsic_query_derives_the_dependent_axis() {
+    let mut h = UiHarness::new(UVec2::new(800, 600));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::hug(), Track::hug()])
+            .rows([Track::hug()])
+            .show(ui, |ui| {
+                Frame::new()
+                    .auto_id()
+                    .size((Sizing::HUG, Sizing::fixed(30.0)))
+                    .aspect_ratio(3.0)
+                    .margin(5.0)
+                    .grid_cell((0, 0))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("next-col"))
+                    .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+                    .grid_cell((0, 1))
+                    .show(ui);
+            });
+    });
+    assert_eq!(rect(&h, "next-col").min.x, 100.0);
+}
+
+/// Re-running the same frame lands on the same rects: the derived axis
+/// reads nothing from the previous frame.
+#[test]
+fn resolution_is_stable_across_frames() {
+    let mut h = UiHarness::new(UVec2::new(333, 600));
+    let mut frames = Vec::new();
+    for _ in 0..3 {
+        h.frame(|ui| {
+            Panel::vstack()
+                .auto_id()
+                .size((Sizing::FILL, Sizing::HUG))
+                .show(ui, |ui| {
+                    Frame::new()
+                        .id(WidgetId::from_hash("thumb"))
+                        .size((Sizing::FILL, Sizing::HUG))
+                        .aspect_ratio(16.0 / 9.0)
+                        .show(ui);
+                });
+        });
+        frames.push(rect(&h, "thumb"));
+    }
+    assert!(frames.windows(2).all(|w| w[0] == w[1]), "{frames:?}");
+}
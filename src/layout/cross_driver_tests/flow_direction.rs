@@ -0,0 +1,267 @@
+//! Pin: `FlowDirection::RightToLeft` mirrors every driver's arrange
+//! about its own inner rect — stack, wrapstack, grid, canvas and scroll —
+//! inherits through the subtree and across a raised layer, swaps
+//! horizontal padding and margin, and lets a nested `LeftToRight` island
+//! lay out as it always did. Hit-testing reads the mirrored rects.
+use crate::layout::types::{align::HAlign, sizing::Sizing, track::Track};
+use crate::primitives::rect::Rect;
+use crate::primitives::spacing::Spacing;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::Ui;
+use crate::ui::harness::UiHarness;
+use crate::widgets::popup::Popup;
+use crate::widgets::scroll::Scroll;
+use crate::widgets::scroll::state::ScrollState;
+use crate::widgets::{frame::Frame, grid::Grid, panel::Panel};
+use crate::{Align, FlowDirection};
+use glam::{UVec2, Vec2};
+
+fn rect(h: &UiHarness, id: &'static str) -> Rect {
+    h.layout_rect(WidgetId::from_hash(id)).expect("arranged")
+}
+
+fn leaf(ui: &mut Ui, id: &'static str, w: f32, h: f32) {
+    Frame::new()
+        .id(WidgetId::from_hash(id))
+        .size((Sizing::fixed(w), Sizing::fixed(h)))
+        .show(ui);
+}
+
+fn toolbar(ui: &mut Ui, dir: FlowDirection) {
+    Panel::hstack()
+        .id(WidgetId::from_hash("bar"))
+        .flow_direction(dir)
+        .size((Sizing::fixed(300.0), Sizing::fixed(40.0)))
+        .gap(10.0)
+        .show(ui, |ui| {
+            leaf(ui, "a", 50.0, 20.0);
+            leaf(ui, "b", 60.0, 20.0);
+        });
+}
+
+/// The first child sits at the leading edge, which right to left is the
+/// right one; the gap still separates the pair.
+#[test]
+fn hstack_lays_out_from_the_right() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| toolbar(ui, FlowDirection::LeftToRight));
+    assert_eq!(rect(&h, "a").min.x, 0.0);
+    assert_eq!(rect(&h, "b").min.x, 60.0);
+
+    h.frame(|ui| toolbar(ui, FlowDirection::RightToLeft));
+    assert_eq!(rect(&h, "a").min.x, 250.0);
+    assert_eq!(rect(&h, "b").min.x, 180.0);
+}
+
+/// `Left` padding and margin mean the leading side, and `HAlign::Left`
+/// pins to the leading edge.
+#[test]
+fn padding_margin_and_align_follow_the_leading_edge() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Panel::vstack()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(200.0), Sizing::fixed(100.0)))
+            .padding(Spacing::new(20.0, 0.0, 5.0, 0.0))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("lead"))
+                    .size((Sizing::fixed(40.0), Sizing::fixed(10.0)))
+                    .margin(Spacing::new(8.0, 0.0, 0.0, 0.0))
+                    .align(Align::h(HAlign::Left))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("trail"))
+                    .size((Sizing::fixed(40.0), Sizing::fixed(10.0)))
+                    .align(Align::h(HAlign::Right))
+                    .show(ui);
+            });
+    });
+    // Inner rect is 5..180 once the 20 px leading padding moves right;
+    // the leading 8 px margin then insets `lead` from 180.
+    assert_eq!(rect(&h, "lead").min.x, 132.0);
+    assert_eq!(rect(&h, "trail").min.x, 5.0);
+}
+
+/// An explicit `LeftToRight` inside a mirrored row lays its own children
+/// out forwards, inside the slot the mirrored parent gave it.
+#[test]
+fn nested_left_to_right_island_keeps_its_order() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Panel::hstack()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(300.0), Sizing::fixed(40.0)))
+            .show(ui, |ui| {
+                leaf(ui, "first", 50.0, 20.0);
+                Panel::hstack()
+                    .id(WidgetId::from_hash("island"))
+                    .flow_direction(FlowDirection::LeftToRight)
+                    .show(ui, |ui| {
+                        leaf(ui, "x", 30.0, 20.0);
+                        leaf(ui, "y", 30.0, 20.0);
+                    });
+            });
+    });
+    assert_eq!(rect(&h, "first").min.x, 250.0);
+    assert_eq!(rect(&h, "island"), Rect::new(190.0, 0.0, 60.0, 20.0));
+    assert_eq!(rect(&h, "x").min.x, 190.0);
+    assert_eq!(rect(&h, "y").min.x, 220.0);
+}
+
+/// Direction inherits: a row with no direction of its own under a
+/// mirrored ancestor mirrors too.
+#[test]
+fn direction_inherits_through_the_subtree() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Panel::vstack()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(300.0), Sizing::HUG))
+            .show(ui, toolbar_inherited);
+    });
+    assert_eq!(rect(&h, "a").min.x, 250.0);
+}
+
+fn toolbar_inherited(ui: &mut Ui) {
+    Panel::hstack()
+        .auto_id()
+        .size((Sizing::FILL, Sizing::fixed(40.0)))
+        .show(ui, |ui| leaf(ui, "a", 50.0, 20.0));
+}
+
+/// Wrapstack fills each line from the right and still wraps downwards.
+#[test]
+fn wrapstack_fills_lines_from_the_right() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Panel::wrap_hstack()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(100.0), Sizing::HUG))
+            .show(ui, |ui| {
+                leaf(ui, "w0", 40.0, 10.0);
+                leaf(ui, "w1", 40.0, 10.0);
+                leaf(ui, "w2", 40.0, 10.0);
+            });
+    });
+    assert_eq!(rect(&h, "w0"), Rect::new(60.0, 0.0, 40.0, 10.0));
+    assert_eq!(rect(&h, "w1"), Rect::new(20.0, 0.0, 40.0, 10.0));
+    assert_eq!(rect(&h, "w2"), Rect::new(60.0, 10.0, 40.0, 10.0));
+}
+
+/// Column 0 is the leading column, so it lands on the right.
+#[test]
+fn grid_columns_run_from_the_right() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(300.0), Sizing::fixed(50.0)))
+            .cols([Track::fixed(100.0), Track::fill()])
+            .rows([Track::fill()])
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("label"))
+                    .grid_cell((0, 0))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("field"))
+                    .grid_cell((0, 1))
+                    .show(ui);
+            });
+    });
+    assert_eq!(rect(&h, "label"), Rect::new(200.0, 0.0, 100.0, 50.0));
+    assert_eq!(rect(&h, "field"), Rect::new(0.0, 0.0, 200.0, 50.0));
+}
+
+/// A canvas position measures from the leading edge.
+#[test]
+fn canvas_positions_measure_from_the_right() {
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    h.frame(|ui| {
+        Panel::canvas()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::fixed(200.0), Sizing::fixed(100.0)))
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("pin"))
+                    .position((10.0, 20.0))
+                    .size((30.0, 30.0))
+                    .show(ui);
+            });
+    });
+    assert_eq!(rect(&h, "pin"), Rect::new(160.0, 20.0, 30.0, 30.0));
+}
+
+/// A popup below a mirrored trigger hangs from the trigger's right edge,
+/// and its body inherits the direction across the layer switch.
+#[test]
+fn popup_below_a_mirrored_trigger_aligns_right_and_inherits() {
+    let mut h = UiHarness::new(UVec2::new(400, 300));
+    let anchor = Rect::new(200.0, 10.0, 100.0, 20.0);
+    let build = |ui: &mut Ui| {
+        Panel::vstack()
+            .auto_id()
+            .flow_direction(FlowDirection::RightToLeft)
+            .size((Sizing::FILL, Sizing::FILL))
+            .show(ui, |ui| {
+                Popup::below(anchor).auto_id().show(ui, |ui, _| {
+                    Panel::hstack()
+                        .id(WidgetId::from_hash("menu"))
+                        .size((Sizing::fixed(150.0), Sizing::HUG))
+                        .show(ui, |ui| leaf(ui, "item", 40.0, 20.0));
+                });
+            });
+    };
+    h.frame(build);
+    h.frame(build);
+    let menu = rect(&h, "menu");
+    assert_eq!(menu.max().x, anchor.max().x);
+    assert_eq!(menu.min.y, anchor.max().y);
+    assert_eq!(rect(&h, "item").max().x, menu.max().x);
+}
+
+fn mirrored_list(ui: &mut Ui) {
+    Panel::vstack()
+        .auto_id()
+        .flow_direction(FlowDirection::RightToLeft)
+        .show(ui, |ui| {
+            Scroll::vertical()
+                .id(WidgetId::from_hash("list"))
+                .size((Sizing::fixed(200.0), Sizing::fixed(200.0)))
+                .show(ui, |ui| leaf(ui, "tall", 100.0, 800.0));
+        });
+}
+
+/// The vertical bar moves to the left edge, the content to the right of
+/// it; the thumb is hit where it is drawn, and dragging it still pans.
+#[test]
+fn scroll_bar_moves_to_the_left_and_still_drags() {
+    let mut h = UiHarness::new(UVec2::new(400, 300));
+    h.frame(mirrored_list);
+    h.frame(mirrored_list);
+    let outer = WidgetId::from_hash("list");
+    let thumb = outer.with("viewport").with("vthumb");
+    let thumb_rect = h.rect(thumb).expect("thumb arranged");
+    assert!(
+        thumb_rect.max().x <= 12.0,
+        "bar sits in the leading-edge strip, got {thumb_rect:?}",
+    );
+    assert_eq!(rect(&h, "tall").max().x, 200.0);
+    let press = h.center_of(thumb);
+    assert_eq!(h.hit_at(press), Some(thumb));
+
+    h.press_on(thumb);
+    h.move_to(press + Vec2::new(0.0, 30.0));
+    h.frame(mirrored_list);
+    let offset = h.ui.state_mut::<ScrollState>(outer).offset;
+    assert!(offset.y > 0.0, "thumb drag pans, got {offset:?}");
+    assert_eq!(rect(&h, "tall").max().x, 200.0, "x is not panned");
+}
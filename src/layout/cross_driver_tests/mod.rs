@@ -9,10 +9,13 @@
 //! dozen items to `pub` purely for tests.
 
 mod arrange_axis;
+mod aspect_ratio;
 mod convergence;
 mod fill_propagation;
 mod fill_solvers;
+mod flow_direction;
 mod no_overlap;
+mod relative_sizes;
 mod stretch_semantics;
 mod support;
 mod text_wrap;
@@ -0,0 +1,183 @@
+//! Pin: `Sizing::percent` takes its share of what the parent offers in
+//! every driver and hugs where the offer is unbounded; viewport units in
+//! sizes, size bounds and grid tracks follow the surface; and a resize
+//! re-keys the measure cache rather than replaying last frame's extent.
+use crate::Length;
+use crate::layout::types::{sizing::Sizing, track::Track};
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::Ui;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{frame::Frame, grid::Grid, panel::Panel, scroll::Scroll};
+use glam::UVec2;
+
+fn rect(h: &UiHarness, id: &'static str) -> Rect {
+    h.layout_rect(WidgetId::from_hash(id)).expect("arranged")
+}
+
+fn sidebar_row(ui: &mut Ui) {
+    Panel::hstack()
+        .auto_id()
+        .size((Sizing::FILL, Sizing::FILL))
+        .show(ui, |ui| {
+            Panel::vstack()
+                .id(WidgetId::from_hash("sidebar"))
+                .size((Sizing::percent(25.0), Sizing::FILL))
+                .min_size((200.0, 0.0))
+                .show(ui, |_| {});
+            Frame::new()
+                .id(WidgetId::from_hash("content"))
+                .size((Sizing::FILL, Sizing::FILL))
+                .show(ui);
+        });
+}
+
+/// The share follows the window through the cache: the row's subtree is
+/// unchanged across the resize, so only `available` can re-key it.
+#[test]
+fn sidebar_share_follows_a_resize_and_keeps_its_floor() {
+    let mut h = UiHarness::new(UVec2::new(1200, 400));
+    h.frame(sidebar_row);
+    assert_eq!(rect(&h, "sidebar").size.w, 300.0);
+    assert_eq!(rect(&h, "content").min.x, 300.0);
+
+    h.resize(UVec2::new(600, 400));
+    h.frame(sidebar_row);
+    assert_eq!(rect(&h, "sidebar").size.w, 200.0);
+    assert_eq!(rect(&h, "content").min.x, 200.0);
+
+    h.resize(UVec2::new(2000, 400));
+    h.frame(sidebar_row);
+    assert_eq!(rect(&h, "sidebar").size.w, 500.0);
+}
+
+/// A panned axis offers its content unbounded room: there is nothing to
+/// take a share of, so the axis hugs.
+#[test]
+fn percent_on_an_unbounded_axis_hugs() {
+    let mut h = UiHarness::new(UVec2::new(800, 600));
+    h.frame(|ui| {
+        Scroll::horizontal().auto_id().show(ui, |ui| {
+            Panel::hstack()
+                .id(WidgetId::from_hash("half"))
+                .size((Sizing::percent(50.0), Sizing::HUG))
+                .show(ui, |ui| {
+                    Frame::new()
+                        .auto_id()
+                        .size((Sizing::fixed(40.0), Sizing::fixed(10.0)))
+                        .show(ui);
+                });
+        });
+    });
+    assert_eq!(rect(&h, "half").size.w, 40.0);
+}
+
+fn dialog(ui: &mut Ui) {
+    Panel::vstack()
+        .auto_id()
+        .size((Sizing::FILL, Sizing::FILL))
+        .show(ui, |ui| {
+            Frame::new()
+                .id(WidgetId::from_hash("dialog"))
+                .size((Sizing::FILL, Sizing::vh(25.0)))
+                .max_size((Length::vw(80.0), f32::INFINITY))
+                .show(ui);
+        });
+}
+
+#[test]
+fn viewport_units_cap_a_dialog_and_size_it() {
+    let mut h = UiHarness::new(UVec2::new(500, 400));
+    h.frame(dialog);
+    assert_eq!(rect(&h, "dialog").size.w, 400.0);
+    assert_eq!(rect(&h, "dialog").size.h, 100.0);
+
+    h.resize(UVec2::new(1000, 800));
+    h.frame(dialog);
+    assert_eq!(rect(&h, "dialog").size.w, 800.0);
+    assert_eq!(rect(&h, "dialog").size.h, 200.0);
+}
+
+#[test]
+fn grid_resolves_percent_and_viewport_tracks() {
+    let mut h = UiHarness::new(UVec2::new(400, 600));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .size((Sizing::FILL, Sizing::HUG))
+            .cols([Track::new(Sizing::percent(25.0)), Track::fill()])
+            .rows([Track::new(Sizing::vh(10.0)), Track::hug()])
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("corner"))
+                    .grid_cell((0, 0))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("beside"))
+                    .grid_cell((0, 1))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("below"))
+                    .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+                    .grid_cell((1, 0))
+                    .show(ui);
+            });
+    });
+    let corner = rect(&h, "corner");
+    assert_eq!((corner.size.w, corner.size.h), (100.0, 60.0));
+    assert_eq!(rect(&h, "beside").min.x, 100.0);
+    assert_eq!(rect(&h, "below").min.y, 60.0);
+}
+
+/// Each driver hands a percent child its own offer: the ZStack's inner
+/// rect, the grid cell, the wrap stack's line length. None of them
+/// stretches it past the share, even where the driver would stretch a
+/// Hug child.
+#[test]
+fn percent_resolves_against_each_drivers_offer() {
+    let mut h = UiHarness::new(UVec2::new(800, 600));
+    h.frame(|ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            Panel::zstack()
+                .auto_id()
+                .size((Sizing::fixed(200.0), Sizing::fixed(100.0)))
+                .show(ui, |ui| {
+                    Frame::new()
+                        .id(WidgetId::from_hash("layer"))
+                        .size((Sizing::percent(50.0), Sizing::percent(50.0)))
+                        .show(ui);
+                });
+            Grid::new()
+                .auto_id()
+                .cols([Track::fixed(200.0)])
+                .rows([Track::fixed(40.0)])
+                .show(ui, |ui| {
+                    Frame::new()
+                        .id(WidgetId::from_hash("cell"))
+                        .size((Sizing::percent(50.0), Sizing::HUG))
+                        .grid_cell((0, 0))
+                        .show(ui);
+                });
+            Panel::wrap_hstack()
+                .auto_id()
+                .size((Sizing::fixed(300.0), Sizing::HUG))
+                .show(ui, |ui| {
+                    for id in ["w0", "w1", "w2"] {
+                        Frame::new()
+                            .id(WidgetId::from_hash(id))
+                            .size((Sizing::percent(50.0), Sizing::fixed(10.0)))
+                            .show(ui);
+                    }
+                });
+        });
+    });
+    let layer = rect(&h, "layer");
+    assert_eq!((layer.size.w, layer.size.h), (100.0, 50.0));
+    let cell = rect(&h, "cell");
+    assert_eq!((cell.size.w, cell.size.h), (100.0, 40.0));
+    assert_eq!(rect(&h, "w0").size.w, 150.0);
+    assert_eq!(rect(&h, "w1").min.x - rect(&h, "w0").min.x, 150.0);
+    assert_eq!(rect(&h, "w2").min.x, rect(&h, "w0").min.x);
+    assert_eq!(rect(&h, "w2").min.y - rect(&h, "w0").min.y, 10.0);
+}
@@ -0,0 +1,91 @@
+//! Line-clamped text: the lines a clamped run keeps for the width it is
+//! offered, and the flag a "show more" control reads back.
+
+use crate::Ui;
+use crate::layout::ShapedText;
+use crate::layout::cross_driver_tests::support;
+use crate::layout::cross_driver_tests::text_wrap::support::PARAGRAPH;
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::layer::Layer;
+use crate::scene::node::Configure;
+use crate::scene::tree::node_id::NodeId;
+use crate::text::wrap::TextWrap;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{panel::Panel, text::Text};
+use glam::UVec2;
+
+const BODY: &str = "body";
+
+/// A `w`-wide column holding one `text` run under `wrap`.
+fn column(ui: &mut Ui, w: f32, text: &'static str, wrap: TextWrap) -> NodeId {
+    let mut node = None;
+    Panel::vstack()
+        .auto_id()
+        .size((Sizing::fixed(w), Sizing::HUG))
+        .show(ui, |ui| {
+            node = Some(
+                Text::new(text)
+                    .id(WidgetId::from_hash(BODY))
+                    .text_wrap(wrap)
+                    .size((Sizing::FILL, Sizing::HUG))
+                    .show(ui)
+                    .node(),
+            );
+        });
+    node.unwrap()
+}
+
+fn shaped(h: &mut UiHarness, w: f32, text: &'static str, wrap: TextWrap) -> ShapedText {
+    let node = h.frame_value(|ui| column(ui, w, text, wrap));
+    support::shaped_text(h.ui.layout(Layer::Main), node)
+}
+
+fn clamped(h: &UiHarness) -> bool {
+    h.ui.text_clamped(WidgetId::from_hash(BODY))
+}
+
+/// A paragraph that wraps past its budget keeps two lines, stays inside
+/// the column, and says it was cut.
+#[test]
+fn long_paragraph_keeps_its_lines_and_reports_the_clamp() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let one_line = shaped(&mut h, 120.0, "fox", TextWrap::Clamp(2)).measured.h;
+    assert!(!clamped(&h), "a single line has nothing to clamp");
+
+    let wrapped = shaped(&mut h, 120.0, PARAGRAPH, TextWrap::Wrap).measured;
+    assert!(
+        wrapped.h > 2.0 * one_line,
+        "fixture must wrap past two lines"
+    );
+
+    let cut = shaped(&mut h, 120.0, PARAGRAPH, TextWrap::Clamp(2)).measured;
+    assert!(clamped(&h));
+    assert!(cut.h > one_line && cut.h <= 2.0 * one_line, "got {cut:?}");
+    assert!(cut.w <= 120.0, "got {cut:?}");
+}
+
+/// A column wide enough for the whole paragraph clamps nothing, and the
+/// run measures exactly as the plain wrap would.
+#[test]
+fn paragraph_within_its_budget_is_not_clamped() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let wrapped = shaped(&mut h, 300.0, PARAGRAPH, TextWrap::Wrap);
+    let fitted = shaped(&mut h, 300.0, PARAGRAPH, TextWrap::Clamp(2));
+    assert!(!clamped(&h));
+    assert_eq!(fitted.key, wrapped.key);
+    assert_eq!(fitted.measured, wrapped.measured);
+}
+
+/// "Show more": lifting the clamp opens the run to its wrapped height,
+/// and the flag clears with it.
+#[test]
+fn lifting_the_clamp_opens_to_the_wrapped_height() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let cut = shaped(&mut h, 120.0, PARAGRAPH, TextWrap::Clamp(2));
+    assert!(clamped(&h));
+
+    let open = shaped(&mut h, 120.0, PARAGRAPH, TextWrap::Wrap);
+    assert!(!clamped(&h), "an unclamped run must not report a clamp");
+    assert!(open.measured.h > cut.measured.h);
+}
@@ -0,0 +1,165 @@
+//! Shrink-to-fit text: the size a fitted run settles on for the box it is
+//! offered, and that a replayed frame keeps it.
+
+use crate::Ui;
+use crate::layout::ShapedText;
+use crate::layout::cross_driver_tests::support;
+use crate::layout::cross_driver_tests::text_wrap::support::PARAGRAPH;
+use crate::layout::types::sizing::Sizing;
+use crate::renderer::frontend::capture::PaintCall;
+use crate::scene::layer::Layer;
+use crate::scene::node::Configure;
+use crate::scene::tree::node_id::NodeId;
+use crate::text::TEXT_SCALE_STEP;
+use crate::text::wrap::TextWrap;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{panel::Panel, text::Text};
+use glam::UVec2;
+
+const MIN_PX: f32 = 8.0;
+const MAX_PX: f32 = 48.0;
+
+/// A fixed `w × h` tile filled by one fitted `text`.
+fn tile(ui: &mut Ui, w: f32, h: f32, text: &'static str, lines: u16) -> NodeId {
+    let mut node = None;
+    Panel::vstack()
+        .auto_id()
+        .size((Sizing::fixed(w), Sizing::fixed(h)))
+        .show(ui, |ui| {
+            node = Some(
+                Text::new(text)
+                    .auto_id()
+                    .fit(MIN_PX, MAX_PX)
+                    .fit_lines(lines)
+                    .size((Sizing::FILL, Sizing::FILL))
+                    .show(ui)
+                    .node(),
+            );
+        });
+    node.unwrap()
+}
+
+fn fitted(h: &mut UiHarness, w: f32, ht: f32, text: &'static str, lines: u16) -> ShapedText {
+    let node = h.frame_value(|ui| tile(ui, w, ht, text, lines));
+    support::shaped_text(h.ui.layout(Layer::Main), node)
+}
+
+/// A label that fits at the top of the range paints there.
+#[test]
+fn short_label_keeps_the_largest_size() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let shaped = fitted(&mut h, 300.0, 100.0, "42", 1);
+    assert_eq!(shaped.key.font_size_px(), MAX_PX);
+}
+
+/// A long line shrinks until it fits the tile's width, and the next rung
+/// up would not have.
+#[test]
+fn long_label_shrinks_to_the_box_width() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let shaped = fitted(&mut h, 200.0, 100.0, "the quick brown fox", 1);
+    let size = shaped.key.font_size_px();
+    assert!((MIN_PX..MAX_PX).contains(&size), "got {size}");
+    assert!(shaped.measured.w <= 200.0, "got {:?}", shaped.measured);
+
+    let roomier = fitted(&mut h, 260.0, 100.0, "the quick brown fox", 1);
+    assert!(
+        roomier.key.font_size_px() > size,
+        "a wider tile fits bigger"
+    );
+}
+
+/// A short tile caps the size even when the width has room.
+#[test]
+fn tile_height_caps_the_size() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let shaped = fitted(&mut h, 300.0, 24.0, "42", 1);
+    assert!(shaped.key.font_size_px() < MAX_PX);
+    assert!(shaped.measured.h <= 24.0, "got {:?}", shaped.measured);
+}
+
+/// Nothing fits a 20 px tile at 8 px: the run stops at the floor — the
+/// lowest rung at or above the minimum.
+#[test]
+fn never_shrinks_below_the_minimum() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let shaped = fitted(&mut h, 20.0, 100.0, PARAGRAPH, 1);
+    let size = shaped.key.font_size_px();
+    assert!(size >= MIN_PX, "got {size}");
+    assert!(size - MAX_PX * TEXT_SCALE_STEP < MIN_PX, "got {size}");
+}
+
+/// Letting the run wrap onto two lines fits a bigger face than one.
+#[test]
+fn more_lines_fit_a_bigger_face() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let one = fitted(&mut h, 200.0, 200.0, PARAGRAPH, 1);
+    let two = fitted(&mut h, 200.0, 200.0, PARAGRAPH, 2);
+    assert!(two.key.font_size_px() > one.key.font_size_px());
+    assert!(two.measured.w <= 200.0, "got {:?}", two.measured);
+}
+
+/// A second identical frame replays the fitted measurement and paints the
+/// same face layout picked.
+#[test]
+fn replayed_frame_keeps_the_fitted_face() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let first = fitted(&mut h, 200.0, 100.0, PARAGRAPH, 1);
+    let second = fitted(&mut h, 200.0, 100.0, PARAGRAPH, 1);
+    assert_eq!(first.key, second.key);
+    let draw_keys: Vec<_> = h
+        .encode_paint()
+        .calls
+        .iter()
+        .filter_map(|call| match call {
+            PaintCall::Text(payload) => Some(payload.text.key),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(draw_keys, [second.key]);
+}
+
+/// Two lines at full size are two lines, not a shrink: the wrapped height
+/// is ceil'd, so a fractional leading must not push the budget under it.
+#[test]
+fn wrapping_onto_the_allowed_lines_keeps_the_full_size() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let shaped = fitted(&mut h, 200.0, 300.0, "hello world", 2);
+    assert_eq!(shaped.key.font_size_px(), MAX_PX);
+    assert!(shaped.measured.h > shaped.measured.w * 0.5, "wrapped");
+}
+
+/// `fit_lines` wraps the run whichever side of `text_wrap` it is called
+/// on: a single-line policy gives way to wrapping, a wrapping one stays.
+#[test]
+fn fit_lines_and_text_wrap_commute() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 400));
+    let mut shaped = |wrap: TextWrap, lines_first: bool| {
+        let node = h.frame_value(|ui| {
+            let mut node = None;
+            Panel::vstack()
+                .auto_id()
+                .size((Sizing::fixed(200.0), Sizing::fixed(200.0)))
+                .show(ui, |ui| {
+                    let text = Text::new(PARAGRAPH).auto_id().fit(MIN_PX, MAX_PX);
+                    let text = match lines_first {
+                        true => text.fit_lines(2).text_wrap(wrap),
+                        false => text.text_wrap(wrap).fit_lines(2),
+                    };
+                    node = Some(text.size((Sizing::FILL, Sizing::FILL)).show(ui).node());
+                });
+            node.unwrap()
+        });
+        support::shaped_text(h.ui.layout(Layer::Main), node).key
+    };
+    for wrap in [TextWrap::Ellipsis, TextWrap::Clamp(2)] {
+        assert_eq!(shaped(wrap, true), shaped(wrap, false), "{wrap:?}");
+    }
+    let ellipsis = shaped(TextWrap::Ellipsis, true);
+    assert_eq!(ellipsis, shaped(TextWrap::Wrap, true), "wraps");
+    assert_ne!(
+        shaped(TextWrap::Clamp(2), true),
+        ellipsis,
+        "keeps its clamp"
+    );
+}
@@ -1,7 +1,9 @@
 //! Text that wraps, and the layout drivers it has to agree with.
 
+mod clamp;
 mod container_text;
 mod fill_share;
+mod fit;
 mod hug_cols;
 mod multi_run;
 mod support;
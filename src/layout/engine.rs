@@ -314,9 +314,9 @@ impl LayoutEngine {
                     continue;
                 }
                 let node = NodeId(index as u32);
-                let available_w = (pass.rect(node).size.w - layout.padding.horiz()).max(0.0);
+                let available = pass.rect(node).deflated_by(layout.padding).size;
                 let runs = TextShapeInput::on_container(tree, interned_text, node);
-                pass.shape_text_runs(node, available_w, runs);
+                pass.shape_text_runs(node, available, runs);
             }
         }
         let finish_span = PhaseSpan::start();
@@ -3,7 +3,10 @@
 use crate::layout::axis::Axis;
 use crate::layout::axis_align_pair::AxisAlignPair;
 use crate::layout::axis_placement::AxisPlacement;
+use crate::layout::baseline::aligns_on_baseline;
+use crate::layout::grid::axis_scratch::percent_resolved;
 use crate::layout::grid::grid_context::GridContext;
+use crate::layout::grid::grid_scratch::collect_row_baselines;
 use crate::layout::pass::LayoutPass;
 use crate::layout::types::layout_mode::GridDefId;
 use crate::primitives::span::Span;
@@ -37,6 +40,13 @@ pub(super) fn arrange_inner(
         return;
     }
 
+    let grid_sizing = tree.records.layout()[node.idx()].size;
+    let scratch = pass.grid_mut().depth_stack.at(depth);
+    let mut col_buf = std::mem::take(&mut scratch.col.tracks);
+    let mut row_buf = std::mem::take(&mut scratch.row.tracks);
+    let col_tracks = percent_resolved(col_tracks, grid_sizing.w(), inner.size.w, &mut col_buf);
+    let row_tracks = percent_resolved(row_tracks, grid_sizing.h(), inner.size.h, &mut row_buf);
+
     // Resolve track sizes (Fixed + Hug + Fill) and compute offsets.
     // Fast path: when measure already resolved this axis against the
     // same `total` (recorded in `track_state.total_used`), copy the persisted
@@ -64,8 +74,10 @@ pub(super) fn arrange_inner(
             .resolve_or_reuse(row_tracks, track_state, idx, Axis::Y, inner.size.h, row_gap);
         track_offsets(&s.col.sizes, col_gap, &mut s.col.offsets);
         track_offsets(&s.row.sizes, row_gap, &mut s.row.offsets);
+        (s.col.tracks, s.row.tracks) = (col_buf, row_buf);
     }
 
+    let any_baseline = collect_row_baselines(pass, node, depth, n_rows);
     let parent_child_align = tree.panel(node).child_align;
     let layouts = tree.records.layout();
     for child in tree.children(node) {
@@ -96,10 +108,12 @@ pub(super) fn arrange_inner(
 
         // Grid's default alignment stretches non-Fixed children to their cell.
         let align = AxisAlignPair::resolve(&s_node, parent_child_align).or_stretch_if_auto();
-        pass.arrange(
-            c,
-            AxisPlacement::arrange_rect(align, &s_node, bounds, d, slot),
-        );
+        let mut rect = AxisPlacement::arrange_rect(align, &s_node, bounds, d, slot);
+        if any_baseline && cell.row_span == 1 && aligns_on_baseline(&s_node, parent_child_align) {
+            let line = pass.grid_mut().depth_stack.at(depth).row_baselines[cell.row as usize].above;
+            rect.min.y = slot.min.y + line - pass.baseline(c);
+        }
+        pass.arrange(c, rect);
     }
 }
 
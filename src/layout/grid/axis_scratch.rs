@@ -27,6 +27,11 @@ pub(super) struct AxisScratch {
     pub(super) offsets: Vec<f32>,
     pub(super) flexible: Vec<usize>,
     pub(super) hug_bounds: Vec<HugBound>,
+    /// This axis's tracks with percent sizes resolved, filled by
+    /// [`percent_resolved`] only when the grid has one. Taken out for the
+    /// span of a measure or arrange so the slice can outlive the `&mut`
+    /// borrows of the pass the children recurse through.
+    pub(super) tracks: Vec<Track>,
 }
 
 /// The per-track content range one axis solves against: `min[i]` is
@@ -294,3 +299,33 @@ impl AxisScratch {
         }
     }
 }
+
+/// `tracks` with every percent size resolved against the grid's inner
+/// `extent` on their axis — a Fixed share of it, or Hug when the grid
+/// hugs that axis — copied into `buf` when there is one to resolve, the
+/// tree's slice untouched when there is not.
+///
+/// A Hug grid's extent is not final until its tracks are, so its percent
+/// tracks hug: the same rule that keeps measure from committing its Fill
+/// tracks, and what lets arrange resolve them as measure did.
+pub(super) fn percent_resolved<'t>(
+    tracks: &'t [Track],
+    grid_sizing: Sizing,
+    extent: f32,
+    buf: &'t mut Vec<Track>,
+) -> &'t [Track] {
+    if !tracks.iter().any(|t| t.size.percent_value().is_some()) {
+        return tracks;
+    }
+    let basis = if grid_sizing.is_hug() {
+        f32::INFINITY
+    } else {
+        extent
+    };
+    buf.clear();
+    buf.extend(tracks.iter().map(|t| Track {
+        size: t.size.of_basis(basis),
+        ..*t
+    }));
+    buf
+}
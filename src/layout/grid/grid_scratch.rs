@@ -1,6 +1,9 @@
 //! One nesting depth's worth of per-axis grid scratch.
 
+use crate::layout::baseline::{BaselineGroup, aligns_on_baseline};
 use crate::layout::grid::axis_scratch::AxisScratch;
+use crate::layout::pass::LayoutPass;
+use crate::scene::tree::node_id::NodeId;
 
 /// One grid's two axes of per-frame scratch. Capacity is retained
 /// across frames;
@@ -10,4 +13,37 @@ use crate::layout::grid::axis_scratch::AxisScratch;
 pub(super) struct GridScratch {
     pub(super) col: AxisScratch,
     pub(super) row: AxisScratch,
+    /// Per row, the line its span-1 baseline cells share — empty for a
+    /// row with none. Filled by [`collect_row_baselines`].
+    pub(super) row_baselines: Vec<BaselineGroup>,
+}
+
+/// Fold every measured span-1 cell of `node` that aligns on its baseline
+/// into its row's group at `depth`, returning whether any did. Multi-row
+/// cells are left out, as they are of row Hug sizing: their height is
+/// spread across rows rather than sitting on one line.
+pub(super) fn collect_row_baselines(
+    pass: &mut LayoutPass<'_>,
+    node: NodeId,
+    depth: usize,
+    n_rows: usize,
+) -> bool {
+    let tree = pass.tree;
+    let parent_child_align = tree.panel(node).child_align;
+    let layouts = tree.records.layout();
+    let groups = &mut pass.grid_mut().depth_stack.at(depth).row_baselines;
+    groups.clear();
+    groups.resize(n_rows, BaselineGroup::default());
+    let mut any = false;
+    for c in tree.active_children(node) {
+        let cell = tree.bounds(c).grid;
+        if cell.row_span != 1 || !aligns_on_baseline(&layouts[c.idx()], parent_child_align) {
+            continue;
+        }
+        let (baseline, height) = (pass.baseline(c), pass.desired(c).h);
+        pass.grid_mut().depth_stack.at(depth).row_baselines[cell.row as usize]
+            .add(baseline, height);
+        any = true;
+    }
+    any
 }
@@ -1,7 +1,9 @@
 //! What a grid asks of its children, and the size that falls out.
 
 use crate::layout::axis::Axis;
+use crate::layout::grid::axis_scratch::percent_resolved;
 use crate::layout::grid::grid_context::GridContext;
+use crate::layout::grid::grid_scratch::collect_row_baselines;
 use crate::layout::grid::grid_track_store::HugKind;
 use crate::layout::intrinsic::LenReq;
 use crate::layout::pass::LayoutPass;
@@ -42,6 +44,15 @@ pub(super) fn measure_inner(
         return Size::ZERO;
     }
 
+    let grid_sizing = tree.records.layout()[node.idx()].size;
+    let grid_sizing_w = grid_sizing.w();
+    let grid_sizing_h = grid_sizing.h();
+    let scratch = pass.grid_mut().depth_stack.at(depth);
+    let mut col_buf = std::mem::take(&mut scratch.col.tracks);
+    let mut row_buf = std::mem::take(&mut scratch.row.tracks);
+    let col_tracks = percent_resolved(col_tracks, grid_sizing_w, inner_avail.w, &mut col_buf);
+    let row_tracks = percent_resolved(row_tracks, grid_sizing_h, inner_avail.h, &mut row_buf);
+
     // Phase 1: query column intrinsics for Hug-column span-1 cells.
     //
     // The second walk over `active_children` this measure — `grid::intrinsic`
@@ -103,9 +114,6 @@ pub(super) fn measure_inner(
     // measure's `inner_avail.w` matches arrange's `inner.w`, so Fill cols
     // at measure time give cells the same width they'll get at arrange —
     // wrap text shapes correctly.
-    let grid_sizing = tree.records.layout()[node.idx()].size;
-    let grid_sizing_w = grid_sizing.w();
-    let grid_sizing_h = grid_sizing.h();
     {
         let GridContext {
             depth_stack,
@@ -179,6 +187,25 @@ pub(super) fn measure_inner(
         }
     }
 
+    // Baseline cells share a line per row, so a row needs the deepest
+    // ascent plus the deepest descent among them, which can exceed every
+    // cell's own height.
+    if collect_row_baselines(pass, node, depth, n_rows) {
+        for row in 0..n_rows {
+            let extent = pass.grid_mut().depth_stack.at(depth).row_baselines[row].extent();
+            let sizing = row_tracks[row].size;
+            let kind = if sizing.is_hug() {
+                HugKind::Max
+            } else if sizing.fill_weight().is_some() {
+                HugKind::Min
+            } else {
+                continue;
+            };
+            let hugs = pass.grid_track_state_mut().slice_mut(idx, Axis::Y, kind);
+            hugs[row] = hugs[row].max(extent);
+        }
+    }
+
     // Resolve row heights. Shares `resolve_axis` with the col pass, so
     // Phase 4 still runs — but the row `resolved` marking is inert here:
     // its only reader (`known_span_size` in Phase 2) has already run,
@@ -210,6 +237,7 @@ pub(super) fn measure_inner(
         sum_non_fill(col_tracks, &s.col.sizes) + col_gap * n_cols.saturating_sub(1) as f32;
     let total_h =
         sum_non_fill(row_tracks, &s.row.sizes) + row_gap * n_rows.saturating_sub(1) as f32;
+    (s.col.tracks, s.row.tracks) = (col_buf, row_buf);
     Size::new(total_w, total_h)
 }
 
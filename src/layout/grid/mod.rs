@@ -69,6 +69,7 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, idx:
 ///   the same axis, clamped to `[Track.min, Track.max]`.
 /// - `Fill(_)`: same content floor as Hug; weight is ignored until
 ///   distribution.
+/// - percent: Hug — the query has no grid extent to take a share of.
 ///
 /// Span > 1 cells are excluded, matching `measure`.
 pub(super) fn intrinsic(
@@ -1,12 +1,15 @@
 //! Placing a child inside its resolved cell, and the depth stack that
 //! brackets the walk.
 
+use crate::TextStyle;
 use crate::layout::grid::grid_depth_stack::GridDepthStack;
+use crate::layout::types::align::{Align, VAlign};
 use crate::layout::types::track::Track;
 use crate::primitives::widget_id::WidgetId;
+use crate::scene::layer::Layer;
 use crate::scene::node::Configure;
 use crate::ui::harness::UiHarness;
-use crate::widgets::{frame::Frame, grid::Grid};
+use crate::widgets::{frame::Frame, grid::Grid, text::Text};
 use glam::UVec2;
 
 #[test]
@@ -37,6 +40,60 @@ fn grid_cell_alignment_override_pins_child_to_corner() {
     assert_eq!(r.min.y, 80.0);
 }
 
+/// Pin: a row's baseline cells share a line, under real shaping. A
+/// 14 px label beside a 28 px value in a two-row form lines up on the
+/// value's baseline; the second row's line is its own, starting below
+/// the first row's full ascent + descent.
+#[test]
+fn grid_baseline_cells_share_their_rows_line() {
+    let value = TextStyle::default().with_font_size(28.0);
+    let label = TextStyle::default().with_font_size(14.0);
+    let id = |name: &str, row: u16| WidgetId::from_hash((name, row));
+    let mut h = UiHarness::with_text(UVec2::new(400, 200));
+    let _ = h.frame_value(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::hug(), Track::hug()])
+            .rows([Track::hug(), Track::hug()])
+            .child_align(Align::v(VAlign::Baseline))
+            .show(ui, |ui| {
+                for row in 0..2 {
+                    Text::new("Width")
+                        .id(id("label", row))
+                        .style(&label)
+                        .grid_cell((row, 0))
+                        .show(ui);
+                    Text::new("120")
+                        .id(id("value", row))
+                        .style(&value)
+                        .grid_cell((row, 1))
+                        .show(ui);
+                }
+            })
+            .response
+            .node()
+    });
+    let baseline_y = |name: &str, row: u16| {
+        let node = h.node_for_widget_id(id(name, row));
+        let layout = h.ui.layout(Layer::Main);
+        let span = layout.text_spans[node.idx()];
+        layout.rect[node.idx()].min.y + layout.text_shapes[span.start as usize].baseline
+    };
+    for row in 0..2 {
+        assert!((baseline_y("label", row) - baseline_y("value", row)).abs() < 1e-3);
+        let (label, value) = (
+            h.layout_rect(id("label", row)).unwrap(),
+            h.layout_rect(id("value", row)).unwrap(),
+        );
+        assert!(label.min.y > value.min.y, "row {row}: the label drops");
+    }
+    let first_value = h.layout_rect(id("value", 0)).unwrap();
+    assert_eq!(
+        h.layout_rect(id("value", 1)).unwrap().min.y,
+        first_value.max().y
+    );
+}
+
 #[test]
 #[should_panic(expected = "GridDepthStack::exit underflow")]
 fn grid_depth_stack_rejects_exit_without_enter() {
@@ -3,5 +3,6 @@
 mod cells;
 mod degenerate;
 mod hug_grid;
+mod placement;
 mod spans;
 mod tracks;
@@ -0,0 +1,207 @@
+//! Cells the grid resolves while it records: auto-flow, named areas, and
+//! auto-fill columns.
+
+use crate::layout::types::grid_template::GridFlow;
+use crate::layout::types::sizing::Sizing;
+use crate::layout::types::track::Track;
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::Ui;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{frame::Frame, grid::Grid};
+use glam::UVec2;
+
+fn tile(name: &str, n: u16) -> Frame {
+    Frame::new()
+        .id(WidgetId::from_hash((name, n)))
+        .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+}
+
+fn origin(h: &UiHarness, name: &str, n: u16) -> (f32, f32) {
+    let r: Rect = h
+        .layout_rect(WidgetId::from_hash((name, n)))
+        .expect("arranged");
+    (r.min.x, r.min.y)
+}
+
+#[test]
+fn row_flow_fills_rows_and_appends_more() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::fixed(20.0); 3])
+            .auto_flow(GridFlow::Row)
+            .auto_track(Track::fixed(30.0))
+            .show(ui, |ui| {
+                for n in 0..5 {
+                    tile("t", n).show(ui);
+                }
+            });
+    });
+    let got: Vec<_> = (0..5).map(|n| origin(&h, "t", n)).collect();
+    assert_eq!(
+        got,
+        [
+            (0.0, 0.0),
+            (20.0, 0.0),
+            (40.0, 0.0),
+            (0.0, 30.0),
+            (20.0, 30.0)
+        ]
+    );
+}
+
+#[test]
+fn column_flow_fills_columns_and_appends_more() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .rows([Track::fixed(20.0); 2])
+            .auto_flow(GridFlow::Column)
+            .auto_track(Track::fixed(30.0))
+            .show(ui, |ui| {
+                for n in 0..3 {
+                    tile("t", n).show(ui);
+                }
+            });
+    });
+    let got: Vec<_> = (0..3).map(|n| origin(&h, "t", n)).collect();
+    assert_eq!(got, [(0.0, 0.0), (0.0, 20.0), (30.0, 0.0)]);
+}
+
+/// An explicit cell is claimed before any child flows, even one recorded
+/// after the flowed children; an explicit cell past the declared rows
+/// grows the grid like a flowed one does.
+#[test]
+fn explicit_cells_are_claimed_before_flowing() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::fixed(20.0); 2])
+            .auto_flow(GridFlow::Row)
+            .auto_track(Track::fixed(30.0))
+            .show(ui, |ui| {
+                tile("t", 0).show(ui);
+                tile("t", 1).show(ui);
+                tile("pinned", 0).grid_cell((0, 1)).show(ui);
+                tile("pinned", 1).grid_cell((3, 0)).show(ui);
+            });
+    });
+    assert_eq!(origin(&h, "t", 0), (0.0, 0.0));
+    assert_eq!(origin(&h, "t", 1), (0.0, 30.0));
+    assert_eq!(origin(&h, "pinned", 0), (20.0, 0.0));
+    assert_eq!(origin(&h, "pinned", 1), (0.0, 90.0));
+}
+
+/// A child too wide for the rest of a row leaves a hole: the sparse flow
+/// never goes back for it, the dense flow hands it to the next child
+/// that fits.
+#[test]
+fn dense_flow_backfills_holes_sparse_flow_skips() {
+    for (flow, small_at) in [
+        (GridFlow::Row, (40.0, 20.0)),
+        (GridFlow::RowDense, (40.0, 0.0)),
+    ] {
+        let mut h = UiHarness::new(UVec2::new(400, 400));
+        h.frame(|ui| {
+            Grid::new()
+                .auto_id()
+                .cols([Track::fixed(20.0); 3])
+                .auto_flow(flow)
+                .auto_track(Track::fixed(20.0))
+                .show(ui, |ui| {
+                    tile("t", 0).show(ui);
+                    tile("t", 1).show(ui);
+                    tile("wide", 0).grid_span((1, 2)).show(ui);
+                    tile("t", 2).show(ui);
+                });
+        });
+        assert_eq!(origin(&h, "wide", 0), (0.0, 20.0), "{flow:?}");
+        assert_eq!(origin(&h, "t", 2), small_at, "{flow:?}");
+    }
+}
+
+#[test]
+fn named_areas_place_and_span_their_children() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::fixed(100.0), Track::fixed(200.0)])
+            .rows([Track::fixed(40.0), Track::fixed(300.0)])
+            .areas(["header header", "nav    main"])
+            .show(ui, |ui| {
+                for area in ["main", "nav", "header"] {
+                    Frame::new()
+                        .id(WidgetId::from_hash(area))
+                        .size((Sizing::FILL, Sizing::FILL))
+                        .grid_area(area)
+                        .show(ui);
+                }
+            });
+    });
+    let rect = |area: &str| h.layout_rect(WidgetId::from_hash(area)).unwrap();
+    assert_eq!(rect("header"), Rect::new(0.0, 0.0, 300.0, 40.0));
+    assert_eq!(rect("nav"), Rect::new(0.0, 40.0, 100.0, 300.0));
+    assert_eq!(rect("main"), Rect::new(100.0, 40.0, 200.0, 300.0));
+}
+
+#[test]
+#[should_panic(expected = "rectangles")]
+#[cfg(debug_assertions)]
+fn non_rectangular_area_panics() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    h.frame(|ui| {
+        Grid::new()
+            .auto_id()
+            .cols([Track::fixed(10.0); 2])
+            .rows([Track::fixed(10.0); 2])
+            .areas(["a a", "a b"])
+            .show(ui, |_| {});
+    });
+}
+
+/// An auto-fill gallery takes as many columns as its width fits, and a
+/// resize across a column boundary lays the new count out on the frame
+/// that crossed it.
+#[test]
+fn auto_fill_columns_follow_the_grid_width() {
+    let gallery = |ui: &mut Ui| {
+        Grid::new()
+            .id(WidgetId::from_hash("gallery"))
+            .size((Sizing::FILL, Sizing::HUG))
+            .cols_auto_fill(Track::fill().min(100.0))
+            .gap(10.0)
+            .auto_flow(GridFlow::Row)
+            .show(ui, |ui| {
+                for n in 0..4 {
+                    Frame::new()
+                        .id(WidgetId::from_hash(("t", n)))
+                        .size((Sizing::FILL, Sizing::fixed(50.0)))
+                        .show(ui);
+                }
+            });
+    };
+    let mut h = UiHarness::new(UVec2::new(330, 400));
+    h.frame(gallery);
+    // (330 + 10) / (100 + 10) → 3 columns of (330 - 20) / 3.
+    let first = h.layout_rect(WidgetId::from_hash(("t", 0))).unwrap();
+    assert!((first.size.w - 310.0 / 3.0).abs() < 1e-3, "{first:?}");
+    assert_eq!(origin(&h, "t", 3), (0.0, 60.0));
+
+    h.resize(UVec2::new(450, 400));
+    h.frame(gallery);
+    assert_eq!(
+        h.layout_rect(WidgetId::from_hash(("t", 0))).unwrap().size.w,
+        105.0,
+    );
+    assert_eq!(origin(&h, "t", 3), (345.0, 0.0));
+
+    h.resize(UVec2::new(50, 400));
+    h.frame(gallery);
+    assert_eq!(origin(&h, "t", 1), (0.0, 60.0));
+}
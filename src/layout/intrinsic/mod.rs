@@ -11,6 +11,7 @@
 //! alongside that driver's `measure`/`arrange` in its own module — same
 //! per-driver-file convention as the rest of layout.
 
+use crate::layout::aspect_ratio::AspectRatio;
 use crate::layout::axis::Axis;
 use crate::layout::axis_ctx::AxisCtx;
 use crate::layout::engine::LayoutEngine;
@@ -18,6 +19,7 @@ use crate::layout::text_shape_input::TextShapeInput;
 use crate::layout::types::layout_mode::LayoutMode;
 use crate::layout::{canvas, grid, scroll, scrollbars, stack, wrapstack, zstack};
 use crate::primitives::interned_text::InternedText;
+use crate::primitives::size::Size;
 use crate::scene::node::layout_core::LayoutCore;
 use crate::scene::tree::Tree;
 use crate::scene::tree::node_id::NodeId;
@@ -236,6 +238,28 @@ pub(crate) fn compute(
         return IntrinsicRange::ZERO;
     }
     let bounds = tree.bounds(node);
+    // The query is of the subtree alone, so a percent axis has no offer
+    // to take a share of and hugs, as it does on a scrolling axis.
+    let layout = LayoutCore {
+        size: layout.size.of_basis(Size::INF),
+        ..layout
+    };
+
+    // An aspect-ratio node's dependent axis answers from its driving
+    // axis's answer to the same query, the way measure derives it — a
+    // Fixed driver makes it exact, otherwise it tracks the driver's
+    // content.
+    if let Some(aspect) = AspectRatio::of(layout.size, bounds)
+        && aspect.dependent() == axis
+    {
+        let driver = aspect.driver();
+        let mut range = query.child(engine, tree, node, driver, interned_text);
+        let (driver_margin, margin) = (driver.spacing(layout.margin), axis.spacing(layout.margin));
+        for (_, value) in range.requested(query) {
+            *value = aspect.derive(*value - driver_margin, bounds) + margin;
+        }
+        return range;
+    }
 
     let sizing = axis.main_sizing(layout.size);
     let margin = axis.spacing(layout.margin);
@@ -335,9 +359,14 @@ fn leaf(
             ts.shape_request(),
             ts.wrap,
         );
+        // A fitted run can shrink to its floor before it has to wrap or
+        // cut, so that is where its min-content is read.
+        let floor = ts
+            .fit
+            .map(|fit| engine.text.fit_floor_root(ts.shape_request(), fit, ts.wrap));
         for (req, slot) in range.requested(query) {
             let run = match req {
-                LenReq::MinContent => ts.wrap.min_content(&unbounded),
+                LenReq::MinContent => ts.wrap.min_content(floor.as_ref().unwrap_or(&unbounded)),
                 LenReq::MaxContent => ts.wrap.max_content(&unbounded),
             };
             *slot = slot.max(axis.main(run));
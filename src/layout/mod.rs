@@ -1,7 +1,9 @@
+pub(crate) mod aspect_ratio;
 pub(crate) mod axis;
 pub(crate) mod axis_align_pair;
 pub(crate) mod axis_ctx;
 pub(crate) mod axis_placement;
+mod baseline;
 pub(crate) mod cache;
 mod canvas;
 pub(crate) mod counters;
@@ -85,6 +87,14 @@ impl Layout {
         self.layers[endpoint.layer].scroll_content[endpoint.node.idx()]
     }
 
+    /// Whether any text run the node at `endpoint` owns hit its line clamp.
+    /// Keyed by [`Endpoint`] for the reason [`Self::scroll_content`] is.
+    pub(crate) fn text_clamped(&self, endpoint: Endpoint) -> bool {
+        let layer = &self.layers[endpoint.layer];
+        let span = layer.text_spans[endpoint.node.idx()];
+        layer.text_shapes[span.range()].iter().any(|s| s.clamped)
+    }
+
     /// The node's arranged rect — pre-transform, unclipped, in world
     /// coords. Takes an [`Endpoint`] for the same reason
     /// [`Self::scroll_content`] does: this table is keyed by
@@ -121,6 +131,13 @@ impl IndexMut<Layer> for Layout {
 pub(crate) struct ShapedText {
     pub(crate) measured: Size,
     pub(crate) key: TextShapeKey,
+    /// First line's baseline below the top of `measured` — what
+    /// [`VAlign::Baseline`](crate::VAlign::Baseline) lines up.
+    pub(crate) baseline: f32,
+    /// The run hit its [line clamp](crate::TextWrap::Clamp) and lost
+    /// lines. Its own field rather than read off `key`, which the mono
+    /// metric blanks; it sits in what was padding.
+    pub(crate) clamped: bool,
 }
 
 impl LayerLayout {
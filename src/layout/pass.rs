@@ -16,7 +16,7 @@
 //! [`LayoutPass::intrinsic_range`] are one-line forwarders so driver call
 //! sites stay short without widening what the query can touch.
 
-use crate::layout::LayerLayout;
+use crate::layout::aspect_ratio::AspectRatio;
 use crate::layout::axis::Axis;
 use crate::layout::axis_ctx::AxisCtx;
 use crate::layout::cache::quantize_available;
@@ -30,6 +30,7 @@ use crate::layout::stack::StackScratch;
 use crate::layout::text_shape_input::TextShapeInput;
 use crate::layout::types::layout_mode::LayoutMode;
 use crate::layout::wrapstack::WrapScratch;
+use crate::layout::{LayerLayout, ShapedText};
 use crate::layout::{canvas, grid, scroll, scrollbars, stack, wrapstack, zstack};
 use crate::primitives::interned_text::InternedText;
 use crate::primitives::rect::Rect;
@@ -57,6 +58,12 @@ pub(crate) struct LayoutPass<'a> {
     pub(super) tree: &'a Tree,
     pub(super) interned_text: &'a InternedText<'a>,
     out: &'a mut LayerLayout,
+    /// `min.x + max.x` of the inner rect of the node whose children are
+    /// being arranged, when that node is right to left: every slot its
+    /// driver hands [`Self::arrange`] reflects across it. Set per driver
+    /// dispatch and put back after, so it only ever reaches direct
+    /// children.
+    mirror_x: Option<f32>,
 }
 
 impl<'a> LayoutPass<'a> {
@@ -115,6 +122,7 @@ impl<'a> LayoutPass<'a> {
             tree,
             interned_text,
             out,
+            mirror_x: None,
         }
     }
 }
@@ -191,6 +199,14 @@ impl LayoutPass<'_> {
         self.out.rect[node.idx()]
     }
 
+    /// The text runs this node shaped — a leaf's as of its `measure`.
+    /// Read by the baseline query, which needs a leaf's first run.
+    #[inline]
+    pub(super) fn text_shapes_of(&self, node: NodeId) -> &[ShapedText] {
+        let span = self.out.text_spans[node.idx()];
+        &self.out.text_shapes[span.range()]
+    }
+
     /// Fold a closed measure span into this run's probe. The spans are
     /// opened around the root walk, which happens inside the pass, so
     /// they close through it too.
@@ -271,7 +287,14 @@ impl LayoutPass<'_> {
         }
 
         let bounds = tree.bounds(node);
-        let (min_size, max_size) = (bounds.min_size, bounds.max_size);
+        // Percent axes take their share of this offer before anything
+        // reads the sizing — Fixed from here on, or Hug under an
+        // unbounded offer. `available` is already in the cache key, so
+        // the share is keyed with it.
+        let layout = LayoutCore {
+            size: layout.size.of_basis(available),
+            ..layout
+        };
 
         // Min-content intrinsic — the smallest this node can shrink
         // to without breaking a rigid descendant (Fixed widget,
@@ -304,14 +327,10 @@ impl LayoutPass<'_> {
         // content into a margin-inclusive `desired`. `AxisCtx::resolve_node`
         // contains the rationale for each step (intrinsic_min floor,
         // outer clamp to `[min, max]`, single-dispatch monotonicity).
-        let desired = AxisCtx::resolve_node(
-            layout,
-            available,
-            intrinsic_min,
-            min_size,
-            max_size,
-            |inner_avail| self.measure_dispatch(node, layout, inner_avail),
-        );
+        let desired =
+            AxisCtx::resolve_node(layout, available, intrinsic_min, bounds, |inner_avail| {
+                self.measure_dispatch(node, layout, inner_avail)
+            });
 
         self.engine.scratch.desired[node.idx()] = desired;
 
@@ -373,7 +392,7 @@ impl LayoutPass<'_> {
             LayoutMode::Leaf => {
                 let (tree, interned_text) = (self.tree, self.interned_text);
                 let runs = TextShapeInput::on_leaf(tree, interned_text, node);
-                self.shape_text_runs(node, inner_avail.w, runs)
+                self.shape_text_runs(node, inner_avail, runs)
             }
             LayoutMode::HStack => stack::measure(self, node, inner_avail, Axis::X),
             LayoutMode::VStack => stack::measure(self, node, inner_avail, Axis::Y),
@@ -396,11 +415,26 @@ impl LayoutPass<'_> {
     pub(super) fn arrange(&mut self, node: NodeId, slot: Rect) {
         let tree = self.tree;
         let layout = tree.records.layout()[node.idx()];
+        // A right-to-left parent's driver arranged this slot left to
+        // right; reflecting it is the whole of mirroring. The margin
+        // deflated next already had its sides traded at record time.
+        let slot = match self.mirror_x {
+            Some(axis) => Rect {
+                min: Vec2::new(axis - slot.max().x, slot.min.y),
+                size: slot.size,
+            },
+            None => slot,
+        };
         if layout.meta.visibility().is_collapsed() {
             self.zero_subtree(node, slot.min);
             return;
         }
         let rendered = slot.deflated_by(layout.margin);
+        // The parent decided the driving extent; the other follows it,
+        // whatever the slot offered on that axis.
+        let bounds = tree.bounds(node);
+        let rendered = AspectRatio::of(layout.size, bounds)
+            .map_or(rendered, |aspect| aspect.apply_rect(rendered, bounds));
         let mode = LayoutMode::from(layout.meta);
         // Replay's precondition is that arrange is a pure function of the
         // slot (see `replay_arranged`). `Scrollbars` is the one driver
@@ -415,6 +449,10 @@ impl LayoutPass<'_> {
         }
         self.out.rect[node.idx()] = rendered;
         let inner = rendered.deflated_by(layout.padding);
+        let parent_mirror = self.mirror_x;
+        self.mirror_x = tree.records.attrs()[node.idx()]
+            .is_rtl()
+            .then(|| inner.min.x + inner.max().x);
 
         match mode {
             LayoutMode::Leaf => {}
@@ -431,6 +469,7 @@ impl LayoutPass<'_> {
                 scrollbars::arrange(self, node, inner, def);
             }
         }
+        self.mirror_x = parent_mirror;
     }
 
     /// Replay a measure-cache-hit subtree's arranged rects instead of
@@ -499,16 +538,19 @@ impl LayoutPass<'_> {
     /// Shape every text run `runs` yields for `node`, append them to the
     /// frame's flat buffer, and stamp the covering span. Returns the
     /// largest run's content size — a leaf's text contribution.
+    ///
+    /// Width is what wrapping and truncation bind to; height only matters
+    /// to a [fitted](crate::text::fit) run, which shrinks to stay inside it.
     pub(super) fn shape_text_runs<'t>(
         &mut self,
         node: NodeId,
-        available_w: f32,
+        available: Size,
         runs: impl Iterator<Item = TextShapeInput<'t>>,
     ) -> Size {
         let span_start = self.out.text_shapes.len() as u32;
         let mut s = Size::ZERO;
         for ts in runs {
-            let m = self.shape_text(node, &ts, available_w);
+            let m = self.shape_text(node, &ts, available);
             s = s.max(m);
         }
         let span_len = self.out.text_shapes.len() as u32 - span_start;
@@ -519,20 +561,30 @@ impl LayoutPass<'_> {
         s
     }
 
-    fn shape_text(&mut self, node: NodeId, ts: &TextShapeInput<'_>, available_w: f32) -> Size {
+    fn shape_text(&mut self, node: NodeId, ts: &TextShapeInput<'_>, available: Size) -> Size {
         let wid = self.tree.records.widget_id()[node.idx()];
         let slot = TextRunSlot {
             widget_id: wid,
             ordinal: ts.ordinal,
         };
 
-        let shaped = self.engine.text.measure(
-            slot,
-            ts.shape_request(),
-            ts.wrap,
-            ts.halign,
-            available_w.is_finite().then_some(available_w),
-        );
+        let shaped = match ts.fit {
+            Some(fit) => self.engine.text.measure_fit(
+                slot,
+                ts.shape_request(),
+                fit,
+                ts.wrap,
+                ts.halign,
+                available,
+            ),
+            None => self.engine.text.measure(
+                slot,
+                ts.shape_request(),
+                ts.wrap,
+                ts.halign,
+                available.w.is_finite().then_some(available.w),
+            ),
+        };
 
         self.out.text_shapes.push(shaped);
         ts.wrap.content_size(shaped.measured)
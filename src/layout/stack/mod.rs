@@ -1,5 +1,6 @@
 use crate::layout::axis::Axis;
 use crate::layout::axis_placement::AxisPlacement;
+use crate::layout::baseline::aligns_on_baseline;
 use crate::layout::engine::LayoutEngine;
 use crate::layout::intrinsic::{IntrinsicQuery, IntrinsicRange, LenReq};
 use crate::layout::justify_offsets::JustifyOffsets;
@@ -15,9 +16,9 @@ use crate::scene::tree::node_id::NodeId;
 /// the call. `frozen_alloc = Some(v)` means this child has been
 /// removed from the active pool and gets exactly `v` main-axis space.
 #[derive(Clone, Copy, Debug)]
-struct FillEntry {
-    node: NodeId,
-    weight: f32,
+pub(super) struct FillEntry {
+    pub(super) node: NodeId,
+    pub(super) weight: f32,
     /// Minimum main-axis extent this entry will accept. The freeze
     /// loop pins an entry at `floor` when its weighted share would be
     /// lower. Source depends on phase: `measure` uses the child's
@@ -26,9 +27,9 @@ struct FillEntry {
     /// Stretch this is the child's content size). Invariant:
     /// arrange-floor ≥ measure-floor for the same child, since
     /// `AxisCtx::resolve` floors `desired` by `intrinsic_min`.
-    floor: f32,
-    cap: f32,
-    frozen_alloc: Option<f32>,
+    pub(super) floor: f32,
+    pub(super) cap: f32,
+    pub(super) frozen_alloc: Option<f32>,
 }
 
 /// Distribute `leftover` across the Fill entries by weight, with
@@ -50,7 +51,14 @@ struct FillEntry {
 /// one driver's edge-case results. `cross_driver_tests/fill_solvers.rs`
 /// pins that difference, which makes merging them a decision about what
 /// `Sizing::fill` should mean rather than a refactor.
-fn freeze_distribute(entries: &mut [FillEntry], mut leftover: f32, mut active_weight: f64) {
+///
+/// `wrapstack` runs it too, once per line at arrange — the same rule, with
+/// the line as the stack.
+pub(super) fn freeze_distribute(
+    entries: &mut [FillEntry],
+    mut leftover: f32,
+    mut active_weight: f64,
+) {
     loop {
         if active_weight <= 0.0 {
             break;
@@ -109,9 +117,29 @@ impl StackScratch {
     /// Entries this depth pushed, as a mutable slice. `start` is the
     /// pool length the caller captured on entry.
     #[inline]
-    fn from(&mut self, start: usize) -> &mut [FillEntry] {
+    pub(super) fn from(&mut self, start: usize) -> &mut [FillEntry] {
         &mut self.pool[start..]
     }
+
+    #[inline]
+    pub(super) fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    #[inline]
+    pub(super) fn at(&self, index: usize) -> FillEntry {
+        self.pool[index]
+    }
+
+    #[inline]
+    pub(super) fn push(&mut self, entry: FillEntry) {
+        self.pool.push(entry);
+    }
+
+    #[inline]
+    pub(super) fn truncate(&mut self, len: usize) {
+        self.pool.truncate(len);
+    }
 }
 
 #[derive(Debug)]
@@ -270,6 +298,15 @@ pub(super) fn measure(
     }
     pass.stack_scratch_mut().pool.truncate(fill_start);
 
+    // Baseline children stack their ascents and descents rather than
+    // their heights: a short label whose baseline sits low next to a
+    // tall field can hang below the field's bottom.
+    if axis == Axis::X
+        && let Some(group) = pass.baseline_group(node)
+    {
+        max_cross = max_cross.max(group.extent());
+    }
+
     axis.compose_size(sum_non_fill_main + fill_main + total_gap, max_cross)
 }
 
@@ -337,6 +374,10 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis
     } = JustifyOffsets::new(justify, leftover_for_justify, gap, count);
 
     let cross_min = axis.cross_v(inner.min);
+    let baseline_line = match axis {
+        Axis::X => pass.baseline_group(node).map(|group| group.above),
+        Axis::Y => None,
+    };
     let mut cursor = axis.main_v(inner.min) + start_offset;
     let mut first = true;
     let mut fill_cursor = fill_start;
@@ -368,7 +409,12 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis
         };
 
         let bounds = tree.bounds(c);
-        let cross_p = AxisPlacement::cross(axis, &s, bounds, parent_child_align, d, cross);
+        let mut cross_p = AxisPlacement::cross(axis, &s, bounds, parent_child_align, d, cross);
+        if let Some(line) = baseline_line
+            && aligns_on_baseline(&s, parent_child_align)
+        {
+            cross_p.offset = line - pass.baseline(c);
+        }
 
         let child_rect =
             axis.compose_rect(cursor, cross_min + cross_p.offset, main_size, cross_p.size);
@@ -1,3 +1,4 @@
+use crate::TextStyle;
 use crate::layout::axis::Axis;
 use crate::layout::types::{
     align::Align,
@@ -9,7 +10,7 @@ use crate::primitives::widget_id::WidgetId;
 use crate::scene::layer::Layer;
 use crate::scene::node::Configure;
 use crate::ui::harness::UiHarness;
-use crate::widgets::{button::Button, frame::Frame, panel::Panel};
+use crate::widgets::{button::Button, frame::Frame, panel::Panel, text::Text};
 use glam::UVec2;
 
 #[test]
@@ -675,3 +676,94 @@ fn hstack_child_align_per_axis_with_overrides() {
         assert_eq!(b.size.h, 20.0, "case: {label} b.size.h");
     }
 }
+
+/// Where `id`'s first line sits: its arranged top plus the baseline its
+/// leaf run shaped with.
+fn baseline_y(h: &UiHarness, id: &'static str) -> f32 {
+    let node = h.node_for_widget_id(WidgetId::from_hash(id));
+    let layout = h.ui.layout(Layer::Main);
+    let span = layout.text_spans[node.idx()];
+    layout.rect[node.idx()].min.y + layout.text_shapes[span.start as usize].baseline
+}
+
+fn layout_rect(h: &UiHarness, id: &'static str) -> Rect {
+    h.layout_rect(WidgetId::from_hash(id)).unwrap()
+}
+
+/// Pin: a 16 px label and a 32 px one share a baseline, the label
+/// dropping to meet the larger run's. A text-free 40-high box sits its
+/// bottom edge on the line, and since the box rises above the big run,
+/// the stack grows to the box plus the big run's descent — taller than
+/// any one child.
+#[test]
+fn hstack_baseline_children_share_one_line() {
+    let big = TextStyle::default().with_font_size(32.0);
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    let root = h.frame_value(|ui| {
+        Panel::hstack()
+            .auto_id()
+            .child_align(Align::v(VAlign::Baseline))
+            .show(ui, |ui| {
+                Text::new("small").id(WidgetId::from_hash("small")).show(ui);
+                Text::new("big")
+                    .id(WidgetId::from_hash("big"))
+                    .style(&big)
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("box"))
+                    .size((Sizing::fixed(10.0), Sizing::fixed(40.0)))
+                    .show(ui);
+            })
+            .response
+            .node()
+    });
+    let line = baseline_y(&h, "big");
+    assert!((baseline_y(&h, "small") - line).abs() < 1e-3);
+    assert!(layout_rect(&h, "small").min.y > layout_rect(&h, "big").min.y);
+
+    let boxed = layout_rect(&h, "box");
+    assert_eq!((boxed.min.y, boxed.max().y), (0.0, line));
+    let descent = layout_rect(&h, "big").max().y - line;
+    let stack = h.ui.arranged_rect(Layer::Main, root);
+    assert!((stack.size.h - (40.0 + descent)).abs() < 1e-3);
+}
+
+/// Pin: a container reports its first child's baseline, past its own
+/// padding — a padded chip lines its label up with a bare one beside
+/// it. A child's own `VAlign::Baseline` opts in without the parent's
+/// `child_align`, and a sibling left at `Auto` stays at the top.
+#[test]
+fn a_container_aligns_on_its_first_childs_baseline() {
+    let big = TextStyle::default().with_font_size(32.0);
+    let mut h = UiHarness::new(UVec2::new(400, 200));
+    let _ = h.frame_value(|ui| {
+        Panel::hstack()
+            .auto_id()
+            .show(ui, |ui| {
+                Panel::zstack()
+                    .id(WidgetId::from_hash("chip"))
+                    .padding(8.0)
+                    .align(Align::v(VAlign::Baseline))
+                    .show(ui, |ui| {
+                        Text::new("chip").id(WidgetId::from_hash("label")).show(ui);
+                    });
+                Text::new("big")
+                    .id(WidgetId::from_hash("big"))
+                    .style(&big)
+                    .align(Align::v(VAlign::Baseline))
+                    .show(ui);
+                Frame::new()
+                    .id(WidgetId::from_hash("top"))
+                    .size((Sizing::fixed(10.0), Sizing::fixed(10.0)))
+                    .show(ui);
+            })
+            .response
+            .node()
+    });
+    assert!((baseline_y(&h, "label") - baseline_y(&h, "big")).abs() < 1e-3);
+    assert_eq!(
+        layout_rect(&h, "label").min.y,
+        layout_rect(&h, "chip").min.y + 8.0
+    );
+    assert_eq!(layout_rect(&h, "top").min.y, 0.0);
+}
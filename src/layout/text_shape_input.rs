@@ -6,6 +6,7 @@ use crate::scene::shapes::record::ShapeRecord;
 use crate::scene::tree::Tree;
 use crate::scene::tree::iter::TreeItem;
 use crate::scene::tree::node_id::NodeId;
+use crate::text::fit::TextFit;
 use crate::text::glyph_font::GlyphFont;
 use crate::text::key::TextShapeKey;
 use crate::text::request::TextShapeRequest;
@@ -36,6 +37,8 @@ pub(super) struct TextShapeInput<'a> {
     /// identical text/size/wrap but different halign aren't
     /// interchangeable.
     pub(super) halign: HAlign,
+    /// Shrink-to-fit bounds, `None` for a run that keeps its face.
+    pub(super) fit: Option<TextFit>,
 }
 
 impl<'a> TextShapeInput<'a> {
@@ -115,6 +118,7 @@ fn text_shape_input<'a>(
             font,
             wrap,
             align,
+            fit,
             ..
         } => Some(TextShapeInput {
             ordinal: checked_text_ordinal(ordinal),
@@ -123,6 +127,7 @@ fn text_shape_input<'a>(
             font: *font,
             wrap: *wrap,
             halign: align.halign(),
+            fit: *fit,
         }),
         _ => None,
     }
@@ -169,6 +174,7 @@ mod tests {
             },
             wrap: TextWrap::SingleLine,
             halign: HAlign::Auto,
+            fit: None,
         };
         let request = input(hash::hash_str("hello")).shape_request();
         assert_eq!(request.text(), "hello");
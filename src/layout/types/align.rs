@@ -50,6 +50,17 @@ pub enum VAlign {
     Bottom = 3,
     /// Fill the inner rect's height, overriding the child's measured height.
     Stretch = 4,
+    /// Line up the first text baseline with the siblings that also align
+    /// on it — a label beside a larger-font field reads as one line. An
+    /// HStack aligns its baseline children on a shared line, and a Grid
+    /// its span-1 baseline cells per row; every other parent places a
+    /// baseline child as [`Self::Top`].
+    ///
+    /// A text leaf's baseline is its first line's; a container's is its
+    /// first visible child's, offset by where that child sits. A child
+    /// with no text at all aligns its bottom margin edge on the line, the
+    /// way CSS synthesizes a baseline for a box with none.
+    Baseline = 5,
 }
 
 /// Both alignment enums' discriminants, which [`Align::new`] packs into
@@ -73,6 +84,7 @@ const _: () = {
             && VAlign::Center as u8 == 2
             && VAlign::Bottom as u8 == 3
             && VAlign::Stretch as u8 == 4
+            && VAlign::Baseline as u8 == 5
     );
 };
 
@@ -135,6 +147,7 @@ impl Align {
             2 => VAlign::Center,
             3 => VAlign::Bottom,
             4 => VAlign::Stretch,
+            5 => VAlign::Baseline,
             _ => unreachable!(),
         }
     }
@@ -178,7 +191,7 @@ impl Align {
             HAlign::Right => outer.size.w - content.w,
         };
         let dy = match self.valign() {
-            VAlign::Auto | VAlign::Top | VAlign::Stretch => 0.0,
+            VAlign::Auto | VAlign::Top | VAlign::Stretch | VAlign::Baseline => 0.0,
             VAlign::Center => (outer.size.h - content.h) * 0.5,
             VAlign::Bottom => outer.size.h - content.h,
         };
@@ -245,6 +258,10 @@ impl VAlign {
             VAlign::Center => AxisAlign::Center,
             VAlign::Bottom => AxisAlign::End,
             VAlign::Stretch => AxisAlign::Stretch,
+            // The drivers that align baselines offset the child themselves,
+            // on top of a start-pinned placement; to every other one it is
+            // `Top`.
+            VAlign::Baseline => AxisAlign::Start,
         }
     }
     pub(crate) const fn or(self, default: VAlign) -> VAlign {
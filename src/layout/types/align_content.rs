@@ -0,0 +1,40 @@
+use crate::layout::types::justify::Justify;
+
+/// Cross-axis distribution of the lines of a wrapping stack. Mirrors CSS
+/// `align-content`: the lines' leftover cross-axis space, once every line
+/// has its own cross extent, is handed out the way [`Justify`] hands out a
+/// line's main-axis leftover.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum AlignContent {
+    /// Pack lines to the cross start (top for WrapHStack, left for
+    /// WrapVStack). Default.
+    #[default]
+    Start,
+    /// Pack lines to the center of the cross axis.
+    Center,
+    /// Pack lines to the cross end.
+    End,
+    /// First line at start, last at end, leftover split as equal extra
+    /// line gaps. With <2 lines, behaves like `Start`.
+    SpaceBetween,
+    /// Equal padding around each line, as [`Justify::SpaceAround`].
+    SpaceAround,
+    /// Each line grows by an equal share of the leftover, so cross-axis
+    /// `Fill` children stretch into it.
+    Stretch,
+}
+
+impl AlignContent {
+    /// The offset rule for the lines, with `Stretch` packed from the start
+    /// once the lines have grown into the leftover.
+    pub(crate) fn distribution(self) -> Justify {
+        match self {
+            Self::Start | Self::Stretch => Justify::Start,
+            Self::Center => Justify::Center,
+            Self::End => Justify::End,
+            Self::SpaceBetween => Justify::SpaceBetween,
+            Self::SpaceAround => Justify::SpaceAround,
+        }
+    }
+}
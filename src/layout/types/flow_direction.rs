@@ -0,0 +1,28 @@
+/// Which way a subtree reads horizontally — WPF's `FlowDirection`. Set on
+/// a node with [`Configure::flow_direction`](crate::Configure::flow_direction)
+/// and inherited by every descendant that doesn't set its own, including
+/// the popups and tooltips raised from inside it.
+///
+/// `RightToLeft` mirrors layout rather than re-implementing it: each
+/// driver arranges as it always does and the result is reflected inside
+/// the parent, so stacks run right to left, grid column 0 and canvas
+/// positions measure from the right edge, `HAlign::Left` and
+/// `Justify::Start` mean the leading (right) edge, and `left` / `right`
+/// padding and margins trade sides. Vertical placement is untouched.
+/// Text shaping is separate — this moves boxes, not glyphs.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum FlowDirection {
+    /// Leading edge on the left — the layout every driver computes.
+    #[default]
+    LeftToRight,
+    /// Leading edge on the right: the subtree arranged mirrored.
+    RightToLeft,
+}
+
+impl FlowDirection {
+    #[inline]
+    pub(crate) const fn is_rtl(self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+}
@@ -24,6 +24,78 @@ impl std::hash::Hash for GridCell {
 }
 
 impl GridCell {
+    /// Position marker for a child an auto-flow grid has yet to place:
+    /// a `Node`'s until `grid_cell` sets one, and a recorded child's from
+    /// open until its grid closes. Spans are the child's own.
+    pub(crate) const AUTO_POSITION: u16 = u16::MAX;
+
+    /// Position marker for a child placed by `grid_area`: the area's key
+    /// rides in the two spans, which the area replaces, until the parent
+    /// grid resolves it at record time. Authoring only — it never
+    /// reaches the columns.
+    pub(crate) const AREA_POSITION: u16 = u16::MAX - 1;
+
+    /// A `Node`'s cell before any placement call: left to the parent
+    /// grid, 1×1.
+    pub(crate) const UNPLACED: Self = Self {
+        row: Self::AUTO_POSITION,
+        col: Self::AUTO_POSITION,
+        row_span: 1,
+        col_span: 1,
+    };
+
+    /// A 1×1 cell at track `main` along `axis`, track 0 across it.
+    #[inline]
+    pub(crate) fn on_axis(axis: Axis, main: u16) -> Self {
+        let mut cell = Self::default();
+        cell.set_main(axis, main);
+        cell
+    }
+
+    /// The cell standing for area `key` until its grid resolves it.
+    #[inline]
+    pub(crate) fn area(key: u32) -> Self {
+        Self {
+            row: Self::AREA_POSITION,
+            col: Self::AREA_POSITION,
+            row_span: key as u16,
+            col_span: (key >> 16) as u16,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_auto(&self) -> bool {
+        self.row == Self::AUTO_POSITION && self.col == Self::AUTO_POSITION
+    }
+
+    /// Where an authored cell asks to sit, decoded from its markers.
+    #[inline]
+    pub(crate) fn place(&self) -> GridPlace {
+        match (self.row, self.col) {
+            (Self::AUTO_POSITION, Self::AUTO_POSITION) => GridPlace::Auto,
+            (Self::AREA_POSITION, Self::AREA_POSITION) => {
+                GridPlace::Area(u32::from(self.row_span) | (u32::from(self.col_span) << 16))
+            }
+            _ => GridPlace::Cell,
+        }
+    }
+
+    /// The cell as recorded, before a parent grid resolves it: a position
+    /// left to the grid, or to an area, reads as the origin. Spans an
+    /// area carries its key in fall back to 1×1.
+    #[inline]
+    pub(crate) fn at_origin_if_unplaced(self) -> Self {
+        match self.place() {
+            GridPlace::Cell => self,
+            GridPlace::Auto => Self {
+                row: 0,
+                col: 0,
+                ..self
+            },
+            GridPlace::Area(_) => Self::default(),
+        }
+    }
+
     /// Track-index span on `axis`: `(col, col_span)` for X,
     /// `(row, row_span)` for Y. Bundles the start/length pair the grid
     /// track math slices with, so the two can't be passed swapped.
@@ -46,6 +118,16 @@ impl GridCell {
             Axis::Y => self.row = main,
         }
     }
+
+    /// Span `span` tracks along `axis` — [`Self::set_main`]'s twin for
+    /// the length half of [`Self::track_span`].
+    #[inline]
+    pub(crate) fn set_span(&mut self, axis: Axis, span: u16) {
+        match axis {
+            Axis::X => self.col_span = span,
+            Axis::Y => self.row_span = span,
+        }
+    }
 }
 
 impl Default for GridCell {
@@ -58,3 +140,17 @@ impl Default for GridCell {
         }
     }
 }
+
+/// Where a child asked to sit in its `Grid` parent, as far as the child
+/// can say before it knows that parent — read off the markers of its
+/// authored [`GridCell`] by [`GridCell::place`]. `Auto` defers to the
+/// grid: its auto-flow if it has one, otherwise the cell at the origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum GridPlace {
+    #[default]
+    Auto,
+    /// `Configure::grid_cell` was called.
+    Cell,
+    /// `Configure::grid_area`, by [`area_key`](crate::layout::types::grid_template::area_key).
+    Area(u32),
+}
@@ -0,0 +1,210 @@
+use crate::common::hash::Hasher;
+use crate::layout::axis::Axis;
+use crate::layout::types::grid_cell::GridCell;
+use crate::layout::types::track::Track;
+use crate::primitives::span::Span;
+use std::hash::Hasher as _;
+
+/// How a `Grid` places the children that name neither a cell nor an area
+/// — CSS's `grid-auto-flow`. `Row` fills each row left to right and
+/// appends rows as they run out; `Column` fills each column top to bottom
+/// and appends columns. The `Dense` forms go back for holes an earlier
+/// wide child skipped, so later, smaller children can land out of order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GridFlow {
+    #[default]
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
+
+impl GridFlow {
+    /// The axis children advance along before wrapping — the one whose
+    /// track count is fixed. The other axis grows.
+    #[inline]
+    pub(crate) fn fills(self) -> Axis {
+        match self {
+            GridFlow::Row | GridFlow::RowDense => Axis::X,
+            GridFlow::Column | GridFlow::ColumnDense => Axis::Y,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_dense(self) -> bool {
+        matches!(self, GridFlow::RowDense | GridFlow::ColumnDense)
+    }
+}
+
+/// Everything `Tree::push_grid_def` interns for one `Grid`. The tracks
+/// are borrowed: they are copied into the tree's track arena, `cols`
+/// repeated `col_repeat` times (an auto-fill grid hands over its one
+/// track and the count its width fits).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GridTemplate<'a> {
+    pub(crate) rows: &'a [Track],
+    pub(crate) cols: &'a [Track],
+    pub(crate) col_repeat: usize,
+    pub(crate) row_gap: f32,
+    pub(crate) col_gap: f32,
+    pub(crate) flow: Option<GridFlow>,
+    /// Size of every track auto-flow appends.
+    pub(crate) auto_track: Track,
+    /// Named-area template, one string per row.
+    pub(crate) areas: &'a [&'a str],
+}
+
+impl<'a> GridTemplate<'a> {
+    /// Explicit tracks only: no gaps, no auto-flow, no areas.
+    pub(crate) fn new(rows: &'a [Track], cols: &'a [Track]) -> Self {
+        Self {
+            rows,
+            cols,
+            col_repeat: 1,
+            row_gap: 0.0,
+            col_gap: 0.0,
+            flow: None,
+            auto_track: Track::hug(),
+            areas: &[],
+        }
+    }
+}
+
+/// One named area of a grid template, keyed by [`area_key`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GridArea {
+    pub(crate) key: u32,
+    pub(crate) cell: GridCell,
+}
+
+/// The key a `grid_area` name is matched by. Children carry the key
+/// rather than the name, so `Node` stays `Copy` with no lifetime.
+#[inline]
+pub(crate) fn area_key(name: &str) -> u32 {
+    let mut h = Hasher::new();
+    h.write(name.as_bytes());
+    h.finish() as u32
+}
+
+/// Parse a `"header header" "nav main"` template — one string per row,
+/// whitespace-separated names, `.` for an unnamed cell — appending one
+/// [`GridArea`] per name to `out`. Returns the span of the new rows.
+///
+/// Each name's area is the bounding box of the cells it appears in;
+/// debug builds check that it fills that box (areas are rectangles) and
+/// that every row names the same number of cells.
+pub(crate) fn push_areas(template: &[&str], out: &mut Vec<GridArea>) -> Span {
+    let start = out.len();
+    for (row, line) in template.iter().enumerate() {
+        for (col, name) in line.split_whitespace().enumerate() {
+            if name.chars().all(|c| c == '.') {
+                continue;
+            }
+            let (row, col) = (row as u16, col as u16);
+            let key = area_key(name);
+            match out[start..].iter_mut().find(|a| a.key == key) {
+                Some(area) => {
+                    let c = &mut area.cell;
+                    let end_row = (c.row + c.row_span).max(row + 1);
+                    let end_col = (c.col + c.col_span).max(col + 1);
+                    c.row = c.row.min(row);
+                    c.col = c.col.min(col);
+                    c.row_span = end_row - c.row;
+                    c.col_span = end_col - c.col;
+                }
+                None => out.push(GridArea {
+                    key,
+                    cell: GridCell {
+                        row,
+                        col,
+                        row_span: 1,
+                        col_span: 1,
+                    },
+                }),
+            }
+        }
+    }
+    debug_assert!(
+        template
+            .windows(2)
+            .all(|w| w[0].split_whitespace().count() == w[1].split_whitespace().count()),
+        "grid area rows name different numbers of cells: {template:?}",
+    );
+    debug_assert!(
+        out[start..].iter().all(|a| {
+            let named = template
+                .iter()
+                .flat_map(|line| line.split_whitespace())
+                .filter(|name| area_key(name) == a.key)
+                .count();
+            named == usize::from(a.cell.row_span) * usize::from(a.cell.col_span)
+        }),
+        "grid areas must be rectangles: {template:?}",
+    );
+    Span::from(start..out.len())
+}
+
+/// Cells taken so far in an auto-flow grid, `fixed` tracks across and as
+/// many along the growing axis as placement has reached. Coordinates are
+/// `(major, minor)`: `minor` runs along [`GridFlow::fills`], `major`
+/// along the growing axis.
+#[derive(Debug)]
+pub(crate) struct Occupancy<'a> {
+    cells: &'a mut Vec<bool>,
+    fixed: usize,
+}
+
+impl<'a> Occupancy<'a> {
+    pub(crate) fn new(cells: &'a mut Vec<bool>, fixed: usize) -> Self {
+        cells.clear();
+        Self { cells, fixed }
+    }
+
+    fn is_free(&self, major: usize, minor: usize, major_span: usize, minor_span: usize) -> bool {
+        (major..major + major_span).all(|r| {
+            (minor..minor + minor_span)
+                .all(|c| !self.cells.get(r * self.fixed + c).copied().unwrap_or(false))
+        })
+    }
+
+    /// Mark a rect taken. Minor extents past the fixed count are clipped
+    /// — the open-time range check has already reported them.
+    pub(crate) fn take(
+        &mut self,
+        major: usize,
+        minor: usize,
+        major_span: usize,
+        minor_span: usize,
+    ) {
+        let end = (major + major_span) * self.fixed;
+        if self.cells.len() < end {
+            self.cells.resize(end, false);
+        }
+        for r in major..major + major_span {
+            for c in minor..(minor + minor_span).min(self.fixed) {
+                self.cells[r * self.fixed + c] = true;
+            }
+        }
+    }
+
+    /// First free slot for a `major_span × minor_span` child at or after
+    /// `cursor`, in flow order. Taken by the caller via [`Self::take`];
+    /// `minor_span` is at most the fixed count.
+    pub(crate) fn find(
+        &self,
+        mut cursor: (usize, usize),
+        major_span: usize,
+        minor_span: usize,
+    ) -> (usize, usize) {
+        loop {
+            let (major, minor) = cursor;
+            if minor + minor_span > self.fixed {
+                cursor = (major + 1, 0);
+            } else if self.is_free(major, minor, major_span, minor_span) {
+                return cursor;
+            } else {
+                cursor = (major, minor + 1);
+            }
+        }
+    }
+}
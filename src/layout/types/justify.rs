@@ -1,6 +1,7 @@
-/// Main-axis distribution of leftover space in a stack panel. Mirrors CSS
-/// `justify-content`. Has no effect when any child is `Sizing::fill` along
-/// the main axis — Fill consumes the leftover first.
+/// Main-axis distribution of leftover space in a stack panel, or in each
+/// line of a wrapping one. Mirrors CSS `justify-content`. Has no effect when
+/// any child (of that line) is `Sizing::fill` along the main axis — Fill
+/// consumes the leftover first.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum Justify {
@@ -0,0 +1,140 @@
+use crate::primitives::{num::Num, size::Size};
+
+/// A size bound in logical pixels or in viewport units — what
+/// `Configure::min_size` / `max_size` take per axis. Plain numbers convert
+/// as pixels, so `.max_size((680.0, f32::INFINITY))` reads as before and
+/// `.max_size((Length::vw(80.0), f32::INFINITY))` caps a dialog at 80% of
+/// the window.
+///
+/// Viewport units resolve to pixels when the node is recorded, the same
+/// point [`Sizing::vw`](crate::Sizing::vw) does.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    pub(crate) value: f32,
+    pub(crate) unit: LengthUnit,
+}
+
+/// What a [`Length`]'s value is measured in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum LengthUnit {
+    #[default]
+    Px,
+    /// Percent of the viewport's width.
+    Vw,
+    /// Percent of the viewport's height.
+    Vh,
+}
+
+impl LengthUnit {
+    /// `value` in this unit, in pixels against `viewport`.
+    #[inline]
+    pub(crate) fn resolve(self, value: f32, viewport: Size) -> f32 {
+        match self {
+            LengthUnit::Px => value,
+            LengthUnit::Vw => viewport.w * value * 0.01,
+            LengthUnit::Vh => viewport.h * value * 0.01,
+        }
+    }
+}
+
+impl Length {
+    /// Logical pixels.
+    #[inline]
+    pub const fn px(value: f32) -> Self {
+        Self {
+            value,
+            unit: LengthUnit::Px,
+        }
+    }
+
+    /// Percent of the viewport's width.
+    #[inline]
+    pub const fn vw(value: f32) -> Self {
+        Self {
+            value,
+            unit: LengthUnit::Vw,
+        }
+    }
+
+    /// Percent of the viewport's height.
+    #[inline]
+    pub const fn vh(value: f32) -> Self {
+        Self {
+            value,
+            unit: LengthUnit::Vh,
+        }
+    }
+}
+
+impl<T: Num> From<T> for Length {
+    fn from(v: T) -> Self {
+        Self::px(v.as_f32())
+    }
+}
+
+/// Per-axis [`Length`] — to a size bound what [`Sizes`](crate::Sizes) is
+/// to [`Sizing`](crate::Sizing). Built from a number (uniform pixels), a
+/// [`Length`] (uniform), a `Size`, or a `(w, h)` pair of anything that
+/// converts to a [`Length`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lengths {
+    pub(crate) w: Length,
+    pub(crate) h: Length,
+}
+
+impl Lengths {
+    #[inline]
+    pub(crate) fn new(values: Size, [w, h]: [LengthUnit; 2]) -> Self {
+        Self {
+            w: Length {
+                value: values.w,
+                unit: w,
+            },
+            h: Length {
+                value: values.h,
+                unit: h,
+            },
+        }
+    }
+
+    /// The two values with their units dropped.
+    #[inline]
+    pub(crate) fn values(self) -> Size {
+        Size::new(self.w.value, self.h.value)
+    }
+
+    #[inline]
+    pub(crate) fn units(self) -> [LengthUnit; 2] {
+        [self.w.unit, self.h.unit]
+    }
+}
+
+impl<T: Num> From<T> for Lengths {
+    fn from(v: T) -> Self {
+        Length::from(v).into()
+    }
+}
+
+impl From<Length> for Lengths {
+    fn from(l: Length) -> Self {
+        Self { w: l, h: l }
+    }
+}
+
+impl From<Size> for Lengths {
+    fn from(s: Size) -> Self {
+        Self {
+            w: Length::px(s.w),
+            h: Length::px(s.h),
+        }
+    }
+}
+
+impl<W: Into<Length>, H: Into<Length>> From<(W, H)> for Lengths {
+    fn from((w, h): (W, H)) -> Self {
+        Self {
+            w: w.into(),
+            h: h.into(),
+        }
+    }
+}
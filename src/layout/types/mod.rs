@@ -1,8 +1,12 @@
 pub(crate) mod align;
+pub(crate) mod align_content;
 pub(crate) mod clip_mode;
+pub(crate) mod flow_direction;
 pub(crate) mod grid_cell;
+pub(crate) mod grid_template;
 pub(crate) mod justify;
 pub(crate) mod layout_mode;
+pub(crate) mod length;
 pub(crate) mod limits;
 pub(crate) mod overlay;
 pub(crate) mod placement;
@@ -70,6 +70,27 @@ impl OverlayPosition {
         Self::new(anchor, OverlaySide::Right, AxisAlign::Start, gap)
     }
 
+    /// Reflected for a right-to-left scope: `Left` and `Right` trade, and
+    /// above or below the anchor the popup hangs from its other edge, so
+    /// `Start` stays the leading one.
+    pub(crate) const fn mirrored_x(self) -> Self {
+        let side = match self.side {
+            OverlaySide::Left => OverlaySide::Right,
+            OverlaySide::Right => OverlaySide::Left,
+            side => side,
+        };
+        let align = match (side.axis(), self.align) {
+            (Axis::X, align) | (Axis::Y, align @ AxisAlign::Center) => align,
+            (Axis::Y, AxisAlign::End) => AxisAlign::Start,
+            (Axis::Y, _) => AxisAlign::End,
+        };
+        Self {
+            side,
+            align,
+            ..self
+        }
+    }
+
     pub(crate) fn resolve(self, measured: Size, bounds: Rect) -> Vec2 {
         let axis = self.side.axis();
         let primary_extent = axis.main(measured);
@@ -30,6 +30,15 @@ impl Placement {
         }
     }
 
+    /// The placement as a right-to-left scope means it. A fixed anchor is
+    /// a point on the surface and stays put; an overlay trades sides.
+    pub(crate) fn mirrored_x(self) -> Self {
+        match self {
+            Self::Fixed { .. } => self,
+            Self::Overlay(position) => Self::Overlay(position.mirrored_x()),
+        }
+    }
+
     pub(crate) fn origin(self, measured: Size, surface: Rect) -> Vec2 {
         match self {
             Self::Fixed { anchor, .. } => anchor,
@@ -11,6 +11,11 @@ use crate::primitives::{approx, num::Num, size::Size};
 /// floor exceeds their weighted share freeze at the floor and the rest
 /// re-divide, CSS-flexbox style. A parent never grows to fit a child, so
 /// overflow only happens when rigid descendants genuinely do not fit.
+///
+/// Three relative forms resolve to a Fixed extent before anything sizes
+/// against them: [`Self::vw`] / [`Self::vh`] against the viewport when the
+/// node is recorded, [`Self::percent`] against the parent's offer when it
+/// is measured.
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct Sizing(SizingValue);
 
@@ -20,6 +25,9 @@ enum SizingValue {
     #[default]
     Hug,
     Fill(f32),
+    Percent(f32),
+    Vw(f32),
+    Vh(f32),
 }
 
 impl Sizing {
@@ -58,6 +66,55 @@ impl Sizing {
         Self(SizingValue::Fill(weight))
     }
 
+    /// `value` percent of what the parent offers on this axis — its inner
+    /// extent for every driver, the spanned cells for a grid child. Where
+    /// the offer is unbounded (a scrolling axis, an intrinsic query) there
+    /// is nothing to take a percentage of, and the axis hugs instead, as a
+    /// CSS percentage of an indefinite size falls back to `auto`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative or non-finite.
+    #[inline]
+    pub const fn percent(value: f32) -> Self {
+        assert!(
+            value.is_finite() && value >= 0.0,
+            "percent sizing must be finite and non-negative",
+        );
+        Self(SizingValue::Percent(value))
+    }
+
+    /// `value` percent of the viewport's width, on either axis. Resolved to
+    /// a Fixed extent when the node is recorded, so a resize re-keys
+    /// exactly the subtrees that use it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative or non-finite.
+    #[inline]
+    pub const fn vw(value: f32) -> Self {
+        assert!(
+            value.is_finite() && value >= 0.0,
+            "viewport sizing must be finite and non-negative",
+        );
+        Self(SizingValue::Vw(value))
+    }
+
+    /// `value` percent of the viewport's height, on either axis — the
+    /// counterpart of [`Self::vw`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative or non-finite.
+    #[inline]
+    pub const fn vh(value: f32) -> Self {
+        assert!(
+            value.is_finite() && value >= 0.0,
+            "viewport sizing must be finite and non-negative",
+        );
+        Self(SizingValue::Vh(value))
+    }
+
     /// A relative share that may be zero. Zero becomes `fixed(0.0)`;
     /// positive values become [`Self::fill`].
     ///
@@ -97,7 +154,7 @@ impl Sizing {
     pub const fn fixed_value(self) -> Option<f32> {
         match self.0 {
             SizingValue::Fixed(value) => Some(value),
-            SizingValue::Hug | SizingValue::Fill(_) => None,
+            _ => None,
         }
     }
 
@@ -106,7 +163,7 @@ impl Sizing {
     pub const fn fill_weight(self) -> Option<f32> {
         match self.0 {
             SizingValue::Fill(weight) => Some(weight),
-            SizingValue::Fixed(_) | SizingValue::Hug => None,
+            _ => None,
         }
     }
 
@@ -116,6 +173,47 @@ impl Sizing {
         matches!(self.0, SizingValue::Hug)
     }
 
+    /// The percentage if this is a [`Self::percent`], else `None`.
+    #[inline]
+    pub(crate) const fn percent_value(self) -> Option<f32> {
+        match self.0 {
+            SizingValue::Percent(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// `true` for a Fixed or percent axis — the ones whose extent is set
+    /// from outside the content and never stretches at arrange.
+    #[inline]
+    pub(crate) const fn is_rigid(self) -> bool {
+        matches!(self.0, SizingValue::Fixed(_) | SizingValue::Percent(_))
+    }
+
+    /// A [`Self::percent`] resolved against `basis`: Fixed at that share
+    /// when `basis` is finite, Hug otherwise. Every other form passes
+    /// through.
+    #[inline]
+    pub(crate) fn of_basis(self, basis: f32) -> Self {
+        match self.0 {
+            SizingValue::Percent(p) if basis.is_finite() => {
+                Self(SizingValue::Fixed((basis * p * 0.01).max(0.0)))
+            }
+            SizingValue::Percent(_) => Self::HUG,
+            _ => self,
+        }
+    }
+
+    /// A [`Self::vw`] / [`Self::vh`] resolved to a Fixed extent against
+    /// `viewport`. Every other form passes through.
+    #[inline]
+    pub(crate) fn of_viewport(self, viewport: Size) -> Self {
+        match self.0 {
+            SizingValue::Vw(v) => Self(SizingValue::Fixed(viewport.w * v * 0.01)),
+            SizingValue::Vh(v) => Self(SizingValue::Fixed(viewport.h * v * 0.01)),
+            _ => self,
+        }
+    }
+
     /// One participant's cut of `space`, given its own [`Self::fill`]
     /// weight and the total across every participant sharing it. Shared
     /// by the stack's Fill distribution and the grid's Phase-3 track
@@ -125,13 +223,22 @@ impl Sizing {
         (f64::from(space) * f64::from(weight) / total_weight) as f32
     }
 
+    /// Discriminant + payload, the pair both hashes encode.
     #[inline]
-    pub(crate) fn hash_visual<H: std::hash::Hasher>(&self, h: &mut H) {
-        let (tag, value) = match self.0 {
-            SizingValue::Fixed(value) => (0u8, value),
+    fn tagged(self) -> (u8, f32) {
+        match self.0 {
+            SizingValue::Fixed(value) => (0, value),
             SizingValue::Hug => (1, 0.0),
-            SizingValue::Fill(value) => (2, value),
-        };
+            SizingValue::Fill(weight) => (2, weight),
+            SizingValue::Percent(value) => (3, value),
+            SizingValue::Vw(value) => (4, value),
+            SizingValue::Vh(value) => (5, value),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn hash_visual<H: std::hash::Hasher>(&self, h: &mut H) {
+        let (tag, value) = self.tagged();
         h.write_u64((tag as u64) | ((approx::canon_bits(value) as u64) << 8));
     }
 }
@@ -148,22 +255,22 @@ impl<T: Num> From<T> for Sizing {
 impl std::hash::Hash for Sizing {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, h: &mut H) {
-        let (tag, v) = match self.0 {
-            SizingValue::Fixed(v) => (0u8, v),
-            SizingValue::Hug => (1, 0.0),
-            SizingValue::Fill(w) => (2, w),
-        };
+        let (tag, v) = self.tagged();
         h.write_u64((tag as u64) | ((approx::eq_bits(v) as u64) << 8));
     }
 }
 
 /// Per-axis `Sizing`, packed into 8 B (two `u32` slots). Each slot
-/// encodes one `Sizing`: top 2 bits = tag (0=Fixed, 1=Hug, 2=Fill),
-/// low 30 bits = the high 30 bits of the payload `f32`. Drops 2
+/// encodes one `Sizing`: top 2 bits = tag (0=Fixed, 1=Hug, 2=Fill,
+/// 3=relative), low 30 bits = the high 30 bits of the payload `f32`. Drops 2
 /// mantissa bits — ULP at 1 px ≈ 1e-7, at 1280 px ≈ 1e-3 — well below
 /// physical-pixel snapping resolution. Saves 8 B per `LayoutCore`
 /// (56 → 48) across the per-node SoA column.
 ///
+/// The relative tag spends one more mantissa bit to name its unit
+/// (percent / vw / vh) in bits 28–29: payloads are non-negative, so the
+/// sign bit that lands on bit 28 after the shift is always clear.
+///
 /// Construct via `Default` (Hug × Hug), `Sizes::from(s)` (uniform),
 /// `Sizes::from(n)` (uniform Fixed via `Num`), or `Sizes::from((w, h))`
 /// for asymmetric. The `From` impls are the public surface —
@@ -186,8 +293,18 @@ impl Default for Sizes {
 const SIZING_TAG_FIXED: u32 = 0;
 const SIZING_TAG_HUG: u32 = 1;
 const SIZING_TAG_FILL: u32 = 2;
+const SIZING_TAG_RELATIVE: u32 = 3;
 const SIZING_TAG_SHIFT: u32 = 30;
 const SIZING_VAL_MASK: u32 = (1 << 30) - 1;
+const RELATIVE_UNIT_SHIFT: u32 = 28;
+const RELATIVE_VAL_MASK: u32 = (1 << RELATIVE_UNIT_SHIFT) - 1;
+
+#[inline]
+const fn encode_relative(unit: u32, value: f32) -> u32 {
+    (SIZING_TAG_RELATIVE << SIZING_TAG_SHIFT)
+        | (unit << RELATIVE_UNIT_SHIFT)
+        | (approx::eq_bits(value) >> 3)
+}
 
 #[inline]
 const fn encode_sizing(s: Sizing) -> u32 {
@@ -202,6 +319,9 @@ const fn encode_sizing(s: Sizing) -> u32 {
             let payload = if payload == 0 { 1 } else { payload };
             (SIZING_TAG_FILL << SIZING_TAG_SHIFT) | payload
         }
+        SizingValue::Percent(value) => encode_relative(0, value),
+        SizingValue::Vw(value) => encode_relative(1, value),
+        SizingValue::Vh(value) => encode_relative(2, value),
     }
 }
 
@@ -213,8 +333,16 @@ const fn decode_sizing(packed: u32) -> Sizing {
         SIZING_TAG_FIXED => Sizing(SizingValue::Fixed(val)),
         SIZING_TAG_HUG => Sizing::HUG,
         SIZING_TAG_FILL => Sizing(SizingValue::Fill(val)),
-        // Tag 3 is unconstructible by `encode_sizing`.
-        _ => unreachable!(),
+        _ => {
+            let val = f32::from_bits((packed & RELATIVE_VAL_MASK) << 3);
+            match (packed >> RELATIVE_UNIT_SHIFT) & 0b11 {
+                0 => Sizing(SizingValue::Percent(val)),
+                1 => Sizing(SizingValue::Vw(val)),
+                2 => Sizing(SizingValue::Vh(val)),
+                // Unit 3 is unconstructible by `encode_relative`.
+                _ => unreachable!(),
+            }
+        }
     }
 }
 
@@ -243,6 +371,12 @@ impl Sizes {
     pub const fn h(self) -> Sizing {
         decode_sizing(self.h_packed)
     }
+    /// Both axes through [`Sizing::of_basis`], each against its own
+    /// extent of `basis`.
+    #[inline]
+    pub(crate) fn of_basis(self, basis: Size) -> Self {
+        Self::new(self.w().of_basis(basis.w), self.h().of_basis(basis.h))
+    }
 }
 
 impl PartialEq for Sizes {
@@ -296,6 +430,7 @@ impl From<Size> for Sizes {
 #[cfg(test)]
 mod tests {
     use crate::layout::types::sizing::{Sizes, Sizing};
+    use crate::primitives::size::Size;
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
@@ -377,6 +512,10 @@ mod tests {
             ("negative share", || Sizing::share(-1.0)),
             ("NaN share", || Sizing::share(f32::NAN)),
             ("infinite share", || Sizing::share(f32::INFINITY)),
+            ("negative percent", || Sizing::percent(-1.0)),
+            ("NaN percent", || Sizing::percent(f32::NAN)),
+            ("infinite vw", || Sizing::vw(f32::INFINITY)),
+            ("negative vh", || Sizing::vh(-1.0)),
         ];
         for &(label, construct) in cases {
             assert!(
@@ -385,4 +524,31 @@ mod tests {
             );
         }
     }
+
+    /// The three relative forms share one packed tag, split by a unit
+    /// field: each must come back as itself, and no two may collide —
+    /// in equality or in the hash the measure cache keys on — with each
+    /// other or with the Fixed extent of the same number.
+    #[test]
+    fn relative_forms_round_trip_and_stay_distinct() {
+        let forms = [
+            Sizing::percent(25.0),
+            Sizing::vw(25.0),
+            Sizing::vh(25.0),
+            Sizing::fixed(25.0),
+        ];
+        for (i, a) in forms.into_iter().enumerate() {
+            let packed = Sizes::new(a, Sizing::HUG);
+            assert_eq!(packed.w(), a);
+            for b in forms.into_iter().skip(i + 1) {
+                assert_ne!(packed, Sizes::new(b, Sizing::HUG), "{a:?} vs {b:?}");
+                assert_ne!(hash_value(a), hash_value(b), "{a:?} vs {b:?}");
+            }
+        }
+        assert_eq!(Sizing::percent(50.0).of_basis(300.0), Sizing::fixed(150.0));
+        assert_eq!(Sizing::percent(50.0).of_basis(f32::INFINITY), Sizing::HUG);
+        let viewport = Size::new(800.0, 600.0);
+        assert_eq!(Sizing::vw(10.0).of_viewport(viewport), Sizing::fixed(80.0));
+        assert_eq!(Sizing::vh(10.0).of_viewport(viewport), Sizing::fixed(60.0));
+    }
 }
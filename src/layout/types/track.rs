@@ -1,3 +1,4 @@
+use crate::layout::types::grid_template::GridFlow;
 use crate::layout::types::limits::{valid_lower_bound, valid_upper_bound};
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::approx::FloatHash;
@@ -105,6 +106,14 @@ pub(crate) struct GridDef {
     pub(crate) cols: Span,
     pub(crate) row_gap: f32,
     pub(crate) col_gap: f32,
+    /// Auto-flow for children without a cell, and the track it appends
+    /// once the declared ones run out. Record-time only: by the time
+    /// layout reads the def every child has a cell and `rows` / `cols`
+    /// already include the appended tracks.
+    pub(crate) flow: Option<GridFlow>,
+    pub(crate) auto_track: Track,
+    /// Span into `Tree::grid_areas`.
+    pub(crate) areas: Span,
 }
 
 impl GridDef {
@@ -206,6 +215,9 @@ mod tests {
             cols: Span::new(start + 1, 1),
             row_gap,
             col_gap: -row_gap,
+            flow: None,
+            auto_track: Track::hug(),
+            areas: Span::default(),
         };
 
         assert_eq!(
@@ -229,6 +241,9 @@ mod tests {
                 cols: Span::new(rows.len() as u32, cols.len() as u32),
                 row_gap: 2.0,
                 col_gap: 3.0,
+                flow: None,
+                auto_track: Track::hug(),
+                areas: Span::default(),
             };
             grid_content_hash(def, &tracks)
         }
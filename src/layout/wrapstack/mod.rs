@@ -6,12 +6,17 @@
 //! Two gap fields: `gap` is within-line sibling spacing (same role as
 //! Stack's gap); `line_gap` is between-line spacing.
 //!
-//! `Sizing::fill` on the main axis is treated as `Hug` here — wrap
-//! semantics conflict with "consume row leftover" and need explicit
-//! per-line distribution that's outside this MVP. Cross-axis Fill works
-//! identically to Stack: each line's cross size = max child cross, and
-//! shared arrange-axis resolution makes Fill children grow to that
-//! height without shrinking below their measured content.
+//! `Sizing::fill` on the main axis packs at its content size — where a
+//! line breaks never depends on Fill — and then, at arrange, each line
+//! runs Stack's freeze loop over its own Fill children: the line's
+//! leftover is shared by weight, floored at each child's measured size
+//! and capped at its `max_size`. So a row of Fill cards stretches to the
+//! row, and `justify` only sees what capped Fill children leave over.
+//! Cross-axis Fill works identically to Stack: each line's cross size =
+//! max child cross, and shared arrange-axis resolution makes Fill
+//! children grow to that height without shrinking below their measured
+//! content. `align_content` spreads the lines over any cross-axis space
+//! they don't use.
 
 use crate::layout::axis::Axis;
 use crate::layout::axis_placement::AxisPlacement;
@@ -19,6 +24,8 @@ use crate::layout::engine::LayoutEngine;
 use crate::layout::intrinsic::{IntrinsicQuery, IntrinsicRange, LenReq};
 use crate::layout::justify_offsets::JustifyOffsets;
 use crate::layout::pass::LayoutPass;
+use crate::layout::stack::{FillEntry, freeze_distribute};
+use crate::layout::types::align_content::AlignContent;
 use crate::primitives::interned_text::InternedText;
 use crate::primitives::{rect::Rect, size::Size};
 use crate::scene::tree::Tree;
@@ -145,7 +152,9 @@ pub(super) fn measure(
 
     // Measure each non-collapsed child once. Pass `INF` on main with the
     // committed cross — same height-given-width pattern as Stack pass-1
-    // (so wrap text in a child shapes against `cross_avail`).
+    // (so wrap text in a child shapes against `cross_avail`). A percent
+    // main axis is the exception: its share is of the line length, so it
+    // sees `main_avail` rather than an offer it can only hug under.
     let mut max_line_main = 0.0f32;
     let mut total_cross = 0.0f32;
     let mut line = LinePack::default();
@@ -156,8 +165,13 @@ pub(super) fn measure(
         total_cross += line_cross;
         line_count += 1;
     };
+    let layouts = tree.records.layout();
     for c in tree.active_children(node) {
-        let d = pass.measure(c, axis.compose_size(f32::INFINITY, cross_avail));
+        let main_offer = match axis.main_sizing(layouts[c.idx()].size).percent_value() {
+            Some(_) => main_avail,
+            None => f32::INFINITY,
+        };
+        let d = pass.measure(c, axis.compose_size(main_offer, cross_avail));
         let pack = child_pack(axis, d);
         pack_child(&mut line, gap, main_avail, pack, &mut complete_line);
     }
@@ -173,26 +187,93 @@ pub(super) fn measure(
     axis.compose_size(max_line_main, total_cross)
 }
 
+/// Where lines go on the cross axis: the first line's offset, the gap
+/// between lines, and what each line's cross extent grows by.
+#[derive(Debug)]
+struct LineSpread {
+    start: f32,
+    gap: f32,
+    grow: f32,
+}
+
+/// `align_content` over the lines `desired` packs into, against
+/// `cross_avail`. `Start` needs no lookahead; the rest pack once more to
+/// learn the lines' total cross extent before the first is placed.
+fn spread_lines(
+    pass: &LayoutPass<'_>,
+    node: NodeId,
+    axis: Axis,
+    main_avail: f32,
+    cross_avail: f32,
+) -> LineSpread {
+    let tree = pass.tree;
+    let panel = tree.panel(node);
+    let (gap, line_gap) = (panel.gaps.gap(), panel.gaps.line_gap());
+    let start = LineSpread {
+        start: 0.0,
+        gap: line_gap,
+        grow: 0.0,
+    };
+    if panel.align_content == AlignContent::Start {
+        return start;
+    }
+    let mut total_cross = 0.0f32;
+    let mut line_count = 0usize;
+    let mut line = LinePack::default();
+    let mut complete_line = |_: f32, line_cross: f32| {
+        total_cross += line_cross;
+        line_count += 1;
+    };
+    for c in tree.active_children(node) {
+        let pack = child_pack(axis, pass.desired(c));
+        pack_child(&mut line, gap, main_avail, pack, &mut complete_line);
+    }
+    if line.occupied {
+        complete_line(line.main, line.cross);
+    }
+    if line_count == 0 {
+        return start;
+    }
+    let leftover = (cross_avail - total_cross - line_gap * (line_count - 1) as f32).max(0.0);
+    if panel.align_content == AlignContent::Stretch {
+        return LineSpread {
+            grow: leftover / line_count as f32,
+            ..start
+        };
+    }
+    let JustifyOffsets { start, gap } = JustifyOffsets::new(
+        panel.align_content.distribution(),
+        leftover,
+        line_gap,
+        line_count,
+    );
+    LineSpread {
+        start,
+        gap,
+        grow: 0.0,
+    }
+}
+
 pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis: Axis) {
     let tree = pass.tree;
     let panel = tree.panel(node);
     let gap = panel.gaps.gap();
-    let line_gap = panel.gaps.line_gap();
     let justify = panel.justify;
     let parent_child_align = panel.child_align;
     let main_avail = axis.main(inner.size);
+    let spread = spread_lines(pass, node, axis, main_avail, axis.cross(inner.size));
 
     // Same packing logic as `measure`. Each row needs lookahead —
     // can't place a child until we know the row's `line_main` (for
-    // justify) and `line_cross` (for cross-axis place). Buffer node
-    // IDs in the engine's flat `wrap.pool` at this depth's slice,
+    // Fill and justify) and `line_cross` (for cross-axis place). Buffer
+    // node IDs in the engine's flat `wrap.pool` at this depth's slice,
     // flush on overflow / end-of-children. Sizes come from
     // `pass.desired(..)` at flush time, so the buffer is just node
     // IDs.
     let layouts = tree.records.layout();
     let line_start = pass.wrap_scratch_mut().len() as u32;
     let mut line = LinePack::default();
-    let mut cross_cursor = axis.cross_v(inner.min);
+    let mut cross_cursor = axis.cross_v(inner.min) + spread.start;
     let mut first_line = true;
 
     let place_line = |pass: &mut LayoutPass<'_>,
@@ -206,17 +287,56 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis
             return;
         }
         if !*first_line {
-            *cross_cursor += line_gap;
+            *cross_cursor += spread.gap;
         }
         *first_line = false;
+        let line_cross = line_cross + spread.grow;
+
+        // The line is a stack of its own on the main axis: its Fill
+        // children share what's left of `main_avail` by weight, frozen at
+        // their measured size as the floor — Stack's arrange rule, over
+        // the same depth-shared entry pool. `line_main` counts them at
+        // that floor, so their growth comes out of justify's leftover.
+        let fill_start = pass.stack_scratch_mut().len();
+        let mut fill_floor = 0.0f32;
+        let mut total_weight = 0.0f64;
+        for i in line_start..line_end {
+            let c = pass.wrap_scratch_mut().at(i);
+            let s = layouts[c.idx()];
+            if let Some(weight) = axis.main_sizing(s.size).fill_weight() {
+                let floor = axis.main(pass.desired(c));
+                fill_floor += floor;
+                total_weight += f64::from(weight);
+                pass.stack_scratch_mut().push(FillEntry {
+                    node: c,
+                    weight,
+                    floor,
+                    cap: axis.main(tree.bounds(c).max_size) + axis.spacing(s.margin),
+                    frozen_alloc: None,
+                });
+            }
+        }
+        freeze_distribute(
+            pass.stack_scratch_mut().from(fill_start),
+            (main_avail - line_main + fill_floor).max(0.0),
+            total_weight,
+        );
+        // unwrap: `freeze_distribute` fills in every entry.
+        let fill_main: f32 = pass
+            .stack_scratch_mut()
+            .from(fill_start)
+            .iter()
+            .map(|e| e.frozen_alloc.unwrap())
+            .sum();
 
         let count = line_end - line_start;
-        let leftover = (main_avail - line_main).max(0.0);
+        let leftover = (main_avail - line_main - (fill_main - fill_floor)).max(0.0);
         let JustifyOffsets {
             start: start_offset,
             gap: eff_gap,
         } = JustifyOffsets::new(justify, leftover, gap, count);
         let mut main_cursor = axis.main_v(inner.min) + start_offset;
+        let mut fill_cursor = fill_start;
         // Iterate by index so we copy each `NodeId` out before
         // calling `layout.arrange`, which needs `&mut layout`.
         // `NodeId` is `Copy`, so no slice borrow into the pool.
@@ -232,7 +352,18 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis
             // line_cross, Hug aligns per child.
             let bounds = tree.bounds(c);
             let cross_p = AxisPlacement::cross(axis, &s, bounds, parent_child_align, d, line_cross);
-            let main_size = axis.main(d);
+            let main_size = if axis.main_sizing(s.size).fill_weight().is_some() {
+                // unwrap: every Fill child on the line pushed an entry.
+                let alloc = pass
+                    .stack_scratch_mut()
+                    .at(fill_cursor)
+                    .frozen_alloc
+                    .unwrap();
+                fill_cursor += 1;
+                alloc
+            } else {
+                axis.main(d)
+            };
             let child_rect = axis.compose_rect(
                 main_cursor,
                 *cross_cursor + cross_p.offset,
@@ -243,10 +374,11 @@ pub(super) fn arrange(pass: &mut LayoutPass<'_>, node: NodeId, inner: Rect, axis
             main_cursor += main_size;
         }
         *cross_cursor += line_cross;
-        // Drop our line from the pool (capacity retained). Recursive
+        // Drop our line from both pools (capacity retained). Recursive
         // `layout.arrange` calls above may have temporarily extended
-        // and re-truncated the pool past `line_end`; we ignore those
-        // and reset to our depth's start.
+        // and re-truncated them past our entries; we ignore those and
+        // reset to our depth's start.
+        pass.stack_scratch_mut().truncate(fill_start);
         pass.wrap_scratch_mut().truncate(line_start);
     };
 
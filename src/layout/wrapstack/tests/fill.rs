@@ -0,0 +1,151 @@
+//! Main-axis Fill shared per line, and `align_content` spreading the lines.
+
+use crate::Ui;
+use crate::layout::types::align_content::AlignContent;
+use crate::layout::types::{justify::Justify, sizing::Sizing};
+use crate::layout::wrapstack::tests::support::{cell, rect_of};
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::harness::UiHarness;
+use crate::widgets::{frame::Frame, panel::Panel};
+use glam::UVec2;
+
+/// A `weight`-Fill card with content width `min`, keyed `id`.
+fn card(ui: &mut Ui, id: &'static str, weight: f32, min: f32) {
+    Frame::new()
+        .id(WidgetId::from_hash(id))
+        .size((Sizing::fill(weight), Sizing::fixed(20.0)))
+        .min_size((min, 0.0))
+        .show(ui);
+}
+
+/// Pin: the responsive card grid. Three 80-wide Fill cards in a 200-wide
+/// row (gap 10) break 2 + 1. Line one shares 200 - 10 = 190 → 95 each;
+/// the lone card on line two takes the whole 200. Lines break on content
+/// size, so the stretch never pushes a card onto another line.
+#[test]
+fn each_lines_fill_children_share_that_lines_leftover() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    let _ = h.under_outer(|ui| {
+        Panel::wrap_hstack()
+            .id(WidgetId::from_hash("w"))
+            .size((Sizing::fixed(200.0), Sizing::HUG))
+            .gap(10.0)
+            .line_gap(5.0)
+            .show(ui, |ui| {
+                card(ui, "a", 1.0, 80.0);
+                card(ui, "b", 1.0, 80.0);
+                card(ui, "c", 1.0, 80.0);
+            })
+            .response
+            .node()
+    });
+    let (a, b, c) = (rect_of(&h, "a"), rect_of(&h, "b"), rect_of(&h, "c"));
+    assert_eq!((a.min.x, a.size.w), (0.0, 95.0));
+    assert_eq!((b.min.x, b.size.w), (105.0, 95.0));
+    assert_eq!((c.min.x, c.min.y, c.size.w), (0.0, 25.0, 200.0));
+}
+
+/// Pin: Stack's freeze rule, per line. Beside a fixed 10 and two gaps of
+/// 10, a 1:3 split of 170 would give the weight-1 card 42.5, under its 70
+/// floor — it freezes at 70 and the weight-3 card takes the other 100.
+#[test]
+fn a_lines_fill_children_freeze_at_their_measured_floor() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    let _ = h.under_outer(|ui| {
+        Panel::wrap_hstack()
+            .id(WidgetId::from_hash("w"))
+            .size((Sizing::fixed(200.0), Sizing::HUG))
+            .gap(10.0)
+            .show(ui, |ui| {
+                cell(ui, "fixed", 10.0, 20.0);
+                card(ui, "narrow", 1.0, 70.0);
+                card(ui, "wide", 3.0, 10.0);
+            })
+            .response
+            .node()
+    });
+    let (narrow, wide) = (rect_of(&h, "narrow"), rect_of(&h, "wide"));
+    assert_eq!((narrow.min.x, narrow.size.w), (20.0, 70.0));
+    assert_eq!((wide.min.x, wide.size.w), (100.0, 100.0));
+}
+
+/// Pin: `justify` runs on lines with no Fill child, and on whatever a
+/// capped Fill leaves. Line one holds a Fill capped at 60 beside a
+/// fixed 60: 200 - 130 = 70 left, centered → starts at 35. Line two,
+/// a lone fixed 150, centers in its own 50 of leftover → 25.
+#[test]
+fn justify_takes_what_fill_leaves_on_each_line() {
+    let mut h = UiHarness::new(UVec2::new(400, 400));
+    let _ = h.under_outer(|ui| {
+        Panel::wrap_hstack()
+            .id(WidgetId::from_hash("w"))
+            .size((Sizing::fixed(200.0), Sizing::HUG))
+            .gap(10.0)
+            .justify(Justify::Center)
+            .show(ui, |ui| {
+                Frame::new()
+                    .id(WidgetId::from_hash("capped"))
+                    .size((Sizing::FILL, Sizing::fixed(20.0)))
+                    .min_size((20.0, 0.0))
+                    .max_size((60.0, f32::INFINITY))
+                    .show(ui);
+                cell(ui, "beside", 60.0, 20.0);
+                cell(ui, "alone", 150.0, 20.0);
+            })
+            .response
+            .node()
+    });
+    let capped = rect_of(&h, "capped");
+    assert_eq!((capped.min.x, capped.size.w), (35.0, 60.0));
+    assert_eq!(rect_of(&h, "beside").min.x, 105.0);
+    assert_eq!(rect_of(&h, "alone").min.x, 25.0);
+}
+
+/// Pin: `align_content` over two 20-high lines (line gap 10) in a
+/// 100-high wrap, 50 of cross leftover:
+///   Start:        0, 30.
+///   Center:       25, 55.
+///   End:          50, 80.
+///   SpaceBetween: 0, 80 (the one line gap takes all 50).
+///   SpaceAround:  12.5, 67.5 (25 per line, half leading).
+///   Stretch:      lines grow by 25 → 0, 55; a cross-Fill child fills 45.
+#[test]
+fn align_content_spreads_lines_over_the_cross_leftover() {
+    let cases: &[(AlignContent, [f32; 2], f32)] = &[
+        (AlignContent::Start, [0.0, 30.0], 20.0),
+        (AlignContent::Center, [25.0, 55.0], 20.0),
+        (AlignContent::End, [50.0, 80.0], 20.0),
+        (AlignContent::SpaceBetween, [0.0, 80.0], 20.0),
+        (AlignContent::SpaceAround, [12.5, 67.5], 20.0),
+        (AlignContent::Stretch, [0.0, 55.0], 45.0),
+    ];
+    for &(align_content, expected, stretched_h) in cases {
+        let mut h = UiHarness::new(UVec2::new(400, 400));
+        let _ = h.under_outer(|ui| {
+            Panel::wrap_hstack()
+                .id(WidgetId::from_hash("w"))
+                .size((Sizing::fixed(100.0), Sizing::fixed(100.0)))
+                .gap(0.0)
+                .line_gap(10.0)
+                .align_content(align_content)
+                .show(ui, |ui| {
+                    cell(ui, "top", 80.0, 20.0);
+                    Frame::new()
+                        .id(WidgetId::from_hash("bottom"))
+                        .size((Sizing::fixed(80.0), Sizing::FILL))
+                        .min_size((0.0, 20.0))
+                        .show(ui);
+                })
+                .response
+                .node()
+        });
+        let (top, bottom) = (rect_of(&h, "top"), rect_of(&h, "bottom"));
+        assert_eq!(
+            [top.min.y, bottom.min.y],
+            expected,
+            "case: {align_content:?}"
+        );
+        assert_eq!(bottom.size.h, stretched_h, "case: {align_content:?}");
+    }
+}
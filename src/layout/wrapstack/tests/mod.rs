@@ -1,6 +1,8 @@
-//! Wrapping stacks: where a line breaks, and what its cross extent becomes.
+//! Wrapping stacks: where a line breaks, what its cross extent becomes,
+//! and how Fill and `align_content` share out the space left over.
 
 mod bounds;
+mod fill;
 mod line_cross;
 mod packing;
 mod support;
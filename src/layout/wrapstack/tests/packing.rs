@@ -88,13 +88,11 @@ fn wrap_hstack_collapsed_child_in_pack_is_skipped() {
     assert_eq!((hidden.size.w, hidden.size.h), (0.0, 0.0));
 }
 
-/// Pin (today's behavior): `Sizing::fill` on a child's main axis is
-/// treated as `Hug` — measure runs at INF main and the child reports
-/// its content size, no per-row leftover distribution. Future work
-/// adding flex-style row-leftover distribution should update this
-/// test rather than introduce the new behavior silently.
+/// Pin: `Sizing::fill` on a child's main axis takes the row's leftover —
+/// measure still packs it at its content size (its `min_size`, 40),
+/// arrange hands it the rest of the row: 300 - 60 - 10 = 230.
 #[test]
-fn wrap_hstack_fill_main_child_treated_as_hug_for_now() {
+fn wrap_hstack_fill_main_child_takes_row_leftover() {
     let mut h = UiHarness::new(UVec2::new(400, 400));
     let _ = h.under_outer(|ui| {
         Panel::wrap_hstack()
@@ -106,23 +104,12 @@ fn wrap_hstack_fill_main_child_treated_as_hug_for_now() {
                 Frame::new()
                     .id(WidgetId::from_hash("filler"))
                     .size((Sizing::FILL, Sizing::fixed(20.0)))
-                    // min_size makes Fill measurable as a positive
-                    // number even with no row-leftover distribution.
                     .min_size((40.0, 0.0))
                     .show(ui);
             })
             .response
             .node()
     });
-    let r = h
-        .layout_rect(WidgetId::from_hash("filler"))
-        .expect("arranged");
-    // Fill child got its min_size width (40), NOT the row leftover
-    // (300 - 60 - 10 - 10 = 220). If a future change distributes
-    // leftover, this assertion flips and the test becomes the spec.
-    assert!(
-        r.size.w < 100.0,
-        "Fill main treated as Hug today; got w={}",
-        r.size.w
-    );
+    let r = rect_of(&h, "filler");
+    assert_eq!((r.min.x, r.size.w), (70.0, 230.0));
 }
@@ -44,10 +44,17 @@
 //! - [`Sizing::fill`] takes the leftover, split between fill siblings by
 //!   weight; a sibling whose floor exceeds its share freezes at the floor and
 //!   the rest re-divide.
+//! - [`Sizing::percent`] is a share of what the parent offers, and
+//!   [`Sizing::vw`] / [`Sizing::vh`] a share of the window. Both settle to a
+//!   fixed extent before anything sizes against them; [`Length`] carries the
+//!   viewport units to `min_size` / `max_size`.
 //!
 //! Children clamp down to fit their parent — a parent never grows to fit a
 //! child. Overflow happens only when rigid descendants genuinely do not fit.
 //!
+//! [`Configure::aspect_ratio`] ties the two axes together: one resolves as
+//! above, the other follows it at the given ratio within the same pass.
+//!
 //! # Feature flags
 //!
 //! | flag | default | what it does |
@@ -224,6 +231,9 @@ pub use host::winit::{
     error::{HostDisconnected, WinitHostError},
     handle::{HostHandle, UserEvent},
 };
+pub use input::command::binding::{KeyBinding, KeyBindingParseError};
+pub use input::command::keymap::Keymap;
+pub use input::command::{BindingConflict, Command, CommandRegistry, ConflictKind};
 pub use input::input_event::InputEvent;
 pub use input::key_class::{KeyClass, KeyFilter};
 pub use input::keyboard::{Key, KeyPress, KeyboardEvent, Modifiers, TextChunk};
@@ -237,9 +247,13 @@ pub use input::sense::Sense;
 pub use input::shortcut::{Mods, Shortcut};
 pub use input::watch::{KeyboardWake, PointerWake};
 pub use layout::types::align::{Align, HAlign, VAlign};
+pub use layout::types::align_content::AlignContent;
 pub use layout::types::clip_mode::ClipMode;
+pub use layout::types::flow_direction::FlowDirection;
 pub use layout::types::grid_cell::GridCell;
+pub use layout::types::grid_template::GridFlow;
 pub use layout::types::justify::Justify;
+pub use layout::types::length::{Length, Lengths};
 pub use layout::types::sizing::{Sizes, Sizing};
 pub use layout::types::track::Track;
 pub use palantir_anim_derive::Animatable;
@@ -315,6 +329,9 @@ pub use ui::layer_scope::LayerScope;
 pub use widgets::button::Button;
 pub use widgets::checkbox::Checkbox;
 pub use widgets::combo_box::ComboBox;
+pub use widgets::command_palette::{
+    CommandPalette, CommandPaletteResponse, PaletteEntry, PalettePlacement, RecentEntries,
+};
 pub use widgets::context_menu::ContextMenu;
 pub use widgets::context_menu::menu_item::MenuItem;
 pub use widgets::context_menu::menu_separator::MenuSeparator;
@@ -337,16 +354,33 @@ pub use widgets::spinner::Spinner;
 pub use widgets::splitter::{SplitHalf, Splitter};
 pub use widgets::switch::Switch;
 pub use widgets::text::Text;
+pub use widgets::text_edit::autocomplete::{Autocomplete, SuggestAnchor, SuggestionProvider};
+pub use widgets::text_edit::buffer::TextBuffer;
+pub use widgets::text_edit::buffer::rope::TextRope;
+pub use widgets::text_edit::diagnostics::{Diagnostic, DiagnosticProvider, Severity};
+pub use widgets::text_edit::highlight::{HighlightSpan, Highlighter};
+pub use widgets::text_edit::validate::{
+    IntRange, Mask, Numeric, RegexValidator, ValidateFn, Validator, Verdict,
+};
 pub use widgets::text_edit::{TextEdit, TextEditResponse};
 pub use widgets::theme::Theme;
+pub use widgets::theme::autocomplete::AutocompleteTheme;
 pub use widgets::theme::button::ButtonTheme;
+pub use widgets::theme::code_edit::CodeEditTheme;
 pub use widgets::theme::combo_box::ComboBoxTheme;
+pub use widgets::theme::command_palette::CommandPaletteTheme;
 pub use widgets::theme::context_menu::ContextMenuTheme;
 pub use widgets::theme::context_menu::menu_item::MenuItemTheme;
+pub use widgets::theme::diagnostics::DiagnosticsTheme;
 pub use widgets::theme::drag_value::DragValueTheme;
+pub use widgets::theme::find_bar::FindBarTheme;
 pub use widgets::theme::modal::ModalTheme;
 pub use widgets::theme::palette::Palette;
+pub use widgets::theme::palette::contrast::{ContrastCheck, ContrastReport, PaletteRole};
+pub use widgets::theme::palette::seed::Mode;
 pub use widgets::theme::progress_bar::ProgressBarTheme;
+#[cfg(feature = "theme-reload")]
+pub use widgets::theme::reload::{ThemeLoadError, ThemeWatcher};
 pub use widgets::theme::scrollbar::ScrollbarTheme;
 pub use widgets::theme::separator::SeparatorTheme;
 pub use widgets::theme::slider::SliderTheme;
@@ -485,7 +519,7 @@ mod hot_struct_sizes {
     /// sole feature-conditional footprint a `Ui` used to carry was
     /// `LayoutCounters`' `bench`-only `PhaseTimings`, and that rode
     /// `LayoutEngine` into [`FrameEngines`](crate::ui::frame_engines).
-    const UI_SIZE: usize = 5256;
+    const UI_SIZE: usize = 6048;
 
     /// Expected `size_of::<FrameEngines>()`. Two numbers because
     /// `LayoutCounters` carries a `PhaseTimings` only under `bench` — the
@@ -522,7 +556,7 @@ mod hot_struct_sizes {
         LayoutCore => "scene::LayoutCore": 28 / 4,
         NodeFlags => "scene::NodeFlags": 2 / 2,
         ExtrasIdx => "scene::ExtrasIdx": 6 / 2,
-        BoundsExtras => "scene::BoundsExtras": 32 / 4,
+        BoundsExtras => "scene::BoundsExtras": 40 / 4,
         PanelExtras => "scene::PanelExtras": 20 / 4,
         Node => "scene::Node": 120 / 8,
         // Per-shape / per-chrome paint records + lowered fill forms.
@@ -543,13 +577,13 @@ mod hot_struct_sizes {
         ComboBox<'static, &'static str> => "widgets::ComboBox": 160 / 8,
         DragValue<'static> => "widgets::DragValue": 200 / 8,
         RadioButton<'static, u8> => "widgets::RadioButton<u8>": 168 / 8,
-        TextEdit<'static> => "widgets::TextEdit": 184 / 8,
-        Text<'static> => "widgets::Text": 160 / 8,
+        TextEdit<'static> => "widgets::TextEdit": 264 / 8,
+        Text<'static> => "widgets::Text": 176 / 8,
         Slider<'static> => "widgets::Slider": 152 / 8,
         ProgressBar<'static> => "widgets::ProgressBar": 136 / 8,
         Splitter<'static> => "widgets::Splitter": 144 / 8,
         // Layout / text outputs.
-        ShapedText => "layout::ShapedText": 32 / 8,
+        ShapedText => "layout::ShapedText": 40 / 8,
         TextShapeKey => "text::TextShapeKey": 24 / 8,
         // Cross-frame animation rows.
         AnimRow<AnimatedLook> => "animation::AnimRow<AnimatedLook>": 472 / 8,
@@ -560,7 +594,7 @@ mod hot_struct_sizes {
         EntryRow => "cascade::EntryRow": 32 / 4,
         HitRow => "cascade::HitRow": 32 / 8,
         Paint => "cascade::Paint": 24 / 8,
-        ResponseState => "input::ResponseState": 136 / 4,
+        ResponseState => "input::ResponseState": 184 / 4,
         Widget => "widgets::Widget": 128 / 8,
         TargetScrollDelta => "input::TargetScrollDelta": 32 / 8,
         // Damage.
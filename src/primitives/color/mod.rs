@@ -162,6 +162,66 @@ impl Color {
         }
     }
 
+    /// [`Self::lerp`] through Oklab: the colour channels blend in the
+    /// perceptual space [`Interp::Oklab`](crate::Interp) uses for
+    /// gradients, alpha blends straight. A light-to-dark swap then passes
+    /// through an even mid-grey rather than the dark-heavy linear-light
+    /// midpoint, and complementary pairs don't dip to mud.
+    ///
+    /// A fully transparent end has no hue of its own — its channels are
+    /// whatever the author left there, usually black — so it borrows the
+    /// other end's and only the alpha travels: a fill fading in from
+    /// [`Self::TRANSPARENT`] keeps its colour the whole way.
+    ///
+    /// `t = 0` and `t = 1` return the ends exactly rather than after a
+    /// round trip through Oklab, so a blend parked at either end compares
+    /// equal to it.
+    pub fn lerp_oklab(self, other: Self, t: f32) -> Self {
+        if t == 0.0 {
+            return self;
+        }
+        if t == 1.0 {
+            return other;
+        }
+        let (from, to) = match (self.is_noop(), other.is_noop()) {
+            (true, false) => (other.with_alpha(self.a), other),
+            (false, true) => (self, self.with_alpha(other.a)),
+            _ => (self, other),
+        };
+        let a = linear_to_oklab(from.r, from.g, from.b);
+        let b = linear_to_oklab(to.r, to.g, to.b);
+        let [r, g, b] = oklab_to_linear(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t));
+        Self {
+            r,
+            g,
+            b,
+            a: from.a + (to.a - from.a) * t,
+        }
+    }
+
+    /// WCAG 2 relative luminance: the Rec. 709 weighting of the linear
+    /// channels, `0.0` for black to `1.0` for white. Storage is already
+    /// linear, so this is the weighted sum with no decode. Alpha is
+    /// ignored — see [`Self::contrast_ratio`] for a colour over a backdrop.
+    pub fn relative_luminance(self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// WCAG 2 contrast ratio of `self` drawn over `background`, from
+    /// `1.0` (identical) to `21.0` (black on white). AA asks 4.5 for body
+    /// text and 3.0 for large text and UI chrome.
+    ///
+    /// A translucent `self` is composited over `background` first, so a
+    /// 20 % tint is judged by what actually reaches the screen;
+    /// `background`'s own alpha is ignored — it is the surface, and a
+    /// surface is taken as opaque.
+    pub fn contrast_ratio(self, background: Self) -> f32 {
+        let a = self.a.clamp(0.0, 1.0);
+        let seen = background.lerp(self.with_alpha(1.0), a);
+        let (l1, l2) = (seen.relative_luminance(), background.relative_luminance());
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
     /// 8-bit sRGB channels (Figma/CSS/Photoshop convention). Linearized
     /// internally, same as `Color::rgb`. `#3366CC` → `Color::rgb_u8(0x33, 0x66, 0xCC)`.
     pub const fn rgb_u8(r: u8, g: u8, b: u8) -> Self {
@@ -546,15 +606,12 @@ const fn srgb_to_linear(c: f32) -> f32 {
     c * (c * (c * 0.305_306_01 + 0.682_171_1) + 0.012_522_878)
 }
 
-// Used by the gradient LUT bake; no other in-crate caller until slice 2
-// wires the atlas through the encoder/composer. `dead_code` would
-// otherwise trip `clippy -D warnings` on a clean step-1-only branch.
 /// Linear-RGB → Oklab. Matrix constants from Björn Ottosson's reference
 /// (https://bottosson.github.io/posts/oklab/). Used by the gradient LUT
-/// bake when `Interp::Oklab` is selected — interpolation in Oklab gives
-/// perceptually-uniform transitions without the muddy red↔green
-/// midpoint that linear-RGB lerps produce. Output components are
-/// roughly `L ∈ 0..1, a/b ∈ -0.5..0.5`.
+/// bake when `Interp::Oklab` is selected and by [`Color::lerp_oklab`] —
+/// interpolation in Oklab gives perceptually-uniform transitions without
+/// the muddy red↔green midpoint that linear-RGB lerps produce. Output
+/// components are roughly `L ∈ 0..1, a/b ∈ -0.5..0.5`.
 #[inline]
 pub(crate) fn linear_to_oklab(r: f32, g: f32, b: f32) -> [f32; 3] {
     let l = 0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b;
@@ -189,6 +189,57 @@ fn lerp_spans_both_endpoints_and_overshoots() {
     assert_eq!(a.lerp(b, 2.0).r, -1.0);
 }
 
+#[test]
+fn lerp_oklab_passes_through_a_perceptual_midpoint() {
+    let close = |x: Color, y: Color| {
+        (x.r - y.r).abs() < 1e-3
+            && (x.g - y.g).abs() < 1e-3
+            && (x.b - y.b).abs() < 1e-3
+            && (x.a - y.a).abs() < 1e-6
+    };
+    let black = Color::BLACK;
+    let white = Color::WHITE;
+    assert!(close(black.lerp_oklab(white, 0.0), black));
+    assert!(close(black.lerp_oklab(white, 1.0), white));
+
+    // Oklab's L is roughly the cube root of linear light, so the halfway
+    // grey sits at L = 0.5 — an eighth of linear white, far darker than
+    // the straight lerp's 0.5.
+    let mid = black.lerp_oklab(white, 0.5);
+    assert!((mid.r - 0.125).abs() < 1e-3, "{mid:?}");
+    assert!((mid.r - mid.g).abs() < 1e-4 && (mid.g - mid.b).abs() < 1e-4);
+
+    // A transparent end borrows the other's hue: only alpha moves.
+    let red = Color::rgb(0.9, 0.1, 0.1);
+    let fading = Color::TRANSPARENT.lerp_oklab(red, 0.5);
+    assert!(close(fading, red.with_alpha(0.5)), "{fading:?}");
+    assert!(close(
+        red.lerp_oklab(Color::TRANSPARENT, 0.25),
+        red.with_alpha(0.75)
+    ));
+}
+
+#[test]
+fn contrast_ratio_matches_the_wcag_reference_points() {
+    let ratio = |fg: Color, bg: Color| fg.contrast_ratio(bg);
+    assert!((ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 1e-4);
+    assert_eq!(ratio(Color::WHITE, Color::WHITE), 1.0);
+    assert_eq!(
+        ratio(Color::WHITE, Color::BLACK),
+        ratio(Color::BLACK, Color::WHITE),
+        "symmetric for opaque colours",
+    );
+    // #767676 on white is the classic just-passes-AA grey: 4.54:1.
+    let grey = ratio(Color::hex(0x767676), Color::WHITE);
+    assert!((grey - 4.54).abs() < 0.02, "{grey}");
+
+    // A translucent foreground is judged after compositing: black at
+    // zero alpha is the background itself.
+    assert_eq!(ratio(Color::BLACK.with_alpha(0.0), Color::WHITE), 1.0);
+    let half = ratio(Color::BLACK.with_alpha(0.5), Color::WHITE);
+    assert!(half > 1.0 && half < 21.0, "{half}");
+}
+
 /// The direct `ColorF16 → ColorU8` quantize must stay byte-identical
 /// to the two-hop form (`ColorU8::from(Color::from(x))`) it replaced
 /// at the composer's per-run/tint call sites.
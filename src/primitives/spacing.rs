@@ -24,6 +24,14 @@ impl Spacing {
     pub(crate) fn as_u64(self) -> u64 {
         self.0.as_u64()
     }
+
+    /// `left` and `right` traded — the spacing as a right-to-left node
+    /// lays it out.
+    #[inline]
+    pub(crate) fn mirrored_x(self) -> Self {
+        let [l, t, r, b] = self.as_array();
+        Self::new(r, t, l, b)
+    }
 }
 
 impl Spacing {
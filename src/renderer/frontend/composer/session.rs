@@ -359,7 +359,8 @@ impl PaintSink for ComposeSession<'_> {
         // The raster size is decided here, not upstream: this is the first
         // point that knows the display scale and every ancestor transform, and
         // so the first point that knows how many device pixels the icon covers.
-        let key = IconRasterKey::for_box(p.icon, Vec2::new(phys_rect.size.w, phys_rect.size.h));
+        let key = IconRasterKey::for_box(p.icon, Vec2::new(phys_rect.size.w, phys_rect.size.h))
+            .mirrored(p.mirror);
         // Whole-pixel origin, with the raster centred in the box it was sized
         // from. The ladder can round the raster a pixel or two off that box
         // (§ `IconRasterKey`), and centring spreads the difference instead of
@@ -137,6 +137,7 @@ pub(super) fn icon(buf: &mut PaintCapture, r: Rect, icon: IconRef) {
         icon,
         tint: Color::WHITE.into(),
         desaturate: false,
+        mirror: false,
     });
 }
 
@@ -305,6 +305,7 @@ impl LayerCtx<'_> {
                 fit,
                 tint,
                 desaturate,
+                mirror,
             } => {
                 let base = geometry::resolve_local_rect(owner_rect, *local_rect);
                 out.draw_icon(DrawIconPayload {
@@ -312,6 +313,7 @@ impl LayerCtx<'_> {
                     icon: handle.icon,
                     tint: *tint,
                     desaturate: *desaturate,
+                    mirror: *mirror,
                 });
             }
             ShapeRecord::Image {
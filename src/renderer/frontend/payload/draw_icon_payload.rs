@@ -19,6 +19,9 @@ pub(crate) struct DrawIconPayload {
     pub(crate) tint: ColorF16,
     /// Draw a colour icon as its own luminance.
     pub(crate) desaturate: bool,
+    /// Flip the artwork horizontally — a directional icon under a
+    /// right-to-left owner.
+    pub(crate) mirror: bool,
 }
 
 impl DrawIconPayload {
@@ -4,12 +4,14 @@
 //! per-frame data input and rendering read.
 
 use crate::layout::scrollbars::ScrollbarsDef;
+use crate::layout::types::grid_template::GridTemplate;
 use crate::layout::types::layout_mode::{GridDefId, ScrollbarsDefId};
 use crate::layout::types::placement::Placement;
-use crate::layout::types::track::Track;
 use crate::primitives::background::Background;
+use crate::primitives::size::Size;
 use crate::primitives::widget_id::WidgetId;
 use crate::scene::layer::{Layer, PerLayer};
+use crate::scene::node::node_flags::NodeFlags;
 use crate::scene::node::{Node, Salt};
 use crate::scene::record_store::RecordStore;
 use crate::scene::seen_ids::{CollisionRecord, Endpoint, SeenIds};
@@ -97,15 +99,9 @@ impl Forest {
     /// through the active layer here is what makes that hold by
     /// construction rather than by every caller remembering.
     #[inline]
-    pub(crate) fn push_grid_def(
-        &mut self,
-        rows: &[Track],
-        cols: &[Track],
-        row_gap: f32,
-        col_gap: f32,
-    ) -> GridDefId {
+    pub(crate) fn push_grid_def(&mut self, template: GridTemplate<'_>) -> GridDefId {
         let layer = self.current_layer();
-        self.trees[layer].push_grid_def(rows, cols, row_gap, col_gap)
+        self.trees[layer].push_grid_def(template)
     }
 
     /// Intern a bar overlay's definition into the current layer's tree.
@@ -145,13 +141,14 @@ impl Forest {
         self.ids.curr[&id].node
     }
 
-    pub(crate) fn pre_record(&mut self) {
+    /// Reset for a record pass against a `viewport`-sized surface.
+    pub(crate) fn pre_record(&mut self, viewport: Size) {
         self.record_store.clear();
         self.layer_stack.clear();
         self.ids.pre_record();
         self.collisions.clear();
         for t in self.trees.iter_mut() {
-            t.pre_record();
+            t.pre_record(viewport);
         }
         for s in self.scratch.iter_mut() {
             s.clear();
@@ -294,8 +291,8 @@ impl Forest {
     /// Asserts a node is currently open so widgets can't leak shapes
     /// outside an `open_node` / `close_node` scope.
     pub(crate) fn add_shape<S: Lower>(&mut self, shape: S) {
-        self.push_shape("add_shape", |tree, store| {
-            tree.shapes.add(shape, store).is_some()
+        self.push_shape("add_shape", |tree, store, rtl| {
+            tree.shapes.add(shape, store, rtl).is_some()
         });
     }
 
@@ -309,7 +306,7 @@ impl Forest {
     /// from a user-facing [`Shape`](crate::Shape), so it skips the lowering
     /// path and can never noop-collapse.
     pub(crate) fn add_gpu_view(&mut self, epoch: u64) {
-        self.push_shape("add_gpu_view", |tree, _| {
+        self.push_shape("add_gpu_view", |tree, _, _| {
             tree.shapes.add_gpu_view(epoch);
             true
         });
@@ -328,7 +325,7 @@ impl Forest {
         // Disjoint borrow: `trees` and `scratch` are separate fields.
         let tree = &mut self.trees[layer];
         let frame = self.scratch[layer].open_frames.last_mut().unwrap();
-        let Some(shape_idx) = tree.shapes.add(shape, &self.record_store) else {
+        let Some(shape_idx) = tree.shapes.add(shape, &self.record_store, frame.rtl) else {
             return;
         };
         let row = frame.paint_rows;
@@ -359,13 +356,14 @@ impl Forest {
     /// index *and* the open frame after the push, which would mean
     /// handing the closure the frame too.
     #[inline]
-    fn push_shape(&mut self, what: &str, push: impl FnOnce(&mut Tree, &RecordStore) -> bool) {
+    fn push_shape(&mut self, what: &str, push: impl FnOnce(&mut Tree, &RecordStore, bool) -> bool) {
         let layer = self.current_layer();
         self.assert_node_open(layer, what);
         // Disjoint borrow: record storage, `trees`, and `scratch` are
         // separate fields, so all three can be borrowed for the same call.
         let tree = &mut self.trees[layer];
-        if push(tree, &self.record_store) {
+        let rtl = self.scratch[layer].inherited_rtl();
+        if push(tree, &self.record_store, rtl) {
             self.scratch[layer]
                 .open_frames
                 .last_mut()
@@ -392,12 +390,21 @@ impl Forest {
             "Ui::layer({layer:?}) must rank above the current scope ({active:?}) \
              in Layer::PAINT_ORDER — a nested layer painting under its parent is a bug",
         );
+        // The raised layer reads the way the scope raising it does, and
+        // so does its placement against the anchor: a menu under a
+        // right-to-left trigger hangs from the trigger's right edge.
+        let rtl = self.scratch[active].inherited_rtl();
         let scratch = &mut self.scratch[layer];
         debug_assert!(
             scratch.open_frames.is_empty(),
             "Ui::layer({layer:?}) called while a node is still open in that layer",
         );
-        scratch.pending_placement = Some(placement);
+        scratch.pending_placement = Some(if rtl {
+            placement.mirrored_x()
+        } else {
+            placement
+        });
+        scratch.root_rtl = rtl;
         self.layer_stack.push(layer);
     }
 
@@ -414,6 +421,7 @@ impl Forest {
             layer,
         );
         scratch.pending_placement = None;
+        scratch.root_rtl = false;
     }
 
     /// Borrow the tree for the [`Self::current_layer`] — the one
@@ -432,6 +440,17 @@ impl Forest {
         &self.scratch[self.current_layer()]
     }
 
+    /// Whether a node opened now with `flags` resolves right to left:
+    /// its own direction if it names one, else the open scope's. What a
+    /// widget reads before recording, when its input mapping depends on
+    /// the direction its node will lay out in.
+    #[inline]
+    pub(crate) fn resolves_rtl(&self, flags: NodeFlags) -> bool {
+        flags
+            .flow_direction()
+            .map_or_else(|| self.current_scratch().inherited_rtl(), |d| d.is_rtl())
+    }
+
     /// `WidgetId` of the innermost open node in the active layer — the
     /// parent context auto/salted ids resolve against (`Ui::widget`)
     /// — or `None` at the top of a layer with no node open yet.
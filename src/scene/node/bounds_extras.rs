@@ -1,5 +1,7 @@
-//! The per-node placement column: explicit position, cell, and size bounds.
+//! The per-node placement column: explicit position, anchor, cell, and
+//! size bounds.
 
+use crate::layout::types::align::{Align, HAlign, VAlign};
 use crate::layout::types::grid_cell::GridCell;
 use crate::primitives::approx::{self, FloatHash};
 use crate::primitives::size::Size;
@@ -12,6 +14,13 @@ pub(crate) struct BoundsExtras {
     pub(crate) grid: GridCell,
     pub(crate) min_size: Size,
     pub(crate) max_size: Size,
+    /// Width / height the node keeps, `0.0` when unconstrained — a
+    /// sentinel rather than an `Option` so the column stays four-byte
+    /// lanes. Read through [`Self::aspect_ratio`].
+    pub(crate) aspect_ratio: f32,
+    /// Edges of a `Canvas` parent the node is pinned to; `Auto` on an
+    /// axis places it at `position` from the top-left.
+    pub(crate) anchor: Align,
 }
 
 impl Hash for BoundsExtras {
@@ -21,6 +30,8 @@ impl Hash for BoundsExtras {
         self.grid.hash(h);
         self.min_size.hash_visual(h);
         self.max_size.hash_visual(h);
+        self.aspect_ratio.hash_visual(h);
+        h.write_u8(self.anchor.raw());
     }
 }
 
@@ -35,6 +46,8 @@ impl BoundsExtras {
         },
         min_size: Size::ZERO,
         max_size: Size::INF,
+        aspect_ratio: 0.0,
+        anchor: Align::new(HAlign::Auto, VAlign::Auto),
     };
 
     #[inline]
@@ -44,6 +57,14 @@ impl BoundsExtras {
             && self.grid == Self::DEFAULT.grid
             && self.min_size.approx_zero()
             && self.max_size == Self::DEFAULT.max_size
+            && self.aspect_ratio == 0.0
+            && self.anchor == Self::DEFAULT.anchor
+    }
+
+    /// The configured width / height ratio, if any.
+    #[inline]
+    pub(crate) fn aspect_ratio(&self) -> Option<f32> {
+        (self.aspect_ratio > 0.0).then_some(self.aspect_ratio)
     }
 }
 
@@ -2,6 +2,7 @@
 
 use crate::layout::types::layout_mode::{LayoutMode, PackedLayoutMeta};
 use crate::layout::types::sizing::Sizes;
+use crate::primitives::size::Size;
 use crate::primitives::spacing::Spacing;
 use crate::scene::node::Node;
 use crate::scene::node::node_flags::NodeFlags;
@@ -16,10 +17,14 @@ pub(crate) struct LayoutCore {
 }
 
 impl LayoutCore {
-    pub(super) fn from_node(node: &Node) -> Self {
+    pub(super) fn from_node(node: &Node, viewport: Size) -> Self {
         let mode = node.mode.resolved();
+        let size = node.size.unwrap_or_default();
         Self {
-            size: node.size.unwrap_or_default(),
+            size: Sizes::new(
+                size.w().of_viewport(viewport),
+                size.h().of_viewport(viewport),
+            ),
             padding: node.padding.unwrap_or(Spacing::ZERO),
             margin: node.margin.unwrap_or(Spacing::ZERO),
             meta: PackedLayoutMeta::new(mode, node.align, node.visibility),
@@ -10,10 +10,14 @@ pub(crate) mod panel_extras;
 use crate::input::key_class::KeyFilter;
 use crate::input::sense::Sense;
 use crate::layout::types::align::{Align, HAlign, VAlign};
+use crate::layout::types::align_content::AlignContent;
 use crate::layout::types::clip_mode::ClipMode;
-use crate::layout::types::grid_cell::GridCell;
+use crate::layout::types::flow_direction::FlowDirection;
+use crate::layout::types::grid_cell::{GridCell, GridPlace};
+use crate::layout::types::grid_template::area_key;
 use crate::layout::types::justify::Justify;
 use crate::layout::types::layout_mode::{GridDefId, LayoutMode, ScrollSpec, ScrollbarsDefId};
+use crate::layout::types::length::{LengthUnit, Lengths};
 use crate::layout::types::limits::{valid_lower_bound, valid_upper_bound};
 use crate::layout::types::sizing::Sizes;
 use crate::primitives::background::Background;
@@ -137,21 +141,34 @@ pub struct Node {
     pub(crate) salt: Salt,
     pub(crate) mode: NodeMode,
 
-    /// The five themable fields are `None` until explicitly set, so
+    /// The five themable fields are unset until explicitly set, so
     /// widgets can layer theme defaults under user intent with a plain
     /// `get_or_insert` / `unwrap_or` — there is no separate provenance
-    /// tracking. [`Self::into_columns`] resolves `None` to the layout
-    /// defaults (`Sizes::default()`, `Size::ZERO`/`Size::INF` bounds,
-    /// `Spacing::ZERO`).
+    /// tracking. [`Self::into_columns`] resolves unset fields to the
+    /// layout defaults (`Sizes::default()`, `Size::ZERO`/`Size::INF`
+    /// bounds, `Spacing::ZERO`).
     pub(crate) size: Option<Sizes>,
-    pub(crate) min_size: Option<Size>,
-    pub(crate) max_size: Option<Size>,
+    /// The two bounds are [`Self::UNSET_BOUND`] rather than `None`, which
+    /// saves each an `Option` tag; read them through
+    /// [`Self::min_bound`] / [`Self::max_bound`].
+    pub(crate) min_size: Size,
+    pub(crate) max_size: Size,
+    /// Per-axis units of `min_size` / `max_size`, pixels unless set
+    /// through a viewport [`Length`](crate::Length). The values stay raw
+    /// until [`Self::into_columns`] resolves them against the viewport.
+    pub(crate) min_units: [LengthUnit; 2],
+    pub(crate) max_units: [LengthUnit; 2],
     pub(crate) padding: Option<Spacing>,
     pub(crate) margin: Option<Spacing>,
     /// Clip mode, `None` until set. Kept out of [`NodeFlags`] during
     /// authoring for the same theme-fallback reason; folded into the
     /// recorded flags by [`Self::into_columns`].
     pub(crate) clip: Option<ClipMode>,
+    /// Width / height, `0.0` until set — the same sentinel
+    /// [`BoundsExtras::aspect_ratio`] keeps, which it is routed into next
+    /// to the size bounds it works with. A plain `f32` fits the padding
+    /// `Node` had spare; an `Option` would not.
+    pub(crate) aspect_ratio: f32,
 
     /// Within-line gap + between-line gap packed as two f16 lanes.
     /// `gaps.gap()` (HStack/VStack/WrapHStack/WrapVStack) is the
@@ -160,17 +177,29 @@ pub struct Node {
     /// Grid (Grid uses its own row_gap/col_gap).
     pub(crate) gaps: Gaps,
 
-    /// Main-axis distribution of leftover space (HStack/VStack only).
+    /// Main-axis distribution of leftover space (HStack/VStack, and each
+    /// line of WrapHStack/WrapVStack).
     pub(crate) justify: Justify,
+    /// Cross-axis distribution of lines (WrapHStack/WrapVStack only).
+    pub(crate) align_content: AlignContent,
     /// Own alignment within the parent's inner rect.
     pub(crate) align: Align,
     /// Default alignment applied to children with `Auto` axis (panels only).
     pub(crate) child_align: Align,
-    /// Absolute position inside a `Canvas` parent (parent-inner coordinates).
-    /// Defaults to `Vec2::ZERO`. Ignored when the parent isn't a `Canvas`.
+    /// Absolute position inside a `Canvas` parent (parent-inner coordinates),
+    /// or the nudge from the edge `anchor` pins the child to. Defaults to
+    /// `Vec2::ZERO`. Ignored when the parent isn't a `Canvas`.
     pub(crate) position: Vec2,
-    /// Cell + span inside a `Grid` parent. Defaults to `(0, 0)` placement and
-    /// `(1, 1)` span. Ignored when the parent isn't a `Grid`.
+    /// Edges of a `Canvas` parent the node is pinned to. `Auto` on both
+    /// axes by default (placed at `position`). Ignored when the parent
+    /// isn't a `Canvas`.
+    pub(crate) anchor: Align,
+    /// Cell + span inside a `Grid` parent. Defaults to
+    /// [`GridCell::UNPLACED`] — the position left to the parent grid —
+    /// and `(1, 1)` span; a `grid_area` child carries its area here as
+    /// [`GridCell::AREA_POSITION`]. Resolved against the parent at record
+    /// time (`Tree::place_in_grid`). Ignored when the parent isn't a
+    /// `Grid`.
     pub(crate) grid: GridCell,
 
     /// Packed paint/input flags copied directly into the recorded tree.
@@ -298,23 +327,67 @@ impl Node {
         spec
     }
 
+    /// The "caller never set this" value of `min_size` / `max_size`.
+    /// No valid bound is NaN ([`debug_assert_valid_bounds`]), so — as
+    /// with `Gaps::UNSET` — NaN carries the flag for free.
+    pub(crate) const UNSET_BOUND: Size = Size::new(f32::NAN, f32::NAN);
+
+    /// `min_size`, if a caller or theme set one.
+    #[inline]
+    pub(crate) fn min_bound(&self) -> Option<Size> {
+        (!self.min_size.w.is_nan()).then_some(self.min_size)
+    }
+
+    /// `max_size`, if a caller or theme set one.
+    #[inline]
+    pub(crate) fn max_bound(&self) -> Option<Size> {
+        (!self.max_size.w.is_nan()).then_some(self.max_size)
+    }
+
+    /// `min_size` as set, units and all — for widgets that forward a
+    /// staged node's bounds to an inner one.
+    pub(crate) fn min_lengths(&self) -> Lengths {
+        Lengths::new(self.min_bound().unwrap_or(Size::ZERO), self.min_units)
+    }
+
+    /// `max_size` as set, units and all. See [`Self::min_lengths`].
+    pub(crate) fn max_lengths(&self) -> Lengths {
+        Lengths::new(self.max_bound().unwrap_or(Size::INF), self.max_units)
+    }
+
+    /// `min_size` resolved to pixels against `viewport`, for widgets
+    /// that raise the floor in pixels while staging the node: the units
+    /// fold in place, so a pixel `max` against a `vw` bound compares
+    /// like with like.
+    pub(crate) fn min_size_px(&mut self, viewport: Size) -> &mut Size {
+        let [w, h] = std::mem::replace(&mut self.min_units, [LengthUnit::Px; 2]);
+        let min = self.min_bound().unwrap_or(Size::ZERO);
+        self.min_size = Size::new(w.resolve(min.w, viewport), h.resolve(min.h, viewport));
+        &mut self.min_size
+    }
+
     #[track_caller]
     fn new(mode: NodeMode) -> Self {
         Self {
             salt: Salt::Auto(WidgetId::auto_stable()),
             mode,
             size: None,
-            min_size: None,
-            max_size: None,
+            min_size: Self::UNSET_BOUND,
+            max_size: Self::UNSET_BOUND,
+            min_units: [LengthUnit::Px; 2],
+            max_units: [LengthUnit::Px; 2],
             padding: None,
             margin: None,
             clip: None,
+            aspect_ratio: 0.0,
             gaps: Gaps::UNSET_PAIR,
             justify: Justify::Start,
+            align_content: AlignContent::Start,
             align: Align::new(HAlign::Auto, VAlign::Auto),
             child_align: Align::new(HAlign::Auto, VAlign::Auto),
             position: Vec2::ZERO,
-            grid: GridCell::default(),
+            anchor: Align::new(HAlign::Auto, VAlign::Auto),
+            grid: GridCell::UNPLACED,
             flags: NodeFlags::default(),
             visibility: Visibility::Visible,
             transform: TranslateScale::IDENTITY,
@@ -335,23 +408,39 @@ impl Node {
     /// has no reason to read it again, and handing it over lets the
     /// chain feed it forward rather than keep a live copy alongside the
     /// columns.
+    ///
+    /// Viewport units — in `size` and in the size bounds — resolve to
+    /// pixels here against `viewport`, so the columns, and every hash
+    /// folded from them, only ever hold pixels: a resize re-keys exactly
+    /// the nodes that read the viewport.
     #[inline(always)]
-    pub(super) fn into_columns(self, widget_id: WidgetId) -> NodeColumns {
+    pub(super) fn into_columns(self, widget_id: WidgetId, viewport: Size) -> NodeColumns {
         let mut attrs = self.flags;
         attrs.set_clip(self.clip.unwrap_or(ClipMode::None));
+        let resolve = |value: Size, [w, h]: [LengthUnit; 2]| {
+            Size::new(w.resolve(value.w, viewport), h.resolve(value.h, viewport))
+        };
+        let min_size = resolve(self.min_bound().unwrap_or(Size::ZERO), self.min_units);
+        // Bounds in different units are only ordered once resolved; where
+        // they cross, the minimum wins, as it does in CSS.
+        let max_size = resolve(self.max_bound().unwrap_or(Size::INF), self.max_units);
+        let max_size = Size::new(max_size.w.max(min_size.w), max_size.h.max(min_size.h));
         NodeColumns {
             widget_id,
-            layout: LayoutCore::from_node(&self),
+            layout: LayoutCore::from_node(&self, viewport),
             attrs,
             bounds: BoundsExtras {
                 position: self.position,
-                grid: self.grid,
-                min_size: self.min_size.unwrap_or(Size::ZERO),
-                max_size: self.max_size.unwrap_or(Size::INF),
+                grid: self.grid.at_origin_if_unplaced(),
+                min_size,
+                max_size,
+                aspect_ratio: self.aspect_ratio,
+                anchor: self.anchor,
             },
             panel: PanelExtras {
                 gaps: self.gaps,
                 justify: self.justify,
+                align_content: self.align_content,
                 child_align: self.child_align,
                 transform: self.transform,
             },
@@ -368,15 +457,22 @@ pub struct ConfigureNode<'a> {
 }
 
 #[inline]
-fn debug_assert_valid_bounds(min_size: Size, max_size: Size) {
+fn same_units(a: [LengthUnit; 2], b: [LengthUnit; 2]) -> [bool; 2] {
+    [a[0] == b[0], a[1] == b[1]]
+}
+
+/// `ordered` names the axes whose two bounds share a unit — the only
+/// ones whose order can be checked before the viewport is known.
+#[inline]
+fn debug_assert_valid_bounds(min_size: Size, max_size: Size, ordered: [bool; 2]) {
     // Builder setters run per widget per frame, so validation compiles out in release.
     debug_assert!(
         valid_lower_bound(min_size.w)
             && valid_lower_bound(min_size.h)
             && valid_upper_bound(max_size.w)
             && valid_upper_bound(max_size.h)
-            && min_size.w <= max_size.w
-            && min_size.h <= max_size.h,
+            && (!ordered[0] || min_size.w <= max_size.w)
+            && (!ordered[1] || min_size.h <= max_size.h),
         "node minimums must be finite, bounds must be non-negative and ordered, and only \
          maximums may be infinite; got min_size {min_size:?}, max_size {max_size:?}",
     );
@@ -476,18 +572,59 @@ pub trait Configure: Sized {
         self.node_mut().node.size = Some(s.into());
         self
     }
-    fn min_size(mut self, s: impl Into<Size>) -> Self {
+    /// Lower size bound, per axis in pixels or viewport units — see
+    /// [`Length`](crate::Length).
+    fn min_size(mut self, s: impl Into<Lengths>) -> Self {
         let node = self.node_mut().node;
-        let value = s.into();
-        debug_assert_valid_bounds(value, node.max_size.unwrap_or(Size::INF));
-        node.min_size = Some(value);
+        let s = s.into();
+        let (value, units) = (s.values(), s.units());
+        debug_assert_valid_bounds(
+            value,
+            node.max_bound().unwrap_or(Size::INF),
+            same_units(units, node.max_units),
+        );
+        node.min_size = value;
+        node.min_units = units;
         self
     }
-    fn max_size(mut self, s: impl Into<Size>) -> Self {
+    /// Upper size bound, per axis in pixels or viewport units — see
+    /// [`Length`](crate::Length).
+    fn max_size(mut self, s: impl Into<Lengths>) -> Self {
         let node = self.node_mut().node;
-        let value = s.into();
-        debug_assert_valid_bounds(node.min_size.unwrap_or(Size::ZERO), value);
-        node.max_size = Some(value);
+        let s = s.into();
+        let (value, units) = (s.values(), s.units());
+        debug_assert_valid_bounds(
+            node.min_bound().unwrap_or(Size::ZERO),
+            value,
+            same_units(node.min_units, units),
+        );
+        node.max_size = value;
+        node.max_units = units;
+        self
+    }
+
+    /// Keep this node's width / height at `ratio`, resolving one axis
+    /// from the other: `16.0 / 9.0` under `Sizing::FILL` width gives a
+    /// thumbnail whose height follows whatever width its parent grants.
+    ///
+    /// The *driving* axis is picked from [`Sizing`](crate::Sizing): a Fixed axis drives,
+    /// then a Fill axis, then width. The other axis then sizes as if it
+    /// were `Sizing::fixed(driving / ratio)` (or `× ratio` when height
+    /// drives) — in measure, in arrange, and in the intrinsic queries a
+    /// Grid or Stack runs against it — still clamped to its own
+    /// `min_size` / `max_size`. Content that doesn't fit the derived
+    /// extent overflows it, as it would a fixed one. With both axes
+    /// Fixed there is nothing left to resolve and the ratio is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Debug builds panic unless `ratio` is finite and positive.
+    fn aspect_ratio(mut self, ratio: f32) -> Self {
+        debug_assert!(
+            ratio.is_finite() && ratio > 0.0,
+            "aspect ratio must be finite and positive; got {ratio}"
+        );
+        self.node_mut().node.aspect_ratio = ratio;
         self
     }
     fn padding(mut self, p: impl Into<Spacing>) -> Self {
@@ -528,25 +665,55 @@ pub trait Configure: Sized {
     }
 
     /// Absolute position inside a `Canvas` parent (parent-inner coords).
-    /// Ignored by other layout modes.
+    /// On an axis [`Self::anchor`] pins, an offset from the anchored spot
+    /// instead. Ignored by other layout modes.
     fn position(mut self, p: impl Into<Vec2>) -> Self {
         self.node_mut().node.position = p.into();
         self
     }
-    /// Cell `(row, col)` inside a `Grid` parent. Default `(0, 0)`. Ignored
-    /// outside a Grid parent.
+    /// Pin to the edges of a `Canvas` parent: `Right` / `Bottom` hold the
+    /// child against that edge, `Center` centres it, `Stretch` spans edge
+    /// to edge, and `.margin(...)` insets it from the edges it is pinned
+    /// to. `Auto` (the default) leaves that axis at `position`. Ignored by
+    /// other layout modes — their children use [`Self::align`].
+    fn anchor(mut self, a: Align) -> Self {
+        self.node_mut().node.anchor = a;
+        self
+    }
+    /// Cell `(row, col)` inside a `Grid` parent. Default `(0, 0)`, or the
+    /// next free cell under [`Grid::auto_flow`](crate::Grid::auto_flow).
+    /// Ignored outside a Grid parent.
     fn grid_cell(mut self, (row, col): (u16, u16)) -> Self {
+        debug_assert!(
+            row < GridCell::AREA_POSITION && col < GridCell::AREA_POSITION,
+            "grid cell ({row}, {col}) collides with a placement marker"
+        );
         let node = self.node_mut().node;
+        if let GridPlace::Area(_) = node.grid.place() {
+            node.grid = GridCell::default();
+        }
         node.grid.row = row;
         node.grid.col = col;
         self
     }
+    /// Place into the parent `Grid`'s area `name`, as laid out by
+    /// [`Grid::areas`](crate::Grid::areas) — cell and span both. A name
+    /// the template lacks panics at record time in debug builds.
+    /// Ignored outside a Grid parent.
+    fn grid_area(mut self, name: &str) -> Self {
+        self.node_mut().node.grid = GridCell::area(area_key(name));
+        self
+    }
     /// Span `(row_span, col_span)` inside a `Grid` parent. Default `(1, 1)`.
     /// Cell + span are validated against the parent's grid def at record
-    /// time — an out-of-range placement panics (`Tree::check_grid_cell`).
-    /// Ignored outside a Grid parent.
+    /// time — an out-of-range placement panics (`Tree::place_in_grid`).
+    /// Inert after [`Self::grid_area`], whose area sets the span. Ignored
+    /// outside a Grid parent.
     fn grid_span(mut self, (rs, cs): (u16, u16)) -> Self {
         let node = self.node_mut().node;
+        if let GridPlace::Area(_) = node.grid.place() {
+            return self;
+        }
         node.grid.row_span = rs.max(1);
         node.grid.col_span = cs.max(1);
         self
@@ -567,12 +734,20 @@ pub trait Configure: Sized {
         self.node_mut().node.gaps.set_line_gap(g);
         self
     }
-    /// Main-axis distribution of leftover space for `HStack`/`VStack`.
-    /// Ignored when any child has [`crate::Sizing::fill`] on the main axis.
+    /// Main-axis distribution of leftover space for `HStack`/`VStack`, and
+    /// per line for `WrapHStack`/`WrapVStack`. Ignored when any child (of
+    /// that line) has [`crate::Sizing::fill`] on the main axis.
     fn justify(mut self, j: Justify) -> Self {
         self.node_mut().node.justify = j;
         self
     }
+    /// Cross-axis distribution of the lines of a `WrapHStack`/
+    /// `WrapVStack` when they don't fill it. Mirrors CSS `align-content`.
+    /// Inert in every other layout mode.
+    fn align_content(mut self, a: AlignContent) -> Self {
+        self.node_mut().node.align_content = a;
+        self
+    }
     /// Alignment inside the parent's inner rect. For single-axis use the
     /// [`Align::h`] / [`Align::v`] constructors.
     fn align(mut self, a: Align) -> Self {
@@ -590,6 +765,14 @@ pub trait Configure: Sized {
         self.node_mut().node.flags.set_sense(s);
         self
     }
+    /// Lay this subtree out left to right or mirrored. Unset, a node takes
+    /// its parent's direction — a layer root takes the direction of the
+    /// scope that raised it — and the `Main` root reads left to right.
+    /// See [`FlowDirection`].
+    fn flow_direction(mut self, d: FlowDirection) -> Self {
+        self.node_mut().node.flags.set_flow_direction(d);
+        self
+    }
     /// Suppress this node's interactions and cascade to all descendants.
     fn disabled(mut self, d: bool) -> Self {
         self.node_mut().node.flags.set_disabled(d);
@@ -727,10 +910,14 @@ pub(crate) trait ThemeDefaults: Configure {
     /// Lower size bound to fall back on when the caller set none.
     fn default_min_size(mut self, s: impl Into<Size>) -> Self {
         let node = self.node_mut().node;
-        if node.min_size.is_none() {
+        if node.min_bound().is_none() {
             let value = s.into();
-            debug_assert_valid_bounds(value, node.max_size.unwrap_or(Size::INF));
-            node.min_size = Some(value);
+            debug_assert_valid_bounds(
+                value,
+                node.max_bound().unwrap_or(Size::INF),
+                same_units([LengthUnit::Px; 2], node.max_units),
+            );
+            node.min_size = value;
         }
         self
     }
@@ -738,10 +925,14 @@ pub(crate) trait ThemeDefaults: Configure {
     /// Upper size bound to fall back on when the caller set none.
     fn default_max_size(mut self, s: impl Into<Size>) -> Self {
         let node = self.node_mut().node;
-        if node.max_size.is_none() {
+        if node.max_bound().is_none() {
             let value = s.into();
-            debug_assert_valid_bounds(node.min_size.unwrap_or(Size::ZERO), value);
-            node.max_size = Some(value);
+            debug_assert_valid_bounds(
+                node.min_bound().unwrap_or(Size::ZERO),
+                value,
+                same_units(node.min_units, [LengthUnit::Px; 2]),
+            );
+            node.max_size = value;
         }
         self
     }
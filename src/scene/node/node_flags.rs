@@ -1,8 +1,10 @@
-//! The per-node bitset column: sense, disabled, clip mode, key scope.
+//! The per-node bitset column: sense, disabled, clip mode, key scope,
+//! flow direction.
 
 use crate::input::key_class::KeyFilter;
 use crate::input::sense::Sense;
 use crate::layout::types::clip_mode::ClipMode;
+use crate::layout::types::flow_direction::FlowDirection;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub(crate) struct NodeFlags {
@@ -17,6 +19,13 @@ impl NodeFlags {
     const FOCUSABLE: u16 = 1 << 8;
     const SCOPE_SHIFT: u16 = 9;
     const SCOPE_MASK: u16 = 0b1_1111 << Self::SCOPE_SHIFT;
+    /// The node names its own [`FlowDirection`] rather than taking its
+    /// parent's.
+    const DIRECTION_SET: u16 = 1 << 14;
+    /// Right to left. Authored alongside `DIRECTION_SET`; once recorded
+    /// (`Tree::open_node`), the direction the node resolved to, inherited
+    /// or not.
+    const RTL: u16 = 1 << 15;
 
     /// The whole bitset, for callers that fold it into a hash rather
     /// than reading one field — [`LayoutCore::hash_with_flags`] mixes
@@ -63,6 +72,26 @@ impl NodeFlags {
         KeyFilter::from_bits_truncate(((self.bits & Self::SCOPE_MASK) >> Self::SCOPE_SHIFT) as u8)
     }
 
+    /// The direction this node names for itself, `None` when it inherits.
+    #[inline]
+    pub(crate) fn flow_direction(self) -> Option<FlowDirection> {
+        (self.bits & Self::DIRECTION_SET != 0).then(|| {
+            if self.is_rtl() {
+                FlowDirection::RightToLeft
+            } else {
+                FlowDirection::LeftToRight
+            }
+        })
+    }
+
+    /// Whether the node lays out right to left. On a recorded node this
+    /// is the resolved direction; on an authoring `Node` only what it set
+    /// for itself.
+    #[inline]
+    pub(crate) fn is_rtl(self) -> bool {
+        self.bits & Self::RTL != 0
+    }
+
     #[inline]
     pub(crate) fn set_sense(&mut self, s: Sense) {
         self.bits = (self.bits & !Self::SENSE_MASK) | ((s.bits() as u16) & Self::SENSE_MASK);
@@ -83,6 +112,18 @@ impl NodeFlags {
         self.bits = (self.bits & !Self::FOCUSABLE) | (if v { Self::FOCUSABLE } else { 0 });
     }
 
+    #[inline]
+    pub(crate) fn set_flow_direction(&mut self, d: FlowDirection) {
+        self.bits |= Self::DIRECTION_SET;
+        self.set_rtl(d.is_rtl());
+    }
+
+    /// Stamp the resolved direction, leaving `DIRECTION_SET` alone.
+    #[inline]
+    pub(crate) fn set_rtl(&mut self, v: bool) {
+        self.bits = (self.bits & !Self::RTL) | (if v { Self::RTL } else { 0 });
+    }
+
     #[inline]
     pub(crate) fn set_key_filter(&mut self, f: KeyFilter) {
         self.bits = (self.bits & !Self::SCOPE_MASK)
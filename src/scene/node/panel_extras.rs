@@ -1,6 +1,8 @@
-//! The per-node container column: gaps, justification, child alignment, transform.
+//! The per-node container column: gaps, justification, line alignment,
+//! child alignment, transform.
 
 use crate::layout::types::align::{Align, HAlign, VAlign};
+use crate::layout::types::align_content::AlignContent;
 use crate::layout::types::justify::Justify;
 use crate::primitives::approx::FloatHash;
 use crate::primitives::translate_scale::TranslateScale;
@@ -11,6 +13,7 @@ use std::hash::Hash;
 pub(crate) struct PanelExtras {
     pub(crate) gaps: Gaps,
     pub(crate) justify: Justify,
+    pub(crate) align_content: AlignContent,
     pub(crate) child_align: Align,
     pub(crate) transform: TranslateScale,
 }
@@ -21,7 +24,8 @@ impl Hash for PanelExtras {
         let gaps_u32 = self.gaps.resolved();
         let packed = (gaps_u32 as u64)
             | ((self.child_align.raw() as u64) << 32)
-            | ((self.justify as u64) << 40);
+            | ((self.justify as u64) << 40)
+            | ((self.align_content as u64) << 48);
         h.write_u64(packed);
         if !self.transform.is_noop() {
             h.write_u8(1);
@@ -37,6 +41,7 @@ impl PanelExtras {
     pub(crate) const DEFAULT: Self = Self {
         gaps: Gaps::ZERO,
         justify: Justify::Start,
+        align_content: AlignContent::Start,
         child_align: Align::new(HAlign::Auto, VAlign::Auto),
         transform: TranslateScale::IDENTITY,
     };
@@ -45,6 +50,7 @@ impl PanelExtras {
     pub(crate) fn is_default(&self) -> bool {
         self.gaps.resolved() == Self::DEFAULT.gaps.resolved()
             && self.justify == Self::DEFAULT.justify
+            && self.align_content == Self::DEFAULT.align_content
             && self.child_align == Self::DEFAULT.child_align
             && self.transform.is_noop()
     }
@@ -1,3 +1,5 @@
+use crate::layout::types::grid_cell::GridPlace;
+use crate::layout::types::grid_template::area_key;
 use crate::layout::types::layout_mode::PackedLayoutMeta;
 use crate::layout::types::limits::MAX_PACKED_GAP;
 use crate::primitives::widget_id::WidgetId;
@@ -96,8 +98,8 @@ fn default_setters_fill_only_where_the_caller_stayed_silent() {
         .default_max_size(Size::new(200.0, 300.0));
     assert!(matches!(filled.salt, Salt::Verbatim(v) if v == fallback_id));
     assert_eq!(filled.padding, Some(fallback_padding));
-    assert_eq!(filled.min_size, Some(Size::new(10.0, 0.0)));
-    assert_eq!(filled.max_size, Some(Size::new(200.0, 300.0)));
+    assert_eq!(filled.min_bound(), Some(Size::new(10.0, 0.0)));
+    assert_eq!(filled.max_bound(), Some(Size::new(200.0, 300.0)));
 
     // Caller spoke first: every default is a no-op — including the
     // deliberate zero, which is exactly the value a "is it still the
@@ -113,8 +115,12 @@ fn default_setters_fill_only_where_the_caller_stayed_silent() {
         .default_max_size(Size::new(200.0, 300.0));
     assert!(matches!(kept.salt, Salt::Verbatim(v) if v == caller_id));
     assert_eq!(kept.padding, Some(caller_padding));
-    assert_eq!(kept.min_size, Some(Size::ZERO), "an explicit zero survives");
-    assert_eq!(kept.max_size, Some(Size::new(50.0, 60.0)));
+    assert_eq!(
+        kept.min_bound(),
+        Some(Size::ZERO),
+        "an explicit zero survives"
+    );
+    assert_eq!(kept.max_bound(), Some(Size::new(50.0, 60.0)));
 
     // `id_salt` is explicit too, so it blocks the fallback the same way.
     let salted = Node::leaf().id_salt("row").default_id(fallback_id);
@@ -150,6 +156,7 @@ fn builder_setters_cover_the_complete_external_node_surface() {
         .gap(6.0)
         .line_gap(7.0)
         .justify(Justify::SpaceBetween)
+        .align_content(AlignContent::Center)
         .align(align)
         .child_align(child_align)
         .sense(sense)
@@ -161,8 +168,8 @@ fn builder_setters_cover_the_complete_external_node_surface() {
 
     assert!(matches!(node.salt, Salt::Verbatim(value) if value == id));
     assert_eq!(node.size, Some(size));
-    assert_eq!(node.min_size, Some(min_size));
-    assert_eq!(node.max_size, Some(max_size));
+    assert_eq!(node.min_bound(), Some(min_size));
+    assert_eq!(node.max_bound(), Some(max_size));
     assert_eq!(node.padding, Some(padding));
     assert_eq!(node.margin, Some(margin));
     assert_eq!(node.position, position);
@@ -178,6 +185,7 @@ fn builder_setters_cover_the_complete_external_node_surface() {
     assert_eq!(node.gaps.gap(), 6.0);
     assert_eq!(node.gaps.line_gap(), 7.0);
     assert_eq!(node.justify, Justify::SpaceBetween);
+    assert_eq!(node.align_content, AlignContent::Center);
     assert_eq!(node.align, align);
     assert_eq!(node.child_align, child_align);
     assert_eq!(node.flags.sense(), sense);
@@ -214,8 +222,8 @@ fn widget_specific_node_setters_reach_the_inner_node() {
 fn unconfigured_and_explicit_default_values_remain_distinct() {
     let inherited = Node::leaf();
     assert_eq!(inherited.size, None);
-    assert_eq!(inherited.min_size, None);
-    assert_eq!(inherited.max_size, None);
+    assert_eq!(inherited.min_bound(), None);
+    assert_eq!(inherited.max_bound(), None);
     assert_eq!(inherited.padding, None);
     assert_eq!(inherited.margin, None);
     assert_eq!(inherited.clip, None);
@@ -231,14 +239,14 @@ fn unconfigured_and_explicit_default_values_remain_distinct() {
         .visibility(Visibility::Visible)
         .clip(ClipMode::None);
     assert_eq!(explicit.size, Some(Sizes::default()));
-    assert_eq!(explicit.min_size, Some(Size::ZERO));
-    assert_eq!(explicit.max_size, Some(Size::INF));
+    assert_eq!(explicit.min_bound(), Some(Size::ZERO));
+    assert_eq!(explicit.max_bound(), Some(Size::INF));
     assert_eq!(explicit.padding, Some(Spacing::ZERO));
     assert_eq!(explicit.margin, Some(Spacing::ZERO));
     assert_eq!(explicit.clip, Some(ClipMode::None));
 
     // Explicitly-set defaults record identically to unset fields.
-    let columns = explicit.into_columns(WidgetId::from_hash("explicit-defaults"));
+    let columns = explicit.into_columns(WidgetId::from_hash("explicit-defaults"), Size::ZERO);
     assert_eq!(columns.attrs, NodeFlags::default());
     assert_eq!(columns.bounds, BoundsExtras::DEFAULT);
 }
@@ -261,7 +269,7 @@ fn constructors_install_layout_modes() {
 
     let mut grid = Node::grid();
     assert_eq!(grid.mode, NodeMode::PendingGrid);
-    assert!(std::panic::catch_unwind(|| LayoutCore::from_node(&grid)).is_err());
+    assert!(std::panic::catch_unwind(|| LayoutCore::from_node(&grid, Size::ZERO)).is_err());
     let grid_id = GridDefId::from_index(42);
     grid.set_grid_def(grid_id);
     assert_eq!(grid.mode, NodeMode::Resolved(LayoutMode::Grid(grid_id)));
@@ -343,7 +351,7 @@ fn layout_core_round_trips_mode_align_visibility() {
         let mut node = Node::new(NodeMode::Resolved(mode));
         node.align = align;
         node.visibility = vis;
-        let core = LayoutCore::from_node(&node);
+        let core = LayoutCore::from_node(&node, Size::ZERO);
         assert_eq!(
             LayoutMode::from(core.meta),
             mode,
@@ -365,15 +373,15 @@ fn layout_core_round_trips_mode_align_visibility() {
 #[test]
 fn node_bounds_accept_ordered_ranges_and_equal_axis_boundaries() {
     let min_then_max = Node::leaf().min_size((10.0, 20.0)).max_size((10.0, 30.0));
-    assert_eq!(min_then_max.min_size, Some(Size::new(10.0, 20.0)));
-    assert_eq!(min_then_max.max_size, Some(Size::new(10.0, 30.0)));
+    assert_eq!(min_then_max.min_bound(), Some(Size::new(10.0, 20.0)));
+    assert_eq!(min_then_max.max_bound(), Some(Size::new(10.0, 30.0)));
 
     let max_then_min = Node::leaf().max_size((30.0, 20.0)).min_size((10.0, 20.0));
-    assert_eq!(max_then_min.min_size, Some(Size::new(10.0, 20.0)));
-    assert_eq!(max_then_min.max_size, Some(Size::new(30.0, 20.0)));
+    assert_eq!(max_then_min.min_bound(), Some(Size::new(10.0, 20.0)));
+    assert_eq!(max_then_min.max_bound(), Some(Size::new(30.0, 20.0)));
 
     let unbounded = Node::leaf().max_size(Size::INF);
-    assert_eq!(unbounded.max_size, Some(Size::INF));
+    assert_eq!(unbounded.max_bound(), Some(Size::INF));
 }
 
 #[test]
@@ -425,6 +433,59 @@ fn node_bounds_reject_inversions_on_each_axis_and_setter_order() {
     }
 }
 
+/// Placement rides in `grid`'s marker values: the last of `grid_cell` /
+/// `grid_area` wins, an area owns its span, and an unplaced child
+/// records at the origin with its own span.
+#[test]
+fn grid_placement_calls_combine_through_the_cell_markers() {
+    let key = area_key("main");
+    assert_eq!(Node::leaf().grid.place(), GridPlace::Auto);
+    let area = Node::leaf().grid_span((2, 3)).grid_area("main");
+    assert_eq!(area.grid.place(), GridPlace::Area(key));
+    assert_eq!(area.grid_span((4, 4)).grid.place(), GridPlace::Area(key));
+    assert_eq!(area.grid.at_origin_if_unplaced(), GridCell::default());
+
+    let cell = area.grid_cell((1, 2));
+    assert_eq!(cell.grid.place(), GridPlace::Cell);
+    assert_eq!(
+        cell.grid,
+        GridCell {
+            row: 1,
+            col: 2,
+            row_span: 1,
+            col_span: 1,
+        },
+    );
+    assert_eq!(
+        Node::leaf().grid_span((2, 1)).grid.at_origin_if_unplaced(),
+        GridCell {
+            row: 0,
+            col: 0,
+            row_span: 2,
+            col_span: 1,
+        },
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn aspect_ratio_rejects_non_positive_and_non_finite_values() {
+    type Case = (&'static str, fn() -> Node);
+    let cases: &[Case] = &[
+        ("zero", || Node::leaf().aspect_ratio(0.0)),
+        ("negative", || Node::leaf().aspect_ratio(-1.0)),
+        ("NaN", || Node::leaf().aspect_ratio(f32::NAN)),
+        ("infinite", || Node::leaf().aspect_ratio(f32::INFINITY)),
+    ];
+    for &(label, build) in cases {
+        assert!(
+            std::panic::catch_unwind(build).is_err(),
+            "case `{label}` must panic",
+        );
+    }
+    assert_eq!(Node::leaf().aspect_ratio(1.5).aspect_ratio, 1.5);
+}
+
 #[test]
 #[cfg(debug_assertions)]
 fn packed_gaps_accept_f16_boundaries_and_reject_invalid_values() {
@@ -19,6 +19,7 @@ use crate::primitives::image::ImageFit;
 use crate::primitives::rect::Rect;
 use crate::scene::shapes::paint::{CurveBasis, ImageSource, QuadShape, ShapeBrush};
 use crate::scene::shapes::record::ShapeRecord;
+use crate::text::wrap::TextWrap;
 use std::hash::{Hash, Hasher as _};
 use std::mem;
 
@@ -107,6 +108,7 @@ pub(crate) fn compute_record_hash(record: &ShapeRecord) -> ContentHash {
             font,
             wrap,
             align,
+            fit,
         } => {
             match local_origin {
                 None => h.write_u8(0),
@@ -124,9 +126,20 @@ pub(crate) fn compute_record_hash(record: &ShapeRecord) -> ContentHash {
             // in the node hash (would break damage/reuse).
             let style = ((font.weight as u32) << 24)
                 | ((align.raw() as u32) << 16)
-                | ((*wrap as u32) << 8)
+                | ((wrap.tag() as u32) << 8)
                 | (font.family as u32);
             h.write_u32(style);
+            if let TextWrap::Clamp(lines) = wrap {
+                h.write_u8(*lines);
+            }
+            match fit {
+                None => h.write_u8(0),
+                Some(fit) => {
+                    h.write_u8(1);
+                    fit.min_px.hash_visual(&mut h);
+                    h.write_u16(fit.max_lines);
+                }
+            }
         }
         // Fields named exhaustively for the reason given on the
         // `Polyline` arm above.
@@ -197,12 +210,13 @@ pub(crate) fn compute_record_hash(record: &ShapeRecord) -> ContentHash {
             fit,
             tint,
             desaturate,
+            mirror,
         } => {
             hash_optional_rect(*local_rect, &mut h);
             tint.hash(&mut h);
             h.write_u32(handle.icon.set.bits());
             h.write_u16(handle.icon.icon.0);
-            h.write_u8((*fit as u8) | (u8::from(*desaturate) << 2));
+            h.write_u8((*fit as u8) | (u8::from(*desaturate) << 2) | (u8::from(*mirror) << 3));
         }
         // Geometry + style hashed inline — every input lives on the
         // record, so no lowering-time content hash is needed (unlike
@@ -310,6 +324,7 @@ mod tests {
             },
             wrap: TextWrap::Truncate,
             align: Align::default(),
+            fit: None,
         }
     }
 
@@ -64,11 +64,23 @@ impl Shapes {
     /// Returns the index of the pushed `ShapeRecord` in `self.records`,
     /// or `None` if the shape was dropped as a no-op — the index is what
     /// `Forest::add_shape_animated` keys its paint-anim row by.
-    pub(crate) fn add<S: Lower>(&mut self, shape: S, store: &RecordStore) -> Option<u32> {
+    ///
+    /// `rtl` is the owner's resolved flow direction: a
+    /// [directional](crate::IconShape::directional) icon recorded under a
+    /// right-to-left node keeps its mirror bit, every other record ignores it.
+    pub(crate) fn add<S: Lower>(
+        &mut self,
+        shape: S,
+        store: &RecordStore,
+        rtl: bool,
+    ) -> Option<u32> {
         if shape.is_noop() {
             return None;
         }
-        let record = shape.lower(store);
+        let mut record = shape.lower(store);
+        if let ShapeRecord::Icon { mirror, .. } = &mut record {
+            *mirror &= rtl;
+        }
         // **The one NaN gate.** Lowered rather than authored, because by
         // here every bulk input has been folded into a `bbox` under the
         // AABB NaN contract — so this is `O(1)` for every shape and can
@@ -11,6 +11,7 @@ use crate::primitives::span::Span;
 use crate::scene::shapes::paint::{CurveBasis, ImageSource, QuadShape, ShapeBrush};
 use crate::shape::icon::IconFit;
 use crate::shape::style::{LineCap, LineJoin};
+use crate::text::fit::TextFit;
 use crate::text::glyph_font::GlyphFont;
 use crate::text::wrap::TextWrap;
 use glam::Vec2;
@@ -105,6 +106,10 @@ pub(crate) enum ShapeRecord {
         font: GlyphFont,
         wrap: TextWrap,
         align: Align,
+        /// Shrink-to-fit bounds, with `font` the largest size — layout
+        /// picks the size it paints at, and carries it on the measured
+        /// key. See [`TextShape::fit`](crate::TextShape::fit).
+        fit: Option<TextFit>,
     },
     /// User-supplied colored triangle mesh. Vertex/index data lives on
     /// the `RecordPayloads`' `meshes` pool; these spans index into its
@@ -154,6 +159,10 @@ pub(crate) enum ShapeRecord {
         /// Draw a colour icon as its own luminance — see
         /// [`IconShape::desaturate`](crate::IconShape::desaturate).
         desaturate: bool,
+        /// Draw the artwork flipped horizontally: a
+        /// [directional](crate::IconShape::directional) icon whose owner
+        /// resolved right to left. Settled in `Shapes::add`.
+        mirror: bool,
     },
     /// Native GPU stroke — a cubic Bézier or an exact circular arc, per
     /// [`CurveBasis`] (quadratics promote to cubic at lowering, lines
@@ -13,6 +13,7 @@ use crate::scene::shapes::hash::compute_record_hash;
 use crate::scene::shapes::paint::{LoweredShadow, ShapeStroke};
 use crate::scene::shapes::record::*;
 use crate::shape::rect::RectKind;
+use crate::text::fit::TextFit;
 use crate::text::glyph_font::GlyphFont;
 use crate::text::{FontFamily, FontWeight};
 use glam::Vec2;
@@ -444,6 +445,16 @@ fn every_named_field_either_moves_the_hash_or_is_pinned_as_excluded() {
         font,
         wrap,
         align,
+        fit: None,
+    };
+    let fitted = |min_px, max_lines| ShapeRecord::Text {
+        local_origin: None,
+        text: recorded(1),
+        color: white,
+        font: face,
+        wrap: TextWrap::SingleLine,
+        align: Align::CENTER,
+        fit: Some(TextFit { min_px, max_lines }),
     };
     let base = text(
         None,
@@ -543,6 +554,9 @@ fn every_named_field_either_moves_the_hash_or_is_pinned_as_excluded() {
             Align::TOP_LEFT,
         ),
     );
+    moves("Text.fit", &base, &fitted(8.0, 1));
+    moves("Text.fit.min_px", &fitted(8.0, 1), &fitted(9.0, 1));
+    moves("Text.fit.max_lines", &fitted(8.0, 1), &fitted(8.0, 2));
     moves(
         "Text.font.family",
         &base,
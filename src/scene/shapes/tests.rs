@@ -1,3 +1,6 @@
+use crate::icons::icon_atlas::IconId;
+use crate::icons::icon_registry::IconSetId;
+use crate::icons::icon_set::{IconHandle, IconRef};
 use crate::primitives::color::Color;
 use crate::primitives::image::Image;
 use crate::primitives::rect::Rect;
@@ -73,7 +76,7 @@ fn polyline_color_cardinality_is_enforced_at_lowering() {
                     source.colors(&colors[..colors_len]),
                     1.0,
                 );
-                let result = catch_unwind(AssertUnwindSafe(|| shapes.add(shape, &store)));
+                let result = catch_unwind(AssertUnwindSafe(|| shapes.add(shape, &store, false)));
                 // What the no-op gate drops never reaches lowering, and so is
                 // never checked: fewer than two points, or a per-vertex colour
                 // slice with nothing visible in it — which an *empty* slice is,
@@ -140,7 +143,7 @@ fn image_dimensions_above_u16_survive_lowering() {
     let mut shapes = Shapes::default();
     let store = RecordStore::default();
 
-    assert_eq!(shapes.add(Shape::image(handle), &store), Some(0));
+    assert_eq!(shapes.add(Shape::image(handle), &store, false), Some(0));
     let ShapeRecord::Image {
         source: ImageSource::Texture { size, .. },
         ..
@@ -200,7 +203,7 @@ fn the_nan_gate_drops_every_shape_kind() {
     fn gate<T: Lower, C: Lower>(label: &str, tainted: T, clean: C) {
         let mut shapes = Shapes::default();
         let store = RecordStore::default();
-        let got = catch_unwind(AssertUnwindSafe(|| shapes.add(tainted, &store)));
+        let got = catch_unwind(AssertUnwindSafe(|| shapes.add(tainted, &store, false)));
         assert_eq!(
             got.unwrap_or(None),
             None,
@@ -228,7 +231,7 @@ fn the_nan_gate_drops_every_shape_kind() {
         let mut shapes = Shapes::default();
         let store = RecordStore::default();
         assert_eq!(
-            shapes.add(clean, &store),
+            shapes.add(clean, &store, false),
             Some(0),
             "case {label}: the clean twin must record — otherwise the \
              tainted arm proves nothing",
@@ -298,3 +301,32 @@ fn the_nan_gate_drops_every_shape_kind() {
     };
     gate("shadow_blur", shadow(N), shadow(4.0));
 }
+
+/// Only a directional icon under a right-to-left owner keeps the mirror
+/// bit; the flag alone, or the direction alone, draws the artwork as is.
+#[test]
+fn directional_icon_mirrors_only_right_to_left() {
+    let handle = IconHandle {
+        icon: IconRef {
+            set: IconSetId::new(0, 0),
+            icon: IconId(0),
+        },
+        view_box: Vec2::splat(16.0),
+    };
+    let store = RecordStore::default();
+    let cases = [
+        (false, false, false),
+        (true, false, false),
+        (false, true, false),
+        (true, true, true),
+    ];
+    for (directional, rtl, want) in cases {
+        let mut shapes = Shapes::default();
+        let icon = Shape::icon(handle).directional(directional);
+        assert_eq!(shapes.add(icon, &store, rtl), Some(0));
+        let ShapeRecord::Icon { mirror, .. } = shapes.records[0] else {
+            panic!("expected an icon record");
+        };
+        assert_eq!(mirror, want, "directional {directional}, rtl {rtl}");
+    }
+}
@@ -27,11 +27,15 @@ use crate::ClipMode;
 use crate::common::content_hash::ContentHash;
 use crate::common::hash::Hasher;
 use crate::common::index16::Index16;
+use crate::layout::axis::Axis;
 use crate::layout::scrollbars::ScrollbarsDef;
+use crate::layout::types::grid_cell::{GridCell, GridPlace};
+use crate::layout::types::grid_template::{GridArea, GridTemplate, Occupancy, push_areas};
 use crate::layout::types::layout_mode::{GridDefId, LayoutMode, ScrollbarsDefId};
 use crate::layout::types::track::{GridDef, Track};
 use crate::primitives::approx::noop_f32;
 use crate::primitives::background::Background;
+use crate::primitives::size::Size;
 use crate::primitives::spacing::Spacing;
 use crate::primitives::span::Span;
 use crate::primitives::translate_scale::TranslateScale;
@@ -99,6 +103,9 @@ pub(crate) struct Tree {
 
     pub(crate) grid_tracks: Vec<Track>,
     pub(crate) grid_defs: Vec<GridDef>,
+    /// Named areas of every grid template, sliced by `GridDef::areas`.
+    /// Only read at record time, to place `grid_area` children.
+    pub(crate) grid_areas: Vec<GridArea>,
     /// Side table for [`LayoutMode::Scrollbars`], same arrangement as
     /// `grid_defs`: the def is too wide for the packed 16-bit payload.
     pub(crate) scrollbar_defs: Vec<ScrollbarsDef>,
@@ -123,6 +130,10 @@ pub(crate) struct Tree {
     /// width — a worklist, not a hash, which is why it sits here rather
     /// than with the rollup columns.
     pub(crate) container_text: FixedBitSet,
+    /// The logical surface size this frame records against, stamped by
+    /// [`Self::pre_record`]. Viewport units in node sizes, size bounds and
+    /// grid tracks resolve to pixels against it as they are recorded.
+    pub(crate) viewport: Size,
 }
 
 /// The chrome half of a [`Tree::open_node`] call: the background to
@@ -153,7 +164,8 @@ impl Tree {
         self.records.subtree_end()[i].has_grid()
     }
 
-    pub(crate) fn pre_record(&mut self) {
+    pub(crate) fn pre_record(&mut self, viewport: Size) {
+        self.viewport = viewport;
         self.records.clear();
         self.bounds_table.clear();
         self.panel_table.clear();
@@ -162,6 +174,7 @@ impl Tree {
         self.paint_anims.clear();
         self.grid_tracks.clear();
         self.grid_defs.clear();
+        self.grid_areas.clear();
         self.scrollbar_defs.clear();
         self.roots.clear();
     }
@@ -345,24 +358,42 @@ impl Tree {
         id
     }
 
-    pub(crate) fn push_grid_def(
-        &mut self,
-        rows: &[Track],
-        cols: &[Track],
-        row_gap: f32,
-        col_gap: f32,
-    ) -> GridDefId {
+    pub(crate) fn push_grid_def(&mut self, template: GridTemplate<'_>) -> GridDefId {
+        let GridTemplate {
+            rows,
+            cols,
+            col_repeat,
+            row_gap,
+            col_gap,
+            flow,
+            auto_track,
+            areas,
+        } = template;
         let id = GridDefId::from_index(self.grid_defs.len());
-        self.grid_tracks.reserve(rows.len() + cols.len());
+        self.grid_tracks
+            .reserve(rows.len() + cols.len() * col_repeat);
+        // Viewport-unit tracks fold to pixels here, for the reason
+        // `Node::into_columns` gives.
+        let viewport = self.viewport;
+        let resolve = |t: &Track| Track {
+            size: t.size.of_viewport(viewport),
+            ..*t
+        };
         let row_start = self.grid_tracks.len();
-        self.grid_tracks.extend_from_slice(rows);
+        self.grid_tracks.extend(rows.iter().map(resolve));
         let col_start = self.grid_tracks.len();
-        self.grid_tracks.extend_from_slice(cols);
+        for _ in 0..col_repeat {
+            self.grid_tracks.extend(cols.iter().map(resolve));
+        }
+        let areas = push_areas(areas, &mut self.grid_areas);
         self.grid_defs.push(GridDef {
             rows: Span::from(row_start..col_start),
             cols: Span::from(col_start..self.grid_tracks.len()),
             row_gap,
             col_gap,
+            flow,
+            auto_track: resolve(&auto_track),
+            areas,
         });
         id
     }
@@ -389,6 +420,7 @@ impl Tree {
         node: Node,
         chrome: Option<ChromeInput<'_>>,
     ) -> NodeId {
+        let grid_place = node.grid.place();
         // Overflow guard lives in `SubtreeEnd::new_open` (the 31-bit
         // arena ceiling), which asserts for this same id below.
         let new_id = NodeId(self.records.len() as u32);
@@ -402,7 +434,24 @@ impl Tree {
                 placement: pending,
             });
         }
-        let mut cols = node.into_columns(widget_id);
+        let mut cols = node.into_columns(widget_id, self.viewport);
+        // Flow direction resolves here, against the open chain, so every
+        // pass downstream reads it off the node alone. Mirroring trades
+        // the sides of the spacing that belongs to each frame: the
+        // node's own padding under its own direction, its margin under
+        // the parent's, which is the one its slot is mirrored in.
+        let parent_rtl = scratch.inherited_rtl();
+        let rtl = cols
+            .attrs
+            .flow_direction()
+            .map_or(parent_rtl, |d| d.is_rtl());
+        cols.attrs.set_rtl(rtl);
+        if rtl {
+            cols.layout.padding = cols.layout.padding.mirrored_x();
+        }
+        if parent_rtl {
+            cols.layout.margin = cols.layout.margin.mirrored_x();
+        }
         // A rounded clip with no radius to round is a plain scissor.
         // Applied to the recorded flags rather than to the node, because
         // this is the only hop that sees both the node's request and the
@@ -427,7 +476,7 @@ impl Tree {
             ),
             _ => {}
         }
-        self.check_grid_cell(parent_frame.map(|f| f.node), &cols.bounds);
+        self.place_in_grid(parent_frame.map(|f| f.node), grid_place, &mut cols.bounds);
 
         let mut ex = ExtrasIdx::default();
         if !cols.bounds.is_default() {
@@ -488,39 +537,177 @@ impl Tree {
             node: new_id,
             ancestor_or_self_disabled,
             effectively_visible,
+            rtl,
             paint_rows: u32::from(ex.chrome.is_some()),
         });
         new_id
     }
 
-    /// Range-check a child's `grid` cell against its parent's
-    /// `GridDef` row/col counts. Only fires when the parent is a
-    /// `Grid` node and the def has nonzero rows + cols.
+    /// The grid def `node` lays out by, if it is a `Grid`.
+    #[inline]
+    fn grid_def_of(&self, node: NodeId) -> Option<&GridDef> {
+        let layout = self.records.layout()[node.idx()];
+        let LayoutMode::Grid(id) = LayoutMode::from(layout.meta) else {
+            return None;
+        };
+        Some(&self.grid_defs[usize::from(id)])
+    }
+
+    /// Resolve a child's `grid` cell against its parent's `GridDef`: a
+    /// named area becomes its cell, a child an auto-flow grid will place
+    /// is marked [`GridCell::is_auto`] until that grid closes. Then
+    /// range-check the cell against the row/col counts — on an auto-flow
+    /// grid only across the fixed axis, since the other grows to fit.
+    /// Only fires when the parent is a `Grid` node and the def has
+    /// nonzero rows + cols.
     #[inline(always)]
-    fn check_grid_cell(&self, parent: Option<NodeId>, bounds: &BoundsExtras) {
-        if let Some(parent_id) = parent {
-            let parent_layout = self.records.layout()[parent_id.0 as usize];
-            let LayoutMode::Grid(grid_def_id) = LayoutMode::from(parent_layout.meta) else {
-                return;
-            };
-            let def = &self.grid_defs[usize::from(grid_def_id)];
-            let n_rows = def.rows.len as usize;
-            let n_cols = def.cols.len as usize;
-            if n_rows > 0 && n_cols > 0 {
-                let c = bounds.grid;
-                let row = c.row as usize;
-                let col = c.col as usize;
-                let row_span = c.row_span as usize;
-                let col_span = c.col_span as usize;
+    fn place_in_grid(&self, parent: Option<NodeId>, place: GridPlace, bounds: &mut BoundsExtras) {
+        let Some(def) = parent.and_then(|p| self.grid_def_of(p)) else {
+            return;
+        };
+        match place {
+            GridPlace::Area(key) => {
+                let area = self.grid_areas[def.areas.range()]
+                    .iter()
+                    .find(|a| a.key == key);
                 debug_assert!(
-                    row < n_rows
-                        && col < n_cols
-                        && row_span >= 1
-                        && col_span >= 1
-                        && row + row_span <= n_rows
-                        && col + col_span <= n_cols,
-                    "grid cell out of range: {c:?} for {n_rows}x{n_cols}"
+                    area.is_some(),
+                    "grid_area names no area of its parent grid's template",
                 );
+                if let Some(area) = area {
+                    bounds.grid = area.cell;
+                }
+            }
+            GridPlace::Auto if def.flow.is_some() => {
+                bounds.grid.row = GridCell::AUTO_POSITION;
+                bounds.grid.col = GridCell::AUTO_POSITION;
+                return;
+            }
+            GridPlace::Auto | GridPlace::Cell => {}
+        }
+        let n_rows = def.rows.len as usize;
+        let n_cols = def.cols.len as usize;
+        let grows = def.flow.map(|f| f.fills().other());
+        if n_rows > 0 && n_cols > 0 {
+            let c = bounds.grid;
+            let fits = |start: u16, span: u16, n: usize, axis: Axis| {
+                span >= 1 && (grows == Some(axis) || usize::from(start) + usize::from(span) <= n)
+            };
+            debug_assert!(
+                fits(c.row, c.row_span, n_rows, Axis::Y)
+                    && fits(c.col, c.col_span, n_cols, Axis::X),
+                "grid cell out of range: {c:?} for {n_rows}x{n_cols}"
+            );
+        }
+    }
+
+    /// Place the auto-flow children of grid `node`, now that all of them
+    /// are recorded, then append the tracks they ran past. Explicitly
+    /// placed children (cells and areas) claim their cells first, so
+    /// flowed ones go around them wherever they sit in record order.
+    /// Collapsed children take no cell.
+    fn flow_grid_children(&mut self, scratch: &mut RecordingScratch, node: NodeId) {
+        let Some(&def) = self.grid_def_of(node) else {
+            return;
+        };
+        let Some(flow) = def.flow else {
+            return;
+        };
+        let minor_axis = flow.fills();
+        let major_axis = minor_axis.other();
+        let (fixed, grown) = match minor_axis {
+            Axis::X => (def.cols, def.rows),
+            Axis::Y => (def.rows, def.cols),
+        };
+        let fixed = fixed.len as usize;
+
+        let RecordingScratch {
+            grid_occupancy,
+            grid_flowed,
+            ..
+        } = scratch;
+        grid_flowed.clear();
+        let mut occupancy = Occupancy::new(grid_occupancy, fixed);
+        let mut needed = grown.len as usize;
+        for child in self.active_children(node) {
+            let Some(slot) = self.records.extras()[child.idx()].bounds else {
+                // A default bounds row is an explicit `(0, 0)` 1×1 cell.
+                occupancy.take(0, 0, 1, 1);
+                needed = needed.max(1);
+                continue;
+            };
+            let cell = self.bounds_table[slot.idx()].grid;
+            if cell.is_auto() {
+                grid_flowed.push(slot);
+                continue;
+            }
+            let (major, minor) = (cell.track_span(major_axis), cell.track_span(minor_axis));
+            occupancy.take(
+                major.start as usize,
+                minor.start as usize,
+                major.len as usize,
+                minor.len as usize,
+            );
+            needed = needed.max(major.range().end);
+        }
+        debug_assert!(
+            fixed > 0 || grid_flowed.is_empty(),
+            "auto-flow grid needs at least one {} to flow children into",
+            match minor_axis {
+                Axis::X => "column",
+                Axis::Y => "row",
+            },
+        );
+
+        let mut cursor = (0, 0);
+        for &slot in grid_flowed.iter() {
+            let cell = &mut self.bounds_table[slot.idx()].grid;
+            if fixed == 0 {
+                // Nothing to flow into; the empty axis makes the grid
+                // measure to zero whatever cell this reads.
+                cell.row = 0;
+                cell.col = 0;
+                continue;
+            }
+            let major_span = cell.track_span(major_axis).len as usize;
+            let mut minor_span = cell.track_span(minor_axis).len as usize;
+            debug_assert!(
+                minor_span <= fixed,
+                "auto-flow child spans {minor_span} tracks of {fixed}: {cell:?}",
+            );
+            if minor_span > fixed {
+                minor_span = fixed;
+                cell.set_span(minor_axis, fixed as u16);
+            }
+            if flow.is_dense() {
+                cursor = (0, 0);
+            }
+            let (major, minor) = occupancy.find(cursor, major_span, minor_span);
+            occupancy.take(major, minor, major_span, minor_span);
+            cell.set_main(major_axis, major as u16);
+            cell.set_main(minor_axis, minor as u16);
+            cursor = (major, minor + minor_span);
+            needed = needed.max(major + major_span);
+        }
+
+        // Append the implicit tracks. The grown axis's span moves to the
+        // arena's tail so it stays contiguous past any nested grid's
+        // tracks; its old slots are dead until the next `pre_record`.
+        if needed > grown.len as usize {
+            let start = self.grid_tracks.len();
+            self.grid_tracks.extend_from_within(grown.range());
+            let extra = needed - grown.len as usize;
+            self.grid_tracks
+                .extend(std::iter::repeat_n(def.auto_track, extra));
+            let span = Span::from(start..self.grid_tracks.len());
+            let def_id = match LayoutMode::from(self.records.layout()[node.idx()].meta) {
+                LayoutMode::Grid(id) => usize::from(id),
+                _ => unreachable!("grid_def_of returned a def"),
+            };
+            let def = &mut self.grid_defs[def_id];
+            match major_axis {
+                Axis::X => def.cols = span,
+                Axis::Y => def.rows = span,
             }
         }
     }
@@ -563,6 +750,9 @@ impl Tree {
         // close. No `layout[i].meta` read needed — drops close_node
         // from 3 record-column touches to 2.
         let child_end = self.records.subtree_end()[i];
+        if child_end.has_grid() {
+            self.flow_grid_children(scratch, closing);
+        }
 
         if let Some(parent) = scratch.open_frames.last().map(|f| f.node) {
             let pi = parent.idx();
@@ -3,6 +3,7 @@
 //! transient state — `Tree` itself is the finalized output. Cleared by
 //! `Forest::pre_record`.
 
+use crate::common::index16::Index16;
 use crate::layout::types::placement::Placement;
 use crate::scene::tree::node_id::NodeId;
 
@@ -18,6 +19,8 @@ pub(crate) struct OpenFrame {
     pub(crate) node: NodeId,
     pub(crate) ancestor_or_self_disabled: bool,
     pub(crate) effectively_visible: bool,
+    /// The node's resolved flow direction — its own, else its parent's.
+    pub(crate) rtl: bool,
     /// Paint-arena rows this node's span holds so far: the chrome row
     /// (seeded to 1 when a `ChromeRow` was allocated at open) plus one
     /// per direct shape / immediate child, bumped in record order.
@@ -49,12 +52,32 @@ pub(crate) struct RecordingScratch {
     /// roots can share the policy. `Main` falls through to
     /// `Placement::default()`.
     pub(crate) pending_placement: Option<Placement>,
+
+    /// Direction a root minted in this layer inherits: the direction of
+    /// the scope that raised the layer (`Forest::push_layer`), so a popup
+    /// reads the way its trigger does. Left to right for `Main`.
+    pub(crate) root_rtl: bool,
+
+    /// Auto-flow placement scratch for the grid `Tree::close_node` is
+    /// closing: its taken cells, and the bounds rows of the children
+    /// still to place. Only live inside that one call — a nested grid
+    /// closes before its parent does. Capacity retained across frames.
+    pub(crate) grid_occupancy: Vec<bool>,
+    pub(crate) grid_flowed: Vec<Index16>,
 }
 
 impl RecordingScratch {
     pub(crate) fn clear(&mut self) {
         self.open_frames.clear();
         self.pending_placement = None;
+        self.root_rtl = false;
+    }
+
+    /// The direction a node opened now inherits: the innermost open
+    /// node's, else the layer's root direction.
+    #[inline]
+    pub(crate) fn inherited_rtl(&self) -> bool {
+        self.open_frames.last().map_or(self.root_rtl, |f| f.rtl)
     }
 
     /// True when any currently-open ancestor in the active recording
@@ -2,6 +2,7 @@
 
 use crate::Ui;
 use crate::common::content_hash::ContentHash;
+use crate::layout::types::align_content::AlignContent;
 use crate::layout::types::{justify::Justify, sizing::Sizing};
 use crate::primitives::approx::EPS;
 use crate::primitives::background::Background;
@@ -226,6 +227,25 @@ fn changing_layout_property_changes_hash() {
                     .node()
             },
         ),
+        (
+            "align_content",
+            |ui| {
+                Panel::wrap_hstack()
+                    .id(WidgetId::from_hash("root"))
+                    .align_content(AlignContent::Start)
+                    .show(ui, |_| {})
+                    .response
+                    .node()
+            },
+            |ui| {
+                Panel::wrap_hstack()
+                    .id(WidgetId::from_hash("root"))
+                    .align_content(AlignContent::Stretch)
+                    .show(ui, |_| {})
+                    .response
+                    .node()
+            },
+        ),
         (
             "focusable",
             |ui| {
@@ -60,6 +60,7 @@ pub struct IconShape {
     pub(crate) fit: IconFit,
     pub(crate) tint: Color,
     pub(crate) desaturate: bool,
+    pub(crate) directional: bool,
 }
 
 local_rect_shape!(IconShape, at);
@@ -82,6 +83,16 @@ impl IconShape {
         self.desaturate = desaturate;
         self
     }
+
+    /// Mark the artwork as pointing somewhere — a back arrow, a chevron, a
+    /// "next" glyph — so it flips horizontally when its owner lays out
+    /// [right to left](crate::FlowDirection::RightToLeft). Symmetric or
+    /// brand artwork (a search glass, a logo) leaves this off and draws the
+    /// same either way.
+    pub fn directional(mut self, directional: bool) -> Self {
+        self.directional = directional;
+        self
+    }
 }
 
 impl sealed::LowerShape for IconShape {
@@ -96,6 +107,7 @@ impl sealed::LowerShape for IconShape {
             fit,
             tint,
             desaturate,
+            directional,
         } = self;
         ShapeRecord::Icon {
             local_rect,
@@ -103,6 +115,7 @@ impl sealed::LowerShape for IconShape {
             fit,
             tint: tint.into(),
             desaturate,
+            mirror: directional,
         }
     }
 }
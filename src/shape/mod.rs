@@ -317,6 +317,7 @@ impl Shape {
             fit: IconFit::default(),
             tint: Color::WHITE,
             desaturate: false,
+            directional: false,
         }
     }
 
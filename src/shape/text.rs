@@ -5,6 +5,7 @@ use crate::primitives::nan::NanCheck;
 use crate::scene::record_store::RecordStore;
 use crate::scene::shapes::record::ShapeRecord;
 use crate::shape::sealed;
+use crate::text::fit::TextFit;
 use crate::text::glyph_font::GlyphFont;
 use crate::text::wrap::TextWrap;
 use crate::text::{FontFamily, FontWeight};
@@ -36,6 +37,9 @@ pub struct TextShape {
     /// `set_align` and text cache key. Same field because both
     /// consumers want the user-intended alignment.
     pub(crate) align: Align,
+    /// Shrink-to-fit bounds; `font` is then the largest size. See
+    /// [`Self::fit`].
+    pub(crate) fit: Option<TextFit>,
 }
 
 impl TextShape {
@@ -47,6 +51,7 @@ impl TextShape {
             font,
             wrap: TextWrap::SingleLine,
             align: Align::TOP_LEFT,
+            fit: None,
         }
     }
 
@@ -58,6 +63,19 @@ impl TextShape {
         self.local_origin = Some(origin);
         self
     }
+
+    /// Shrink from the font's size toward `min_px` until the run fits the
+    /// box layout offers it — on one line, or within `max_lines` when
+    /// [`wrap`](Self::wrap) wraps. The size painted is the one measure
+    /// picked, stepped on the text-scale ladder; see
+    /// [`Text::fit`](crate::Text::fit).
+    pub fn fit(mut self, min_px: f32, max_lines: u16) -> Self {
+        self.fit = Some(TextFit {
+            min_px,
+            max_lines: max_lines.max(1),
+        });
+        self
+    }
 }
 shape_setters!(TextShape {
     color: Color => color,
@@ -78,6 +96,7 @@ impl sealed::LowerShape for TextShape {
             // paid for that and left the bytes behind.
             || self.local_origin.has_nan()
             || !self.font.metrics_valid()
+            || self.fit.is_some_and(|fit| !fit.min_px.is_finite())
     }
 
     fn lower(self, store: &RecordStore) -> ShapeRecord {
@@ -88,6 +107,7 @@ impl sealed::LowerShape for TextShape {
             font,
             wrap,
             align,
+            fit,
         } = self;
         ShapeRecord::Text {
             local_origin,
@@ -96,6 +116,7 @@ impl sealed::LowerShape for TextShape {
             font,
             wrap,
             align,
+            fit,
         }
     }
 }
@@ -26,6 +26,9 @@ pub(super) struct ShapedGeometry {
     pub(super) intrinsic_min: Option<f32>,
     /// Whether the buffer laid out as one visual line.
     pub(super) single_line: bool,
+    /// The first line's baseline below the block top; `0.0` when the
+    /// buffer laid out no line at all.
+    pub(super) baseline: f32,
     /// See [`CacheEntry::left`](super::cache_entry::CacheEntry::left).
     pub(super) left: f32,
 }
@@ -39,6 +42,7 @@ impl ShapedGeometry {
             size: self.size,
             intrinsic_min: self.intrinsic_min,
             single_line: self.single_line,
+            baseline: self.baseline,
         }
     }
 }
@@ -67,7 +71,11 @@ pub(super) fn shaped_geometry(
     let mut right = f32::NEG_INFINITY;
     let mut total_h = 0.0_f32;
     let mut runs = 0usize;
+    let mut baseline = 0.0_f32;
     for run in buffer.layout_runs() {
+        if runs == 0 {
+            baseline = run.line_y;
+        }
         runs += 1;
         total_h = total_h.max(run.line_top + run.line_height);
         for glyph in run.glyphs {
@@ -86,6 +94,7 @@ pub(super) fn shaped_geometry(
         size: Size::new(width.ceil(), total_h.ceil()),
         intrinsic_min: (floor == WrapFloor::Scan).then(|| intrinsic_min_width(buffer, breaks)),
         single_line: runs <= 1,
+        baseline,
         left,
     }
 }
@@ -29,6 +29,7 @@ use crate::common::expiry_wheel::ExpiryWheel;
 use crate::layout::types::align::HAlign;
 use crate::text::cosmic::counters::CacheCounters;
 use crate::text::key::TextShapeKey;
+use crate::text::probe::cursor_to_byte;
 use crate::text::request::TextShapeRequest;
 use crate::text::root::TextRoot;
 use crate::text::wrap::{LineFit, WrapFloor};
@@ -360,7 +361,8 @@ impl CosmicMeasure {
         }
         match key.fit() {
             LineFit::Clip | LineFit::Ellipsis => self.shape_truncated(request),
-            _ => self.shape_wrapped(request, WrapFloor::Skip).size,
+            LineFit::Clamp(lines) => self.shape_clamped(request, lines),
+            LineFit::Wrap => self.shape_wrapped(request, WrapFloor::Skip).size,
         }
     }
 
@@ -527,10 +529,18 @@ impl CosmicMeasure {
     ///
     /// Silent on a key that isn't resident: the buffer may already have
     /// aged out, and superseding what is gone is a no-op, not an error.
+    ///
+    /// A clamp takes its plain wrap down with it. That buffer is keyed on
+    /// the same width and only ever reached to cut the clamp from, so it
+    /// goes dead in the same step — and no reuse slot holds its key to
+    /// demote it on its own.
     pub(super) fn supersede(&mut self, key: TextShapeKey) {
         if key.is_invalid() {
             return;
         }
+        if let LineFit::Clamp(_) = key.fit() {
+            self.supersede(key.wrapped_version());
+        }
         let keep_until = self.frame + PROBATION_KEEP_FRAMES;
         let Some(entry) = self.cache.get_mut(&key) else {
             return;
@@ -878,6 +888,122 @@ impl CosmicMeasure {
         self.ellipsis.insert(0, want.measured(advance));
         advance
     }
+
+    // ---- line clamping ----
+
+    /// Shape `text` wrapped at its committed width and kept to `lines`
+    /// lines, the last one cut cluster-precisely with a trailing `…`. The
+    /// lines before it are the plain wrap's, unchanged; the last holds as
+    /// much of the rest of its paragraph as fits beside the ellipsis.
+    ///
+    /// Cuts from the plain wrap at the same width, which `TextSystem` has
+    /// just resolved to decide the run clamps at all (see
+    /// [`LineFit::settle`]), so finding where the last line starts is a
+    /// walk of a resident buffer. From there it is
+    /// [`Self::shape_truncated`] over the tail: one unbounded shape of it
+    /// to read advances from, [`fitting_prefix`] to cut them, and the
+    /// memoized ellipsis reservation. The tail is shaped on a scratch
+    /// buffer rather than cached as a probe — its text moves with the
+    /// width, so a cached probe would be a fresh entry per width anyway.
+    ///
+    /// What lands in the buffer is a prefix of the source plus `…`, so a
+    /// byte offset before the cut still addresses the source, and a
+    /// selection over a clamped run hit-tests the text it shows.
+    ///
+    /// Verified the way truncation is: the composed text reshapes in its
+    /// own context, so while it wraps onto more than `lines` lines, the
+    /// cut retires one more cluster.
+    fn shape_clamped(&mut self, request: TextShapeRequest<'_>, lines: u8) -> Size {
+        let key = request.key;
+        let width = key
+            .max_width_px()
+            .expect("a clamp resolves against a committed width");
+        let wrapped = request.wrapped_version();
+        self.resolve(wrapped);
+        let kept = usize::from(lines);
+        // The last kept line's paragraph and where it starts in it, when
+        // the wrap runs on past it.
+        let last_line = {
+            let buffer = &self
+                .cache
+                .get(&wrapped.key)
+                .expect("a clamp cuts from its resident wrap")
+                .buffer;
+            let mut runs = buffer.layout_runs().skip(kept - 1);
+            runs.next().filter(|_| runs.next().is_some()).map(|run| {
+                let start = run.glyphs.iter().map(|g| g.start).min().unwrap_or(0);
+                cosmic_text::Cursor::new(run.line_i, start)
+            })
+        };
+        // Nothing to cut — reached only by restoring a clamp key against a
+        // wrap that now fits, which `settle` never mints.
+        let Some(last_line) = last_line else {
+            return self.shape_wrapped(request, WrapFloor::Skip).size;
+        };
+        let head_len = cursor_to_byte(request.text, last_line);
+        let tail = &request.text[head_len..];
+        let tail = &tail[..tail.find('\n').unwrap_or(tail.len())];
+
+        let metrics = Metrics::new(key.font_size_px(), key.line_height_px());
+        let family = key.family();
+        let weight = key.weight();
+        let attrs = attrs_for(family, weight);
+        let ellipsis_w = self.ellipsis_advance(key.size_q, metrics, family, weight);
+        let append_ellipsis = ellipsis_w <= width;
+        let avail = if append_ellipsis {
+            width - ellipsis_w
+        } else {
+            width
+        };
+        let mut probe = self.acquire_buffer(metrics, None);
+        probe.set_text(tail, &attrs, Shaping::Advanced, None);
+        probe.shape_until_scroll(&mut self.font_system, false);
+
+        let alignment = cosmic_align(key.halign());
+        let mut buffer = self.acquire_buffer(metrics, Some(width));
+        let mut max_end = usize::MAX;
+        let geometry = loop {
+            let cut = match probe.layout_runs().next() {
+                Some(run) => fitting_prefix(
+                    run.glyphs.len(),
+                    |i| ClusterGlyph {
+                        start: run.glyphs[i].start,
+                        end: run.glyphs[i].end,
+                        advance: run.glyphs[i].w,
+                    },
+                    &mut self.logical_order,
+                    avail,
+                    max_end,
+                ),
+                None => 0,
+            };
+            self.truncate_scratch.clear();
+            self.truncate_scratch.push_str(&request.text[..head_len]);
+            self.truncate_scratch.push_str(tail[..cut].trim_end());
+            if append_ellipsis {
+                self.truncate_scratch.push('…');
+            }
+            buffer.set_text(
+                self.truncate_scratch.as_str(),
+                &attrs,
+                Shaping::Advanced,
+                alignment,
+            );
+            buffer.shape_until_scroll(&mut self.font_system, false);
+            if buffer.layout_runs().nth(kept).is_none() || cut == 0 {
+                break shaped_geometry(&buffer, WrapFloor::Skip, &mut self.break_scratch);
+            }
+            max_end = cut;
+        };
+        recycle_buffer(&mut self.recycle_pool, probe);
+        self.insert(
+            key,
+            buffer,
+            CachedExtent::Bounded(geometry.size),
+            geometry.left,
+        );
+        geometry.size
+    }
 }
 
 impl Default for CosmicMeasure {
@@ -0,0 +1,71 @@
+//! Shrink-to-fit sizing: the rungs a fitted run's font size is picked
+//! from, and what it means for one of them to fit.
+//!
+//! A fitted run records its *largest* face. Layout steps it down until it
+//! fits the box it is offered, and paints whatever it settled on — the
+//! size travels in the [`TextShapeKey`](crate::text::key::TextShapeKey) of
+//! the measurement, so nothing downstream of measure knows a fit happened.
+
+use crate::text::TEXT_SCALE_STEP;
+
+/// The rung a run's recorded face sits on: scale `1.0` on the
+/// [`TEXT_SCALE_STEP`] ladder.
+pub(super) const TOP_RUNG: u32 = (1.0 / TEXT_SCALE_STEP + 0.5) as u32;
+
+/// Shrink-to-fit bounds on one text run — see
+/// [`Text::fit`](crate::Text::fit).
+///
+/// Candidate sizes are the recorded size times a rung of the same additive
+/// ladder the composer snaps zoomed text to, so a fitted run mints at most
+/// [`TOP_RUNG`] distinct cache keys however its box moves — a resize drag
+/// walks rungs rather than minting a face per pixel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TextFit {
+    /// The smallest size the run shrinks to. Below it the run's
+    /// [`TextWrap`](crate::TextWrap) takes over, at this size.
+    pub(crate) min_px: f32,
+    /// Lines the run may wrap onto before it shrinks. `1` fits the
+    /// natural, unbroken line.
+    pub(crate) max_lines: u16,
+}
+
+impl TextFit {
+    /// The lowest rung a face of `size_px` may shrink to: the first at or
+    /// above `min_px`, so the floor is never undershot.
+    pub(super) fn floor_rung(self, size_px: f32) -> u32 {
+        let rung = (self.min_px / size_px / TEXT_SCALE_STEP).ceil();
+        (rung as u32).clamp(1, TOP_RUNG)
+    }
+}
+
+/// Scale of `rung` against the recorded face.
+#[inline]
+pub(super) fn rung_scale(rung: u32) -> f32 {
+    rung as f32 * TEXT_SCALE_STEP
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::fit::{TOP_RUNG, TextFit, rung_scale};
+
+    #[test]
+    fn top_rung_is_full_size() {
+        assert_eq!(TOP_RUNG, 200);
+        assert_eq!(rung_scale(TOP_RUNG), 1.0);
+    }
+
+    /// The floor rounds *up* to a rung, so a 10 px minimum on a 48 px face
+    /// never paints at 9.98 px; and it clamps both ways.
+    #[test]
+    fn floor_rung_never_undershoots_the_minimum() {
+        let fit = |min_px| TextFit {
+            min_px,
+            max_lines: 1,
+        };
+        let rung = fit(10.0).floor_rung(48.0);
+        assert!(48.0 * rung_scale(rung) >= 10.0);
+        assert!(48.0 * rung_scale(rung - 1) < 10.0);
+        assert_eq!(fit(64.0).floor_rung(48.0), TOP_RUNG, "min above max");
+        assert_eq!(fit(0.0).floor_rung(48.0), 1, "never rung zero");
+    }
+}
@@ -51,10 +51,11 @@ pub(crate) struct TextShapeKey {
     /// has to discriminate. `0` (`HAlign::Auto`) means "no per-line
     /// alignment" and matches the previous behaviour.
     pub(crate) halign_q: u8,
-    /// [`LineFit`] discriminant. Truncating fits bake different source text
-    /// into the shaped buffer at the same width, so fit is independent cache
-    /// identity rather than part of the text-content hash. This occupies the
-    /// former trailing padding byte, keeping the key at 24 bytes.
+    /// [`LineFit::tag`]. Truncating and clamping fits bake different source
+    /// text into the shaped buffer at the same width, so fit is independent
+    /// cache identity rather than part of the text-content hash. This
+    /// occupies the former trailing padding byte, keeping the key at 24
+    /// bytes — and a clamp's line count shares it for the same reason.
     pub(crate) fit_q: u8,
 }
 
@@ -118,7 +119,7 @@ impl TextShapeKey {
             family_q: family as u8,
             weight_q: weight as u8,
             halign_q: HAlign::Auto as u8,
-            fit_q: LineFit::Wrap as u8,
+            fit_q: LineFit::Wrap.tag(),
         }
     }
 
@@ -139,16 +140,36 @@ impl TextShapeKey {
         }
     }
 
+    /// The plain wrap at this key's width a [clamp](LineFit::Clamp) cuts
+    /// from: the same bound under [`LineFit::Wrap`].
+    pub(super) fn wrapped_version(self) -> Self {
+        Self {
+            fit_q: LineFit::Wrap.tag(),
+            ..self
+        }
+    }
+
     pub(super) fn unbounded_version(self) -> Self {
         Self {
             max_w_q: MAX_W_NONE,
             halign_q: HAlign::Auto as u8,
-            fit_q: LineFit::Wrap as u8,
+            fit_q: LineFit::Wrap.tag(),
+            ..self
+        }
+    }
+
+    /// The same run with both metrics at `scale` times this key's — one
+    /// rung of a [fitted](crate::text::fit) run. Leading scales with the
+    /// size, so the face keeps its proportions on every rung.
+    pub(super) fn scaled(self, scale: f32) -> Self {
+        Self {
+            size_q: quantize_metric(self.font_size_px() * scale),
+            lh_q: quantize_metric(self.line_height_px() * scale),
             ..self
         }
     }
 
-    pub(super) fn font_size_px(self) -> f32 {
+    pub(crate) fn font_size_px(self) -> f32 {
         dequantize(self.size_q)
     }
 
@@ -160,12 +181,12 @@ impl TextShapeKey {
         (self.max_w_q != MAX_W_NONE).then(|| dequantize(self.max_w_q))
     }
 
-    /// The four decoders below `debug_assert` their range and then make
-    /// the last variant total, so release builds decode with a jump
-    /// table and no panic path.
+    /// The first three decoders below `debug_assert` their range and then
+    /// make the last variant total, so release builds decode with a jump
+    /// table and no panic path. [`Self::fit`] has no range to assert.
     ///
     /// Every one of these bytes was written by this crate from the enum
-    /// itself (`family as u8`), so a bad tag is a logic error here, never
+    /// itself (`family as u8`, [`LineFit::tag`]), so a bad tag is a logic error here, never
     /// bad data — and these run per shape, which release builds must not
     /// pay a check for. The restore path is what forces the round-trip to
     /// exist at all; see `CosmicMeasure::shape_truncated`.
@@ -214,16 +235,15 @@ impl TextShapeKey {
         }
     }
 
+    /// Total over the byte, so nothing to assert: every tag past the
+    /// fixed three is a clamp, and [`LineFit::MAX_CLAMP_LINES`] is what
+    /// keeps `2 + lines` from wrapping back into them.
     pub(super) fn fit(self) -> LineFit {
-        debug_assert!(
-            self.fit_q <= LineFit::Ellipsis as u8,
-            "invalid LineFit tag {}",
-            self.fit_q
-        );
         match self.fit_q {
             0 => LineFit::Wrap,
             1 => LineFit::Clip,
-            _ => LineFit::Ellipsis,
+            2 => LineFit::Ellipsis,
+            tag => LineFit::Clamp(tag - 2),
         }
     }
 }
@@ -246,7 +266,13 @@ const _: () = {
             && HAlign::Right as u8 == 3
             && HAlign::Stretch as u8 == 4
     );
-    assert!(LineFit::Wrap as u8 == 0 && LineFit::Clip as u8 == 1 && LineFit::Ellipsis as u8 == 2);
+    assert!(
+        LineFit::Wrap.tag() == 0
+            && LineFit::Clip.tag() == 1
+            && LineFit::Ellipsis.tag() == 2
+            && LineFit::Clamp(1).tag() == 3
+            && LineFit::Clamp(LineFit::MAX_CLAMP_LINES).tag() == u8::MAX
+    );
 };
 
 /// Everything a committed width varies on a [`TextShapeKey`]: the three
@@ -282,13 +308,13 @@ impl WrapBound {
                 // here minted two keys, two cache entries and two
                 // reshapes for a byte-identical buffer. `Stretch` is a
                 // box-alignment concept with no per-line meaning.
-                LineFit::Wrap => match halign {
+                LineFit::Wrap | LineFit::Clamp(_) => match halign {
                     HAlign::Auto | HAlign::Stretch => HAlign::Auto as u8,
                     other => other as u8,
                 },
                 LineFit::Clip | LineFit::Ellipsis => HAlign::Auto as u8,
             },
-            fit_q: fit as u8,
+            fit_q: fit.tag(),
         }
     }
 }
@@ -58,6 +58,7 @@ pub(crate) mod bench;
 // `crate::text`, not the crate — and is what a consumer in a sibling of
 // `cosmic` needs, since `pub(super)` there stops at `cosmic` itself.
 mod cosmic;
+pub(crate) mod fit;
 pub(crate) mod glyph_font;
 pub(crate) mod glyphs;
 pub(crate) mod key;
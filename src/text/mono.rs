@@ -85,7 +85,12 @@ fn layout(request: TextShapeRequest<'_>) -> MonoLayout {
         Some(max) if truncating_fit => (Size::new(max, line_h), true),
         Some(max) => {
             let chars_per_line = (max / glyph_w).floor().max(1.0);
-            let lines = (total_chars / chars_per_line).ceil().max(1.0);
+            let mut lines = (total_chars / chars_per_line).ceil().max(1.0);
+            // A clamp keeps its lines; the cut and its `…` fit inside the
+            // last one, so the extent is the wrap's, shorter.
+            if let LineFit::Clamp(max_lines) = request.key.fit() {
+                lines = lines.min(f32::from(max_lines));
+            }
             (
                 Size::new((chars_per_line * glyph_w).min(unbroken_w), lines * line_h),
                 lines <= 1.0,
@@ -125,9 +130,17 @@ pub(super) fn root(request: TextShapeRequest<'_>, floor: WrapFloor) -> TextRoot
         size,
         intrinsic_min,
         single_line,
+        baseline: mono_baseline(request),
     }
 }
 
+/// The first baseline under the mono metric: an em box with a 0.8 em
+/// ascent, centered in the line the way cosmic centers a line's glyphs.
+fn mono_baseline(request: TextShapeRequest<'_>) -> f32 {
+    let font_size_px = request.key.font_size_px();
+    (request.key.line_height_px() - font_size_px) * 0.5 + font_size_px * 0.8
+}
+
 /// The extent this run resolves to at its key's committed width — the
 /// twin of [`CosmicMeasure::resolve`](crate::text::cosmic::CosmicMeasure).
 pub(super) fn resolve(request: TextShapeRequest<'_>) -> Size {
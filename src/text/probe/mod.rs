@@ -353,7 +353,7 @@ fn cursor_from_byte(text: &str, byte_offset: usize) -> cosmic_text::Cursor {
 
 /// Inverse of [`cursor_from_byte`]. Walks `text` to find the
 /// `line`-th `\n` and adds `cursor.index`.
-fn cursor_to_byte(text: &str, cursor: cosmic_text::Cursor) -> usize {
+pub(super) fn cursor_to_byte(text: &str, cursor: cosmic_text::Cursor) -> usize {
     let line_start = if cursor.line == 0 {
         0
     } else {
@@ -71,12 +71,27 @@ impl<'a> TextShapeRequest<'a> {
         }
     }
 
+    pub(super) fn wrapped_version(self) -> Self {
+        Self {
+            key: self.key.wrapped_version(),
+            ..self
+        }
+    }
+
     pub(super) fn unbounded_version(self) -> Self {
         Self {
             key: self.key.unbounded_version(),
             ..self
         }
     }
+
+    /// This run with its face scaled — see [`TextShapeKey::scaled`].
+    pub(super) fn scaled(self, scale: f32) -> Self {
+        Self {
+            key: self.key.scaled(scale),
+            ..self
+        }
+    }
 }
 
 // Wider than `cfg(test)`, unlike its sibling in `text::root`: the text
@@ -30,6 +30,11 @@ pub(crate) struct TextRoot {
     /// whose natural width fits the committed width needs no Clip/Ellipsis
     /// resolve — the unbounded root stands in.
     pub(super) single_line: bool,
+    /// Distance from the block's top to the first line's baseline. A
+    /// property of the first line alone, so the root answers it for every
+    /// width the run wraps at — the line breaks move, the first line's
+    /// metrics don't.
+    pub(super) baseline: f32,
 }
 
 impl TextRoot {
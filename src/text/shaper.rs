@@ -213,12 +213,13 @@ impl TextShaper {
             // exactly the policy that asks for the floor scan), while a
             // truncating fit asks the root whether the text already fits.
             //
-            // A plain `Wrap` consults neither — `target_width` is the
-            // identity and `resolves_to_unbounded` is false — so it binds
-            // without paying for a root shape, and an invalid width still
-            // fails in `WrapBound::new` before anything is shaped.
+            // A plain `Wrap` or a clamp consults neither — `target_width`
+            // is the identity and `resolves_to_unbounded` is false — so it
+            // binds without paying for a root shape, and an invalid width
+            // still fails in `WrapBound::new` before anything is shaped.
             (Some(width), Some(fit)) => {
-                let committed = if run.wrap.floor_scan() == WrapFloor::Scan || fit != LineFit::Wrap
+                let committed = if run.wrap.floor_scan() == WrapFloor::Scan
+                    || matches!(fit, LineFit::Clip | LineFit::Ellipsis)
                 {
                     let root = inner.root(unbounded, run.wrap.floor_scan());
                     if fit.resolves_to_unbounded(&root, width) {
@@ -231,8 +232,18 @@ impl TextShaper {
                 } else {
                     width
                 };
-                let bound =
-                    unbounded.with_bound(WrapBound::new(committed, run.align.halign(), fit));
+                let halign = run.align.halign();
+                let fit = fit.settle(
+                    || {
+                        inner.resolve(unbounded.with_bound(WrapBound::new(
+                            committed,
+                            halign,
+                            LineFit::Wrap,
+                        )))
+                    },
+                    unbounded.key.line_height_px(),
+                );
+                let bound = unbounded.with_bound(WrapBound::new(committed, halign, fit));
                 (bound.key, inner.resolve(bound))
             }
             _ => (unbounded.key, inner.root(unbounded, WrapFloor::Skip).size),
@@ -383,10 +394,18 @@ pub(crate) mod internals {
         /// test that read one was pinning the invention rather than the
         /// shaper.
         pub(crate) fn measure(&self, text: &str, shape: TestShape) -> ShapedText {
-            self.probe_layout(text, shape, |probe| ShapedText {
-                measured: probe.size(),
-                key: probe.shaped_key(),
-            })
+            let (measured, key) =
+                self.probe_layout(text, shape, |probe| (probe.size(), probe.shaped_key()));
+            // After the probe, so a width the probe rejects panics before
+            // anything is shaped.
+            let baseline = TextShapeRequest::unbounded(text, shape.font)
+                .map_or(0.0, |request| self.root(request, WrapFloor::Skip).baseline);
+            ShapedText {
+                measured,
+                key,
+                baseline,
+                clamped: matches!(key.fit(), LineFit::Clamp(_)),
+            }
         }
 
         /// Describes the fixture as a [`TextRun`] rather than lowering it
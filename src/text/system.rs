@@ -34,11 +34,12 @@ use crate::layout::ShapedText;
 use crate::layout::types::align::HAlign;
 use crate::primitives::size::Size;
 use crate::primitives::widget_id::WidgetId;
+use crate::text::fit::{TOP_RUNG, TextFit, rung_scale};
 use crate::text::key::{TextShapeKey, WrapBound};
 use crate::text::request::TextShapeRequest;
 use crate::text::root::TextRoot;
 use crate::text::shaper::TextShaper;
-use crate::text::wrap::{TextWrap, WrapFloor};
+use crate::text::wrap::{LineFit, TextWrap, WrapFloor, canonical_wrap_width};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Both entry points take the run's *unbounded* request and derive every
@@ -192,12 +193,26 @@ impl TextSystem {
         let entry = Self::refresh(entries, shaper, slot, request, wrap_policy.floor_scan());
 
         let (Some(width), Some(fit)) = (available_width_px, wrap_policy.line_fit()) else {
-            return shaped(shapes_buffers, request.key, entry.root.size);
+            return shaped(
+                shapes_buffers,
+                request.key,
+                entry.root.size,
+                entry.root.baseline,
+            );
         };
         if fit.resolves_to_unbounded(&entry.root, width) {
-            return shaped(shapes_buffers, request.key, entry.root.size);
+            return shaped(
+                shapes_buffers,
+                request.key,
+                entry.root.size,
+                entry.root.baseline,
+            );
         }
         let width = wrap_policy.target_width(width, &entry.root);
+        let fit = fit.settle(
+            || shaper.resolve(request.with_bound(WrapBound::new(width, halign, LineFit::Wrap))),
+            request.key.line_height_px(),
+        );
         let bound = WrapBound::new(width, halign, fit);
         let size = match entry.wrap.filter(|slot| slot.bound == bound) {
             Some(slot) => slot.size,
@@ -216,7 +231,106 @@ impl TextSystem {
                 size
             }
         };
-        shaped(shapes_buffers, request.key.with_bound(bound), size)
+        shaped(
+            shapes_buffers,
+            request.key.with_bound(bound),
+            size,
+            entry.root.baseline,
+        )
+    }
+
+    /// [`Self::measure`] for a [fitted](TextFit) run: the largest rung of
+    /// `request`'s face that fits `available`, measured under `slot`.
+    ///
+    /// The search probes the shaper directly rather than through the row.
+    /// A row holds one key, and walking it across rungs would supersede
+    /// each probe the moment the next one replaced it. Straight through,
+    /// a probe is an ordinary content-cache entry: it starts on
+    /// probation and ages out unless something asks for it again, which a
+    /// resize drag crossing back over the same rungs does. Only the rung
+    /// picked lands in the row, so the row's key is the one painted.
+    ///
+    /// Text width tracks size closely, so the first probe is a guess from
+    /// the full-size root; the guess and its neighbour usually bracket the
+    /// answer, and a bisection settles the rest. A few shapes the first
+    /// time a box size is seen, and none on a frame the measure cache
+    /// replays. When no rung fits, the floor rung stands and `wrap_policy`
+    /// handles the overflow there.
+    pub(crate) fn measure_fit(
+        &mut self,
+        slot: TextRunSlot,
+        request: TextShapeRequest<'_>,
+        fit: TextFit,
+        wrap_policy: TextWrap,
+        halign: HAlign,
+        available: Size,
+    ) -> ShapedText {
+        debug_assert!(request.key.max_width_px().is_none(), "{UNBOUND_REQUEST}");
+        let floor = fit.floor_rung(request.key.font_size_px());
+        let shaper = &self.shaper;
+        let fits = |rung: u32| {
+            fits_at(
+                shaper,
+                request.scaled(rung_scale(rung)),
+                fit,
+                wrap_policy,
+                halign,
+                available,
+            )
+        };
+        let rung = if floor == TOP_RUNG || fits(TOP_RUNG) {
+            TOP_RUNG
+        } else {
+            // Invariant: `hi` does not fit; `lo` fits, or is the floor,
+            // which stands either way.
+            let (mut lo, mut hi) = (floor, TOP_RUNG);
+            let full = shaper.root(request, wrap_policy.floor_scan()).size;
+            let lines = f32::from(fit.max_lines.max(1));
+            let ratio = (available.w * lines / full.w).min(available.h / full.h);
+            let guess = ((ratio * TOP_RUNG as f32) as u32).clamp(lo, hi - 1);
+            if fits(guess) {
+                lo = guess;
+                if guess + 1 < hi && fits(guess + 1) {
+                    lo = guess + 1;
+                } else {
+                    hi = guess + 1;
+                }
+            } else {
+                hi = guess;
+            }
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if fits(mid) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        };
+        self.measure(
+            slot,
+            request.scaled(rung_scale(rung)),
+            wrap_policy,
+            halign,
+            available.w.is_finite().then_some(available.w),
+        )
+    }
+
+    /// A fitted run's unbounded shape at its smallest size — what its
+    /// min-content derives from, since that is as narrow as it gets before
+    /// `wrap_policy` has to step in. Straight through the shaper, for the
+    /// reason [`Self::measure_fit`] probes that way.
+    pub(crate) fn fit_floor_root(
+        &self,
+        request: TextShapeRequest<'_>,
+        fit: TextFit,
+        wrap_policy: TextWrap,
+    ) -> TextRoot {
+        debug_assert!(request.key.max_width_px().is_none(), "{UNBOUND_REQUEST}");
+        let floor = fit.floor_rung(request.key.font_size_px());
+        self.shaper
+            .root(request.scaled(rung_scale(floor)), wrap_policy.floor_scan())
     }
 
     /// Reuse row for `slot`, reshaped if it answers a different run.
@@ -256,7 +370,43 @@ impl TextSystem {
     }
 }
 
-/// Pair an extent with the buffer key the renderer resolves it through.
+/// Whether `request` fits `available` under `fit`: its natural line does,
+/// or — when it may take more lines and its policy wraps — its wrap at the
+/// available width stays inside both the line budget and the height.
+///
+/// The natural line is checked first because it is the root, which the
+/// wrapped check would shape anyway; a run that fits unbroken never mints
+/// a bounded buffer for the search.
+fn fits_at(
+    shaper: &TextShaper,
+    request: TextShapeRequest<'_>,
+    fit: TextFit,
+    wrap_policy: TextWrap,
+    halign: HAlign,
+    available: Size,
+) -> bool {
+    let width = canonical_wrap_width(available.w);
+    let root = shaper.root(request, wrap_policy.floor_scan());
+    if root.size.h > available.h {
+        return false;
+    }
+    if root.size.w <= width {
+        return true;
+    }
+    if fit.max_lines <= 1 || wrap_policy.line_fit() != Some(LineFit::Wrap) {
+        return false;
+    }
+    let bound = WrapBound::new(available.w, halign, LineFit::Wrap);
+    let wrapped = shaper.resolve(request.with_bound(bound));
+    // The shaped height is a ceil'd sum of line heights, so the budget
+    // rounds up the same way, with half a pixel for the sum drifting
+    // from the product.
+    let budget = (f32::from(fit.max_lines) * request.key.line_height_px() + 0.5).ceil();
+    wrapped.w <= width && wrapped.h <= budget.min(available.h)
+}
+
+/// Pair an extent and its first baseline with the buffer key the renderer
+/// resolves it through.
 /// The key is *derived* from the request rather than stored, so it cannot
 /// drift from the row it came out of; the gated mono metric shapes no
 /// buffer, so its runs carry the invalid sentinel and the encoder drops
@@ -266,9 +416,13 @@ impl TextSystem {
 /// mid-way through a split borrow of [`TextSystem`] and holds the flag by
 /// value already.
 #[inline]
-fn shaped(shapes_buffers: bool, key: TextShapeKey, measured: Size) -> ShapedText {
+fn shaped(shapes_buffers: bool, key: TextShapeKey, measured: Size, baseline: f32) -> ShapedText {
     ShapedText {
         measured,
+        baseline,
+        // Read off the key before the mono metric blanks it: a clamp key
+        // is only ever bound for a run that lost lines.
+        clamped: matches!(key.fit(), LineFit::Clamp(_)),
         key: if shapes_buffers {
             key
         } else {
@@ -0,0 +1,152 @@
+use super::*;
+
+const PARAGRAPH: &str = "The quick brown fox jumps over the lazy dog and keeps on running \
+                         well past the edge of any reasonable panel width";
+
+/// Text of every line the shaped buffer holds under `key`, joined the way
+/// the source joins paragraphs.
+fn buffer_text(cosmic: &CosmicMeasure, key: TextShapeKey) -> String {
+    let shaped = cosmic.shaped_run(key).expect("shaped buffer must exist");
+    shaped
+        .buffer
+        .lines
+        .iter()
+        .map(|line| line.text())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn clamp_keeps_the_line_budget_and_ends_in_an_ellipsis() {
+    let mut cosmic = CosmicMeasure::with_bundled_fonts();
+    let narrow = ui_shape(16.0).width(120.0).halign(HAlign::Left);
+    let unbounded = cosmic.measure(PARAGRAPH, narrow.unbounded());
+    let wrapped = cosmic.measure(PARAGRAPH, narrow);
+    assert!(
+        wrapped.size.h > 2.0 * one_line_h(narrow),
+        "fixture must wrap past the clamp: {:?}",
+        wrapped.size,
+    );
+
+    let clamped = cosmic.measure_with_fit(PARAGRAPH, narrow, LineFit::Clamp(2), unbounded.key);
+    assert_eq!(clamped.key.fit(), LineFit::Clamp(2));
+    assert_eq!(
+        clamped.size.h,
+        (2.0 * narrow.font.line_height_px).ceil(),
+        "a clamp to two lines must be two lines tall",
+    );
+    assert!(
+        clamped.size.w <= 120.0,
+        "clamp overflowed: {:?}",
+        clamped.size
+    );
+
+    // What the buffer holds is a source prefix plus the ellipsis, so byte
+    // offsets before the cut still address the source.
+    let shown = buffer_text(&cosmic, clamped.key);
+    let kept = shown
+        .strip_suffix('…')
+        .expect("a cutting clamp must end in an ellipsis");
+    assert!(
+        PARAGRAPH.starts_with(kept),
+        "{kept:?} is not a source prefix"
+    );
+    assert!(kept.len() < PARAGRAPH.len());
+}
+
+/// The clamp only rewrites its last line: every line above it sits
+/// exactly where the plain wrap put it.
+#[test]
+fn clamp_leaves_the_lines_above_the_cut_as_wrapped() {
+    let mut cosmic = CosmicMeasure::with_bundled_fonts();
+    let narrow = ui_shape(16.0).width(120.0).halign(HAlign::Left);
+    let unbounded = cosmic.measure(PARAGRAPH, narrow.unbounded());
+    let wrapped = cosmic.measure(PARAGRAPH, narrow);
+    let clamped = cosmic.measure_with_fit(PARAGRAPH, narrow, LineFit::Clamp(3), unbounded.key);
+
+    let above_cut = |glyphs: Vec<GlyphPosition>| -> Vec<GlyphPosition> {
+        glyphs
+            .into_iter()
+            .filter(|g| g.line_top < 2.0 * g.line_height - 0.5)
+            .collect()
+    };
+    let wrapped_head = above_cut(glyph_positions(&cosmic, wrapped.key));
+    assert!(!wrapped_head.is_empty());
+    assert_eq!(
+        above_cut(glyph_positions(&cosmic, clamped.key)),
+        wrapped_head
+    );
+}
+
+/// Only the lines of the clamped paragraph count toward the cut: a hard
+/// break ahead of it keeps its paragraph whole.
+#[test]
+fn clamp_counts_lines_across_hard_breaks() {
+    let mut cosmic = CosmicMeasure::with_bundled_fonts();
+    let text = format!("Title\n{PARAGRAPH}");
+    let narrow = ui_shape(16.0).width(120.0).halign(HAlign::Left);
+    let unbounded = cosmic.measure(&text, narrow.unbounded());
+    let clamped = cosmic.measure_with_fit(&text, narrow, LineFit::Clamp(2), unbounded.key);
+
+    assert_eq!(clamped.size.h, (2.0 * narrow.font.line_height_px).ceil());
+    let shown = buffer_text(&cosmic, clamped.key);
+    assert!(shown.starts_with("Title\n"), "{shown:?}");
+    assert!(shown.ends_with('…'), "{shown:?}");
+}
+
+/// A run that wraps within its budget is bound as the plain wrap: the
+/// clamp identity is kept for runs that actually lose lines, so no
+/// clamp buffer is minted and the shape reports as unclamped.
+#[test]
+fn clamp_within_its_budget_binds_as_the_plain_wrap() {
+    let mut text = TextSystem::cosmic();
+    let s = slot(WidgetId::from_hash("short clamp"));
+    let narrow = ui_shape(16.0).width(120.0).halign(HAlign::Left);
+
+    let fitted = text.shape_run(s, "two words", narrow, TextWrap::Clamp(2));
+    assert_eq!(fitted.key.fit(), LineFit::Wrap);
+    let clamp_key = narrow.request("two words", LineFit::Clamp(2)).key;
+    assert!(
+        !text.shaper.has_cosmic_buffer(clamp_key),
+        "a clamp that cuts nothing must not mint a clamp buffer",
+    );
+
+    let cut = text.shape_run(s, PARAGRAPH, narrow, TextWrap::Clamp(2));
+    assert_eq!(cut.key.fit(), LineFit::Clamp(2));
+    assert_eq!(cut.size.h, (2.0 * narrow.font.line_height_px).ceil());
+    assert!(text.shaper.has_cosmic_buffer(cut.key));
+}
+
+/// A clamp key rides on the plain wrap it cuts from, so a width change
+/// must retire both — else every drag frame leaves a wrapped companion
+/// behind on the protected window.
+#[test]
+fn superseding_a_clamp_retires_its_wrapped_companion() {
+    let mut text = TextSystem::cosmic();
+    let s = slot(WidgetId::from_hash("clamp drag"));
+    let at = |w: f32| ui_shape(14.0).width(w).halign(HAlign::Left);
+
+    let first = text.shape_run(s, PARAGRAPH, at(120.0), TextWrap::Clamp(2));
+    assert_eq!(first.key.fit(), LineFit::Clamp(2));
+    text.end_full_record(&FxHashSet::default());
+
+    let before = text.shaper.cache_counts();
+    let second = text.shape_run(s, PARAGRAPH, at(130.0), TextWrap::Clamp(2));
+    assert_eq!(second.key.fit(), LineFit::Clamp(2));
+    let counts = text.shaper.cache_counts() - before;
+    assert_eq!(
+        counts.supersedes, 2,
+        "a moved clamp must retire its own buffer and the wrap it cut from",
+    );
+}
+
+/// Mono has no glyphs to cut, so it clamps by line count alone.
+#[test]
+fn mono_clamp_caps_the_line_count() {
+    let narrow = shape(16.0).width(80.0);
+    let wrapped = mono_shape(PARAGRAPH, narrow, LineFit::Wrap);
+    let clamped = mono_shape(PARAGRAPH, narrow, LineFit::Clamp(2));
+    assert!(wrapped.size.h > 2.0 * 16.0);
+    assert_eq!(clamped.size.h, 2.0 * 16.0);
+    assert!(clamped.size.w <= 80.0);
+}
@@ -69,7 +69,7 @@ fn text_shape_key_validity_is_tagged_by_text_hash() {
     };
     assert!(!real.is_invalid());
     let zero_hash = TextShapeKey {
-        fit_q: LineFit::Ellipsis as u8,
+        fit_q: LineFit::Ellipsis.tag(),
         ..TextShapeKey::INVALID
     };
     assert!(zero_hash.is_invalid());
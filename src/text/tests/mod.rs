@@ -2,7 +2,7 @@
 //!
 //! Split by what a failure points at: [`key`] cache identity and metric
 //! validation, [`wrap`] shaping and the wrap policies, [`truncate`] the
-//! clip/ellipsis cut, [`geometry`] caret, hit-test and selection,
+//! clip/ellipsis cut, [`clamp`] the line clamp, [`geometry`] caret, hit-test and selection,
 //! [`retention`] the shaped-buffer cache's windows, [`reuse`] the
 //! per-window rows and the supersede signal they carry.
 
@@ -30,6 +30,7 @@ use crate::text::{FontFamily, FontWeight};
 use crate::widgets::theme::text_style::LINE_HEIGHT_MULT;
 use rustc_hash::FxHashSet;
 
+mod clamp;
 mod geometry;
 mod key;
 mod retention;
@@ -33,7 +33,7 @@ fn fitting_truncate_returns_the_unbounded_root_without_reshaping() {
         // Over-wide text still resolves through the truncating path.
         let truncated = text.shape_run(run_slot, "wider than twenty", fitting.width(20.0), wrap);
         assert_ne!(truncated.key, truncated.key.unbounded_version());
-        assert_eq!(truncated.key.fit_q, fit as u8);
+        assert_eq!(truncated.key.fit_q, fit.tag());
         assert!(truncated.size.w <= 20.0);
     }
 
@@ -91,7 +91,7 @@ fn a_truncating_fit_cuts_an_overflowing_label_to_one_fitting_line() {
             "{fit:?} is a bounded resolve, which has no wrapping floor to \
              report — the floor belongs to the unbounded root",
         );
-        assert_eq!(cut.key.fit_q, fit as u8);
+        assert_eq!(cut.key.fit_q, fit.tag());
         assert_eq!(
             cut.key.text_hash, full.key.text_hash,
             "{fit:?}: bounded keys reuse the source text hash",
@@ -110,7 +110,7 @@ fn a_truncating_fit_cuts_an_overflowing_label_to_one_fitting_line() {
     // Clip, ellipsis, and wrap bake three different strings at the same
     // width, so all three must key distinct cache slots.
     let wrapped = c.measure(long, params);
-    assert_eq!(wrapped.key.fit_q, LineFit::Wrap as u8);
+    assert_eq!(wrapped.key.fit_q, LineFit::Wrap.tag());
     assert_ne!(keys[0], keys[1], "clip and ellipsis must key distinctly");
     assert_ne!(keys[0], wrapped.key, "clip and wrap must key distinctly");
     assert_ne!(
@@ -36,18 +36,70 @@ pub(crate) enum WrapFloor {
 /// [`TextWrap`] via [`TextWrap::line_fit`] (`SingleLine`/`Scroll` stay on
 /// the unbounded path); folded into the shape cache key by
 /// [`TextSystem::measure`](crate::text::system::TextSystem::measure).
-#[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum LineFit {
     /// Multi-line reflow at the target width.
-    Wrap = 0,
+    Wrap,
     /// One line, hard-cut to the target width with no marker.
-    Clip = 1,
+    Clip,
     /// One line, cut to the target width with a trailing `…`.
-    Ellipsis = 2,
+    Ellipsis,
+    /// Multi-line reflow kept to this many lines, the last cut with a
+    /// trailing `…`. Only ever names a run that runs past them — see
+    /// [`Self::settle`].
+    Clamp(u8),
 }
 
 impl LineFit {
+    /// Most lines a [`Self::Clamp`] can name: its tag is `2 + lines`, and
+    /// that has to fit the key's one byte.
+    pub(crate) const MAX_CLAMP_LINES: u8 = u8::MAX - 2;
+
+    /// This fit's byte in [`TextShapeKey`](crate::text::key::TextShapeKey):
+    /// the three fixed fits take `0..=2` and a clamp takes `2 + lines`, so
+    /// the line count rides the byte the fit already owns instead of
+    /// growing the key past 24 bytes.
+    pub(crate) const fn tag(self) -> u8 {
+        match self {
+            LineFit::Wrap => 0,
+            LineFit::Clip => 1,
+            LineFit::Ellipsis => 2,
+            LineFit::Clamp(lines) => {
+                debug_assert!(lines >= 1 && lines <= Self::MAX_CLAMP_LINES);
+                2 + lines
+            }
+        }
+    }
+
+    /// What a clamp resolves under, given what the plain wrap at the same
+    /// width measures to (`wrapped`, asked only for a clamp): itself when
+    /// the wrap runs past its lines, [`Self::Wrap`] when it doesn't.
+    ///
+    /// A clamp that cuts nothing would shape the wrapped buffer a second
+    /// time under its own key, so it binds as the wrap instead — the same
+    /// skip [`Self::resolves_to_unbounded`] makes for a truncation with
+    /// room to spare. It also makes the key the one record of whether the
+    /// run was cut: a clamp key is a run that lost lines. `TextSystem`
+    /// and the text probe both bind through here, so the buffer a
+    /// selection hit-tests is the one layout drew.
+    ///
+    /// The wrapped height is a ceil'd sum of line heights, so the budget
+    /// rounds up the same way, with half a pixel for the sum drifting from
+    /// the product — as a fitted run's line budget does.
+    pub(super) fn settle(self, wrapped: impl FnOnce() -> Size, line_height_px: f32) -> Self {
+        match self {
+            LineFit::Clamp(lines) => {
+                let budget = (f32::from(lines) * line_height_px + 0.5).ceil();
+                if wrapped().h <= budget {
+                    LineFit::Wrap
+                } else {
+                    self
+                }
+            }
+            LineFit::Wrap | LineFit::Clip | LineFit::Ellipsis => self,
+        }
+    }
+
     /// Whether resolving this fit at `target_width_px` reproduces the
     /// unbounded root, letting the caller skip the second shape and the
     /// bounded cache entry it would mint.
@@ -92,6 +144,11 @@ pub enum TextWrap {
     /// Wrap only at word boundaries; words wider than the committed width
     /// overflow rather than breaking.
     WrapWithOverflow,
+    /// Wrap like [`Self::Wrap`] onto at most this many lines, cutting the
+    /// last one with a trailing ellipsis when the text runs on. `0` reads
+    /// as one line and counts past 253 as 253. See
+    /// [`Text::max_lines`](crate::Text::max_lines).
+    Clamp(u8),
 }
 
 /// Every layout consequence of a wrap policy is a pure function of the
@@ -107,6 +164,24 @@ impl TextWrap {
             TextWrap::Truncate => Some(LineFit::Clip),
             TextWrap::Ellipsis => Some(LineFit::Ellipsis),
             TextWrap::Wrap | TextWrap::WrapWithOverflow => Some(LineFit::Wrap),
+            TextWrap::Clamp(lines) => {
+                Some(LineFit::Clamp(lines.clamp(1, LineFit::MAX_CLAMP_LINES)))
+            }
+        }
+    }
+
+    /// Which policy this is, as one byte — what the shape hash packs
+    /// beside the run's other style bytes. A clamp's line count is not in
+    /// it; the hash writes that after.
+    pub(crate) const fn tag(self) -> u8 {
+        match self {
+            TextWrap::SingleLine => 0,
+            TextWrap::Scroll => 1,
+            TextWrap::Truncate => 2,
+            TextWrap::Ellipsis => 3,
+            TextWrap::Wrap => 4,
+            TextWrap::WrapWithOverflow => 5,
+            TextWrap::Clamp(_) => 6,
         }
     }
 
@@ -124,7 +199,8 @@ impl TextWrap {
             | TextWrap::Scroll
             | TextWrap::Truncate
             | TextWrap::Ellipsis
-            | TextWrap::Wrap => WrapFloor::Skip,
+            | TextWrap::Wrap
+            | TextWrap::Clamp(_) => WrapFloor::Skip,
         }
     }
 
@@ -136,9 +212,11 @@ impl TextWrap {
             TextWrap::SingleLine => unbounded.size,
             // Scroll owns clipping and panning; truncating and wrapping
             // runs can shrink to nothing.
-            TextWrap::Scroll | TextWrap::Truncate | TextWrap::Ellipsis | TextWrap::Wrap => {
-                Size::new(0.0, unbounded.size.h)
-            }
+            TextWrap::Scroll
+            | TextWrap::Truncate
+            | TextWrap::Ellipsis
+            | TextWrap::Wrap
+            | TextWrap::Clamp(_) => Size::new(0.0, unbounded.size.h),
             TextWrap::WrapWithOverflow => Size::new(unbounded.wrap_floor(), unbounded.size.h),
         }
     }
@@ -152,7 +230,8 @@ impl TextWrap {
             | TextWrap::Truncate
             | TextWrap::Ellipsis
             | TextWrap::Wrap
-            | TextWrap::WrapWithOverflow => unbounded.size,
+            | TextWrap::WrapWithOverflow
+            | TextWrap::Clamp(_) => unbounded.size,
         }
     }
 
@@ -169,7 +248,8 @@ impl TextWrap {
             | TextWrap::Scroll
             | TextWrap::Truncate
             | TextWrap::Ellipsis
-            | TextWrap::Wrap => available_width_px,
+            | TextWrap::Wrap
+            | TextWrap::Clamp(_) => available_width_px,
         }
     }
 
@@ -181,7 +261,8 @@ impl TextWrap {
             | TextWrap::Truncate
             | TextWrap::Ellipsis
             | TextWrap::Wrap
-            | TextWrap::WrapWithOverflow => resolved,
+            | TextWrap::WrapWithOverflow
+            | TextWrap::Clamp(_) => resolved,
         }
     }
 }
@@ -196,13 +277,14 @@ mod tests {
 
     /// Every policy, in declaration order — so a new one has to be added
     /// here to compile, rather than quietly escaping the sweeps below.
-    const ALL: [TextWrap; 6] = [
+    const ALL: [TextWrap; 7] = [
         TextWrap::SingleLine,
         TextWrap::Scroll,
         TextWrap::Truncate,
         TextWrap::Ellipsis,
         TextWrap::Wrap,
         TextWrap::WrapWithOverflow,
+        TextWrap::Clamp(3),
     ];
 
     /// The whole policy-to-fit mapping, plus the reachability it implies.
@@ -223,10 +305,18 @@ mod tests {
             (TextWrap::Ellipsis, Some(LineFit::Ellipsis)),
             (TextWrap::Wrap, Some(LineFit::Wrap)),
             (TextWrap::WrapWithOverflow, Some(LineFit::Wrap)),
+            (TextWrap::Clamp(3), Some(LineFit::Clamp(3))),
+            (TextWrap::Clamp(0), Some(LineFit::Clamp(1))),
+            (TextWrap::Clamp(u8::MAX), Some(LineFit::Clamp(253))),
         ] {
             assert_eq!(policy.line_fit(), expected, "{policy:?}");
         }
-        for fit in [LineFit::Wrap, LineFit::Clip, LineFit::Ellipsis] {
+        for fit in [
+            LineFit::Wrap,
+            LineFit::Clip,
+            LineFit::Ellipsis,
+            LineFit::Clamp(3),
+        ] {
             assert!(
                 ALL.iter().any(|policy| policy.line_fit() == Some(fit)),
                 "{fit:?} is reachable from no TextWrap, so a fixture taking \
@@ -246,6 +336,7 @@ mod tests {
             size: Size::new(width_px, 16.0),
             intrinsic_min: Some(intrinsic_min),
             single_line,
+            baseline: 12.0,
         }
     }
 
@@ -274,6 +365,21 @@ mod tests {
         }
     }
 
+    /// A clamp binds as itself only when the wrap runs past its lines;
+    /// anything that fits, to the half-pixel, binds as the plain wrap.
+    #[test]
+    fn a_clamp_settles_to_the_wrap_unless_it_cuts() {
+        let wrapped = |lines: f32| move || Size::new(80.0, (lines * 19.2_f32).ceil());
+        let clamp = LineFit::Clamp(2);
+        assert_eq!(clamp.settle(wrapped(1.0), 19.2), LineFit::Wrap);
+        assert_eq!(clamp.settle(wrapped(2.0), 19.2), LineFit::Wrap);
+        assert_eq!(clamp.settle(wrapped(3.0), 19.2), clamp);
+        // Only a clamp measures the wrap at all.
+        for fit in [LineFit::Wrap, LineFit::Clip, LineFit::Ellipsis] {
+            assert_eq!(fit.settle(|| unreachable!(), 19.2), fit);
+        }
+    }
+
     #[test]
     fn only_wrap_with_overflow_floors_the_shaping_width_at_its_widest_segment() {
         // 40 px committed against a 60 px unbreakable segment: every
@@ -286,6 +392,7 @@ mod tests {
             TextWrap::Truncate,
             TextWrap::Ellipsis,
             TextWrap::Wrap,
+            TextWrap::Clamp(2),
         ] {
             assert_eq!(policy.target_width(40.0, &narrow), 40.0, "{policy:?}");
         }
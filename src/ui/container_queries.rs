@@ -0,0 +1,150 @@
+//! Width-dependent records made this pass — breakpoint panels and
+//! auto-fill grids — and the check that re-records the frame when one of
+//! them lands in a different tier.
+//!
+//! A node's width is only known once the pass that recorded it has
+//! been laid out, so its body records against the width the *previous*
+//! pass arranged. [`ContainerQueries::any_crossed`] compares each body's
+//! tier with the one its fresh rect falls in; a mismatch asks for the
+//! same settling pass [`Ui::request_relayout`](crate::Ui::request_relayout)
+//! does, so a resize that crosses a breakpoint paints the new layout on
+//! the frame that caused it rather than the one after.
+
+use crate::layout::Layout;
+use crate::primitives::span::Span;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::cascade::Cascade;
+
+/// One query: its id, how its width maps to a tier, and the tier its
+/// body recorded under.
+#[derive(Clone, Copy, Debug)]
+struct ContainerQuery {
+    id: WidgetId,
+    rule: TierRule,
+    tier: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TierRule {
+    /// A breakpoint panel: the slice of [`ContainerQueries::thresholds`]
+    /// it was shown with.
+    Breakpoints(Span),
+    /// An auto-fill grid: how many `track`-wide tracks, `gap` apart, fit
+    /// in the width less `inset`.
+    Repeat { track: f32, gap: f32, inset: f32 },
+}
+
+impl TierRule {
+    #[inline]
+    fn tier(self, thresholds: &[f32], width: f32) -> usize {
+        match self {
+            TierRule::Breakpoints(span) => tier_of(&thresholds[span.range()], width),
+            TierRule::Repeat { track, gap, inset } => repeat_count(track, gap, width - inset),
+        }
+    }
+}
+
+/// Every query recorded this pass. Cleared per pass in
+/// `FrameCycle::begin_pass`, since the closure that runs next re-adds
+/// each one it still shows. Capacity is retained across frames.
+#[derive(Debug, Default)]
+pub(crate) struct ContainerQueries {
+    queries: Vec<ContainerQuery>,
+    thresholds: Vec<f32>,
+}
+
+impl ContainerQueries {
+    pub(crate) fn clear(&mut self) {
+        self.queries.clear();
+        self.thresholds.clear();
+    }
+
+    /// The tier `id`'s last arranged width puts it in under
+    /// `breakpoints`, noted for [`Self::any_crossed`]. Tier `0` before
+    /// the panel has ever been arranged.
+    pub(crate) fn tier(
+        &mut self,
+        id: WidgetId,
+        breakpoints: &[f32],
+        cascade: &Cascade,
+        layout: &Layout,
+    ) -> usize {
+        debug_assert!(
+            breakpoints.is_sorted() && breakpoints.iter().all(|b| b.is_finite()),
+            "breakpoints must be finite and ascending; got {breakpoints:?}",
+        );
+        let start = self.thresholds.len();
+        self.thresholds.extend_from_slice(breakpoints);
+        let rule = TierRule::Breakpoints(Span::from(start..self.thresholds.len()));
+        self.query(id, rule, 0, cascade, layout)
+    }
+
+    /// How many `track`-wide columns, `gap` apart, fit across `id`'s last
+    /// arranged width less `inset`, noted for [`Self::any_crossed`].
+    /// Never less than one, and one before the grid has ever been
+    /// arranged.
+    pub(crate) fn repeat_count(
+        &mut self,
+        id: WidgetId,
+        track: f32,
+        gap: f32,
+        inset: f32,
+        cascade: &Cascade,
+        layout: &Layout,
+    ) -> usize {
+        debug_assert!(
+            track > 0.0 && track.is_finite(),
+            "auto-fill track needs a positive, finite size; got {track}",
+        );
+        self.query(
+            id,
+            TierRule::Repeat { track, gap, inset },
+            1,
+            cascade,
+            layout,
+        )
+    }
+
+    fn query(
+        &mut self,
+        id: WidgetId,
+        rule: TierRule,
+        unarranged: usize,
+        cascade: &Cascade,
+        layout: &Layout,
+    ) -> usize {
+        let tier = arranged_width(id, cascade, layout)
+            .map_or(unarranged, |w| rule.tier(&self.thresholds, w));
+        self.queries.push(ContainerQuery { id, rule, tier });
+        tier
+    }
+
+    /// Whether any query's width, as this pass just arranged it, falls in
+    /// a different tier from the one its body recorded under. A node the
+    /// pass did not arrange has nothing to compare and never crosses.
+    pub(crate) fn any_crossed(&self, cascade: &Cascade, layout: &Layout) -> bool {
+        self.queries.iter().any(|q| {
+            arranged_width(q.id, cascade, layout)
+                .is_some_and(|w| q.rule.tier(&self.thresholds, w) != q.tier)
+        })
+    }
+}
+
+/// How many of `breakpoints` `width` has reached.
+#[inline]
+fn tier_of(breakpoints: &[f32], width: f32) -> usize {
+    breakpoints.partition_point(|&b| b <= width)
+}
+
+/// CSS's `repeat(auto-fill, …)` count: `n` tracks take `n·track +
+/// (n−1)·gap`, so `n = ⌊(avail + gap) / (track + gap)⌋`, at least one.
+#[inline]
+fn repeat_count(track: f32, gap: f32, avail: f32) -> usize {
+    (((avail + gap) / (track + gap)).floor() as usize).max(1)
+}
+
+#[inline]
+fn arranged_width(id: WidgetId, cascade: &Cascade, layout: &Layout) -> Option<f32> {
+    let loc = cascade.locate(id)?;
+    Some(layout.arranged_rect(loc.endpoint).size.w)
+}
@@ -129,6 +129,7 @@ impl<'a> FrameCycle<'a> {
                 FrameProcessing::PaintOnly
             }
             FramePlan::FullRecord { .. } => {
+                self.ui.step_theme_transition();
                 {
                     profiling::scope!("Ui::update_user");
                     app.update(win, self.ui);
@@ -303,13 +304,23 @@ impl<'a> FrameCycle<'a> {
         }
         self.ui.close_node();
         self.post_record();
+        // A breakpoint panel whose fresh width left the tier its body
+        // recorded under takes the same single retry `request_relayout`
+        // does, so the switch paints on this frame rather than the next.
+        if self
+            .ui
+            .container_queries
+            .any_crossed(&self.ui.cascade, &self.ui.layout)
+        {
+            self.ui.frame_runtime.relayout_requested = true;
+        }
         action_flag
     }
 
     /// Open the pass: clear everything the user closure is about to
     /// refill. Opening half of [`Self::post_record`].
     ///
-    /// **All three resets share one lifetime** — per *pass*, not per
+    /// **All four resets share one lifetime** — per *pass*, not per
     /// frame — and one reason to live here: each is re-asserted by the
     /// closure that runs immediately after, so a `PaintOnly` frame,
     /// which runs no closure, must keep the previous frame's values
@@ -320,16 +331,18 @@ impl<'a> FrameCycle<'a> {
     /// once-per-frame sweep instead — clock, wake queue, cross-frame
     /// widget state, animation rows.
     ///
-    /// A fourth per-pass reset goes here, and nowhere else.
+    /// A fifth per-pass reset goes here, and nowhere else.
     fn begin_pass(&mut self) {
         profiling::scope!("Ui::pre_record");
         // Clears both the trees and the retained payloads their shape
         // records index into.
-        self.ui.forest.pre_record();
+        self.ui.forest.pre_record(self.ui.display.logical_size());
         // Record-scoped input ownership and wake watches.
         self.ui.input.begin_record(&self.ui.cascade);
         // Re-asserted by whoever still wants the cursor this pass.
         self.ui.window_requests.levels.cursor = CursorIcon::default();
+        // Re-added by every breakpoint panel the closure still shows.
+        self.ui.container_queries.clear();
     }
 
     /// Record-half of a pass: finalize hashes, run measure / arrange,
@@ -397,6 +410,9 @@ impl<'a> FrameCycle<'a> {
         // Each carries its own early-out for the nothing-to-do case.
         self.engines.layout.text.end_full_record(removed);
         self.ui.anim.sweep_removed(removed);
+        // Palette-derived themes expire the same way: one no scope used
+        // this frame goes.
+        self.ui.palette_themes.sweep();
         // These two react to removals only, and both walk their whole map
         // to do it — so the one guard sits here rather than being spelled
         // differently inside each.
@@ -351,10 +351,10 @@ impl UiHarness {
     /// `n` frames stepping `dt` each — the correct way to move an
     /// animation, since a single large jump is clamped to `MAX_ANIM_DT`.
     ///
-    /// No caller yet: the animation suite drives absolute stamps through
-    /// [`Self::at`]. Kept because the assert below is the crate's only
-    /// guard on rule 8, and a test that trips it fails silently — it
-    /// under-integrates rather than panicking.
+    /// The animation suite mostly drives absolute stamps through
+    /// [`Self::at`]; the assert below is the crate's only guard on rule 8,
+    /// and a test that trips it fails silently — it under-integrates
+    /// rather than panicking.
     pub fn advance_frames(&mut self, n: u32, dt: Duration, mut record: impl FnMut(&mut Ui)) {
         assert!(
             dt.as_secs_f32() <= MAX_ANIM_DT,
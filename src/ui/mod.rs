@@ -1,5 +1,6 @@
 #[cfg(feature = "bench")]
 pub(crate) mod bench;
+pub(crate) mod container_queries;
 mod frame_cycle;
 pub(crate) mod frame_engines;
 pub(crate) mod frame_input;
@@ -11,6 +12,7 @@ mod frame_stats;
 pub(crate) mod layer_scope;
 pub(crate) mod resources;
 pub(crate) mod state;
+mod theme_scope;
 pub(crate) mod wake;
 pub(crate) mod wake_reasons;
 
@@ -28,7 +30,7 @@ use crate::icons::icon_atlas::IconAtlas;
 use crate::icons::icon_set::IconSet;
 use crate::input::input_event::InputEvent;
 use crate::input::input_state::InputState;
-use crate::input::keyboard::{KeyboardEvent, Modifiers};
+use crate::input::keyboard::{KeyPress, KeyboardEvent, Modifiers};
 use crate::input::pointer::PointerEvent;
 use crate::input::policy::FocusPolicy;
 use crate::input::policy::InputPolicy;
@@ -37,9 +39,9 @@ use crate::input::shortcut::Shortcut;
 use crate::input::watch::{KeyboardWake, PointerWake};
 use crate::layout::Layout;
 use crate::layout::scrollbars::ScrollbarsDef;
+use crate::layout::types::grid_template::GridTemplate;
 use crate::layout::types::layout_mode::{GridDefId, ScrollbarsDefId};
 use crate::layout::types::sizing::Sizes;
-use crate::layout::types::track::Track;
 use crate::primitives::background::Background;
 use crate::primitives::image::Image;
 use crate::primitives::size::Size;
@@ -62,6 +64,7 @@ use crate::{InternedStr, TextInput};
 use crate::primitives::widget_id::WidgetId;
 use crate::scene::cascade::Cascade;
 use crate::shape::Lower;
+use crate::ui::container_queries::ContainerQueries;
 use crate::ui::frame_cycle::FrameCycle;
 use crate::ui::frame_engines::FrameEngines;
 use crate::ui::frame_input::FrameInput;
@@ -70,8 +73,11 @@ use crate::ui::frame_runtime::FrameRuntime;
 use crate::ui::layer_scope::LayerScope;
 use crate::ui::resources::UiResources;
 use crate::ui::state::StateMap;
+use crate::ui::theme_scope::{PaletteThemes, ThemeScope};
 use crate::ui::wake_reasons::WakeReasons;
 use crate::widgets::theme::Theme;
+use crate::widgets::theme::palette::Palette;
+use crate::widgets::theme::transition::ThemeTransition;
 use crate::widgets::widget::Widget;
 use crate::window::cursor_icon::CursorIcon;
 use crate::window::vsync::Vsync;
@@ -122,6 +128,22 @@ pub struct Ui {
     /// the handle that makes it a refcount bump instead;
     /// [`Self::set_theme`] is the write side.
     theme: Rc<Theme>,
+    /// Themes shadowed by the open [`Self::with_theme`] scopes, outermost
+    /// first; `theme` is the innermost. Empty between frames, so the
+    /// root — the app's theme — is `theme_scopes[0]` inside a scope and
+    /// `theme` outside one (see `Self::root_theme_mut`).
+    theme_scopes: Vec<Rc<Theme>>,
+    /// Themes [`Self::with_palette`] derived, swept of the palettes no
+    /// scope used once per frame.
+    palette_themes: PaletteThemes,
+    /// A [`Self::set_theme_animated`] switch still easing. While `Some`,
+    /// `theme` is a per-frame blend of its two ends, rebuilt by
+    /// [`Self::step_theme_transition`]. Boxed: live for a fraction of a
+    /// second per switch, so it should cost the pinned `Ui` a pointer,
+    /// not two `Rc`s and a spec.
+    theme_transition: Option<Box<ThemeTransition>>,
+    /// See [`Self::reduced_motion`].
+    reduced_motion: bool,
     /// Cross-frame widget state: per-type dense stores keyed by
     /// `WidgetId` (see [`StateMap`]).
     state: StateMap,
@@ -145,6 +167,9 @@ pub struct Ui {
     /// validity state — the scheduling half of a frame, as against the
     /// authored tables above.
     frame_runtime: FrameRuntime,
+    /// Breakpoint panels recorded this pass, checked after layout for
+    /// one that changed tier — see [`ContainerQueries`].
+    container_queries: ContainerQueries,
     /// Recorder-to-host requests retained across frames.
     window_requests: WindowRequests,
     /// Host-to-recorder facts refreshed before each windowed frame.
@@ -185,6 +210,10 @@ impl Ui {
             resources,
             forest: Default::default(),
             theme: Default::default(),
+            theme_scopes: Vec::new(),
+            palette_themes: PaletteThemes::default(),
+            theme_transition: None,
+            reduced_motion: false,
             state: Default::default(),
             gpu_views: Default::default(),
             layout: Default::default(),
@@ -193,12 +222,14 @@ impl Ui {
             display: Default::default(),
             anim: Default::default(),
             frame_runtime: Default::default(),
+            container_queries: Default::default(),
             window_requests: Default::default(),
             window_frame: Default::default(),
         }
     }
 
-    /// The active theme. Reads go straight through the `Rc` —
+    /// The active theme — the innermost [`Self::with_theme`] scope's,
+    /// else the app's. Reads go straight through the `Rc` —
     /// `ui.theme().button.padding` — and `.clone()` on the result is a
     /// refcount bump, **not** a copy of the ~9 KB bundle tree, which is
     /// why this hands back the handle rather than a plain `&Theme`.
@@ -220,9 +251,143 @@ impl Ui {
     /// Replace the whole theme. Takes the `Rc` so an app swapping
     /// between prebuilt themes (light/dark, a preferences apply) hands
     /// over a handle instead of copying ~9 KB.
+    ///
+    /// Snaps: every colour changes on the next widget recorded. Cancels a
+    /// [`Self::set_theme_animated`] switch still in flight. Called inside
+    /// a [`Self::with_theme`] scope it still replaces the app's theme,
+    /// not the scope's — which the open scopes go on shadowing until
+    /// they close.
     #[inline]
     pub fn set_theme(&mut self, theme: impl Into<Rc<Theme>>) {
-        self.theme = theme.into();
+        *self.root_theme_mut() = theme.into();
+        self.theme_transition = None;
+    }
+
+    /// Record `body` with `theme` as the active theme, then restore the
+    /// one before it. Every widget inside reads the innermost scope's
+    /// theme through [`Self::theme`], so a sidebar in a light palette or
+    /// a danger zone of red buttons is one scope rather than a `.style`
+    /// threaded through every helper. Scopes nest; `.style(&XTheme)` on
+    /// a widget inside one still wins for that widget.
+    ///
+    /// Takes the `Rc` for the same reason [`Self::set_theme`] does: build
+    /// the scoped theme once — from a [`Palette`](crate::Palette) with
+    /// [`Theme::from_palette`], say — and hand over a clone of the handle
+    /// each frame, rather than rebuilding ~9 KB per frame.
+    /// [`Self::with_palette`] does that caching for a palette.
+    ///
+    /// Nothing needs invalidating by hand. A theme is read only while
+    /// widgets record, and what they read lands in the recorded nodes —
+    /// padding, fills, font sizes — so swapping a scope's theme changes
+    /// those nodes' hashes and, through `subtree_hash`, re-measures and
+    /// repaints exactly the subtrees it touched.
+    ///
+    /// ```ignore
+    /// let light: Rc<Theme> = Rc::new(Theme::from_palette(&light_palette));
+    /// ui.with_theme(light.clone(), |ui| sidebar(ui));
+    /// ```
+    pub fn with_theme<R>(
+        &mut self,
+        theme: impl Into<Rc<Theme>>,
+        body: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let scope = ThemeScope::open(self, theme.into());
+        body(scope.ui)
+    }
+
+    /// [`Self::with_theme`] with the theme [`Theme::from_palette`] builds
+    /// from `palette`. The built theme is cached per palette, so handing
+    /// the same palette over every frame builds it once; a palette no
+    /// scope used for a whole frame is dropped from the cache.
+    ///
+    /// ```ignore
+    /// ui.with_palette(&light_palette, |ui| sidebar(ui));
+    /// ```
+    pub fn with_palette<R>(&mut self, palette: &Palette, body: impl FnOnce(&mut Ui) -> R) -> R {
+        let theme = self.palette_themes.get(palette);
+        self.with_theme(theme, body)
+    }
+
+    /// The app's theme, under any open [`Self::with_theme`] scopes.
+    fn root_theme_mut(&mut self) -> &mut Rc<Theme> {
+        match self.theme_scopes.first_mut() {
+            Some(root) => root,
+            None => &mut self.theme,
+        }
+    }
+
+    /// Replace the whole theme, easing every colour — palette, fills,
+    /// strokes, shadows, text — from the current theme to the new one
+    /// under `spec`, in Oklab. Widgets don't opt in: for the length of
+    /// the switch [`Self::theme`] hands out a blend of the two, rebuilt
+    /// once per frame before anything records, and repaints are requested
+    /// until it lands on `theme` itself.
+    ///
+    /// Only colours ease; sizes, spacing and fonts take the new theme's
+    /// values at once (see `Theme::blend`). A switch started mid-flight
+    /// eases on from whatever is on screen. Under
+    /// [`Self::reduced_motion`], or with an instant `spec`, this is
+    /// [`Self::set_theme`].
+    ///
+    /// ```ignore
+    /// ui.set_theme_animated(dark.clone(), AnimSpec::MEDIUM);
+    /// ```
+    pub fn set_theme_animated(&mut self, theme: impl Into<Rc<Theme>>, spec: AnimSpec) {
+        let to = theme.into();
+        if self.reduced_motion || spec.is_instant() {
+            self.set_theme(to);
+            return;
+        }
+        let from = self.root_theme_mut().clone();
+        // Restart progress at 0: drop a mid-flight row, then seed a
+        // settled one that the next frame's step retargets to 1.
+        let (id, slot) = (ThemeTransition::ID, ThemeTransition::SLOT);
+        self.animate::<f32>(id, slot, 0.0, None);
+        self.animate::<f32>(id, slot, 0.0, Some(spec));
+        *self.root_theme_mut() = Rc::new(Theme::blend(&from, &to, 0.0));
+        self.theme_transition = Some(Box::new(ThemeTransition { from, to, spec }));
+        self.request_repaint();
+    }
+
+    /// The user prefers reduced motion — the OS accessibility setting,
+    /// which the host forwards through [`Self::set_reduced_motion`].
+    /// Theme switches honour it by snapping; widgets with motion of their
+    /// own can read it to do the same.
+    #[inline]
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// See [`Self::reduced_motion`]. Turning it on finishes a theme
+    /// switch in flight at once.
+    pub fn set_reduced_motion(&mut self, reduce: bool) {
+        self.reduced_motion = reduce;
+        if reduce && let Some(transition) = self.theme_transition.take() {
+            self.animate::<f32>(ThemeTransition::ID, ThemeTransition::SLOT, 1.0, None);
+            *self.root_theme_mut() = transition.to;
+        }
+    }
+
+    /// Advance a [`Self::set_theme_animated`] switch by this frame's dt
+    /// and install the blend it has reached. Called by the frame driver
+    /// once per recorded frame, before `App::update`, so every widget
+    /// this frame sees the same theme.
+    pub(crate) fn step_theme_transition(&mut self) {
+        let Some(transition) = self.theme_transition.take() else {
+            return;
+        };
+        let (id, slot) = (ThemeTransition::ID, ThemeTransition::SLOT);
+        let t = self.animate::<f32>(id, slot, 1.0, Some(transition.spec));
+        // A settled row returns its target exactly; a spring in flight
+        // that crosses 1.0 lands on it only by accident, and one more
+        // frame of blend is harmless.
+        if t == 1.0 {
+            self.animate::<f32>(id, slot, 1.0, None);
+            *self.root_theme_mut() = transition.to;
+        } else {
+            *self.root_theme_mut() = Rc::new(Theme::blend(&transition.from, &transition.to, t));
+            self.theme_transition = Some(transition);
+        }
     }
 
     /// Drive one application frame for `win`, delegating to
@@ -368,6 +533,17 @@ impl Ui {
         self.input.key_pressed(layer, parent, &self.cascade, sc)
     }
 
+    /// This frame's key presses granted to the recording position, in
+    /// arrival order — what [`Self::key_pressed`] tests each chord
+    /// against, handed over whole for matchers that need the sequence
+    /// (the command registry's multi-step chords). Watches nothing:
+    /// unlike `key_pressed`, the caller picks which chords to wake for.
+    pub(crate) fn granted_key_presses(&mut self) -> impl Iterator<Item = KeyPress> + '_ {
+        let layer = self.forest.current_layer();
+        let parent = self.forest.current_parent_id();
+        self.input.granted_key_presses(layer, parent, &self.cascade)
+    }
+
     /// Sugar for `key_pressed(Shortcut::key(Key::Escape))`.
     /// Used by overlays without exclusive keyboard capture, such as
     /// [`crate::widgets::modal::Modal`].
@@ -406,6 +582,28 @@ impl Ui {
         self.frame_runtime.relayout_requested = true;
     }
 
+    /// The tier `id`'s breakpoint body records under this pass — see
+    /// [`Panel::show_responsive`](crate::Panel::show_responsive).
+    pub(crate) fn breakpoint_tier(&mut self, id: WidgetId, breakpoints: &[f32]) -> usize {
+        self.container_queries
+            .tier(id, breakpoints, &self.cascade, &self.layout)
+    }
+
+    /// How many `track`-wide columns an auto-fill grid `id` fits, from
+    /// the width it last arranged to less `inset` (its horizontal padding).
+    /// Same settling contract as [`Self::breakpoint_tier`]: a change of
+    /// count re-records the frame that caused it.
+    pub(crate) fn auto_fill_count(
+        &mut self,
+        id: WidgetId,
+        track: f32,
+        gap: f32,
+        inset: f32,
+    ) -> usize {
+        self.container_queries
+            .repeat_count(id, track, gap, inset, &self.cascade, &self.layout)
+    }
+
     /// Monotonic time of the current frame, accumulated from the
     /// per-frame `dt`s the host feeds in. Starts at zero on the first
     /// frame and only moves forward. Read-only on purpose: the clock is
@@ -1010,14 +1208,8 @@ impl Ui {
     /// references a definition the tree owns, and neither should have to
     /// name the tree to do it.
     #[inline]
-    pub(crate) fn push_grid_def(
-        &mut self,
-        rows: &[Track],
-        cols: &[Track],
-        row_gap: f32,
-        col_gap: f32,
-    ) -> GridDefId {
-        self.forest.push_grid_def(rows, cols, row_gap, col_gap)
+    pub(crate) fn push_grid_def(&mut self, template: GridTemplate<'_>) -> GridDefId {
+        self.forest.push_grid_def(template)
     }
 
     /// [`Self::push_grid_def`] for a scroll's bar overlay.
@@ -1034,6 +1226,23 @@ impl Ui {
         self.forest.current_node(id)
     }
 
+    /// `WidgetId` of the innermost node open in the active layer — the
+    /// container the next widget records into — or `None` at the top of a
+    /// layer.
+    #[inline]
+    pub(crate) fn current_parent_id(&self) -> Option<WidgetId> {
+        self.forest.current_parent_id()
+    }
+
+    /// Whether `node` lays out right to left once recorded here: its own
+    /// [`FlowDirection`](crate::FlowDirection) if it names one, else the
+    /// direction of the node it is about to open under. For a widget whose
+    /// pointer mapping has to mirror along with its layout.
+    #[inline]
+    pub(crate) fn resolves_rtl(&self, node: &Node) -> bool {
+        self.forest.resolves_rtl(node.flags)
+    }
+
     /// Last frame's measured content extent for the scroll viewport `id`,
     /// `Size::ZERO` for any widget that is not one or has not yet arranged.
     ///
@@ -1051,6 +1260,20 @@ impl Ui {
             .map_or(Size::ZERO, |endpoint| self.layout.scroll_content(endpoint))
     }
 
+    /// Whether the text `id` records lost lines to its
+    /// [`max_lines`](crate::Text::max_lines) last frame — the cue to show a
+    /// "show more" control. Layout picks the lines, so like
+    /// [`Self::response_for`] this answers for the previous frame: `false`
+    /// on the first, and for any widget that clamps no text.
+    ///
+    /// Expanding is then the caller's: lift the clamp, and animate the
+    /// height it opens to with [`Self::animate`] under a clipping parent.
+    pub fn text_clamped(&self, id: WidgetId) -> bool {
+        self.cascade
+            .endpoint(id)
+            .is_some_and(|endpoint| self.layout.text_clamped(endpoint))
+    }
+
     /// Snapshot of input/cascade state for a widget. `rect` and
     /// `disabled` are from the previous frame's cascade; the interaction
     /// fields (`pressed`, `hovered`, `drag_started`, `drag_delta`, …) are
@@ -0,0 +1,73 @@
+//! The bookkeeping behind [`Ui::with_theme`] / [`Ui::with_palette`]: the
+//! guard that closes a scope, and the themes derived from palettes.
+
+use crate::ui::Ui;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::palette::Palette;
+use std::rc::Rc;
+
+/// An open [`Ui::with_theme`] scope, closed when dropped — on return and
+/// on a panic unwinding out of the body alike, so a caught panic can't
+/// leave the scope's theme active for the rest of the frame.
+pub(super) struct ThemeScope<'a> {
+    pub(super) ui: &'a mut Ui,
+}
+
+impl<'a> ThemeScope<'a> {
+    pub(super) fn open(ui: &'a mut Ui, theme: Rc<Theme>) -> Self {
+        let outer = std::mem::replace(&mut ui.theme, theme);
+        ui.theme_scopes.push(outer);
+        Self { ui }
+    }
+}
+
+impl Drop for ThemeScope<'_> {
+    fn drop(&mut self) {
+        self.ui.theme = self
+            .ui
+            .theme_scopes
+            .pop()
+            .expect("`with_theme` scopes are balanced");
+    }
+}
+
+/// Themes [`Ui::with_palette`] derived, keyed by their palette. A linear
+/// scan: an app scopes a handful of palettes, and a `Palette` is colours,
+/// so there is no `Hash` to key a map on.
+#[derive(Debug, Default)]
+pub(super) struct PaletteThemes {
+    entries: Vec<PaletteTheme>,
+}
+
+#[derive(Debug)]
+struct PaletteTheme {
+    palette: Palette,
+    theme: Rc<Theme>,
+    /// Reached for since the last [`PaletteThemes::sweep`].
+    used: bool,
+}
+
+impl PaletteThemes {
+    /// The theme derived from `palette`, built on first use.
+    pub(super) fn get(&mut self, palette: &Palette) -> Rc<Theme> {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.palette == *palette) {
+            entry.used = true;
+            return entry.theme.clone();
+        }
+        let theme = Rc::new(Theme::from_palette(palette));
+        self.entries.push(PaletteTheme {
+            palette: palette.clone(),
+            theme: theme.clone(),
+            used: true,
+        });
+        theme
+    }
+
+    /// Drop the themes no scope reached for since the last sweep. Once
+    /// per frame, so a palette that changes every frame — eased, say —
+    /// holds one entry rather than piling up.
+    pub(super) fn sweep(&mut self) {
+        self.entries
+            .retain_mut(|entry| std::mem::take(&mut entry.used));
+    }
+}
@@ -0,0 +1,93 @@
+//! Subsequence matching for the palette's search field, and for the
+//! suggestions a [`TextEdit`](crate::TextEdit) ranks and highlights.
+//!
+//! A query matches a label when its characters appear in the label in
+//! order, ignoring case: `"gtd"` matches "**G**o **t**o **D**efinition".
+//! The score prefers the matches a user means — characters at word
+//! starts, runs of consecutive characters, a match at the very start —
+//! and charges for the gaps between them, so "Go to Definition" outranks
+//! "Toggle Word Diff" for `"gtd"` even though both match.
+
+use std::ops::Range;
+
+/// Bonus per matched character.
+const MATCH: i32 = 16;
+/// Extra for a character immediately after the previous match.
+const CONSECUTIVE: i32 = 24;
+/// Extra for a character opening a word (see [`is_word_start`]).
+const WORD_START: i32 = 20;
+/// Extra when the label's first character is matched.
+const LABEL_START: i32 = 12;
+/// Charge per skipped label character between two matches, capped at
+/// [`MAX_GAP`] characters so one long skip can't outweigh the bonuses.
+const GAP: i32 = 2;
+const MAX_GAP: i32 = 8;
+
+/// Score `label` against `query`, writing the matched byte ranges of
+/// `label` into `ranges` (merged, in order). `None` if the query is not
+/// a subsequence of the label. An empty query matches everything with
+/// score 0 and no ranges.
+///
+/// Each query character takes the earliest position left in the label —
+/// greedy, so linear in the label. It can miss a better-scoring
+/// alignment further right, which for the short labels a palette lists
+/// is the right trade against a quadratic search.
+pub(crate) fn score(query: &str, label: &str, ranges: &mut Vec<Range<usize>>) -> Option<i32> {
+    ranges.clear();
+    let mut total = 0;
+    let mut rest = label.char_indices().peekable();
+    let mut prev_char: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+    let mut skipped = 0;
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        let mut found = None;
+        for (at, c) in rest.by_ref() {
+            let word_start = is_word_start(prev_char, c);
+            prev_char = Some(c);
+            if eq_ignore_case(q, c) {
+                found = Some((at, c, word_start));
+                break;
+            }
+            skipped += 1;
+        }
+        let (at, c, word_start) = found?;
+        total += MATCH;
+        if word_start {
+            total += WORD_START;
+        }
+        if at == 0 {
+            total += LABEL_START;
+        }
+        match last_match {
+            Some(_) if skipped == 0 => total += CONSECUTIVE,
+            Some(_) => total -= GAP * skipped.min(MAX_GAP),
+            None => total -= skipped.min(MAX_GAP),
+        }
+        skipped = 0;
+        let end = at + c.len_utf8();
+        match ranges.last_mut() {
+            Some(run) if run.end == at => run.end = end,
+            _ => ranges.push(at..end),
+        }
+        last_match = Some(at);
+    }
+    Some(total)
+}
+
+fn eq_ignore_case(q: char, c: char) -> bool {
+    q == c || q.to_lowercase().eq(c.to_lowercase())
+}
+
+/// A character opens a word after a separator, at a lower→upper case
+/// step (`camelCase`), and at a letter↔digit step.
+fn is_word_start(prev: Option<char>, c: char) -> bool {
+    let Some(prev) = prev else {
+        return true;
+    };
+    if !c.is_alphanumeric() {
+        return false;
+    }
+    !prev.is_alphanumeric()
+        || (prev.is_lowercase() && c.is_uppercase())
+        || (prev.is_alphabetic() != c.is_alphabetic())
+}
@@ -0,0 +1,522 @@
+//! A keyboard-first launcher: a search field over a fuzzy-ranked list.
+
+pub(crate) mod fuzzy;
+
+use crate::input::command::binding::KeyBinding;
+use crate::input::keyboard::{Key, KeyboardEvent};
+use crate::input::sense::Sense;
+use crate::layout::types::align::{Align, VAlign};
+use crate::layout::types::justify::Justify;
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::ThemeDefaults;
+use crate::scene::node::{Configure, Node};
+use crate::ui::Ui;
+use crate::widgets::modal::Modal;
+use crate::widgets::popup::{ClickOutside, Popup};
+use crate::widgets::text::Text;
+use crate::widgets::text_edit::TextEdit;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::command_palette::CommandPaletteTheme;
+use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::theme::widget_look::look_plan::LookPlan;
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::ops::Range;
+
+/// One row a [`CommandPalette`] can offer: the label the query is matched
+/// against, and optionally the binding shown beside it.
+#[derive(Clone, Copy, Debug)]
+pub struct PaletteEntry<'a> {
+    label: &'a str,
+    shortcut: Option<KeyBinding>,
+}
+
+impl<'a> PaletteEntry<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            shortcut: None,
+        }
+    }
+
+    /// Show `binding` right-aligned on the row, in the platform's native
+    /// form (`⌘⇧P` / `Ctrl+Shift+P`). Display only: the palette never
+    /// dispatches it — whatever owns the binding does.
+    pub fn shortcut(mut self, binding: impl Into<KeyBinding>) -> Self {
+        self.shortcut = Some(binding.into());
+        self
+    }
+
+    pub fn label(&self) -> &'a str {
+        self.label
+    }
+}
+
+/// Where a [`CommandPalette`] opens.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PalettePlacement {
+    /// Centered over a dimming backdrop, as a [`Modal`].
+    #[default]
+    Modal,
+    /// A [`Popup`] hanging from the top of the surface, horizontally
+    /// centered, with the rest of the app left visible — the editor
+    /// "quick open" look.
+    Top,
+}
+
+/// The entries a user picked most recently, most recent first, which a
+/// [`CommandPalette`] lists ahead of the rest.
+///
+/// Held by the caller rather than the palette: the palette's own state
+/// lives only while it is open, and "recent" has to outlive that — across
+/// openings, and across runs if the app persists it (it serializes as a
+/// plain list). Entries are remembered by label.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+pub struct RecentEntries {
+    labels: Vec<String>,
+    capacity: usize,
+}
+
+impl RecentEntries {
+    /// How many labels [`Self::default`] remembers.
+    pub const DEFAULT_CAPACITY: usize = 8;
+
+    /// Remember at most `capacity` labels.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            labels: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Move `label` to the front, dropping the oldest label past capacity.
+    /// The palette calls this for its own picks.
+    pub fn touch(&mut self, label: &str) {
+        if let Some(at) = self.labels.iter().position(|l| l == label) {
+            let label = self.labels.remove(at);
+            self.labels.insert(0, label);
+        } else if self.capacity > 0 {
+            self.labels.truncate(self.capacity - 1);
+            self.labels.insert(0, label.to_owned());
+        }
+    }
+
+    /// `label`'s position, 0 for the most recent. `None` if not remembered.
+    pub fn rank(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// Remembered labels, most recent first.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.labels.iter().map(String::as_str)
+    }
+
+    pub fn clear(&mut self) {
+        self.labels.clear();
+    }
+}
+
+impl Default for RecentEntries {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// One ranked result. `ranges` indexes [`PaletteState::ranges`].
+#[derive(Clone, Debug)]
+struct Match {
+    entry: usize,
+    score: i32,
+    recency: usize,
+    ranges: Range<usize>,
+}
+
+/// Query, selection and the ranked list for one open palette, keyed off
+/// the palette id. Lives only while the palette is recorded, so a
+/// palette reopened after a frame closed opens on an empty query.
+#[derive(Default, Debug)]
+struct PaletteState {
+    query: String,
+    /// Index into `matches`.
+    selected: usize,
+    /// First `matches` index in the visible window.
+    first_row: usize,
+    /// Focus has been handed to the field. Once, on the first frame, so
+    /// a click elsewhere inside the card isn't fought every frame.
+    focused: bool,
+    /// Refilled every frame; kept so typing into a long list doesn't
+    /// allocate per keystroke.
+    matches: Vec<Match>,
+    ranges: Vec<Range<usize>>,
+    scratch: Vec<Range<usize>>,
+}
+
+/// A search field over a fuzzy-ranked list of entries, for reaching any
+/// command by name without leaving the keyboard. The query matches each
+/// entry's label as a case-insensitive subsequence — `"gtd"` finds "Go to
+/// Definition" — with the matched characters highlighted and the best
+/// matches first; with an empty query, [recent](Self::recent) entries
+/// lead. ArrowUp/ArrowDown move the selection, Enter or a click picks,
+/// and Escape or a click outside dismisses.
+///
+/// Record it every frame the palette is open and close it on
+/// [`CommandPaletteResponse::closed`]; the picked entry comes back as an
+/// index into `entries`. Query and selection live in the state map keyed
+/// off the palette id and reset when it stops being recorded. Running the
+/// picked command is the caller's, as it is for a [`crate::MenuItem`].
+///
+/// The card is [`crate::Theme::modal`]'s in [`PalettePlacement::Modal`]
+/// and [`crate::Theme::context_menu`]'s panel in
+/// [`PalettePlacement::Top`]; rows paint as menu rows, the selected one
+/// in the hovered look. It complements [`crate::ComboBox`], which shows
+/// short lists whole and unfiltered.
+#[derive(Debug)]
+pub struct CommandPalette<'a> {
+    node: Node,
+    entries: &'a [PaletteEntry<'a>],
+    recent: Option<&'a mut RecentEntries>,
+    placement: PalettePlacement,
+    placeholder: Cow<'static, str>,
+    style: Option<&'a CommandPaletteTheme>,
+}
+
+/// Outcome of [`CommandPalette::show`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CommandPaletteResponse {
+    /// Index into the palette's `entries` of the entry picked this frame,
+    /// by Enter or by a click.
+    pub chosen: Option<usize>,
+    /// Escape was pressed, or the user clicked outside, this frame.
+    pub dismissed: bool,
+}
+
+impl CommandPaletteResponse {
+    /// `true` when the palette is done this frame — an entry was picked or
+    /// it was dismissed.
+    pub fn closed(&self) -> bool {
+        self.chosen.is_some() || self.dismissed
+    }
+}
+
+impl<'a> CommandPalette<'a> {
+    #[track_caller]
+    pub fn new(entries: &'a [PaletteEntry<'a>]) -> Self {
+        Self {
+            node: Node::vstack(),
+            entries,
+            recent: None,
+            placement: PalettePlacement::Modal,
+            placeholder: Cow::Borrowed("Type a command"),
+            style: None,
+        }
+    }
+
+    /// Rank `recent` entries first and record this palette's picks in it.
+    pub fn recent(mut self, recent: &'a mut RecentEntries) -> Self {
+        self.recent = Some(recent);
+        self
+    }
+
+    pub fn placement(mut self, placement: PalettePlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Hint shown in the empty search field.
+    pub fn placeholder(mut self, s: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = s.into();
+        self
+    }
+
+    style_setter!(
+        'a,
+        CommandPaletteTheme,
+        command_palette,
+        "The card, search field and rows keep reading \
+         [`crate::Theme::modal`] / [`crate::Theme::context_menu`] and \
+         [`crate::Theme::text_edit`].",
+    );
+
+    pub fn show(self, ui: &mut Ui) -> CommandPaletteResponse {
+        // Handle: the theme is read inside the container's body, which
+        // owns `ui` mutably.
+        let ui_theme = ui.theme().clone();
+        let pt = self.slot(&ui_theme);
+        let mut widget = ui.widget(self.node);
+        let id = widget.id();
+        if widget.node.size.is_none() {
+            widget.node = widget.node.size((Sizing::fixed(pt.width), Sizing::HUG));
+        }
+        widget.node = widget.node.default_gap(pt.gap);
+
+        // Moved out for the record and back after: the field borrows the
+        // query mutably while the rest of the body reads the matches.
+        let mut state = std::mem::take(ui.state_mut::<PaletteState>(id));
+        let field_id = id.with("search");
+        if !state.focused {
+            state.focused = true;
+            ui.request_focus(Some(field_id));
+        }
+
+        let entries = self.entries;
+        let recent = self.recent;
+        let placeholder = self.placeholder;
+        let mut chosen = None;
+        let body = |ui: &mut Ui| {
+            widget.record(ui, None, |ui| {
+                let ctx = Body {
+                    id,
+                    field_id,
+                    entries,
+                    recent: recent.as_deref(),
+                    theme: &ui_theme,
+                    palette: pt,
+                };
+                chosen = ctx.record(ui, &mut state, placeholder);
+            });
+        };
+
+        let dismissed = match self.placement {
+            PalettePlacement::Modal => Modal::new().id(id.with("modal")).show(ui, body).dismissed,
+            PalettePlacement::Top => {
+                let panel = &ui_theme.context_menu;
+                let surface = ui.display().logical_rect();
+                let outer = pt.width + panel.padding.horiz();
+                let anchor = Rect::new(
+                    surface.center().x - outer * 0.5,
+                    surface.min.y + pt.top_margin,
+                    outer,
+                    0.0,
+                );
+                Popup::below(anchor)
+                    .click_outside(ClickOutside::Dismiss)
+                    .background(panel.panel.clone())
+                    .padding(panel.padding)
+                    .id(id.with("popup"))
+                    .show(ui, |ui, _| body(ui))
+                    .dismissed
+            }
+        };
+        *ui.state_mut::<PaletteState>(id) = state;
+
+        if let Some(i) = chosen
+            && let Some(recent) = recent
+        {
+            recent.touch(entries[i].label);
+        }
+        CommandPaletteResponse { chosen, dismissed }
+    }
+}
+
+impl_configure!(CommandPalette<'_>);
+
+/// Everything the palette body reads besides its state, bundled so the
+/// container closure hands over one value.
+struct Body<'b> {
+    id: WidgetId,
+    field_id: WidgetId,
+    entries: &'b [PaletteEntry<'b>],
+    recent: Option<&'b RecentEntries>,
+    theme: &'b Theme,
+    palette: &'b CommandPaletteTheme,
+}
+
+impl Body<'_> {
+    /// Field, then rows. Returns the entry picked this frame.
+    fn record(
+        &self,
+        ui: &mut Ui,
+        state: &mut PaletteState,
+        placeholder: Cow<'static, str>,
+    ) -> Option<usize> {
+        let field = TextEdit::new(&mut state.query)
+            .id(self.field_id)
+            .placeholder(placeholder)
+            .escape_falls_through()
+            .size((Sizing::FILL, Sizing::HUG))
+            .show(ui);
+        let (changed, submitted) = (field.changed, field.submitted);
+
+        // Arrows reach the field too — it holds focus — but a single-line
+        // field has nothing to do with the vertical pair, so reading them
+        // off the stream here takes nothing from it.
+        let mut step = 0i64;
+        for event in ui.keyboard_events() {
+            if let KeyboardEvent::Down(press) = event {
+                match press.key {
+                    Key::ArrowDown => step += 1,
+                    Key::ArrowUp => step -= 1,
+                    _ => {}
+                }
+            }
+        }
+
+        self.rank(state);
+        let n = state.matches.len();
+        if changed {
+            state.selected = 0;
+            state.first_row = 0;
+        }
+        state.selected = match n {
+            0 => 0,
+            n => (state.selected.min(n - 1) as i64 + step).rem_euclid(n as i64) as usize,
+        };
+        let window = usize::from(self.palette.max_rows.max(1));
+        if state.selected < state.first_row {
+            state.first_row = state.selected;
+        } else if state.selected >= state.first_row + window {
+            state.first_row = state.selected + 1 - window;
+        }
+        state.first_row = state.first_row.min(n.saturating_sub(window));
+
+        let mut chosen = None;
+        if submitted {
+            chosen = state.matches.get(state.selected).map(|m| m.entry);
+            if chosen.is_none() {
+                // Enter blurs a single-line field; with nothing to pick,
+                // hand focus back so the user can keep typing.
+                ui.request_focus(Some(self.field_id));
+            }
+        }
+
+        let menu = &self.theme.context_menu;
+        let rows = Node::vstack()
+            .id(self.id.with("rows"))
+            .size((Sizing::FILL, Sizing::HUG))
+            .gap(menu.gap);
+        ui.widget(rows).record(ui, None, |ui| {
+            if n == 0 {
+                let style = TextStyle {
+                    color: self.palette.empty,
+                    ..self.theme.text.clone()
+                };
+                Text::new("No matching commands")
+                    .id(self.id.with("empty"))
+                    .style(&style)
+                    .padding(menu.item.padding)
+                    .show(ui);
+                return;
+            }
+            let end = (state.first_row + window).min(n);
+            for at in state.first_row..end {
+                let m = &state.matches[at];
+                let entry = &self.entries[m.entry];
+                let ranges = &state.ranges[m.ranges.clone()];
+                let row_id = self.id.with(("row", m.entry));
+                if self.row(ui, row_id, entry, ranges, at == state.selected) {
+                    chosen = Some(m.entry);
+                }
+            }
+        });
+        chosen
+    }
+
+    /// Score every entry against the query into `state.matches`, best
+    /// first: score, then recency, then the caller's order. An empty
+    /// query scores everything alike, which leaves recency in charge.
+    fn rank(&self, state: &mut PaletteState) {
+        state.matches.clear();
+        state.ranges.clear();
+        for (entry, e) in self.entries.iter().enumerate() {
+            let Some(score) = fuzzy::score(&state.query, e.label, &mut state.scratch) else {
+                continue;
+            };
+            let start = state.ranges.len();
+            state.ranges.extend_from_slice(&state.scratch);
+            state.matches.push(Match {
+                entry,
+                score,
+                recency: self
+                    .recent
+                    .and_then(|r| r.rank(e.label))
+                    .unwrap_or(usize::MAX),
+                ranges: start..state.ranges.len(),
+            });
+        }
+        state
+            .matches
+            .sort_unstable_by_key(|m| (Reverse(m.score), m.recency, m.entry));
+    }
+
+    /// One result row: the label with its matched runs in the highlight
+    /// colour, and the binding pinned right. Returns whether it was
+    /// clicked.
+    fn row(
+        &self,
+        ui: &mut Ui,
+        row_id: WidgetId,
+        entry: &PaletteEntry<'_>,
+        ranges: &[Range<usize>],
+        selected: bool,
+    ) -> bool {
+        let item = &self.theme.context_menu.item;
+        let node = Node::hstack()
+            .id(row_id)
+            .size((Sizing::FILL, Sizing::HUG))
+            .sense(Sense::CLICK);
+        let mut widget = ui.widget(node);
+        let response = widget.response(ui);
+        // The selection paints as hover does: it is where the keyboard
+        // is pointing, which is what hover says for the mouse.
+        let mut shown = response;
+        shown.hovered |= selected;
+        let look = LookPlan {
+            target: item.pick(&shown).to_animated(&self.theme.text),
+            padding: item.padding,
+            margin: item.margin,
+            anim: item.anim,
+        }
+        .apply(ui, &mut widget);
+        let node = &mut widget.node;
+        node.justify = Justify::SpaceBetween;
+        node.child_align = Align::v(VAlign::Center);
+        node.gaps.set_gap(item.gap);
+
+        let text_style = look.text;
+        let highlight_style = TextStyle {
+            color: self.palette.highlight,
+            ..text_style.clone()
+        };
+        let shortcut_style = TextStyle {
+            color: item.shortcut,
+            ..text_style.clone()
+        };
+        let label = entry.label;
+        let shortcut = entry.shortcut.map(|b| ui.fmt(format_args!("{b}")));
+
+        widget.record(ui, Some(&look.background), |ui| {
+            let segments = Node::hstack().id(row_id.with("label"));
+            ui.widget(segments).record(ui, None, |ui| {
+                let mut at = 0;
+                let segment = |ui: &mut Ui, span: Range<usize>, style: &TextStyle| {
+                    if span.is_empty() {
+                        return;
+                    }
+                    let text = ui.intern(&label[span.clone()]);
+                    Text::new(text)
+                        .id(row_id.with(("segment", span.start)))
+                        .style(style)
+                        .show(ui);
+                };
+                for run in ranges {
+                    segment(ui, at..run.start, &text_style);
+                    segment(ui, run.clone(), &highlight_style);
+                    at = run.end;
+                }
+                segment(ui, at..label.len(), &text_style);
+            });
+            if let Some(shortcut) = shortcut {
+                Text::new(shortcut)
+                    .id(row_id.with("shortcut"))
+                    .style(&shortcut_style)
+                    .show(ui);
+            }
+        });
+        !response.disabled && response.left.clicked()
+    }
+}
+
+#[cfg(test)]
+mod tests;
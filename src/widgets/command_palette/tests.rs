@@ -0,0 +1,167 @@
+use crate::input::keyboard::Key;
+use crate::input::shortcut::Shortcut;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::harness::UiHarness;
+use crate::widgets::command_palette::fuzzy;
+use crate::widgets::command_palette::{
+    CommandPalette, CommandPaletteResponse, PaletteEntry, PalettePlacement, RecentEntries,
+};
+use glam::UVec2;
+
+const SURFACE: UVec2 = UVec2::new(800, 600);
+const PALETTE: &str = "palette";
+
+fn entries() -> [PaletteEntry<'static>; 4] {
+    [
+        PaletteEntry::new("Toggle Word Wrap"),
+        PaletteEntry::new("Go to Definition").shortcut(Shortcut::key(Key::F12)),
+        PaletteEntry::new("Format Document"),
+        PaletteEntry::new("Git: Commit"),
+    ]
+}
+
+fn row_id(entry: usize) -> WidgetId {
+    WidgetId::from_hash(PALETTE).with(("row", entry))
+}
+
+/// Runs one frame of an open palette and returns its response.
+fn frame(
+    h: &mut UiHarness,
+    placement: PalettePlacement,
+    recent: &mut RecentEntries,
+) -> CommandPaletteResponse {
+    let entries = entries();
+    h.frame_value(|ui| {
+        CommandPalette::new(&entries)
+            .id(WidgetId::from_hash(PALETTE))
+            .placement(placement)
+            .recent(recent)
+            .show(ui)
+    })
+}
+
+/// Top-to-bottom order of the entries currently on screen.
+fn shown_order(h: &UiHarness) -> Vec<usize> {
+    let mut shown: Vec<(f32, usize)> = (0..entries().len())
+        .filter_map(|i| h.rect(row_id(i)).map(|r| (r.min.y, i)))
+        .collect();
+    shown.sort_by(|a, b| a.0.total_cmp(&b.0));
+    shown.into_iter().map(|(_, i)| i).collect()
+}
+
+#[test]
+fn fuzzy_score_prefers_word_starts_and_reports_matched_runs() {
+    let mut ranges = Vec::new();
+    let definition = fuzzy::score("gtd", "Go to Definition", &mut ranges).unwrap();
+    assert_eq!(ranges, [0..1, 3..4, 6..7]);
+    let scattered = fuzzy::score("gtd", "Git: Commit to disk", &mut ranges).unwrap();
+    assert!(
+        definition > scattered,
+        "word starts outrank mid-word hits: {definition} vs {scattered}",
+    );
+
+    assert_eq!(
+        fuzzy::score("FORM", "Format Document", &mut ranges),
+        Some(fuzzy::score("form", "Format Document", &mut Vec::new()).unwrap())
+    );
+    assert_eq!(ranges, vec![0..4], "consecutive hits merge into one run");
+    assert_eq!(fuzzy::score("xyz", "Format Document", &mut ranges), None);
+    assert_eq!(fuzzy::score("", "Anything", &mut ranges), Some(0));
+    assert!(ranges.is_empty());
+}
+
+#[test]
+fn typing_filters_and_ranks_then_enter_picks_the_best_match() {
+    let mut h = UiHarness::with_text(SURFACE);
+    let mut recent = RecentEntries::default();
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    assert_eq!(
+        shown_order(&h),
+        [0, 1, 2, 3],
+        "empty query lists everything"
+    );
+
+    h.type_text("gtd");
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    assert_eq!(shown_order(&h), [1], "only `Go to Definition` matches");
+
+    h.key(Key::Enter);
+    let response = frame(&mut h, PalettePlacement::Modal, &mut recent);
+    assert_eq!(response.chosen, Some(1));
+    assert!(response.closed());
+    assert_eq!(
+        recent.labels().collect::<Vec<_>>(),
+        ["Go to Definition"],
+        "a pick is remembered as recent",
+    );
+}
+
+#[test]
+fn recent_entries_lead_an_empty_query() {
+    let mut h = UiHarness::with_text(SURFACE);
+    let mut recent = RecentEntries::default();
+    recent.touch("Format Document");
+    recent.touch("Git: Commit");
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    assert_eq!(shown_order(&h), [3, 2, 0, 1]);
+}
+
+#[test]
+fn arrows_move_and_wrap_the_selection_and_escape_dismisses() {
+    let mut h = UiHarness::with_text(SURFACE);
+    let mut recent = RecentEntries::default();
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+
+    h.key(Key::ArrowDown);
+    h.key(Key::ArrowDown);
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    h.key(Key::Enter);
+    assert_eq!(
+        frame(&mut h, PalettePlacement::Modal, &mut recent).chosen,
+        Some(2),
+    );
+
+    // A fresh palette: the previous one closed, and its state with it.
+    let mut h = UiHarness::with_text(SURFACE);
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    h.key(Key::ArrowUp);
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    h.key(Key::Enter);
+    assert_eq!(
+        frame(&mut h, PalettePlacement::Modal, &mut recent).chosen,
+        Some(3),
+        "ArrowUp from the top wraps to the last row",
+    );
+
+    let mut h = UiHarness::with_text(SURFACE);
+    frame(&mut h, PalettePlacement::Modal, &mut recent);
+    h.key(Key::Escape);
+    let response = frame(&mut h, PalettePlacement::Modal, &mut recent);
+    assert!(
+        response.dismissed,
+        "Escape falls through the search field to the palette"
+    );
+    assert_eq!(response.chosen, None);
+}
+
+#[test]
+fn top_placement_hangs_from_the_top_and_a_click_picks() {
+    let mut h = UiHarness::with_text(SURFACE);
+    let mut recent = RecentEntries::default();
+    frame(&mut h, PalettePlacement::Top, &mut recent);
+    frame(&mut h, PalettePlacement::Top, &mut recent);
+    let row = h.rect(row_id(0)).expect("first row is shown");
+    let theme = crate::widgets::theme::Theme::default();
+    assert!(row.min.y > theme.command_palette.top_margin);
+    assert!(
+        row.min.y < SURFACE.y as f32 / 2.0,
+        "anchored near the top, not centered: {row:?}",
+    );
+
+    h.click_on(row_id(2));
+    assert_eq!(
+        frame(&mut h, PalettePlacement::Top, &mut recent).chosen,
+        Some(2),
+    );
+}
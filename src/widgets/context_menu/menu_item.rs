@@ -1,5 +1,7 @@
 //! One activatable row inside a context menu.
 
+use crate::input::command::CommandRegistry;
+use crate::input::command::binding::KeyBinding;
 use crate::input::sense::Sense;
 use crate::input::shortcut::Shortcut;
 use crate::layout::types::align::{Align, HAlign};
@@ -26,6 +28,11 @@ use crate::widgets::theme::widget_look::look_plan::LookPlan;
 /// synthesize a click (so `if item.left.clicked() { … }` fires) AND
 /// close the menu, mirroring native menu behaviour. Disabled rows
 /// don't intercept.
+///
+/// [`Self::command`] builds the row from a registered
+/// [`Command`](crate::Command) instead: label, enabled state and the
+/// command's *current* binding all come from the registry, so a user
+/// rebinding shows up in every menu without touching it.
 #[derive(Debug)]
 pub struct MenuItem<'a> {
     node: Node,
@@ -39,6 +46,10 @@ enum MenuShortcut {
     None,
     Hint(Shortcut),
     Activate(Shortcut),
+    /// A command's binding, display-only: the registry that owns the
+    /// command dispatches it, so the row intercepting it too would fire
+    /// the command twice.
+    Command(KeyBinding),
 }
 
 impl<'a> MenuItem<'a> {
@@ -54,6 +65,25 @@ impl<'a> MenuItem<'a> {
         }
     }
 
+    /// A row for the registered command `id`: its label, its current
+    /// binding as the shortcut hint, and disabled while its predicate was
+    /// false at the last [`CommandRegistry::update`]. The row reports the
+    /// click; running the command is the caller's, as for any row.
+    /// Panics on an unknown id.
+    #[track_caller]
+    pub fn command<C>(registry: &CommandRegistry<C>, id: &str) -> Self {
+        // `let`-`else`, not `unwrap_or_else`: a panic inside a closure
+        // reports the closure, not this function's caller.
+        let Some(command) = registry.command(id) else {
+            panic!("no command `{id}` is registered");
+        };
+        let mut item = Self::new(command.label).enabled(registry.is_enabled(id));
+        if let Some(binding) = registry.binding(id) {
+            item.shortcut = MenuShortcut::Command(binding);
+        }
+        item
+    }
+
     style_setter!('a, MenuItemTheme, context_menu.item);
 
     /// Attach a keyboard shortcut. Renders the right-aligned hint
@@ -132,12 +162,14 @@ impl<'a> MenuItem<'a> {
             MenuShortcut::None => None,
             MenuShortcut::Hint(shortcut) => {
                 ui.watch_key(shortcut);
-                Some(shortcut)
+                Some(KeyBinding::single(shortcut))
             }
             MenuShortcut::Activate(shortcut) => {
                 shortcut_fired = !disabled && ui.key_pressed(shortcut);
-                Some(shortcut)
+                Some(KeyBinding::single(shortcut))
             }
+            // The registry's `update` already watches every step.
+            MenuShortcut::Command(binding) => Some(binding),
         };
         let shortcut_label = shortcut.map(|s| ui.fmt(format_args!("{s}")));
 
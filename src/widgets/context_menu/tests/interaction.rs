@@ -1,5 +1,7 @@
 //! What opens the menu, what dismisses it, and what an item reports.
 
+use crate::input::command::binding::KeyBinding;
+use crate::input::command::{Command, CommandRegistry};
 use crate::input::keyboard::{Key, Modifiers};
 use crate::input::shortcut::Shortcut;
 use crate::layout::types::sizing::Sizing;
@@ -12,6 +14,7 @@ use crate::widgets::context_menu::ContextMenuState;
 use crate::widgets::context_menu::menu_item::MenuItem;
 use crate::widgets::context_menu::tests::support::{SURFACE, trigger_id};
 use crate::widgets::panel::Panel;
+use crate::widgets::response::ResponseSnapshot;
 use crate::{Sense, Ui};
 use glam::Vec2;
 
@@ -113,6 +116,51 @@ fn shortcut_press_fires_item_and_dismisses() {
     assert!(!menu_open(&h.ui), "shortcut press auto-closes the menu");
 }
 
+/// A command row takes its label, enabled state and current binding from
+/// the registry, and leaves the binding to the registry to dispatch.
+#[test]
+fn command_row_mirrors_registry_and_does_not_intercept() {
+    let mut registry = CommandRegistry::<bool>::new();
+    registry.register(
+        Command::new("file.save", "Save")
+            .binding(KeyBinding::single(Shortcut::ctrl('S')))
+            .enabled(|dirty| *dirty),
+    );
+    let row = WidgetId::from_hash("save-row");
+    let record = |ui: &mut Ui, registry: &mut CommandRegistry<bool>| {
+        registry.update(ui, &false);
+        let mut disabled = false;
+        let mut clicked = false;
+        let trigger = trigger(ui);
+        ContextMenu::attach(ui, &trigger).show(ui, |ui, popup| {
+            let resp = MenuItem::command(registry, "file.save")
+                .id(row)
+                .show(ui, popup);
+            disabled = resp.disabled;
+            clicked = resp.left.clicked();
+        });
+        (disabled, clicked)
+    };
+
+    let mut h = UiHarness::new(SURFACE);
+    h.frame(|ui| {
+        record(ui, &mut registry);
+    });
+    ContextMenu::open(&mut h.ui, trigger_id(), Vec2::new(60.0, 60.0));
+    let (disabled, _) = h.frame_value(|ui| record(ui, &mut registry));
+    assert!(disabled, "predicate was false at the last update");
+    assert!(h.ui.cascade().locate(row.with("shortcut")).is_some());
+
+    h.set_modifiers(Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    });
+    h.key(Key::Char('S'));
+    let (_, clicked) = h.frame_value(|ui| record(ui, &mut registry));
+    assert!(!clicked, "the registry dispatches the binding, not the row");
+    assert!(menu_open(&h.ui));
+}
+
 #[test]
 fn escape_dismisses_menu() {
     let mut h = UiHarness::new(SURFACE);
@@ -193,6 +241,14 @@ fn build(ui: &mut Ui, clicked_copy: &mut bool, _unused: &mut bool) {
         });
 }
 
+fn trigger(ui: &mut Ui) -> ResponseSnapshot {
+    Button::new()
+        .id(trigger_id())
+        .label("right click me")
+        .show(ui)
+        .snapshot()
+}
+
 fn menu_open(ui: &Ui) -> bool {
     ContextMenu::is_open(ui, trigger_id())
 }
@@ -2,7 +2,6 @@ use crate::input::sense::Sense;
 use crate::layout::types::align::Align;
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::rect::Rect;
-use crate::primitives::size::Size;
 use crate::primitives::widget_id::WidgetId;
 use crate::scene::node::{Configure, Node};
 use crate::shape::Shape;
@@ -421,9 +420,13 @@ impl<'a> DragValue<'a> {
         // `min_size.w`) so a long value scrolls inside the chip's box instead
         // of growing a content-hugging row. Before the first chip frame gives
         // us a width to hold, fall back to the field's own width sizing.
-        let min_size = self.node.min_size.unwrap_or(Size::ZERO);
+        let min_size = self.node.min_lengths();
+        let min_w = min_size
+            .w
+            .unit
+            .resolve(min_size.w.value, ui.display().logical_size());
         let sizes = self.node.size.unwrap_or_default();
-        let held_w = prev_rect.map(|r| Sizing::fixed(r.size.w.max(min_size.w)));
+        let held_w = prev_rect.map(|r| Sizing::fixed(r.size.w.max(min_w)));
         let width = held_w.unwrap_or(sizes.w());
         // Entry replaces any scrub state atomically, so its later release
         // cannot overwrite the typed result. Existing edit frames move the
@@ -440,7 +443,7 @@ impl<'a> DragValue<'a> {
                 .style(editor)
                 .size((width, sizes.h()))
                 .min_size(min_size)
-                .max_size(self.node.max_size.unwrap_or(Size::INF));
+                .max_size(self.node.max_lengths());
             // The chip's placement has to survive the swap or the field
             // visibly jumps mid-interaction; which fields that means is
             // `TextEdit`'s call, and documented there.
@@ -1,3 +1,4 @@
+use crate::layout::types::grid_template::{GridFlow, GridTemplate};
 use crate::layout::types::limits::valid_gap;
 use crate::layout::types::track::Track;
 use crate::primitives::background::Background;
@@ -14,19 +15,34 @@ use crate::widgets::response::InnerResponse;
 /// tracks resolve, weighted, with bounded constraint resolution if any
 /// `Track::min` / `Track::max` clamps fire.
 ///
+/// Past explicit cells, three CSS-grid conveniences, all resolved while the
+/// grid records so the layout driver only ever sees cells and tracks:
+/// - [`Self::auto_flow`] places children that name no cell in the next
+///   free one, appending [`Self::auto_track`]s as the declared ones fill.
+/// - [`Self::areas`] names regions of the grid for children to select with
+///   `Configure::grid_area`.
+/// - [`Self::cols_auto_fill`] repeats one column as many times as the
+///   grid's width fits, so a gallery reflows its column count as it
+///   resizes.
+///
 /// Arrays remain inline in the builder and borrowed slices remain borrowed.
 /// On `show`, tracks are copied into the current Tree's capacity-retained
 /// arena, so natural array declarations are allocation-free after warmup.
 ///
 /// The layout driver documents the three-phase solver and its explicit
-/// non-goals: no Auto-vs-Star cycle, `SharedSizeScope`, or auto-flow.
+/// non-goals: no Auto-vs-Star cycle or `SharedSizeScope`.
 #[derive(Debug)]
-pub struct Grid<Rows = [Track; 0], Cols = [Track; 0]> {
+pub struct Grid<Rows = [Track; 0], Cols = [Track; 0], Areas = [&'static str; 0]> {
     node: Node,
     rows: Rows,
     cols: Cols,
+    areas: Areas,
     row_gap: f32,
     col_gap: f32,
+    flow: Option<GridFlow>,
+    auto_track: Track,
+    /// `cols` is one track to repeat across the width.
+    auto_fill: bool,
     chrome: Option<Background>,
 }
 
@@ -37,36 +53,110 @@ impl Grid {
             node: Node::grid(),
             rows: [],
             cols: [],
+            areas: [],
             row_gap: 0.0,
             col_gap: 0.0,
+            flow: None,
+            auto_track: Track::hug(),
+            auto_fill: false,
             chrome: None,
         }
     }
 }
 
-impl<Rows, Cols> Grid<Rows, Cols> {
-    pub fn rows<NewRows: AsRef<[Track]>>(self, rows: NewRows) -> Grid<NewRows, Cols> {
+impl<Rows, Cols, Areas> Grid<Rows, Cols, Areas> {
+    pub fn rows<NewRows: AsRef<[Track]>>(self, rows: NewRows) -> Grid<NewRows, Cols, Areas> {
         Grid {
             node: self.node,
             rows,
             cols: self.cols,
+            areas: self.areas,
             row_gap: self.row_gap,
             col_gap: self.col_gap,
+            flow: self.flow,
+            auto_track: self.auto_track,
+            auto_fill: self.auto_fill,
             chrome: self.chrome,
         }
     }
 
-    pub fn cols<NewCols: AsRef<[Track]>>(self, cols: NewCols) -> Grid<Rows, NewCols> {
+    pub fn cols<NewCols: AsRef<[Track]>>(self, cols: NewCols) -> Grid<Rows, NewCols, Areas> {
         Grid {
             node: self.node,
             rows: self.rows,
             cols,
+            areas: self.areas,
+            row_gap: self.row_gap,
+            col_gap: self.col_gap,
+            flow: self.flow,
+            auto_track: self.auto_track,
+            auto_fill: false,
+            chrome: self.chrome,
+        }
+    }
+
+    /// As many copies of `track` as fit across the grid — CSS's
+    /// `repeat(auto-fill, minmax(200px, 1fr))` is
+    /// `cols_auto_fill(Track::fill().min(200.0))`. A copy is as wide as
+    /// the track's fixed size, else its `min`, which must then be
+    /// positive; the count is at least one. Unlike CSS, a grid too narrow
+    /// for even one copy shrinks that one rather than overflowing.
+    ///
+    /// The count is taken from the width the grid last arranged to, and
+    /// a resize that changes it re-records the same frame, as
+    /// [`Panel::show_responsive`](crate::Panel::show_responsive) does
+    /// for its tiers. A Hug-width grid settles at the count its own
+    /// content width gives back, so give the grid a width to fill for
+    /// the count to follow its container.
+    pub fn cols_auto_fill(self, track: Track) -> Grid<Rows, [Track; 1], Areas> {
+        Grid {
+            auto_fill: true,
+            ..self.cols([track])
+        }
+    }
+
+    /// Named regions, one string per row with whitespace-separated names
+    /// and `.` for an unnamed cell — CSS's `grid-template-areas`:
+    /// `.areas(["header header", "nav main"])`. A child joins one with
+    /// `Configure::grid_area("nav")`, taking its cell and span. Each name
+    /// must cover a rectangle of cells that lies within the grid's
+    /// tracks.
+    pub fn areas<'a, NewAreas: AsRef<[&'a str]>>(
+        self,
+        areas: NewAreas,
+    ) -> Grid<Rows, Cols, NewAreas> {
+        Grid {
+            node: self.node,
+            rows: self.rows,
+            cols: self.cols,
+            areas,
             row_gap: self.row_gap,
             col_gap: self.col_gap,
+            flow: self.flow,
+            auto_track: self.auto_track,
+            auto_fill: self.auto_fill,
             chrome: self.chrome,
         }
     }
 
+    /// Place children that name neither a cell nor an area in the next
+    /// free cell, in `flow` order. Children with a cell or area claim
+    /// theirs first, wherever they sit in record order. Once the declared
+    /// rows (columns, for a column flow) are full, more are appended,
+    /// each an [`Self::auto_track`]; the other axis needs at least one
+    /// declared track.
+    pub fn auto_flow(mut self, flow: GridFlow) -> Self {
+        self.flow = Some(flow);
+        self
+    }
+
+    /// Track appended by [`Self::auto_flow`] when the declared ones run
+    /// out. Default `Track::hug()`, as CSS's `grid-auto-rows: auto`.
+    pub fn auto_track(mut self, track: Track) -> Self {
+        self.auto_track = track;
+        self
+    }
+
     /// Uniform gap on both axes. See `gap_xy` for asymmetric gaps.
     pub fn gap(mut self, g: f32) -> Self {
         debug_assert!(
@@ -98,31 +188,65 @@ impl<Rows, Cols> Grid<Rows, Cols> {
         self
     }
 
-    pub fn show<R>(self, ui: &mut Ui, body: impl FnOnce(&mut Ui) -> R) -> InnerResponse<'_, R>
+    pub fn show<'a, R>(self, ui: &mut Ui, body: impl FnOnce(&mut Ui) -> R) -> InnerResponse<'_, R>
     where
         Rows: AsRef<[Track]>,
         Cols: AsRef<[Track]>,
+        Areas: AsRef<[&'a str]>,
     {
-        let id = ui.push_grid_def(
-            self.rows.as_ref(),
-            self.cols.as_ref(),
-            self.row_gap,
-            self.col_gap,
-        );
         let mut node = self.node;
-        node.set_grid_def(id);
-
         // Theme fallback for chrome / clip — see `Panel::show`.
         let chrome = node.resolve_container_chrome(
             self.chrome,
             ui.theme().panel_background.as_ref(),
             ui.theme().panel_clip,
         );
-        ui.widget(node).show(ui, chrome.as_ref(), body)
+        let mut widget = ui.widget(node);
+
+        let mut cols = self.cols.as_ref();
+        let mut col_repeat = 1;
+        let repeated;
+        if let [track] = cols
+            && self.auto_fill
+        {
+            // The arranged rect is the border box; tracks share what
+            // padding and the stroke inflation `open_node` adds leave.
+            let stroke = chrome.as_ref().map_or(0.0, |bg| bg.stroke.width);
+            let inset = node.padding.map_or(0.0, |p| p.horiz()) + 2.0 * stroke;
+            let extent = track.size.fixed_value().unwrap_or(track.min);
+            col_repeat = ui.auto_fill_count(widget.id(), extent, self.col_gap, inset);
+            repeated = auto_fill_copy(*track, extent);
+            cols = std::slice::from_ref(&repeated);
+        }
+        let id = ui.push_grid_def(GridTemplate {
+            rows: self.rows.as_ref(),
+            cols,
+            col_repeat,
+            row_gap: self.row_gap,
+            col_gap: self.col_gap,
+            flow: self.flow,
+            auto_track: self.auto_track,
+            areas: self.areas.as_ref(),
+        });
+        widget.node.set_grid_def(id);
+        widget.show(ui, chrome.as_ref(), body)
+    }
+}
+
+/// The track an auto-fill grid lays out `extent`-wide copies of. The
+/// count already keeps every copy at least `extent` wide whenever one
+/// fits, so the copy drops the floor — `min`, or a Fixed size, which
+/// becomes a Fill capped there. Kept, the floor would hold the grid at
+/// its current count's width however narrow its container got, and the
+/// count, read back from that width, could never fall.
+fn auto_fill_copy(track: Track, extent: f32) -> Track {
+    match track.size.fixed_value() {
+        Some(_) => Track::fill().max(extent),
+        None => Track::new(track.size).max(track.max),
     }
 }
 
-impl_configure!(<Rows, Cols> Grid<Rows, Cols>);
+impl_configure!(<Rows, Cols, Areas> Grid<Rows, Cols, Areas>);
 
 #[cfg(all(test, debug_assertions))]
 mod tests {
@@ -156,6 +156,7 @@ macro_rules! impl_configure {
 pub(crate) mod button;
 pub(crate) mod checkbox;
 pub(crate) mod combo_box;
+pub(crate) mod command_palette;
 pub(crate) mod context_menu;
 pub(crate) mod drag_value;
 pub(crate) mod frame;
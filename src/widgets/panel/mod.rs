@@ -2,6 +2,7 @@ use crate::primitives::background::Background;
 use crate::scene::node::Node;
 use crate::ui::Ui;
 use crate::widgets::response::InnerResponse;
+use crate::widgets::widget::Widget;
 
 /// The container widget. Lays children out as `HStack` / `VStack` / `ZStack`
 /// (selected via constructor) and optionally paints chrome (via
@@ -28,16 +29,50 @@ impl Panel {
     }
 
     pub fn show<R>(self, ui: &mut Ui, body: impl FnOnce(&mut Ui) -> R) -> InnerResponse<'_, R> {
-        // Theme fallback: if the caller left chrome / clip unset,
-        // inherit from `theme.panel_*`. Caller intent (any non-None
-        // value) wins.
+        let (widget, chrome) = self.resolve(ui);
+        widget.show(ui, chrome.as_ref(), body)
+    }
+
+    /// [`Self::show`] for a body that lays itself out by the panel's
+    /// width. `body` receives the tier `breakpoints` put that width in —
+    /// `0` below the first, `breakpoints.len()` at or past the last — and
+    /// records the layout the tier calls for: a sidebar beside the
+    /// content from `1` up, say, and folded into a drawer at `0`.
+    ///
+    /// The width is the panel's arranged width from the last layout
+    /// pass. When this pass's layout moves it into another tier — a
+    /// resize, a sibling appearing — the frame records once more before
+    /// it paints, so the switch lands on the frame that caused it.
+    /// Between crossings the body records the same tree, so the measure
+    /// cache keeps hitting and no frame records twice.
+    ///
+    /// Size the panel from outside (`Fill`, `Fixed`, a percent): a Hug
+    /// panel whose tiers change its own width can settle in a different
+    /// tier each frame.
+    ///
+    /// `breakpoints` must be finite and ascending; debug builds assert it.
+    pub fn show_responsive<'a, R>(
+        self,
+        ui: &'a mut Ui,
+        breakpoints: &[f32],
+        body: impl FnOnce(&mut Ui, usize) -> R,
+    ) -> InnerResponse<'a, R> {
+        let (widget, chrome) = self.resolve(ui);
+        let tier = ui.breakpoint_tier(widget.id(), breakpoints);
+        widget.show(ui, chrome.as_ref(), |ui| body(ui, tier))
+    }
+
+    /// Resolve the panel's id and its chrome. Theme fallback: if the
+    /// caller left chrome / clip unset, inherit from `theme.panel_*`.
+    /// Caller intent (any non-None value) wins.
+    fn resolve(self, ui: &mut Ui) -> (Widget, Option<Background>) {
         let mut node = self.node;
         let chrome = node.resolve_container_chrome(
             self.chrome,
             ui.theme().panel_background.as_ref(),
             ui.theme().panel_clip,
         );
-        ui.widget(node).show(ui, chrome.as_ref(), body)
+        (ui.widget(node), chrome)
     }
 
     #[track_caller]
@@ -53,10 +88,11 @@ impl Panel {
     /// HStack with overflow wrap: children flow left-to-right; when the
     /// next child wouldn't fit on the current row, wrap to a new row
     /// below. `.gap(g)` spaces siblings within a row; `.line_gap(g)`
-    /// spaces rows. `.justify(...)` applies per row.
-    /// `Sizing::fill` on a child's main axis is treated as `Hug` for
-    /// now (no per-row leftover distribution); cross-axis Fill stretches
-    /// to row height.
+    /// spaces rows. A child's main-axis `Sizing::fill` takes a share of
+    /// its own row's leftover width; `.justify(...)` spreads whatever
+    /// the row's Fill children leave, and `.align_content(...)` spreads
+    /// the rows over any height they don't use. Cross-axis Fill
+    /// stretches to row height.
     #[track_caller]
     pub fn wrap_hstack() -> Self {
         Self::auto(Node::wrap_hstack())
@@ -81,6 +117,12 @@ impl Panel {
     /// Children placed at their declared `Layout.position` (parent-inner
     /// coords). Use per-child `.position(Vec2)`. Canvas hugs to the bounding
     /// box of placed children.
+    ///
+    /// A child can instead pin itself to the canvas's edges with
+    /// `.anchor(...)` — `.anchor(Align::BOTTOM_RIGHT).margin(16.0)` keeps a
+    /// minimap 16 px inside the bottom-right corner through every resize,
+    /// `.anchor(Align::TOP)` centres a toolbar along the top edge.
+    /// `position` still applies on top, as a nudge from the anchored spot.
     #[track_caller]
     pub fn canvas() -> Self {
         Self::auto(Node::canvas())
@@ -9,6 +9,7 @@ use crate::primitives::widget_id::WidgetId;
 use crate::scene::layer::Layer;
 use crate::scene::node::Configure;
 use crate::scene::tree::node_id::NodeId;
+use crate::ui::frame_report::FrameProcessing;
 use crate::ui::harness::UiHarness;
 use crate::widgets::{button::Button, frame::Frame, panel::Panel};
 use glam::{UVec2, Vec2};
@@ -435,3 +436,76 @@ fn zstack_aligns_child_per_axis() {
         );
     }
 }
+
+const BREAKPOINTS: [f32; 2] = [640.0, 1024.0];
+
+/// A `Fill` breakpoint panel naming its body's root after the tier it
+/// recorded under, plus the tiers every pass saw.
+fn responsive(ui: &mut Ui, tiers: &mut Vec<usize>) {
+    Panel::vstack()
+        .id(WidgetId::from_hash("responsive"))
+        .size((Sizing::FILL, Sizing::FILL))
+        .show_responsive(ui, &BREAKPOINTS, |ui, tier| {
+            tiers.push(tier);
+            let id = ["narrow", "medium", "wide"][tier];
+            Frame::new()
+                .id(WidgetId::from_hash(id))
+                .size((Sizing::FILL, Sizing::fixed(10.0)))
+                .show(ui);
+        });
+}
+
+/// A resize across a breakpoint re-records inside the frame that caused
+/// it, so the painted tree already matches the new width; a resize that
+/// stays within a tier, or no resize at all, records once.
+#[test]
+fn show_responsive_switches_tier_on_the_crossing_frame() {
+    let mut h = UiHarness::new(UVec2::new(1200, 400));
+    let mut tiers = Vec::new();
+    h.frame(|ui| responsive(ui, &mut tiers));
+    assert!(h.layout_rect(WidgetId::from_hash("wide")).is_some());
+
+    tiers.clear();
+    let report = h.frame(|ui| responsive(ui, &mut tiers));
+    assert_eq!(report.processing, FrameProcessing::SingleLayout);
+    assert_eq!(tiers, [2]);
+
+    tiers.clear();
+    h.resize(UVec2::new(1100, 400));
+    let report = h.frame(|ui| responsive(ui, &mut tiers));
+    assert_eq!(report.processing, FrameProcessing::SingleLayout);
+    assert_eq!(tiers, [2]);
+
+    tiers.clear();
+    h.resize(UVec2::new(500, 400));
+    let report = h.frame(|ui| responsive(ui, &mut tiers));
+    assert_eq!(report.processing, FrameProcessing::DoubleLayout);
+    assert_eq!(
+        tiers,
+        [2, 0],
+        "pass A records the stale tier, pass B the new one"
+    );
+    assert!(h.layout_rect(WidgetId::from_hash("narrow")).is_some());
+    assert!(h.layout_rect(WidgetId::from_hash("wide")).is_none());
+
+    tiers.clear();
+    h.resize(UVec2::new(800, 400));
+    h.frame(|ui| responsive(ui, &mut tiers));
+    assert_eq!(tiers, [0, 1]);
+}
+
+/// Between crossings the body records an unchanged subtree, so the
+/// panel's measure is a cache hit like any other container's.
+#[test]
+fn show_responsive_keeps_the_measure_cache_between_crossings() {
+    let mut h = UiHarness::new(UVec2::new(900, 400));
+    let mut tiers = Vec::new();
+    h.frame(|ui| responsive(ui, &mut tiers));
+    h.frame(|ui| responsive(ui, &mut tiers));
+    let panel = WidgetId::from_hash("responsive");
+    let hits = h.engines.layout.scratch.counters.cache_hits();
+    assert!(
+        hits.iter().any(|&w| w == WidgetId::VIEWPORT || w == panel),
+        "steady frame should hit the measure cache at or above the panel: {hits:?}",
+    );
+}
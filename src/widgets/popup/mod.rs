@@ -1,6 +1,7 @@
 use crate::input::pointer::PointerButton;
 use crate::input::sense::Sense;
-use crate::layout::types::overlay::OverlayPosition;
+use crate::layout::types::align::AxisAlign;
+use crate::layout::types::overlay::{OverlayPosition, OverlaySide};
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::background::Background;
 use crate::primitives::rect::Rect;
@@ -154,6 +155,20 @@ impl Popup {
         Self::positioned(OverlayPosition::right_of(anchor, 0.0))
     }
 
+    /// Hung inside `anchor` from its top-right corner — over the widget
+    /// the popup belongs to rather than beside it, as a field's find bar
+    /// sits.
+    #[track_caller]
+    pub(crate) fn inside_top_end(anchor: Rect) -> Self {
+        let edge = Rect::new(anchor.min.x, anchor.min.y, anchor.size.w, 0.0);
+        Self::positioned(OverlayPosition::new(
+            edge,
+            OverlaySide::Below,
+            AxisAlign::End,
+            0.0,
+        ))
+    }
+
     #[track_caller]
     fn positioned(position: OverlayPosition) -> Self {
         let mut node = Node::vstack();
@@ -17,7 +17,7 @@ use crate::ui::Ui;
 use crate::widgets::scroll::ScrollGeometry;
 use crate::widgets::scroll::state::{ScrollState, ThumbTravel, TrackPage};
 use crate::widgets::theme::scrollbar::ScrollbarTheme;
-use glam::BVec2;
+use glam::{BVec2, Vec2};
 
 /// the bar's `width` plus a `gap` strip so the bar doesn't touch the
 /// visible content. Returns 0 when the axis isn't panned.
@@ -212,7 +212,12 @@ impl Bars {
     /// anchor is a single slot shared by both axes. Resolving each bar
     /// immediately before it is applied is what keeps the thumb tracking
     /// the cursor within the frame.
+    ///
+    /// Right to left the horizontal bar lays out mirrored, so its drag
+    /// and click positions are reflected back before they reach the
+    /// offset.
     pub(super) fn drive(&self, state: &mut ScrollState, geom: ScrollGeometry, pan: BVec2) {
+        let rtl = geom.mirror_w.is_some();
         let scaled = geom.scaled_content(state.zoom);
         for (axis, bar) in self.axes() {
             if !axis.main_b(pan) {
@@ -224,7 +229,11 @@ impl Bars {
             state.apply_thumb_drag(
                 axis,
                 bar.thumb.left.drag.started(),
-                bar.thumb.left.drag.delta(),
+                bar.thumb
+                    .left
+                    .drag
+                    .delta()
+                    .map(|d| if rtl { Vec2::new(-d.x, d.y) } else { d }),
                 travel,
             );
         }
@@ -237,7 +246,13 @@ impl Bars {
             };
             let page = self
                 .resolve(axis, geom, scaled, axis.main_v(state.offset))
-                .map(|resolved| resolved.page_at(axis.main_v(pointer_local)));
+                .map(|resolved| {
+                    let click = axis.main_v(pointer_local);
+                    resolved.page_at(match axis {
+                        Axis::X if rtl => resolved.track_main - click,
+                        _ => click,
+                    })
+                });
             state.apply_track_page(axis, page);
         }
     }
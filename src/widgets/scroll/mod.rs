@@ -2,6 +2,7 @@ pub(crate) mod bars;
 pub(crate) mod state;
 pub(crate) mod zoom_config;
 
+use crate::common::platform::{PLATFORM, Platform};
 use crate::input::response::ResponseState;
 use crate::input::sense::Sense;
 use crate::input::zoom;
@@ -35,6 +36,12 @@ struct ScrollGeometry {
     /// The builder's, carried so [`Self::bounds`] can hand the offset
     /// solver a whole [`ScrollBounds`].
     content_margin: Spacing,
+    /// Width of the viewport when the scroll lays out right to left —
+    /// the span its content is mirrored across — `None` left to right.
+    /// The offset itself stays measured from the leading edge either
+    /// way: physical input is reflected on the way into it, and the
+    /// transform on the way out.
+    mirror_w: Option<f32>,
 }
 
 impl ScrollGeometry {
@@ -102,13 +109,18 @@ impl ScrollWrappers {
             size,
             min_size,
             max_size,
+            min_units,
+            max_units,
+            aspect_ratio,
             padding,
             margin,
             gaps,
             justify,
+            align_content,
             align,
             child_align,
             position,
+            anchor,
             grid,
             flags,
             visibility,
@@ -125,13 +137,20 @@ impl ScrollWrappers {
         outer.size = size;
         outer.min_size = min_size;
         outer.max_size = max_size;
+        outer.min_units = min_units;
+        outer.max_units = max_units;
+        outer.aspect_ratio = aspect_ratio;
         outer.margin = margin;
         outer.align = align;
         outer.position = position;
+        outer.anchor = anchor;
         outer.grid = grid;
         outer.flags.set_sense(flags.sense());
         outer.flags.set_disabled(flags.is_disabled());
         outer.flags.set_focusable(flags.is_focusable());
+        if let Some(direction) = flags.flow_direction() {
+            outer.flags.set_flow_direction(direction);
+        }
         outer.visibility = visibility;
 
         let mut inner = Node::scroll(scroll_spec);
@@ -141,6 +160,7 @@ impl ScrollWrappers {
         inner.padding = padding;
         inner.gaps = gaps;
         inner.justify = justify;
+        inner.align_content = align_content;
         inner.child_align = child_align;
         Self { outer, inner }
     }
@@ -168,6 +188,7 @@ pub struct Scroll<'a> {
     chrome: Option<Background>,
     bar_mode: BarMode,
     content_margin: Spacing,
+    shift_wheel_horizontal: bool,
 }
 
 impl<'a> Scroll<'a> {
@@ -198,6 +219,9 @@ impl<'a> Scroll<'a> {
             chrome: None,
             bar_mode: BarMode::Reserved,
             content_margin: Spacing::default(),
+            // macOS turns Shift+wheel into a horizontal delta before it
+            // reaches us; everywhere else the wheel still says `y`.
+            shift_wheel_horizontal: !matches!(PLATFORM, Platform::Mac),
         }
     }
 
@@ -241,6 +265,17 @@ impl<'a> Scroll<'a> {
         self
     }
 
+    /// Whether Shift held over a vertical-only wheel delta pans
+    /// horizontally instead. On by default everywhere but macOS, which
+    /// already converts the gesture before it reaches the app — doing
+    /// it twice would swap the axes back. Applies only to a scroll that
+    /// pans on X; a delta that already carries an `x` component (a
+    /// touchpad, a tilt wheel) is left alone.
+    pub fn shift_wheel_horizontal(mut self, on: bool) -> Self {
+        self.shift_wheel_horizontal = on;
+        self
+    }
+
     /// Enable pivot-anchored zoom with a default [`ZoomConfig`]. Asserts
     /// at record time that the scroll pans on both axes (built via
     /// [`Scroll::both`]) — uniform scale on a single-axis scroll has no
@@ -274,7 +309,11 @@ impl<'a> Scroll<'a> {
     /// It falls back to the viewport centre when the pointer is off the
     /// widget, and on the first frame where there is no rect yet, so the
     /// zoom still *feels* anchored before pointer tracking kicks in.
-    fn read_input(&self, ui: &Ui, response: &ResponseState) -> ScrollInput {
+    ///
+    /// Right to left, the horizontal delta and the pivot are reflected
+    /// into the leading-edge frame the offset is kept in, so content still
+    /// follows the fingers.
+    fn read_input(&self, ui: &Ui, response: &ResponseState, rtl: bool) -> ScrollInput {
         // Font-derived line step for wheel→pixel conversion. Pulls
         // `theme.text` (the default font config) rather than scanning
         // children for a dominant font — that's a future polish; for
@@ -283,12 +322,22 @@ impl<'a> Scroll<'a> {
         let text = &ui.theme().text;
         let line_px = text.line_height_for(text.font_size_px);
         let scroll = response.scroll;
-        let pan_raw = scroll.pixels + scroll.lines * line_px;
+        let mods = ui.peek_modifiers();
+        let mut pan_raw = scroll.pixels + scroll.lines * line_px;
+        if rtl {
+            pan_raw.x = -pan_raw.x;
+        }
+        if self.shift_wheel_horizontal
+            && mods.shift
+            && self.node.scroll_spec().pan_mask().x
+            && pan_raw.x == 0.0
+        {
+            pan_raw = Vec2::new(pan_raw.y, 0.0);
+        }
         let notches = scroll.lines + scroll.pixels / line_px.max(f32::EPSILON);
         // Gate on `mods.ctrl` only — Ctrl is the zoom modifier on every
         // platform (macOS Cmd not honored), and `alt`-wheel shouldn't
         // zoom.
-        let mods = ui.peek_modifiers();
         let wheel_zooms = self.zoom.as_ref().is_some_and(|cfg| match cfg.modifier {
             ZoomModifier::Ctrl => mods.ctrl,
             ZoomModifier::Always => true,
@@ -310,7 +359,11 @@ impl<'a> Scroll<'a> {
                     ZoomPivot::Center => centre,
                 },
             )
-            .flatten();
+            .flatten()
+            .map(|p| match (rtl, response.layout_rect) {
+                (true, Some(r)) => Vec2::new(r.size.w - p.x, p.y),
+                _ => p,
+            });
 
         ScrollInput {
             pan_delta,
@@ -334,6 +387,7 @@ impl<'a> Scroll<'a> {
         scroll_id: WidgetId,
         pan: BVec2,
         response: &ResponseState,
+        rtl: bool,
     ) -> ScrollGeometry {
         let outer = response.layout_rect.map_or(Size::ZERO, |r| r.size);
         let content = ui.scroll_content(scroll_id);
@@ -344,6 +398,7 @@ impl<'a> Scroll<'a> {
             padding,
             space,
             content_margin: self.content_margin,
+            mirror_w: rtl.then_some(outer.w - space.reserve_y),
         }
     }
 
@@ -398,9 +453,10 @@ impl<'a> Scroll<'a> {
         &self,
         scroll_id: WidgetId,
         pan: BVec2,
-        space: BarSpace,
+        geom: ScrollGeometry,
         state: ScrollState,
     ) -> ScrollWrappers {
+        let space = geom.space;
         let ScrollWrappers { outer, inner } = ScrollWrappers::split(self.node);
 
         // Inner viewport owns the clip, the pan transform, the user-set
@@ -425,7 +481,10 @@ impl<'a> Scroll<'a> {
         inner.clip = self.node.clip;
         // Raw pan/zoom, from the one place a viewport's transform is
         // derived — `TextEdit`'s text block reads the same method.
-        inner.transform = state.transform();
+        inner.transform = match geom.mirror_w {
+            Some(width) => state.mirrored_transform(width),
+            None => state.transform(),
+        };
         ScrollWrappers { outer, inner }
     }
 
@@ -452,8 +511,9 @@ impl<'a> Scroll<'a> {
 
         // Everything read off `ui` immutably, before the state borrow.
         let response = ui.response_for(id);
-        let geom = self.measure(ui, scroll_id, pan, &response);
-        let input = self.read_input(ui, &response);
+        let rtl = ui.resolves_rtl(&self.node);
+        let geom = self.measure(ui, scroll_id, pan, &response, rtl);
+        let input = self.read_input(ui, &response, rtl);
         let bars = (self.bar_mode != BarMode::Hidden)
             .then(|| Bars::read(ui, scroll_id, self.bars_theme(ui)));
 
@@ -466,7 +526,7 @@ impl<'a> Scroll<'a> {
             *state
         };
 
-        let ScrollWrappers { outer, inner } = self.wrappers(scroll_id, pan, geom.space, state);
+        let ScrollWrappers { outer, inner } = self.wrappers(scroll_id, pan, geom, state);
         let inner_chrome = self.chrome;
         widget.node = outer;
         let inner_value = widget.record(ui, None, |ui| {
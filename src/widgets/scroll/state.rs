@@ -188,6 +188,18 @@ impl ScrollState {
         TranslateScale::new(-self.offset, self.zoom)
     }
 
+    /// [`Self::transform`] for a right-to-left viewport `width` wide,
+    /// whose content lays out mirrored: the same transform seen in a
+    /// mirror, so the offset keeps counting from the leading edge. Content
+    /// moves right as the offset grows, and the zoom's top-left anchor
+    /// folds in as the `width * (1 - zoom)` that keeps it at top-right.
+    pub(crate) fn mirrored_transform(&self, width: f32) -> TranslateScale {
+        TranslateScale::new(
+            Vec2::new(self.offset.x + width * (1.0 - self.zoom), -self.offset.y),
+            self.zoom,
+        )
+    }
+
     pub(super) fn apply_thumb_drag(
         &mut self,
         axis: Axis,
@@ -125,6 +125,59 @@ fn horizontal_scroll_pans_only_x() {
     assert_eq!(row.offset, Vec2::new(75.0, 0.0));
 }
 
+/// Shift over a vertical-only wheel delta pans X when the scroll pans
+/// X and the conversion is on; with it off — the macOS default, where
+/// the OS already converted — the same delta pans Y. A delta that
+/// already carries `x` is never rewritten.
+#[test]
+fn shift_wheel_converts_to_horizontal_when_enabled() {
+    use crate::input::keyboard::Modifiers;
+    let cases: &[(&str, bool, Vec2, Vec2)] = &[
+        (
+            "converted",
+            true,
+            Vec2::new(0.0, 60.0),
+            Vec2::new(60.0, 0.0),
+        ),
+        (
+            "disabled",
+            false,
+            Vec2::new(0.0, 60.0),
+            Vec2::new(0.0, 60.0),
+        ),
+        (
+            "already_horizontal",
+            true,
+            Vec2::new(30.0, 60.0),
+            Vec2::new(30.0, 60.0),
+        ),
+    ];
+    for (label, convert, wheel, expected) in cases {
+        let mut h = UiHarness::new(SURFACE);
+        let build_xy = |ui: &mut Ui| {
+            Scroll::both()
+                .id(WidgetId::from_hash("xy"))
+                .size((Sizing::fixed(200.0), Sizing::fixed(200.0)))
+                .shift_wheel_horizontal(*convert)
+                .show(ui, |ui| {
+                    Frame::new()
+                        .id(WidgetId::from_hash("xy-content"))
+                        .size((Sizing::fixed(800.0), Sizing::fixed(800.0)))
+                        .show(ui);
+                });
+        };
+        h.frame(build_xy);
+        h.set_modifiers(Modifiers {
+            shift: true,
+            ..Modifiers::NONE
+        });
+        h.scroll_pixels_at(Vec2::new(50.0, 50.0), *wheel);
+        h.frame(build_xy);
+        let row = *h.ui.state_mut::<ScrollState>(WidgetId::from_hash("xy"));
+        assert_eq!(row.offset, *expected, "case: {label}");
+    }
+}
+
 #[test]
 fn both_axis_scroll_pans_both_axes() {
     let mut h = UiHarness::new(SURFACE);
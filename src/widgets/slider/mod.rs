@@ -107,7 +107,14 @@ impl<'a> Slider<'a> {
             && (response.pressed() || response.left.drag.dragging() || stopped)
             && let (Some(local), Some(rect)) = (response.pointer_local, response.layout_rect)
         {
-            let f = pointer_to_fraction(local.x, rect.size.w, knob);
+            // Right to left the rails lay out mirrored, `min` at the
+            // right edge, so the cursor is read from there.
+            let x = if ui.resolves_rtl(&widget.node) {
+                rect.size.w - local.x
+            } else {
+                local.x
+            };
+            let f = pointer_to_fraction(x, rect.size.w, knob);
             let v = snap_to_step(
                 fraction_to_value(f, self.min, self.max),
                 self.min,
@@ -1,5 +1,7 @@
 use crate::input::sense::Sense;
 use crate::layout::axis::Axis;
+use crate::layout::types::grid_cell::GridCell;
+use crate::layout::types::grid_template::GridTemplate;
 use crate::layout::types::sizing::Sizing;
 use crate::layout::types::track::Track;
 use crate::primitives::background::Background;
@@ -128,12 +130,14 @@ impl<'a> Splitter<'a> {
             if divider.left.drag.dragging()
                 && let (Some(local), Some(rect)) = (response.pointer_local, response.layout_rect)
             {
-                layout_ratio = pointer_to_ratio(
-                    axis.main_v(local),
-                    axis.main(rect.size),
-                    rule_thickness,
-                    self.min_pane,
-                );
+                // A mirrored horizontal split puts the first pane on the
+                // right, so its share is measured from the right edge.
+                let main = match axis {
+                    Axis::X if ui.resolves_rtl(&widget.node) => rect.size.w - local.x,
+                    _ => axis.main_v(local),
+                };
+                layout_ratio =
+                    pointer_to_ratio(main, axis.main(rect.size), rule_thickness, self.min_pane);
                 resizing = true;
             }
             if divider.left.double_clicked() {
@@ -173,7 +177,7 @@ impl<'a> Splitter<'a> {
         ];
         let cross_tracks = [Track::fill()];
         let [rows, cols] = axis.rows_cols(&main_tracks[..], &cross_tracks[..]);
-        let grid_def_id = ui.push_grid_def(rows, cols, 0.0, 0.0);
+        let grid_def_id = ui.push_grid_def(GridTemplate::new(rows, cols));
         widget.node.set_grid_def(grid_def_id);
         widget.record(ui, None, |ui| {
             pane(ui, first_id, axis, 0, |ui| body(ui, SplitHalf::First));
@@ -181,7 +185,7 @@ impl<'a> Splitter<'a> {
             let mut rule = Node::leaf()
                 .id(id.with("rule"))
                 .size((Sizing::FILL, Sizing::FILL));
-            rule.grid.set_main(axis, 1);
+            rule.grid = GridCell::on_axis(axis, 1);
             ui.widget(rule).record(ui, Some(&rule_bg), |_| {});
 
             pane(ui, second_id, axis, 2, |ui| body(ui, SplitHalf::Second));
@@ -196,7 +200,7 @@ impl<'a> Splitter<'a> {
                 .margin(axis.compose_spacing(inset, 0.0));
             // No `Configure` twin: cell placement there is `(row, col)`, and
             // which of the two this axis means is the whole point here.
-            bar.grid.set_main(axis, 1);
+            bar.grid = GridCell::on_axis(axis, 1);
             ui.widget(bar).record(ui, Some(&bar_bg), |_| {});
         });
 
@@ -212,7 +216,7 @@ fn pane(ui: &mut Ui, id: WidgetId, axis: Axis, main_cell: u16, body: impl FnOnce
         .id(id)
         .size((Sizing::FILL, Sizing::FILL))
         .clip_rect();
-    el.grid.set_main(axis, main_cell);
+    el.grid = GridCell::on_axis(axis, main_cell);
     ui.widget(el).record(ui, None, body)
 }
 
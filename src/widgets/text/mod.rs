@@ -0,0 +1,400 @@
+mod selection;
+#[cfg(test)]
+mod tests;
+
+use crate::input::key_class::KeyFilter;
+use crate::input::keyboard::KeyboardEvent;
+use crate::input::sense::Sense;
+use crate::input::shortcut::Shortcut;
+use crate::layout::types::align::Align;
+use crate::primitives::interned_str::InternedStr;
+use crate::primitives::rect::Rect;
+use crate::primitives::spacing::Spacing;
+use crate::primitives::text_input::TextInput;
+use crate::primitives::text_source::TextSource;
+use crate::scene::node::Node;
+use crate::shape::Shape;
+use crate::text::FontWeight;
+use crate::text::glyph_font::GlyphFont;
+use crate::text::run::TextRun;
+use crate::text::wrap::TextWrap;
+use crate::ui::Ui;
+use crate::widgets::context_menu::ContextMenu;
+use crate::widgets::context_menu::menu_item::MenuItem;
+use crate::widgets::response::{Response, ResponseSnapshot};
+use crate::widgets::text::selection::{Mark, TextSelection};
+use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::widget::Widget;
+use glam::Vec2;
+
+/// Copy, in the keys a focused selectable [`Text`] takes and in its menu.
+const COPY: Shortcut = Shortcut::ctrl('C');
+
+/// Standalone shaped-text leaf. Use for labels, paragraphs, headings —
+/// anything that's just a string. Hugs its measured size when it has room;
+/// **by default a single-line label keeps its full natural width**
+/// ([`TextWrap::SingleLine`]) — its min-content equals its full line, so a
+/// Hug parent / grid track sizes to it and never shrinks it below its text
+/// (the label "stays natural"); if a parent commits a width narrower than
+/// the line, the line runs past the slot rather than being silently cut.
+/// Use [`Self::text_wrap`] to opt into clipping or wrapping instead:
+/// `Truncate` hard-cuts to the committed width (no marker), `Ellipsis`
+/// marks the cut with `…`, `Wrap` / `WrapWithOverflow` reflow onto multiple
+/// lines. Widgets that should clip a too-long label (e.g. `Button`,
+/// `DragValue`) set `SingleLine` explicitly.
+///
+/// Style is all-or-nothing: the optional `style` field replaces every
+/// text axis (font size, color, leading) at once. Defaults to the
+/// global [`crate::TextStyle`] from [`crate::Theme::text`] when not set.
+/// To tweak one axis, build a `TextStyle` from the theme and override
+/// the field you want:
+///
+/// ```
+/// # use palantir::{Color, Text, TextStyle, Ui};
+/// # fn demo(ui: &mut Ui) {
+/// let style = TextStyle {
+///     color: Color::hex(0xd94f4f),
+///     ..ui.theme().text.clone()
+/// };
+/// Text::new("hi").style(&style).show(ui);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Text<'a> {
+    node: Node,
+    text: TextInput<'a>,
+    style: Option<&'a TextStyle>,
+    /// Single-axis weight override applied over the resolved `style` in
+    /// `show`. Lets `Text::new("x").bold()` request bold without cloning
+    /// the whole ambient `TextStyle` at the call site.
+    weight: Option<FontWeight>,
+    wrap: TextWrap,
+    align: Align,
+    selectable: bool,
+    /// `(min_px, max_px)` from [`Self::fit`].
+    fit: Option<(f32, f32)>,
+    fit_lines: u16,
+}
+
+impl<'a> Text<'a> {
+    #[track_caller]
+    pub fn new(text: impl Into<TextInput<'a>>) -> Self {
+        Self {
+            node: Node::leaf(),
+            text: text.into(),
+            style: None,
+            weight: None,
+            wrap: TextWrap::SingleLine,
+            // Default = (Auto, Auto) → top-left. Only matters when the
+            // widget has Fixed size larger than its measured content;
+            // a Hug Text widget has no slack to align in.
+            align: Align::default(),
+            selectable: false,
+            fit: None,
+            fit_lines: 1,
+        }
+    }
+
+    style_setter!(
+        'a,
+        TextStyle,
+        text,
+        "All-or-nothing — every axis the bundle covers (font size, color, \
+         leading) is replaced. To tweak one axis, build the bundle from the \
+         theme: `TextStyle { color: red, ..ui.theme().text.clone() }`.",
+    );
+
+    /// Shape this run bold, overriding just the weight of the resolved
+    /// style (whether that came from `.style(...)` or the theme default).
+    /// One-axis hatch over the resolved bundle — see [`crate::Theme`].
+    pub fn bold(mut self) -> Self {
+        self.weight = Some(FontWeight::Bold);
+        self
+    }
+
+    /// Set how the text handles a committed width narrower than its natural
+    /// line. Default [`TextWrap::SingleLine`] (one unbroken line that runs past
+    /// the slot; its min-content is the full line width, so a Hug track won't
+    /// shrink below it — the label keeps its natural width). Pass
+    /// [`TextWrap::Truncate`] to hard-cut to the committed width with no
+    /// marker, [`TextWrap::Ellipsis`] to mark the cut with `…`, or
+    /// [`TextWrap::WrapWithOverflow`] to reshape onto multiple lines.
+    pub fn text_wrap(mut self, wrap: TextWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Wrap onto at most `lines` lines, cutting the last with `…` when the
+    /// text runs on — [`TextWrap::Clamp`]. [`Ui::text_clamped`] reports
+    /// whether it did, which is what a "show more" control keys on:
+    ///
+    /// ```
+    /// # use palantir::{Configure, Text, TextWrap, Ui, WidgetId};
+    /// # fn demo(ui: &mut Ui, body: &str, open: &mut bool) {
+    /// let id = WidgetId::from_hash("post-body");
+    /// let text = Text::new(body).id(id);
+    /// match *open {
+    ///     true => text.text_wrap(TextWrap::Wrap),
+    ///     false => text.max_lines(3),
+    /// }
+    /// .show(ui);
+    /// if ui.text_clamped(id) {
+    ///     // A "show more" link that sets `*open`.
+    /// }
+    /// # }
+    /// ```
+    pub fn max_lines(mut self, lines: u8) -> Self {
+        self.wrap = TextWrap::Clamp(lines);
+        self
+    }
+
+    /// Position of the glyph bbox inside this text widget's arranged
+    /// rect. Distinct from [`Configure::align`](crate::Configure::align), which positions the
+    /// *widget* inside its parent's slot. Only meaningful when the
+    /// widget has Fixed size larger than the text's measured size;
+    /// otherwise the widget hugs its content and there's no slack to
+    /// align in.
+    pub fn text_align(mut self, a: Align) -> Self {
+        self.align = a;
+        self
+    }
+
+    /// Let the user select and copy this text: drag to select, double-
+    /// click a word, triple-click a paragraph, then Ctrl+C or the Copy
+    /// item of its right-click menu. Off by default — a label senses
+    /// nothing, and clicks pass through it.
+    ///
+    /// Selectable texts recorded in one container share a selection: a
+    /// drag that starts in one runs on into the ones recorded after it,
+    /// and Copy joins what each holds a line apiece. The selection paints
+    /// in [`crate::TextEditTheme::selection`], and a click focuses the
+    /// text, which is what routes Ctrl+C to it.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Shrink to fit: paint at the largest size in `min_px..=max_px` whose
+    /// line fits the box layout gives this text, unbroken — a big-number
+    /// tile, or a button label that runs long in another language. Replaces
+    /// the style's size; leading keeps the style's ratio to it.
+    ///
+    /// Sizes step on the same 0.5% scale ladder zoomed text snaps to, so a
+    /// resize drag walks a bounded set of faces instead of shaping one per
+    /// pixel, and a frame the layout cache replays shapes nothing. A hug
+    /// width offers the text as much room as its parent has; give it a
+    /// fixed or fill size to fit a specific box. Below `min_px` the
+    /// [`text_wrap`](Self::text_wrap) policy handles the overflow, so pair
+    /// with [`TextWrap::Ellipsis`] to mark a label that still runs long.
+    ///
+    /// A fitted text isn't [selectable](Self::selectable): selection reads
+    /// one size at record time, and this one is only picked in layout.
+    pub fn fit(mut self, min_px: f32, max_px: f32) -> Self {
+        debug_assert!(min_px <= max_px, "fit range {min_px}..={max_px} is empty");
+        self.fit = Some((min_px, max_px));
+        self
+    }
+
+    /// Let a [fitted](Self::fit) text wrap onto up to `lines` lines before
+    /// it shrinks further. Above one line, a wrapping policy set through
+    /// [`text_wrap`](Self::text_wrap) wins, and any other policy becomes
+    /// [`TextWrap::Wrap`]; the call order doesn't matter. No effect
+    /// without `fit`.
+    pub fn fit_lines(mut self, lines: u16) -> Self {
+        self.fit_lines = lines.max(1);
+        self
+    }
+
+    /// The wrap the run is shaped with — see [`Self::fit_lines`].
+    fn effective_wrap(&self) -> TextWrap {
+        match self.wrap {
+            TextWrap::Wrap | TextWrap::WrapWithOverflow | TextWrap::Clamp(_) => self.wrap,
+            _ if self.fit.is_some() && self.fit_lines > 1 => TextWrap::Wrap,
+            wrap => wrap,
+        }
+    }
+
+    pub fn show(self, ui: &mut Ui) -> Response<'_> {
+        let style = self.slot(ui.theme());
+        let color = style.color;
+        // The builder's weight over the style's; everything else is the
+        // style's face as-is.
+        let mut font = GlyphFont {
+            weight: self.weight.unwrap_or(style.weight),
+            ..style.font()
+        };
+        if let Some((_, max_px)) = self.fit {
+            font.size_px = max_px;
+            font.line_height_px = style.line_height_for(max_px);
+        }
+        let wrap = self.effective_wrap();
+        let selectable = self.selectable && self.fit.is_none();
+        let mut node = self.node;
+        if selectable {
+            node.flags.set_sense(Sense::CLICK);
+            node.flags.set_focusable(true);
+        }
+        let mut widget = ui.widget(node);
+        let text = ui.intern(self.text);
+        let run = TextRun {
+            text: "",
+            font,
+            wrap,
+            align: self.align,
+            max_width_px: None,
+        };
+        let highlight = if selectable {
+            select(ui, &mut widget, text, run)
+        } else {
+            Vec::new()
+        };
+        let selection_color = ui.theme().text_edit.selection;
+        // No metrics guard here: `TextShape::is_noop` rejects a non-finite
+        // size or leading at `add_shape`, which is where Button and
+        // DragValue leave it too. One owner of the rule, and it is the one
+        // downstream of every recorder.
+        widget
+            .show(ui, None, |ui| {
+                for rect in highlight {
+                    ui.add_shape(Shape::rect(rect).fill(selection_color));
+                }
+                let shape = Shape::text(text, font)
+                    .color(color)
+                    .wrap(wrap)
+                    .align(self.align);
+                ui.add_shape(match self.fit {
+                    Some((min_px, _)) => shape.fit(min_px, self.fit_lines),
+                    None => shape,
+                });
+            })
+            .response
+    }
+}
+
+impl_configure!(Text<'_>);
+
+/// A selectable text's pass over the selection it shares with its
+/// container: the press and drag that move it, Ctrl+C and the Copy menu
+/// that read it. Returns the owner-local rects of what it has selected.
+///
+/// `run` is the shape the text paints with, less its text and width,
+/// which this fills in: hit-testing and the highlight probe the same
+/// layout the encoder draws.
+fn select(ui: &mut Ui, widget: &mut Widget, text: InternedStr, run: TextRun<'_>) -> Vec<Rect> {
+    let id = widget.id();
+    // Owned: the probe below needs `ui`, which the arena is part of.
+    let source = TextSource { span: text.span }
+        .resolve(&ui.payloads().interned_text())
+        .to_owned();
+    // Keyed by the container, so the texts in it share one row; a text
+    // recorded at the top of a layer selects on its own.
+    let doc_id = ui.current_parent_id().unwrap_or(id);
+    let mut doc = std::mem::take(ui.state_mut::<TextSelection>(doc_id));
+    let order = doc.enter(id, ui.frame_id());
+    // Focus taken by anything outside the container ends its selection;
+    // focus dropped to nothing — a press on a menu row — doesn't.
+    if ui.focused_id().is_some_and(|f| !doc.is_member(f)) {
+        doc.clear();
+    }
+
+    let response = ui.response_for(id);
+    // A drag that started in another member reaches this one when the
+    // pointer is over it — that one holds the pointer, so this reads it
+    // rather than being routed it.
+    let mut passing = None;
+    if let Some(from) = doc.dragging.filter(|from| *from != id) {
+        if ui.response_for(from).left.held() {
+            passing = ui.pointer_local(id);
+        } else {
+            doc.dragging = None;
+        }
+    }
+    let extend = ui.peek_modifiers().shift;
+    let clipboard = ui.clipboard();
+    let mut rects = Vec::new();
+    if let Some(rect) = response.layout_rect {
+        // Last frame's rect, like the pointer: the user pressed on what
+        // they saw.
+        let padding = widget.node.padding.unwrap_or(Spacing::ZERO);
+        let inner = Rect {
+            min: Vec2::ZERO,
+            size: rect.size,
+        }
+        .deflated_by(padding);
+        let probe = ui.probe_text(TextRun {
+            text: &source,
+            max_width_px: Some(inner.size.w),
+            ..run
+        });
+        let origin = run.align.place_in(inner, probe.size()).min;
+        let mark = |at: Vec2| Mark {
+            id,
+            order,
+            byte: probe.byte_at(at.x - origin.x, at.y - origin.y),
+        };
+        let in_band = |at: Vec2| (0.0..rect.size.h).contains(&at.y);
+        if let Some(at) = response.pointer_local {
+            if response.left.press_count() > 0 {
+                doc.press(mark(at), response.left.press_count(), extend, &source);
+                doc.dragging = Some(id);
+            } else if doc.dragging == Some(id) && response.left.held() && in_band(at) {
+                // Past either end of its own line still selects to that
+                // end, as the probe clamps.
+                doc.drag_to(mark(at));
+            }
+        }
+        if let Some(at) = passing
+            && in_band(at)
+            && (0.0..rect.size.w).contains(&at.x)
+        {
+            doc.drag_to(mark(at));
+        }
+        if doc.dragging == Some(id) && !response.left.held() {
+            doc.dragging = None;
+        }
+        let range = doc.range_in(order, source.len());
+        if let Some(copied) = doc.gather(order, range.clone().map(|range| &source[range])) {
+            let _ = clipboard.set(&copied);
+        }
+        if let Some(range) = range {
+            probe.selection_rects(range, |r| {
+                rects.push(Rect {
+                    min: r.min + origin,
+                    ..r
+                });
+            });
+        }
+    }
+
+    // A focused member with something selected takes the edit keys, so
+    // Ctrl+C copies this rather than whatever the app would. The copy
+    // lands as the members record on the next pass, which this wakes.
+    let mut copy = false;
+    if response.focused && !doc.is_empty() {
+        widget.node.flags.set_key_filter(KeyFilter::EDIT);
+        ui.each_keyboard_event(|_, event| {
+            if let Some(KeyboardEvent::Down(press)) = KeyFilter::EDIT.accepts(event) {
+                copy |= COPY.matches(press);
+            }
+        });
+    }
+    let snapshot = ResponseSnapshot {
+        id,
+        state: response,
+    };
+    let has_selection = !doc.is_empty();
+    ContextMenu::attach(ui, &snapshot).show(ui, |ui, popup| {
+        copy |= MenuItem::new("Copy")
+            .enabled(has_selection)
+            .shortcut_hint(COPY)
+            .show(ui, popup)
+            .left
+            .clicked();
+    });
+    if copy {
+        doc.ask_copy();
+        ui.request_repaint();
+    }
+    *ui.state_mut::<TextSelection>(doc_id) = doc;
+    rects
+}
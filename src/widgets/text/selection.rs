@@ -0,0 +1,190 @@
+//! Selection over read-only [`Text`](crate::Text): one selection per
+//! container, shared by the selectable texts recorded in it, so a drag
+//! can run from one label into the next the way a document selects.
+
+use crate::primitives::widget_id::WidgetId;
+use crate::widgets::text_edit::unicode::word_range_at;
+use std::ops::Range;
+
+/// One end of a selection: a byte of one member's text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Mark {
+    pub(super) id: WidgetId,
+    /// The member's place in its container's record order, which is the
+    /// order the selection runs in.
+    pub(super) order: u32,
+    pub(super) byte: usize,
+}
+
+impl Mark {
+    fn key(self) -> (u32, usize) {
+        (self.order, self.byte)
+    }
+}
+
+/// The selection the selectable texts of one container share, in a state
+/// row keyed by the container's id.
+#[derive(Debug, Default)]
+pub(super) struct TextSelection {
+    anchor: Option<Mark>,
+    focus: Option<Mark>,
+    /// The member whose press is extending the selection, while it's held.
+    pub(super) dragging: Option<WidgetId>,
+    /// The members recorded this frame, in record order: a member's index
+    /// is its order. Kept across a frame's passes, so a second pass finds
+    /// the same order rather than counting on from the first.
+    members: Vec<WidgetId>,
+    /// Last frame's, for telling a member that has yet to record this
+    /// frame from a widget that is none.
+    prev_members: Vec<WidgetId>,
+    /// The frame `members` is for.
+    stamp: u64,
+    /// The frame a Copy was asked for in. What it copies is gathered from
+    /// the members as they next record — the one that took the key may
+    /// come after others, whose text is no longer to hand — so nothing is
+    /// held between frames. Lapses if the frame after passes without the
+    /// selection's end member recording.
+    copy_asked: Option<u64>,
+    /// The selected text gathered so far, from the selection's first
+    /// member on.
+    gathered: Option<String>,
+}
+
+impl TextSelection {
+    /// Join as member `id` for frame `frame`, returning its order.
+    pub(super) fn enter(&mut self, id: WidgetId, frame: u64) -> u32 {
+        if self.stamp != frame {
+            if self.copy_asked.is_some_and(|asked| asked != self.stamp) {
+                self.drop_copy();
+            }
+            self.stamp = frame;
+            std::mem::swap(&mut self.members, &mut self.prev_members);
+            self.members.clear();
+        }
+        let at = match self.members.iter().position(|m| *m == id) {
+            Some(at) => at,
+            None => {
+                self.members.push(id);
+                self.members.len() - 1
+            }
+        };
+        at as u32
+    }
+
+    /// Whether `id` selects in this container — recorded this frame or
+    /// the last.
+    pub(super) fn is_member(&self, id: WidgetId) -> bool {
+        self.members.contains(&id) || self.prev_members.contains(&id)
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.anchor = None;
+        self.focus = None;
+        self.dragging = None;
+        self.drop_copy();
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.anchor == self.focus
+    }
+
+    /// Start a selection at a press on `mark`: a caret for a single press,
+    /// the word under it for a double, its paragraph for a triple. `extend`
+    /// (a Shift+press) moves just the focus, keeping the anchor.
+    pub(super) fn press(&mut self, mark: Mark, count: u8, extend: bool, text: &str) {
+        self.drop_copy();
+        if extend && self.anchor.is_some() {
+            self.focus = Some(mark);
+            return;
+        }
+        let range = match count {
+            0 | 1 => mark.byte..mark.byte,
+            2 => word_range_at(text, mark.byte),
+            _ => paragraph_at(text, mark.byte),
+        };
+        self.anchor = Some(Mark {
+            byte: range.start,
+            ..mark
+        });
+        self.focus = Some(Mark {
+            byte: range.end,
+            ..mark
+        });
+    }
+
+    pub(super) fn drag_to(&mut self, mark: Mark) {
+        if self.anchor.is_some() {
+            self.focus = Some(mark);
+        }
+    }
+
+    /// The bytes of a `len`-byte member at `order` that the selection
+    /// covers, or `None` where it covers none: all of a member between the
+    /// two ends, the part past or before the end that falls in one.
+    pub(super) fn range_in(&self, order: u32, len: usize) -> Option<Range<usize>> {
+        let (start, end) = self.ends()?;
+        if order < start.order || order > end.order {
+            return None;
+        }
+        let from = if order == start.order { start.byte } else { 0 };
+        let to = if order == end.order { end.byte } else { len };
+        let range = from.min(len)..to.min(len);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The selection's ends in member order, start first.
+    fn ends(&self) -> Option<(Mark, Mark)> {
+        let (anchor, focus) = (self.anchor?, self.focus?);
+        Some(if anchor.key() <= focus.key() {
+            (anchor, focus)
+        } else {
+            (focus, anchor)
+        })
+    }
+
+    /// Copy the selection once its members have recorded again — see
+    /// [`Self::gather`].
+    pub(super) fn ask_copy(&mut self) {
+        self.copy_asked = Some(self.stamp);
+    }
+
+    fn drop_copy(&mut self) {
+        self.copy_asked = None;
+        self.gathered = None;
+    }
+
+    /// Add what the member at `order` has selected to an asked-for Copy,
+    /// a line each, in order. Returns the text once the member holding
+    /// the selection's end has added its part.
+    pub(super) fn gather(&mut self, order: u32, selected: Option<&str>) -> Option<String> {
+        self.copy_asked?;
+        let Some((start, end)) = self.ends() else {
+            self.drop_copy();
+            return None;
+        };
+        if order == start.order {
+            self.gathered = Some(String::new());
+        }
+        let gathered = self.gathered.as_mut()?;
+        if let Some(part) = selected {
+            if !gathered.is_empty() {
+                gathered.push('\n');
+            }
+            gathered.push_str(part);
+        }
+        if order < end.order {
+            return None;
+        }
+        self.copy_asked = None;
+        self.gathered.take()
+    }
+}
+
+/// The paragraph around `byte`: the run between the line breaks either
+/// side of it, breaks excluded.
+fn paragraph_at(text: &str, byte: usize) -> Range<usize> {
+    let byte = byte.min(text.len());
+    let start = text[..byte].rfind('\n').map_or(0, |at| at + 1);
+    let end = text[byte..].find('\n').map_or(text.len(), |at| byte + at);
+    start..end
+}
@@ -0,0 +1,242 @@
+use crate::Ui;
+use crate::input::keyboard::{Key, Modifiers};
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::layer::Layer;
+use crate::scene::node::Configure;
+use crate::scene::shapes::record::ShapeRecord;
+use crate::text::wrap::TextWrap;
+use crate::ui::harness::UiHarness;
+use crate::widgets::context_menu::ContextMenu;
+use crate::widgets::panel::Panel;
+use crate::widgets::text::Text;
+use crate::widgets::text_edit::TextEdit;
+use glam::{UVec2, Vec2};
+
+const SURFACE: UVec2 = UVec2::new(300, 200);
+
+fn line(at: usize) -> WidgetId {
+    WidgetId::from_hash(("selectable-line", at))
+}
+
+/// A column of selectable lines.
+fn page(ui: &mut Ui, lines: &[&str]) {
+    Panel::vstack().auto_id().show(ui, |ui| {
+        for (at, text) in lines.iter().enumerate() {
+            Text::new(*text)
+                .id(line(at))
+                .selectable(true)
+                .text_wrap(TextWrap::Wrap)
+                .show(ui);
+        }
+    });
+}
+
+fn copy(h: &mut UiHarness, lines: &[&str]) -> String {
+    h.set_modifiers(Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    });
+    h.key(Key::Char('c'));
+    h.frame(|ui| page(ui, lines));
+    h.set_modifiers(Modifiers::NONE);
+    h.clipboard_text()
+}
+
+/// `count` presses at `at`, within the double-click window.
+fn clicks(h: &mut UiHarness, at: Vec2, count: usize, lines: &[&str]) {
+    for _ in 0..count {
+        h.press_at(at);
+        h.frame(|ui| page(ui, lines));
+        h.release();
+        h.frame(|ui| page(ui, lines));
+    }
+}
+
+/// The selection highlight rects painted on `id`.
+fn highlights(h: &UiHarness, id: WidgetId) -> usize {
+    h.ui.tree(Layer::Main)
+        .shapes_of(h.node_for_widget_id(id))
+        .filter(|s| matches!(s, ShapeRecord::Quad(_)))
+        .count()
+}
+
+/// Double-click takes the word under the pointer, triple-click the
+/// paragraph; either copies with Ctrl+C, and paints a highlight.
+#[test]
+fn double_and_triple_click_select_a_word_and_a_paragraph() {
+    let lines = ["hello world\nsecond part"];
+    // Real shaping: the mono fallback doesn't break at `\n`.
+    let mut h = UiHarness::with_text(SURFACE);
+    h.set_clipboard_text("");
+    h.frame(|ui| page(ui, &lines));
+    assert_eq!(highlights(&h, line(0)), 0);
+    let rect = h.rect(line(0)).unwrap();
+    let top_right = Vec2::new(rect.max().x - 2.0, rect.min.y + 2.0);
+
+    clicks(&mut h, top_right, 2, &lines);
+    assert_eq!(h.focused_id(), Some(line(0)), "a click focuses it");
+    assert_eq!(copy(&mut h, &lines), "world");
+    assert!(highlights(&h, line(0)) > 0);
+
+    h.advance_past_double_click(|ui| page(ui, &lines));
+    let bottom_left = Vec2::new(rect.min.x + 2.0, rect.max().y - 2.0);
+    clicks(&mut h, bottom_left, 3, &lines);
+    assert_eq!(copy(&mut h, &lines), "second part");
+}
+
+/// A drag that starts in one line runs on into the lines after it, and
+/// Copy joins them a line apiece.
+#[test]
+fn a_drag_selects_across_lines_in_one_container() {
+    let lines = ["alpha", "beta", "gamma"];
+    let mut h = UiHarness::new(SURFACE);
+    h.frame(|ui| page(ui, &lines));
+    let first = h.rect(line(0)).unwrap();
+    let last = h.rect(line(2)).unwrap();
+
+    h.press_at(Vec2::new(first.min.x + 1.0, first.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.drag_to(Vec2::new(last.min.x + last.size.w * 0.5, last.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.frame(|ui| page(ui, &lines));
+    h.release();
+    h.frame(|ui| page(ui, &lines));
+    assert!((0..3).all(|at| highlights(&h, line(at)) > 0));
+
+    let copied = copy(&mut h, &lines);
+    assert!(
+        copied.starts_with("alpha\nbeta\nga") && copied.len() < "alpha\nbeta\ngamma".len(),
+        "through half of gamma: {copied:?}"
+    );
+}
+
+/// Dragged back from the last line, the member that takes Ctrl+C records
+/// after the ones it copies — they still land, in order.
+#[test]
+fn a_drag_back_up_copies_in_order() {
+    let lines = ["alpha", "beta", "gamma"];
+    let mut h = UiHarness::new(SURFACE);
+    h.frame(|ui| page(ui, &lines));
+    let first = h.rect(line(0)).unwrap();
+    let last = h.rect(line(2)).unwrap();
+
+    h.press_at(Vec2::new(last.min.x + last.size.w * 0.5, last.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.drag_to(Vec2::new(first.min.x + 1.0, first.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.frame(|ui| page(ui, &lines));
+    h.release();
+    h.frame(|ui| page(ui, &lines));
+    assert_eq!(h.focused_id(), Some(line(2)));
+
+    let copied = copy(&mut h, &lines);
+    assert!(
+        copied.starts_with("alpha\nbeta\nga") && copied.len() < "alpha\nbeta\ngamma".len(),
+        "from half of gamma up: {copied:?}"
+    );
+}
+
+/// A Copy whose end member didn't record lapses with the frame after it,
+/// rather than landing whenever that member next shows up.
+#[test]
+fn a_copy_missing_its_end_member_lapses() {
+    let lines = ["alpha", "beta", "gamma"];
+    let mut h = UiHarness::new(SURFACE);
+    h.frame(|ui| page(ui, &lines));
+    let first = h.rect(line(0)).unwrap();
+    let last = h.rect(line(2)).unwrap();
+    h.press_at(Vec2::new(first.min.x + 1.0, first.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.drag_to(Vec2::new(last.max().x - 1.0, last.center().y));
+    h.frame(|ui| page(ui, &lines));
+    h.frame(|ui| page(ui, &lines));
+    h.release();
+    h.frame(|ui| page(ui, &lines));
+
+    h.set_clipboard_text("kept");
+    let shown = &lines[..2];
+    assert_eq!(copy(&mut h, shown), "kept", "gamma holds the end");
+    h.frame(|ui| page(ui, shown));
+    h.frame(|ui| page(ui, &lines));
+    h.frame(|ui| page(ui, &lines));
+    assert_eq!(h.clipboard_text(), "kept");
+}
+
+/// The right-click menu copies the selection too.
+#[test]
+fn the_context_menu_copies() {
+    let lines = ["copy me"];
+    let mut h = UiHarness::new(SURFACE);
+    h.set_clipboard_text("");
+    h.frame(|ui| page(ui, &lines));
+    let rect = h.rect(line(0)).unwrap();
+    clicks(&mut h, rect.min + Vec2::new(2.0, 2.0), 2, &lines);
+
+    h.right_click_at(rect.min + Vec2::new(2.0, 2.0));
+    h.frame(|ui| page(ui, &lines));
+    h.frame(|ui| page(ui, &lines));
+    assert!(ContextMenu::is_open(&h.ui, line(0)));
+    // The Copy row: the menu body's first child.
+    let tree = h.ui.tree(Layer::Popup);
+    let body = tree
+        .records
+        .widget_id()
+        .iter()
+        .position(|id| *id == line(0).with("body"))
+        .expect("menu body recorded");
+    h.click_at(h.ui.layout(Layer::Popup).rect[body + 1].center());
+    h.frame(|ui| page(ui, &lines));
+    assert_eq!(h.clipboard_text(), "copy");
+}
+
+/// Focus taken by something outside the container ends its selection.
+#[test]
+fn focus_elsewhere_clears_the_selection() {
+    let field = WidgetId::from_hash("selectable-field");
+    let lines = ["some words"];
+    let mut buf = String::new();
+    let mut body = |ui: &mut Ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            page(ui, &lines);
+            TextEdit::new(&mut buf)
+                .id(field)
+                .size((Sizing::fixed(100.0), Sizing::fixed(30.0)))
+                .show(ui);
+        });
+    };
+    let mut h = UiHarness::new(SURFACE);
+    h.frame(&mut body);
+    let rect = h.rect(line(0)).unwrap();
+    for _ in 0..2 {
+        h.press_at(rect.min + Vec2::new(2.0, 2.0));
+        h.frame(&mut body);
+        h.release();
+        h.frame(&mut body);
+    }
+    assert!(highlights(&h, line(0)) > 0);
+
+    h.click_on(field);
+    h.frame(&mut body);
+    h.frame(&mut body);
+    assert_eq!(highlights(&h, line(0)), 0);
+}
+
+/// A plain label senses nothing: a press on it neither focuses it nor
+/// starts a selection.
+#[test]
+fn a_plain_label_is_not_selectable() {
+    let id = WidgetId::from_hash("plain-label");
+    let mut h = UiHarness::new(SURFACE);
+    let mut body = |ui: &mut Ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            Text::new("just a label").id(id).show(ui);
+        });
+    };
+    h.frame(&mut body);
+    let rect = h.rect(id).unwrap();
+    h.click_at(rect.center());
+    h.frame(&mut body);
+    assert_eq!(h.focused_id(), None);
+    assert_eq!(highlights(&h, id), 0);
+}
@@ -14,6 +14,9 @@ pub(super) enum EditAction {
     Copy,
     Paste,
     Clear,
+    /// Select the word at the caret, then add a caret at each next
+    /// occurrence of the selection.
+    AddNextOccurrence,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -74,6 +77,7 @@ impl EditAction {
             Self::Copy => Some(Shortcut::ctrl('C')),
             Self::Paste => Some(Shortcut::ctrl('V')),
             Self::Clear => None,
+            Self::AddNextOccurrence => Some(Shortcut::ctrl('D')),
         }
     }
 
@@ -82,7 +86,7 @@ impl EditAction {
     /// — an unbound action returns `None` there and simply never
     /// matches, which is what keeps a second hand-curated subset (and
     /// its drift) out of this file.
-    const ALL: [Self; 7] = [
+    const ALL: [Self; 8] = [
         Self::Undo,
         Self::Redo,
         Self::SelectAll,
@@ -90,6 +94,7 @@ impl EditAction {
         Self::Copy,
         Self::Paste,
         Self::Clear,
+        Self::AddNextOccurrence,
     ];
 
     pub(super) fn from_keypress(keypress: KeyPress) -> Option<Self> {
@@ -107,18 +112,19 @@ impl EditAction {
             Self::SelectAll => editor.select_all(),
             Self::Cut => {
                 if let Some(selected) = editor.selected_text()
-                    && clipboard.set(selected).is_ok()
+                    && clipboard.set(&selected).is_ok()
                 {
                     editor.cut_selection();
                 }
             }
             Self::Copy => {
                 if let Some(selected) = editor.selected_text() {
-                    let _ = clipboard.set(selected);
+                    let _ = clipboard.set(&selected);
                 }
             }
             Self::Paste => editor.paste(&clipboard.get()),
             Self::Clear => editor.clear(),
+            Self::AddNextOccurrence => editor.add_next_occurrence(),
         }
     }
 }
@@ -0,0 +1,407 @@
+//! Suggestions for a [`TextEdit`](crate::TextEdit): a
+//! [`SuggestionProvider`] offers completions for the text around the
+//! caret, and the field lists them in a popup below itself while keeping
+//! the keyboard — the list is steered by keys the field already takes.
+
+use crate::common::hash;
+use crate::input::keyboard::{Key, KeyPress};
+use crate::input::sense::Sense;
+use crate::layout::types::align::{Align, VAlign};
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::{Configure, Node};
+use crate::ui::Ui;
+use crate::widgets::command_palette::fuzzy;
+use crate::widgets::popup::{ClickOutside, Popup};
+use crate::widgets::text::Text;
+use crate::widgets::text_edit::buffer::{TextBuffer, line_range};
+use crate::widgets::text_edit::edit_state::EditKind;
+use crate::widgets::text_edit::editor::Editor;
+use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::theme::widget_look::look_plan::LookPlan;
+use std::cmp::Reverse;
+use std::fmt;
+use std::ops::Range;
+
+/// Offers completions for a [`TextEdit`](crate::TextEdit).
+///
+/// Asked again only when the text being completed changes or
+/// [`Self::revision`] does, so a provider that looks suggestions up
+/// elsewhere — on disk, over the network — can answer with what it has,
+/// start the lookup, and bump its revision when the results land, waking
+/// the UI with [`HostHandle::request_repaint`](crate::HostHandle::request_repaint).
+///
+/// A list of strings is a provider that ranks its entries by fuzzy match.
+/// A tag field completes only the tag at the caret:
+///
+/// ```
+/// # use palantir::SuggestionProvider;
+/// # use std::ops::Range;
+/// #[derive(Debug)]
+/// struct Tags(Vec<&'static str>);
+///
+/// impl SuggestionProvider for Tags {
+///     fn suggest(&self, query: &str, out: &mut Vec<String>) {
+///         let query = query.trim_start();
+///         out.extend(self.0.iter().filter(|t| t.starts_with(query)).map(|t| format!(" {t}")));
+///     }
+///
+///     fn token(&self, line: &str, caret: usize) -> Range<usize> {
+///         let start = line[..caret].rfind(',').map_or(0, |at| at + 1);
+///         let end = line[caret..].find(',').map_or(line.len(), |at| caret + at);
+///         start..end
+///     }
+/// }
+/// ```
+pub trait SuggestionProvider: fmt::Debug {
+    /// Push the completions for `query` onto `out`, best first. Accepting
+    /// one replaces the [`token`](Self::token) `query` was cut from.
+    fn suggest(&self, query: &str, out: &mut Vec<String>);
+
+    /// The byte range of `line` that is being completed, given the caret
+    /// at byte `caret` of it. `line` is the caret's line, without its line
+    /// break — all of a single-line field's value — so a token never
+    /// spans lines, and cutting it costs the line rather than the whole
+    /// document. The whole line by default; a field holding a list — tags,
+    /// a path, a command line — narrows it to the item at the caret.
+    fn token(&self, line: &str, caret: usize) -> Range<usize> {
+        let _ = caret;
+        0..line.len()
+    }
+
+    /// Changes whenever the same query would now be answered differently —
+    /// results that arrived later, a list that was reloaded.
+    fn revision(&self) -> u64 {
+        0
+    }
+}
+
+impl<S: AsRef<str> + fmt::Debug> SuggestionProvider for Vec<S> {
+    fn suggest(&self, query: &str, out: &mut Vec<String>) {
+        rank(self, query, out);
+    }
+}
+
+impl<S: AsRef<str> + fmt::Debug, const N: usize> SuggestionProvider for [S; N] {
+    fn suggest(&self, query: &str, out: &mut Vec<String>) {
+        rank(self, query, out);
+    }
+}
+
+/// The entries `query` is a fuzzy match for, best first and then in
+/// their own order — except one that is the query already, which has
+/// nothing left to complete.
+fn rank<S: AsRef<str>>(entries: &[S], query: &str, out: &mut Vec<String>) {
+    let mut scratch = Vec::new();
+    let mut ranked: Vec<(i32, &str)> = entries
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|entry| *entry != query)
+        .filter_map(|entry| Some((fuzzy::score(query, entry, &mut scratch)?, entry)))
+        .collect();
+    ranked.sort_by_key(|&(score, _)| Reverse(score));
+    out.extend(ranked.into_iter().map(|(_, entry)| entry.to_owned()));
+}
+
+/// Where a [`TextEdit`](crate::TextEdit)'s suggestion list hangs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SuggestAnchor {
+    /// Below the field, at least as wide as it — a search box, a combo.
+    #[default]
+    Field,
+    /// Below the caret's line, starting at the caret — a tag field or a
+    /// command line, where what is completed is a word partway along.
+    Caret,
+}
+
+/// Suggestions for a [`TextEdit`](crate::TextEdit) — see
+/// [`TextEdit::autocomplete`](crate::TextEdit::autocomplete).
+#[derive(Copy, Clone, Debug)]
+pub struct Autocomplete<'a> {
+    pub(super) provider: &'a dyn SuggestionProvider,
+    pub(super) anchor: SuggestAnchor,
+}
+
+impl<'a> Autocomplete<'a> {
+    pub fn new(provider: &'a dyn SuggestionProvider) -> Self {
+        Self {
+            provider,
+            anchor: SuggestAnchor::Field,
+        }
+    }
+
+    pub fn anchor(mut self, anchor: SuggestAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+/// A key the list takes from the field while it shows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum ListKey {
+    Step(i64),
+    Accept,
+    Dismiss,
+}
+
+impl ListKey {
+    /// Arrows steer, Tab and Enter accept, Escape closes — unmodified
+    /// only, so Shift+Arrow still selects and Shift+Tab still dedents.
+    pub(super) fn of(press: KeyPress) -> Option<Self> {
+        if press.mods.shift || press.mods.any_command() {
+            return None;
+        }
+        match press.key {
+            Key::ArrowDown => Some(Self::Step(1)),
+            Key::ArrowUp => Some(Self::Step(-1)),
+            Key::Tab | Key::Enter => Some(Self::Accept),
+            Key::Escape => Some(Self::Dismiss),
+            _ => None,
+        }
+    }
+}
+
+/// The field's suggestion list, on its state row.
+#[derive(Clone, Default, Debug)]
+pub(super) struct SuggestState {
+    /// The list is wanted: typing (or ArrowDown) opened it, and accepting,
+    /// Escape or losing focus has not closed it since.
+    pub(super) armed: bool,
+    /// What the provider offered for `token`.
+    pub(super) items: Vec<String>,
+    /// Per item, its matched ranges as a range into `ranges`.
+    spans: Vec<Range<usize>>,
+    ranges: Vec<Range<usize>>,
+    scratch: Vec<Range<usize>>,
+    /// Index into `items`.
+    pub(super) selected: usize,
+    /// First `items` index in the visible window.
+    first_row: usize,
+    /// The rows last shown, whose presses the next pass looks for.
+    rows: Range<usize>,
+    /// What `items` answers: the query's hash and the provider's revision.
+    asked: Option<(u64, u64)>,
+    /// What `token` answers: the buffer's hash, the caret and the
+    /// provider's revision. Re-cutting flattens the buffer, so it waits
+    /// for one of them to move rather than running every frame.
+    cut: Option<(u64, usize, u64)>,
+    /// The bytes being completed, as of the last refresh.
+    token: Range<usize>,
+    /// Their text, kept so the ghost needn't flatten the buffer again.
+    typed: String,
+}
+
+impl SuggestState {
+    pub(super) fn showing(&self) -> bool {
+        self.armed && !self.items.is_empty()
+    }
+
+    pub(super) fn arm(&mut self) {
+        self.armed = true;
+    }
+
+    /// Close the list. The next one asks the provider afresh.
+    pub(super) fn disarm(&mut self) {
+        self.armed = false;
+        self.asked = None;
+        self.cut = None;
+        self.items.clear();
+        self.rows = 0..0;
+    }
+
+    /// Move the selection `by` rows, wrapping at either end.
+    pub(super) fn step(&mut self, by: i64) {
+        let n = self.items.len() as i64;
+        if n > 0 {
+            self.selected = (self.selected as i64 + by).rem_euclid(n) as usize;
+        }
+    }
+
+    /// Replace the token at the caret with item `at` and close the list.
+    /// Returns whether there was an item to accept.
+    pub(super) fn accept(
+        &mut self,
+        ed: &mut Editor<'_>,
+        provider: &dyn SuggestionProvider,
+        at: usize,
+    ) -> bool {
+        let Some(item) = self.items.get(at) else {
+            return false;
+        };
+        let token = token(provider, ed.text, ed.state.caret);
+        ed.replace_range(token, item, EditKind::Other);
+        self.disarm();
+        true
+    }
+
+    /// Re-cut the token at the caret if the buffer, the caret or the
+    /// provider's revision moved, and ask the provider about it if it, or
+    /// the revision, changed since it was last asked. `hash` is the
+    /// buffer's.
+    pub(super) fn refresh(
+        &mut self,
+        provider: &dyn SuggestionProvider,
+        text: &dyn TextBuffer,
+        caret: usize,
+        hash: u64,
+    ) {
+        let revision = provider.revision();
+        let cut = (hash, caret, revision);
+        if self.cut == Some(cut) {
+            return;
+        }
+        self.cut = Some(cut);
+        self.token = token(provider, text, caret);
+        let query = text.slice(self.token.clone());
+        let query = query.as_ref();
+        self.typed.clear();
+        self.typed.push_str(query);
+        let asked = (hash::hash_str(query), revision);
+        if self.asked == Some(asked) {
+            return;
+        }
+        self.asked = Some(asked);
+        self.items.clear();
+        provider.suggest(query, &mut self.items);
+        self.spans.clear();
+        self.ranges.clear();
+        for item in &self.items {
+            let start = self.ranges.len();
+            if fuzzy::score(query, item, &mut self.scratch).is_some() {
+                self.ranges.extend_from_slice(&self.scratch);
+            }
+            self.spans.push(start..self.ranges.len());
+        }
+        self.selected = 0;
+        self.first_row = 0;
+    }
+
+    /// The row pressed this frame, among those shown last frame.
+    pub(super) fn pressed(&self, ui: &Ui, id: WidgetId) -> Option<usize> {
+        if !self.showing() {
+            return None;
+        }
+        self.rows
+            .clone()
+            .find(|&at| ui.response_for(row_id(id, at)).left.press_count() > 0)
+    }
+
+    /// The rest of the selected item, when it extends what is typed and
+    /// the caret is at the end of the text, `len` bytes long, where it
+    /// can be painted.
+    pub(super) fn ghost(&self, len: usize, caret: usize) -> Option<&str> {
+        if !self.showing() || caret != len || self.token.end != caret {
+            return None;
+        }
+        let typed = self.typed.as_str();
+        let rest = self.items.get(self.selected)?.strip_prefix(typed)?;
+        (!typed.is_empty() && !rest.is_empty()).then_some(rest)
+    }
+
+    /// The list, in a popup hung below `anchor` at least `width` wide.
+    /// Pass-through, so the field below keeps its keys and its focus; a
+    /// press on a row is picked up by the next pass, through
+    /// [`Self::pressed`].
+    pub(super) fn show(&mut self, ui: &mut Ui, id: WidgetId, anchor: Rect, width: f32) {
+        let theme = ui.theme();
+        let menu = theme.context_menu.clone();
+        let text = theme.text.clone();
+        let suggest = theme.autocomplete.clone();
+        let window = usize::from(suggest.max_rows.max(1));
+        let n = self.items.len();
+        self.selected = self.selected.min(n.saturating_sub(1));
+        if self.selected < self.first_row {
+            self.first_row = self.selected;
+        } else if self.selected >= self.first_row + window {
+            self.first_row = self.selected + 1 - window;
+        }
+        self.first_row = self.first_row.min(n.saturating_sub(window));
+        self.rows = self.first_row..(self.first_row + window).min(n);
+
+        let anchor = Rect::new(
+            anchor.min.x,
+            anchor.min.y,
+            anchor.size.w,
+            anchor.size.h + suggest.gap,
+        );
+        let highlight = TextStyle {
+            color: suggest.highlight,
+            ..text.clone()
+        };
+        Popup::below(anchor)
+            .click_outside(ClickOutside::PassThrough)
+            .background(menu.panel.clone())
+            .padding(menu.padding)
+            .gap(menu.gap)
+            .id(id.with("suggestions"))
+            .min_size((width, 0.0))
+            .show(ui, |ui, _| {
+                for at in self.rows.clone() {
+                    let node = Node::hstack()
+                        .id(row_id(id, at))
+                        .size((Sizing::FILL, Sizing::HUG))
+                        .sense(Sense::CLICK);
+                    let mut widget = ui.widget(node);
+                    // The selection paints as hover does, as a palette's
+                    // does: it is where the keyboard is pointing.
+                    let mut shown = widget.response(ui);
+                    shown.hovered |= at == self.selected;
+                    let look = LookPlan {
+                        target: menu.item.pick(&shown).to_animated(&text),
+                        padding: menu.item.padding,
+                        margin: menu.item.margin,
+                        anim: menu.item.anim,
+                    }
+                    .apply(ui, &mut widget);
+                    widget.node.child_align = Align::v(VAlign::Center);
+                    let plain = look.text;
+                    let highlight = TextStyle {
+                        color: highlight.color,
+                        ..plain.clone()
+                    };
+                    let item = &self.items[at];
+                    let ranges = &self.ranges[self.spans[at].clone()];
+                    widget.record(ui, Some(&look.background), |ui| {
+                        let mut from = 0;
+                        let segment = |ui: &mut Ui, span: Range<usize>, style: &TextStyle| {
+                            if span.is_empty() {
+                                return;
+                            }
+                            let label = ui.intern(&item[span.clone()]);
+                            Text::new(label)
+                                .id(row_id(id, at).with(("segment", span.start)))
+                                .style(style)
+                                .show(ui);
+                        };
+                        for run in ranges {
+                            segment(ui, from..run.start, &plain);
+                            segment(ui, run.clone(), &highlight);
+                            from = run.end;
+                        }
+                        segment(ui, from..item.len(), &plain);
+                    });
+                }
+            });
+    }
+}
+
+fn row_id(id: WidgetId, at: usize) -> WidgetId {
+    id.with(("suggestion", at))
+}
+
+/// The provider's token at `caret`, cut from the caret's line — or that
+/// whole line where it names bytes that aren't there — as buffer bytes.
+fn token(provider: &dyn SuggestionProvider, text: &dyn TextBuffer, caret: usize) -> Range<usize> {
+    let range = line_range(text, text.byte_to_line(caret));
+    let base = range.start;
+    let slice = text.slice(range);
+    let line = slice.strip_suffix('\n').unwrap_or(&slice);
+    let token = provider.token(line, caret - base);
+    let token = if token.start <= token.end && line.get(token.clone()).is_some() {
+        token
+    } else {
+        0..line.len()
+    };
+    base + token.start..base + token.end
+}
@@ -0,0 +1,218 @@
+//! What a [`TextEdit`](crate::TextEdit) edits through. [`TextBuffer`] is
+//! the storage contract — byte-addressed reads and splices plus a line
+//! index — implemented for `String` (the common small field) and for
+//! [`TextRope`](crate::TextRope) (documents too large to re-hash, re-shape and copy whole
+//! every frame).
+//!
+//! The helpers below are the editor's grapheme and word motion lifted
+//! from `&str` onto a buffer. A contiguous buffer hands its one `&str`
+//! straight to the `unicode` helpers; anything else is walked one
+//! paragraph at a time, which is enough because no grapheme and no word
+//! run spans a line break.
+
+pub(crate) mod rope;
+
+use crate::common::hash;
+use crate::widgets::text_edit::unicode::{
+    next_grapheme_boundary, next_word_boundary, prev_grapheme_boundary, prev_word_boundary,
+    word_range_at,
+};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+/// Storage a [`TextEdit`](crate::TextEdit) can bind to.
+///
+/// Every offset is a **byte** offset into the UTF-8 text, and every range
+/// handed to [`Self::slice`] and [`Self::replace_range`] lies on char
+/// boundaries — the editor repairs its own offsets against
+/// [`Self::is_char_boundary`] before it uses them.
+///
+/// Lines are split on `'\n'` alone and include their terminator: line `i`
+/// spans `line_to_byte(i)..line_to_byte(i + 1)`, the last one running to
+/// [`Self::len`]. A buffer always has at least one line, the empty one.
+///
+/// How the editor reads the buffer depends on [`Self::as_str`]. A buffer
+/// that can lend its whole text as one `&str` is shaped as one run, as a
+/// `String` always was. One that can't is laid out a paragraph at a
+/// time in a multi-line field, and only the paragraphs in view are
+/// shaped — which is what lets [`TextRope`](crate::TextRope) hold a multi-megabyte file.
+pub trait TextBuffer: fmt::Debug {
+    /// Length in bytes.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether `byte` starts a char (or is [`Self::len`]).
+    fn is_char_boundary(&self, byte: usize) -> bool;
+
+    /// The text in `range`, borrowed where the storage allows it.
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str>;
+
+    /// Replace the text in `range` with `with`.
+    fn replace_range(&mut self, range: Range<usize>, with: &str);
+
+    /// The whole buffer as one `&str`, if it is stored contiguously.
+    /// `None` (the default) selects the paragraph-at-a-time layout.
+    fn as_str(&self) -> Option<&str> {
+        None
+    }
+
+    /// Number of chars — what [`TextEdit::max_chars`](crate::TextEdit::max_chars)
+    /// counts.
+    fn char_count(&self) -> usize {
+        self.slice(0..self.len()).chars().count()
+    }
+
+    /// Number of lines: one more than the number of `'\n'`.
+    fn line_count(&self) -> usize;
+
+    /// Byte at which `line` starts; [`Self::len`] for `line_count()`.
+    fn line_to_byte(&self, line: usize) -> usize;
+
+    /// Line holding `byte`. A line's terminator belongs to it, so the
+    /// byte just past a `'\n'` is on the next line.
+    fn byte_to_line(&self, byte: usize) -> usize;
+
+    /// An identity for the current contents, compared frame to frame to
+    /// tell whether something other than the editor changed the text —
+    /// which invalidates its undo history. Equal contents needn't share
+    /// one, but different contents must not. The default hashes the whole
+    /// text; a buffer that can count its own edits should say so here
+    /// instead, as [`TextRope`](crate::TextRope) does.
+    fn content_hash(&self) -> u64 {
+        hash::hash_str(&self.slice(0..self.len()))
+    }
+}
+
+impl TextBuffer for String {
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn is_char_boundary(&self, byte: usize) -> bool {
+        str::is_char_boundary(self, byte)
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        Cow::Borrowed(&self[range])
+    }
+
+    fn replace_range(&mut self, range: Range<usize>, with: &str) {
+        String::replace_range(self, range, with);
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        Some(self)
+    }
+
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn line_count(&self) -> usize {
+        memchr::memchr_iter(b'\n', self.as_bytes()).count() + 1
+    }
+
+    fn line_to_byte(&self, line: usize) -> usize {
+        match line.checked_sub(1) {
+            None => 0,
+            Some(nth) => memchr::memchr_iter(b'\n', self.as_bytes())
+                .nth(nth)
+                .map_or(str::len(self), |newline| newline + 1),
+        }
+    }
+
+    fn byte_to_line(&self, byte: usize) -> usize {
+        let end = byte.min(str::len(self));
+        memchr::memchr_iter(b'\n', &self.as_bytes()[..end]).count()
+    }
+
+    fn content_hash(&self) -> u64 {
+        hash::hash_str(self)
+    }
+}
+
+/// Byte range of `line`, terminator included.
+pub(super) fn line_range(text: &dyn TextBuffer, line: usize) -> Range<usize> {
+    text.line_to_byte(line)..text.line_to_byte(line + 1)
+}
+
+/// The stretch of `text` the motion helpers work in around `byte`, with
+/// the offset it starts at: all of it for a contiguous buffer, else the
+/// paragraph holding `byte`.
+fn window(text: &dyn TextBuffer, byte: usize) -> (usize, Cow<'_, str>) {
+    if let Some(all) = text.as_str() {
+        return (0, Cow::Borrowed(all));
+    }
+    let range = line_range(text, text.byte_to_line(byte));
+    (range.start, text.slice(range))
+}
+
+/// [`next_grapheme_boundary`] over a buffer.
+pub(super) fn next_grapheme(text: &dyn TextBuffer, offset: usize) -> usize {
+    if offset >= text.len() {
+        return text.len();
+    }
+    let (base, window) = window(text, offset);
+    base + next_grapheme_boundary(&window, offset - base)
+}
+
+/// [`prev_grapheme_boundary`] over a buffer. The window is the paragraph
+/// of the byte *before* `offset`, which holds the cluster being stepped
+/// over even when `offset` opens the next line.
+pub(super) fn prev_grapheme(text: &dyn TextBuffer, offset: usize) -> usize {
+    if offset == 0 {
+        return 0;
+    }
+    let (base, window) = window(text, offset - 1);
+    base + prev_grapheme_boundary(&window, offset - base)
+}
+
+/// [`next_word_boundary`] over a buffer. A paragraph that is whitespace
+/// from `from` to its end hands the skip on to the next one, so a run of
+/// blank lines is crossed the way a contiguous buffer crosses it.
+pub(super) fn next_word(text: &dyn TextBuffer, mut from: usize) -> usize {
+    loop {
+        let (base, window) = window(text, from);
+        let end = base + next_word_boundary(&window, from - base);
+        if end < base + window.len() || end >= text.len() {
+            return end;
+        }
+        from = end;
+    }
+}
+
+/// [`prev_word_boundary`] over a buffer; mirror of [`next_word`].
+pub(super) fn prev_word(text: &dyn TextBuffer, mut from: usize) -> usize {
+    loop {
+        if from == 0 {
+            return 0;
+        }
+        let (base, window) = window(text, from - 1);
+        let local = from - base;
+        let start = base + prev_word_boundary(&window, local);
+        if start > base || base == 0 || !window[..local].chars().all(char::is_whitespace) {
+            return start;
+        }
+        from = base;
+    }
+}
+
+/// [`word_range_at`] over a buffer.
+pub(super) fn word_range(text: &dyn TextBuffer, byte: usize) -> Range<usize> {
+    let byte = byte.min(text.len());
+    let (base, window) = window(text, byte);
+    let range = word_range_at(&window, byte - base);
+    base + range.start..base + range.end
+}
+
+pub(super) fn repair_offset(text: &dyn TextBuffer, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
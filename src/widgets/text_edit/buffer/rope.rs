@@ -0,0 +1,161 @@
+//! [`TextRope`]: the large-document [`TextBuffer`].
+
+use crate::widgets::text_edit::buffer::TextBuffer;
+use ropey::Rope;
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of [`TextRope::revision`] values. Process-wide rather than per
+/// rope so two ropes never share a revision unless one is a clone of the
+/// other, which is exactly when they share contents too.
+static NEXT_REVISION: AtomicU64 = AtomicU64::new(1);
+
+fn next_revision() -> u64 {
+    NEXT_REVISION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A rope-backed [`TextBuffer`] for documents too large for a `String`:
+/// a multi-megabyte log, a JSON dump, a source file.
+///
+/// Splices are `O(log n)` in the document's size, where a `String`
+/// shifts everything after the edit. Bound to a multi-line
+/// [`TextEdit`](crate::TextEdit), it is laid out one paragraph at a time
+/// and only the paragraphs in view are shaped, so the cost of a frame
+/// follows the viewport rather than the file. Identity is a revision
+/// counter bumped on every edit, so telling a host replacement from the
+/// editor's own edits never re-hashes the text.
+///
+/// A single-line field reads the rope flattened each frame — it is one
+/// line, and a `String` is the better fit there.
+///
+/// ```
+/// # use palantir::{TextBuffer, TextRope};
+/// let mut doc = TextRope::from("fn main() {}\n");
+/// doc.replace_range(3..7, "start");
+/// assert_eq!(doc.to_string(), "fn start() {}\n");
+/// assert_eq!(doc.line_count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextRope {
+    rope: Rope,
+    revision: u64,
+}
+
+impl TextRope {
+    pub fn new() -> Self {
+        Self::from_rope(Rope::new())
+    }
+
+    /// Read a whole document, e.g. straight from a file. UTF-8 only; the
+    /// reader is buffered internally, so hand it the raw `File`.
+    pub fn from_reader(reader: impl io::Read) -> io::Result<Self> {
+        Rope::from_reader(reader).map(Self::from_rope)
+    }
+
+    /// Write the document out chunk by chunk, without flattening it.
+    pub fn write_to(&self, writer: impl io::Write) -> io::Result<()> {
+        self.rope.write_to(writer)
+    }
+
+    /// The document's text in storage order, for a caller that wants it
+    /// without the copy [`ToString`] makes.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.rope.chunks()
+    }
+
+    /// Changes whenever the contents do. Clones share it with the rope
+    /// they came from until either is edited.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    fn from_rope(rope: Rope) -> Self {
+        Self {
+            rope,
+            revision: next_revision(),
+        }
+    }
+}
+
+impl Default for TextRope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for TextRope {
+    fn from(text: &str) -> Self {
+        Self::from_rope(Rope::from_str(text))
+    }
+}
+
+impl From<String> for TextRope {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl fmt::Display for TextRope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+impl PartialEq<str> for TextRope {
+    fn eq(&self, other: &str) -> bool {
+        self.rope == other
+    }
+}
+
+impl PartialEq<&str> for TextRope {
+    fn eq(&self, other: &&str) -> bool {
+        self.rope == *other
+    }
+}
+
+impl TextBuffer for TextRope {
+    fn len(&self) -> usize {
+        self.rope.len_bytes()
+    }
+
+    fn is_char_boundary(&self, byte: usize) -> bool {
+        byte == self.rope.len_bytes()
+            || (byte < self.rope.len_bytes() && (self.rope.byte(byte) & 0xc0) != 0x80)
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        self.rope.byte_slice(range).into()
+    }
+
+    fn replace_range(&mut self, range: Range<usize>, with: &str) {
+        let start = self.rope.byte_to_char(range.start);
+        if !range.is_empty() {
+            self.rope.remove(start..self.rope.byte_to_char(range.end));
+        }
+        self.rope.insert(start, with);
+        self.revision = next_revision();
+    }
+
+    fn char_count(&self) -> usize {
+        self.rope.len_chars()
+    }
+
+    fn line_count(&self) -> usize {
+        self.rope.len_lines()
+    }
+
+    fn line_to_byte(&self, line: usize) -> usize {
+        self.rope.line_to_byte(line)
+    }
+
+    fn byte_to_line(&self, byte: usize) -> usize {
+        self.rope.byte_to_line(byte.min(self.rope.len_bytes()))
+    }
+
+    fn content_hash(&self) -> u64 {
+        self.revision
+    }
+}
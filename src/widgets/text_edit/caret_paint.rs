@@ -5,9 +5,11 @@ use crate::scene::tree::paint_anims::PaintAnim;
 use crate::text::probe::Caret;
 
 #[derive(Clone, Copy, Debug)]
-pub(super) struct CaretPaint {
+pub(super) struct CaretPaint<'a> {
     pub(super) pos: Caret,
     pub(super) width: f32,
     pub(super) color: Color,
     pub(super) anim: Option<PaintAnim>,
+    /// The extra carets, drawn like the primary and blinking with it.
+    pub(super) extra: &'a [Caret],
 }
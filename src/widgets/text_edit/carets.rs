@@ -0,0 +1,252 @@
+//! Several carets at once: adding them (Alt+click, Ctrl+D, an Alt+drag
+//! column), moving every one with the keys that move the primary, and
+//! editing at every one as a single undoable edit.
+
+use crate::widgets::text_edit::buffer::{TextBuffer, line_range, word_range};
+use crate::widgets::text_edit::edit_state::{EditKind, EditParts, SelectionState};
+use crate::widgets::text_edit::editor::{Editor, Vetted};
+use std::ops::Range;
+
+impl Editor<'_> {
+    /// Run the caret motion `step` at every caret, the primary last, then
+    /// merge any that met. Motion only — an edit made from inside `step`
+    /// would move text under the carets still waiting their turn.
+    pub(super) fn each_caret(&mut self, mut step: impl FnMut(&mut Self)) {
+        if self.state.carets.is_empty() {
+            step(self);
+            return;
+        }
+        let primary = self.selection_state();
+        let mut carets = std::mem::take(&mut self.state.carets);
+        for caret in &mut carets {
+            self.state.caret = caret.caret;
+            self.state.selection = caret.selection;
+            step(self);
+            *caret = self.selection_state();
+        }
+        self.state.caret = primary.caret;
+        self.state.selection = primary.selection;
+        step(self);
+        self.state.carets = carets;
+        self.state.merge_carets();
+    }
+
+    /// Drop every caret but the primary, reporting whether there were any
+    /// — Escape's first stage when there are.
+    pub(super) fn drop_carets(&mut self) -> bool {
+        if self.state.carets.is_empty() {
+            return false;
+        }
+        self.state.carets.clear();
+        self.state.last_edit_kind = None;
+        true
+    }
+
+    /// Alt+click: a new primary caret at `at`, the old one kept beside it.
+    /// A click on a caret already there merges into it.
+    pub(super) fn add_caret(&mut self, at: usize) {
+        let previous = self.selection_state();
+        self.state.carets.push(previous);
+        self.state.caret = at;
+        self.state.selection = None;
+        self.state.last_edit_kind = None;
+        self.state.merge_carets();
+    }
+
+    /// Alt+drag: one selection per row, from `rows`' anchor byte to its
+    /// caret byte, replacing every caret there was. The last row is the
+    /// one under the pointer, and becomes the primary.
+    pub(super) fn set_column(&mut self, rows: impl IntoIterator<Item = (usize, usize)>) {
+        let mut all: Vec<(SelectionState, bool)> = rows
+            .into_iter()
+            .map(|(anchor, caret)| {
+                let selection = (anchor != caret).then_some(anchor);
+                (SelectionState { caret, selection }, false)
+            })
+            .collect();
+        let Some(last) = all.last_mut() else {
+            return;
+        };
+        last.1 = true;
+        self.state.set_carets(all);
+        self.state.last_edit_kind = None;
+    }
+
+    /// Ctrl+D: with nothing selected, select the word at the caret; with a
+    /// selection, add a caret selecting the next occurrence of its text
+    /// after the primary, wrapping past the end, and make it the primary.
+    /// Occurrences already selected are stepped over, so pressing on past
+    /// the last one changes nothing.
+    pub(super) fn add_next_occurrence(&mut self) {
+        self.state.last_edit_kind = None;
+        let Some(range) = self.state.sel_range() else {
+            let word = word_range(self.text, self.state.caret);
+            if !word.is_empty() {
+                self.state.selection = Some(word.start);
+                self.state.caret = word.end;
+            }
+            return;
+        };
+        let needle = self.text.slice(range.clone()).into_owned();
+        let taken: Vec<Range<usize>> = self
+            .state
+            .all_carets()
+            .into_iter()
+            .filter_map(|(caret, _)| caret.sel_range())
+            .collect();
+        // Line by line from the primary's, round past the end and back to
+        // it, so a press costs the lines it searches rather than a copy of
+        // the whole buffer.
+        let first = self.text.byte_to_line(range.end);
+        let lines = self.text.line_count();
+        let starts = |line| occurrences_on(self.text, line, &needle);
+        let after = (first..lines)
+            .flat_map(starts)
+            .filter(|&at| at >= range.end);
+        let before = (0..=first)
+            .flat_map(starts)
+            .filter(|&at| at + needle.len() <= range.end);
+        let found = after
+            .chain(before)
+            .map(|at| at..at + needle.len())
+            .find(|found| {
+                !taken
+                    .iter()
+                    .any(|t| t.start < found.end && found.start < t.end)
+            });
+        if let Some(found) = found {
+            self.state.carets.push(self.state.primary());
+            self.state.selection = Some(found.start);
+            self.state.caret = found.end;
+            self.state.merge_carets();
+        }
+    }
+
+    /// Make one edit at every caret, as one undoable delta.
+    ///
+    /// `piece` says what to do at each caret, given the buffer, the
+    /// caret's place in buffer order and the caret: the bytes to replace
+    /// and what with, or `None` to leave it be. The pieces are spliced
+    /// separately, last first so each lands on offsets the others haven't
+    /// moved, but recorded as a single edit spanning all of them — undo
+    /// takes every one back, and puts every caret back where it was.
+    ///
+    /// Capped like any edit, and whole or not at all: an edit that would
+    /// take the buffer past [`max_chars`](crate::TextEdit::max_chars) at
+    /// any caret is made at none of them. So is one the
+    /// [`validator`](crate::TextEdit::validator) refuses.
+    pub(super) fn edit_each<'s>(
+        &mut self,
+        kind: EditKind,
+        mut piece: impl FnMut(&dyn TextBuffer, usize, SelectionState) -> Option<(Range<usize>, &'s str)>,
+    ) {
+        self.ensure_history_matches();
+        let before = self.state.all_carets();
+        let mut pieces: Vec<(Range<usize>, &str)> = Vec::with_capacity(before.len());
+        let mut after = Vec::with_capacity(before.len());
+        // How far the pieces so far moved the text after them.
+        let mut shift = 0isize;
+        let moved = |at: usize, shift: isize| (at as isize + shift) as usize;
+        let mut floor = 0;
+        for (at, &(caret, primary)) in before.iter().enumerate() {
+            let Some((range, with)) = piece(self.text, at, caret) else {
+                let kept = SelectionState {
+                    caret: moved(caret.caret, shift),
+                    selection: caret.selection.map(|anchor| moved(anchor, shift)),
+                };
+                after.push((kept, primary));
+                continue;
+            };
+            // Carets are merged apart, but a delete can still reach back
+            // over the one before it; start where that piece ended.
+            let range = range.start.max(floor)..range.end.max(floor);
+            floor = range.end;
+            let landed = SelectionState {
+                caret: moved(range.start, shift) + with.len(),
+                selection: None,
+            };
+            after.push((landed, primary));
+            shift += with.len() as isize - range.len() as isize;
+            pieces.push((range, with));
+        }
+        let (Some(first), Some(last)) = (pieces.first(), pieces.last()) else {
+            return;
+        };
+        let span = first.0.start..last.0.end;
+        if let Some(max) = self.max_chars {
+            let count = *self
+                .state
+                .char_count
+                .get_or_insert_with(|| self.text.char_count());
+            let removed: usize = pieces
+                .iter()
+                .map(|(range, _)| self.text.slice(range.clone()).chars().count())
+                .sum();
+            let inserted: usize = pieces.iter().map(|(_, with)| with.chars().count()).sum();
+            if inserted > removed && count - removed + inserted > max {
+                return;
+            }
+        }
+        let removed = self.text.slice(span.clone());
+        let mut inserted = String::with_capacity(removed.len());
+        let mut cursor = span.start;
+        for (range, with) in &pieces {
+            inserted.push_str(&removed[cursor - span.start..range.start - span.start]);
+            inserted.push_str(with);
+            cursor = range.end;
+        }
+        let primary_after = after.iter().find(|(_, primary)| *primary).unwrap().0;
+        match self.vet(span.clone(), &inserted, primary_after.caret) {
+            Vetted::Land => {}
+            Vetted::Reject => return,
+            // A rewritten value has no carets of its own to hand back;
+            // it lands like any one-caret edit.
+            Vetted::Rewrite { value, caret } => {
+                drop(removed);
+                self.land_value(&value, caret, kind);
+                return;
+            }
+        }
+        // The carets land before the history hears of the edit, so the
+        // delta remembers them merged, as the next edit will find them.
+        let (primary_before, extras_before) = (self.state.primary(), self.state.carets.clone());
+        self.state.set_carets(after);
+        let extras_after = self.state.carets.clone();
+        self.state.record_edit(
+            EditParts {
+                start: span.start,
+                removed: &removed,
+                inserted: &inserted,
+                before: primary_before,
+                after: self.state.primary(),
+                carets: [&extras_before, &extras_after],
+            },
+            kind,
+        );
+        drop(removed);
+        for (range, with) in pieces.iter().rev() {
+            self.splice(range.clone(), with);
+        }
+        if self.state.char_count.is_some() {
+            self.state.char_count = Some(self.text.char_count());
+        }
+        self.mark_local_edit();
+        self.edited = true;
+    }
+}
+
+/// Where `needle` starts on `line`, in order. A needle spanning lines is
+/// matched against `line` and as many after it as it has line breaks.
+fn occurrences_on(text: &dyn TextBuffer, line: usize, needle: &str) -> Vec<usize> {
+    let last = line + needle.matches('\n').count();
+    if last >= text.line_count() {
+        return Vec::new();
+    }
+    let own = line_range(text, line);
+    let window = text.slice(own.start..line_range(text, last).end);
+    window
+        .match_indices(needle)
+        .map(|(at, _)| own.start + at)
+        .take_while(|&at| at < own.end)
+        .collect()
+}
@@ -0,0 +1,303 @@
+//! Code mode: the editing [`TextEdit::code`](crate::TextEdit::code) adds
+//! — indent-carrying Enter, Tab and Shift+Tab over whole lines — and the
+//! bracket match and gutter it paints.
+
+use crate::input::keyboard::{Key, KeyPress};
+use crate::layout::types::align::Align;
+use crate::primitives::rect::Rect;
+use crate::text::glyph_font::GlyphFont;
+use crate::text::run::TextRun;
+use crate::text::wrap::TextWrap;
+use crate::ui::Ui;
+use crate::widgets::text_edit::buffer::{TextBuffer, line_range};
+use crate::widgets::text_edit::edit_state::{EditKind, SelectionState};
+use crate::widgets::text_edit::editor::Editor;
+use crate::widgets::text_edit::paragraphs::Paragraphs;
+use crate::widgets::text_edit::shape_ctx::ShapeCtx;
+use std::ops::Range;
+
+/// One level of indentation. Spaces, so a line looks the same in any
+/// editor the file is opened in afterwards.
+const INDENT: &str = "    ";
+
+/// How far a bracket search walks before giving up, in lines — a match
+/// further away than this is off-screen anyway.
+const BRACKET_SCAN_LINES: usize = 1_000;
+
+const PAIRS: [(u8, u8); 3] = [(b'(', b')'), (b'[', b']'), (b'{', b'}')];
+
+/// The code-mode meaning of `press`, if it has one: `true` when it was
+/// handled here and the ordinary key handling should not see it.
+///
+/// None with extra carets: each of these edits a line, and several
+/// carets can share one. Enter falls back to a plain line break at each.
+pub(super) fn apply_key(editor: &mut Editor<'_>, press: KeyPress) -> bool {
+    if press.mods.any_command() || !editor.state.carets.is_empty() {
+        return false;
+    }
+    match press.key {
+        Key::Enter => editor.newline_with_indent(),
+        Key::Tab if press.mods.shift => editor.dedent(),
+        Key::Tab => editor.indent(),
+        _ => return false,
+    }
+    true
+}
+
+impl Editor<'_> {
+    /// Enter, carrying the caret line's indentation onto the new line and
+    /// adding a level after an opening bracket.
+    fn newline_with_indent(&mut self) {
+        let at = self.state.sel_range().map_or(self.state.caret, |r| r.start);
+        let before = self
+            .text
+            .slice(self.text.line_to_byte(self.text.byte_to_line(at))..at);
+        let indent = before.len() - before.trim_start_matches([' ', '\t']).len();
+        let mut insert = String::with_capacity(1 + indent + INDENT.len());
+        insert.push('\n');
+        insert.push_str(&before[..indent]);
+        if before.trim_end().ends_with(['(', '[', '{']) {
+            insert.push_str(INDENT);
+        }
+        drop(before);
+        self.replace_selection(&insert, EditKind::Other);
+    }
+
+    /// Tab: indent every line a multi-line selection touches, or pad the
+    /// caret out to the next indent stop.
+    fn indent(&mut self) {
+        if let Some(lines) = self.selected_lines() {
+            self.reindent(lines, |_| (0, INDENT));
+            return;
+        }
+        let at = self.state.sel_range().map_or(self.state.caret, |r| r.start);
+        let line_start = self.text.line_to_byte(self.text.byte_to_line(at));
+        let column = self.text.slice(line_start..at).chars().count();
+        let pad = INDENT.len() - column % INDENT.len();
+        self.replace_selection(&INDENT[..pad], EditKind::Other);
+    }
+
+    /// Shift+Tab: take one level of indentation off the caret's line, or
+    /// off every line a multi-line selection touches.
+    fn dedent(&mut self) {
+        let lines = self.selected_lines().unwrap_or_else(|| {
+            let line = self.text.byte_to_line(self.state.caret);
+            line..line + 1
+        });
+        self.reindent(lines, |line| {
+            let removed = if line.starts_with('\t') {
+                1
+            } else {
+                line.len() - line.trim_start_matches(' ').len()
+            };
+            (removed.min(INDENT.len()), "")
+        });
+    }
+
+    /// The lines a selection spans, when it spans more than one. A
+    /// selection ending at the very start of a line leaves that line out,
+    /// as a selection of whole lines by Shift+Down does.
+    fn selected_lines(&self) -> Option<Range<usize>> {
+        let range = self.state.sel_range()?;
+        let first = self.text.byte_to_line(range.start);
+        let mut last = self.text.byte_to_line(range.end);
+        if last > first && self.text.line_to_byte(last) == range.end {
+            last -= 1;
+        }
+        (last > first).then_some(first..last + 1)
+    }
+
+    /// Rewrite the start of each of `lines` as one undoable edit:
+    /// `change(line)` says how many leading bytes to drop and what to put
+    /// in their place. Caret and selection move with the text around
+    /// them.
+    fn reindent(&mut self, lines: Range<usize>, change: impl Fn(&str) -> (usize, &'static str)) {
+        let start = self.text.line_to_byte(lines.start);
+        let end = self.text.line_to_byte(lines.end);
+        let old = self.text.slice(start..end);
+        let mut new = String::with_capacity(old.len() + lines.len() * INDENT.len());
+        // `(line start in the buffer, bytes dropped, bytes added)`.
+        let mut edits = Vec::with_capacity(lines.len());
+        let mut line_start = start;
+        for line in old.split_inclusive('\n') {
+            let (removed, added) = change(line);
+            new.push_str(added);
+            new.push_str(&line[removed..]);
+            edits.push((line_start, removed, added.len()));
+            line_start += line.len();
+        }
+        if *old == new {
+            return;
+        }
+        drop(old);
+        let map = |at: usize| {
+            let mut shift = 0isize;
+            for &(line, removed, added) in &edits {
+                if at < line {
+                    break;
+                }
+                if at < line + removed {
+                    return (line as isize + shift) as usize + added;
+                }
+                shift += added as isize - removed as isize;
+            }
+            (at as isize + shift) as usize
+        };
+        let caret = map(self.state.caret);
+        let selection = self
+            .state
+            .selection
+            .map(map)
+            .filter(|&anchor| anchor != caret);
+        self.replace_range_to(
+            start..end,
+            &new,
+            EditKind::Other,
+            SelectionState { caret, selection },
+        );
+    }
+}
+
+/// The bracket next to `caret` and its partner, as the byte of each —
+/// the one just before the caret first, then the one just after.
+pub(super) fn matching_bracket(text: &dyn TextBuffer, caret: usize) -> Option<(usize, usize)> {
+    [caret.checked_sub(1), Some(caret)]
+        .into_iter()
+        .flatten()
+        .find_map(|at| partner(text, at).map(|other| (at, other)))
+}
+
+/// Block-local boxes of the bracket by `caret` and its partner, if it has
+/// one.
+pub(super) fn bracket_rects(
+    ui: &mut Ui,
+    ctx: &ShapeCtx,
+    text: &dyn TextBuffer,
+    paragraphs: &mut Paragraphs,
+    caret: usize,
+) -> Option<[Rect; 2]> {
+    let pair = matching_bracket(text, caret)?;
+    let ends = [pair.0, pair.1].map(|byte| (byte, byte + 1));
+    let carets = if Paragraphs::applies(ctx, text) {
+        ends.map(|(start, end)| {
+            (
+                paragraphs.caret_at(ui, ctx, text, start),
+                paragraphs.caret_at(ui, ctx, text, end),
+            )
+        })
+    } else {
+        let flat = text.slice(0..text.len());
+        let probe = ui.probe_text(ctx.run(&flat));
+        ends.map(|(start, end)| (probe.caret_at(start), probe.caret_at(end)))
+    };
+    Some(
+        carets.map(|(start, end)| {
+            Rect::new(start.x, start.y_top, end.x - start.x, start.line_height)
+        }),
+    )
+}
+
+fn byte_at(text: &dyn TextBuffer, at: usize) -> Option<u8> {
+    (at < text.len() && text.is_char_boundary(at) && text.is_char_boundary(at + 1))
+        .then(|| text.slice(at..at + 1).as_bytes()[0])
+}
+
+/// Where the bracket at `at` closes or opens, counting nesting of its own
+/// kind only.
+fn partner(text: &dyn TextBuffer, at: usize) -> Option<usize> {
+    let byte = byte_at(text, at)?;
+    let (open, close, forward) = PAIRS.iter().find_map(|&(open, close)| {
+        (byte == open)
+            .then_some((open, close, true))
+            .or((byte == close).then_some((open, close, false)))
+    })?;
+    let first = text.byte_to_line(at);
+    let mut depth = 0usize;
+    let lines: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(first..text.line_count().min(first + BRACKET_SCAN_LINES))
+    } else {
+        Box::new((first.saturating_sub(BRACKET_SCAN_LINES)..=first).rev())
+    };
+    for line in lines {
+        let range = line_range(text, line);
+        let base = range.start;
+        let slice = text.slice(range);
+        let bytes = slice.as_bytes();
+        let walk: Box<dyn Iterator<Item = usize>> = if forward {
+            Box::new((at.max(base) - base)..bytes.len())
+        } else {
+            Box::new((0..(at + 1).min(base + bytes.len()) - base).rev())
+        };
+        for i in walk {
+            if bytes[i] == open {
+                if forward {
+                    depth += 1;
+                } else {
+                    depth -= 1;
+                }
+            } else if bytes[i] == close {
+                if forward {
+                    depth -= 1;
+                } else {
+                    depth += 1;
+                }
+            } else {
+                continue;
+            }
+            if depth == 0 {
+                return Some(base + i);
+            }
+        }
+    }
+    None
+}
+
+/// The code gutter's measure for a document of `lines` lines: room for
+/// its widest line number, and the advance of one digit.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Gutter {
+    pub(super) width: f32,
+    pub(super) digit: f32,
+    pub(super) padding: f32,
+}
+
+impl Gutter {
+    pub(super) fn measure(ui: &mut Ui, font: GlyphFont, lines: usize, padding: f32) -> Self {
+        let digit = ui
+            .probe_text(TextRun {
+                text: "0",
+                font,
+                wrap: TextWrap::SingleLine,
+                align: Align::LEFT,
+                max_width_px: None,
+            })
+            .caret_at(1)
+            .x;
+        let digits = lines.max(1).ilog10() as f32 + 1.0;
+        Self {
+            width: digits.max(2.0) * digit + 2.0 * padding,
+            digit,
+            padding,
+        }
+    }
+
+    /// Where the number of a line sits, right-aligned against the text.
+    pub(super) fn number_x(&self, digits: usize) -> f32 {
+        self.width - self.padding - digits as f32 * self.digit
+    }
+}
+
+/// `n` in decimal, written into `buf` — the gutter numbers every visible
+/// line every frame, and this keeps that from allocating.
+pub(super) fn decimal(mut n: usize, buf: &mut [u8; 20]) -> &str {
+    let mut at = buf.len();
+    loop {
+        at -= 1;
+        buf[at] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    std::str::from_utf8(&buf[at..]).unwrap()
+}
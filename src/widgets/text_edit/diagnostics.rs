@@ -0,0 +1,229 @@
+//! Diagnostics for [`TextEdit`](crate::TextEdit): a [`DiagnosticProvider`]
+//! flags ranges of the buffer — misspellings, lint findings, what a server
+//! said about the value — and the field draws a squiggle under each and
+//! offers its suggested replacements in the right-click menu.
+
+use crate::primitives::color::Color;
+use crate::primitives::rect::Rect;
+use crate::shape::Shape;
+use crate::ui::Ui;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::paragraphs::{Paragraphs, paragraph};
+use crate::widgets::text_edit::shape_ctx::ShapeCtx;
+use crate::widgets::theme::diagnostics::DiagnosticsTheme;
+use glam::Vec2;
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+/// Flags ranges of a [`TextEdit`](crate::TextEdit)'s buffer.
+///
+/// Asked about the whole value whenever it changes, or when
+/// [`Self::revision`] does — so a checker that runs elsewhere, a language
+/// server or a form's backend, can report what it has, and bump its
+/// revision when new results land.
+///
+/// ```
+/// # use palantir::{Diagnostic, DiagnosticProvider, Severity};
+/// #[derive(Debug)]
+/// struct NoTabs;
+///
+/// impl DiagnosticProvider for NoTabs {
+///     fn check(&self, text: &str, out: &mut Vec<Diagnostic>) {
+///         for (at, _) in text.match_indices('\t') {
+///             out.push(Diagnostic {
+///                 range: at..at + 1,
+///                 severity: Severity::Warning,
+///                 message: "Tab character".into(),
+///                 suggestions: vec!["    ".into()],
+///             });
+///         }
+///     }
+/// }
+/// ```
+pub trait DiagnosticProvider: fmt::Debug {
+    /// Push what is wrong with `text`, the whole buffer, onto `out`, in
+    /// any order. A range that doesn't fall on char boundaries inside
+    /// `text` is ignored.
+    fn check(&self, text: &str, out: &mut Vec<Diagnostic>);
+
+    /// Changes whenever the same text would now be flagged differently —
+    /// results that arrived later, a dictionary that grew a word.
+    fn revision(&self) -> u64 {
+        0
+    }
+}
+
+/// One flagged range — see [`DiagnosticProvider::check`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// Bytes of the buffer the squiggle runs under, and that a suggestion
+    /// replaces.
+    pub range: Range<usize>,
+    pub severity: Severity,
+    /// What is wrong, written for the user: it heads the right-click menu.
+    pub message: Cow<'static, str>,
+    /// Replacements for the range, best first, offered in the right-click
+    /// menu.
+    pub suggestions: Vec<String>,
+}
+
+/// How bad a [`Diagnostic`] is, which picks its squiggle's colour from
+/// [`crate::Theme::diagnostics`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub(super) fn color(self, theme: &DiagnosticsTheme) -> Color {
+        match self {
+            Self::Error => theme.error,
+            Self::Warning => theme.warning,
+            Self::Info => theme.info,
+        }
+    }
+}
+
+/// What the provider said about the buffer, and where it paints.
+#[derive(Clone, Default, Debug)]
+pub(super) struct DiagnosticState {
+    /// By start.
+    items: Vec<Diagnostic>,
+    /// What `items` answers: the buffer's hash and the provider's revision.
+    asked: Option<(u64, u64)>,
+    /// Block-local, refilled every frame for what is in view.
+    rects: Vec<(Rect, Severity)>,
+    /// The diagnostic the right-click menu was opened over, if it was.
+    /// A copy, so the menu keeps offering it while the buffer changes
+    /// under it.
+    pub(super) menu: Option<Diagnostic>,
+}
+
+impl DiagnosticState {
+    pub(super) fn rects(&self) -> &[(Rect, Severity)] {
+        &self.rects
+    }
+
+    /// Ask the provider about `text` if it, or the provider's revision,
+    /// changed since it was last asked. `hash` is the buffer's.
+    pub(super) fn refresh(
+        &mut self,
+        provider: &dyn DiagnosticProvider,
+        text: &dyn TextBuffer,
+        hash: u64,
+    ) {
+        let asked = (hash, provider.revision());
+        if self.asked == Some(asked) {
+            return;
+        }
+        self.asked = Some(asked);
+        self.items.clear();
+        // A rope is flattened for the provider; a `String` is handed over
+        // as is.
+        match text.as_str() {
+            Some(flat) => provider.check(flat, &mut self.items),
+            None => provider.check(&text.slice(0..text.len()), &mut self.items),
+        }
+        self.items.retain(|d| {
+            let Range { start, end } = d.range;
+            start <= end
+                && end <= text.len()
+                && text.is_char_boundary(start)
+                && text.is_char_boundary(end)
+        });
+        self.items.sort_by_key(|d| d.range.start);
+    }
+
+    /// The diagnostic under byte `at`, the innermost where they overlap.
+    pub(super) fn at(&self, at: usize) -> Option<&Diagnostic> {
+        self.items
+            .iter()
+            .filter(|d| d.range.contains(&at) || (d.range.end == at && !d.range.is_empty()))
+            .min_by_key(|d| d.range.len())
+    }
+
+    /// The squiggles for a buffer shaped as one run, `view` being the
+    /// block-local band on show.
+    pub(super) fn rects_flat(&mut self, ui: &mut Ui, ctx: &ShapeCtx, text: &str, view: Range<f32>) {
+        self.rects.clear();
+        if self.items.is_empty() {
+            return;
+        }
+        let probe = ui.probe_text(ctx.run(text));
+        let first = probe.byte_at(0.0, view.start.max(0.0));
+        let last = probe.byte_at(f32::MAX, view.end);
+        for d in &self.items {
+            if d.range.start > last {
+                break;
+            }
+            if d.range.end < first {
+                continue;
+            }
+            probe.selection_rects(d.range.clone(), |rect| self.rects.push((rect, d.severity)));
+        }
+    }
+
+    /// The squiggles on paragraphs `lines`.
+    pub(super) fn rects_paragraphs(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        paragraphs: &mut Paragraphs,
+        lines: Range<usize>,
+    ) {
+        self.rects.clear();
+        for line in lines {
+            let start = text.line_to_byte(line);
+            let paragraph = paragraph(text, line);
+            let end = start + paragraph.len();
+            let on_line = self
+                .items
+                .iter()
+                .take_while(|d| d.range.start <= end)
+                .filter(|d| d.range.end > start);
+            let mut on_line = on_line.peekable();
+            if on_line.peek().is_none() {
+                continue;
+            }
+            let top = paragraphs.top(line);
+            let probe = ui.probe_text(ctx.run(&paragraph));
+            for d in on_line {
+                let local =
+                    d.range.start.saturating_sub(start)..(d.range.end - start).min(paragraph.len());
+                probe.selection_rects(local, |rect| {
+                    self.rects.push((
+                        Rect {
+                            min: rect.min + Vec2::new(0.0, top),
+                            ..rect
+                        },
+                        d.severity,
+                    ));
+                });
+            }
+        }
+    }
+}
+
+/// A wave along the bottom of `rect`, a quadratic Bézier per half
+/// wavelength, crests alternating above and below its midline.
+pub(super) fn squiggle(ui: &mut Ui, rect: Rect, color: Color, theme: &DiagnosticsTheme) {
+    let half = (theme.wavelength * 0.5).max(1.0);
+    let y = rect.max().y - theme.amplitude - theme.thickness * 0.5;
+    let mut x = rect.min.x;
+    let mut crest = -1.0;
+    while x < rect.max().x {
+        let to = (x + half).min(rect.max().x);
+        // A quadratic's peak is half way to its control point.
+        let control = Vec2::new((x + to) * 0.5, y + crest * 2.0 * theme.amplitude);
+        ui.add_shape(
+            Shape::quadratic_bezier(Vec2::new(x, y), control, Vec2::new(to, y), theme.thickness)
+                .brush(color),
+        );
+        x = to;
+        crest = -crest;
+    }
+}
@@ -2,9 +2,13 @@
 //! history it retains. [`Editor`](super::editor::Editor) is the
 //! behaviour over this data.
 
-use crate::common::hash;
 use crate::text::key::TextShapeKey;
+use crate::widgets::text_edit::buffer::{TextBuffer, repair_offset};
+use crate::widgets::text_edit::find::SpliceLog;
+use crate::widgets::text_edit::paragraphs::LineEdit;
+use glam::Vec2;
 use std::collections::VecDeque;
+use std::ops::Range;
 
 /// Semantic state for the host-owned text buffer.
 ///
@@ -25,6 +29,15 @@ pub(super) struct EditState {
     /// all three together or a drag that outlives a host edit selects
     /// from a stale boundary.
     pub(super) drag_anchor: Option<usize>,
+    /// The carets beyond the primary one, each with its own anchor, in
+    /// buffer order and apart from one another and from the primary —
+    /// [`Self::merge_carets`] keeps them so. Empty for a single caret,
+    /// which is every field that never saw Alt+click, Alt+drag or Ctrl+D.
+    pub(super) carets: Vec<SelectionState>,
+    /// Where an Alt+drag column selection was pressed, in the unscrolled
+    /// block-local frame the hit-test works in. A point rather than a
+    /// byte, so [`Self::normalize`] has nothing to repair in it.
+    pub(super) column_anchor: Option<Vec2>,
     pub(super) undo: VecDeque<EditDelta>,
     pub(super) redo: Vec<EditDelta>,
     /// Kind of the most recent recorded edit, used to coalesce
@@ -35,6 +48,14 @@ pub(super) struct EditState {
     pub(super) expected_hash: Option<u64>,
     pub(super) local_edit_pending: bool,
     pub(super) char_count: Option<usize>,
+    /// Line splices made since the paragraph layout last synced, which it
+    /// replays rather than re-measuring the document. Here because the
+    /// editor is what splices, and this is the state it holds.
+    pub(super) line_edits: Vec<LineEdit>,
+    /// Splices made since the find bar last synced, which it replays to
+    /// update its matches. `None` while the bar is closed, and then
+    /// nothing is logged.
+    pub(super) splices: Option<SpliceLog>,
 }
 
 /// Caret + anchor as one comparable unit. An [`EditDelta`] stores the
@@ -46,6 +67,19 @@ pub(super) struct SelectionState {
     pub(super) selection: Option<usize>,
 }
 
+impl SelectionState {
+    /// The selected bytes, sorted; `None` for a bare caret.
+    pub(super) fn sel_range(self) -> Option<Range<usize>> {
+        let a = self.selection?;
+        Some(a.min(self.caret)..a.max(self.caret))
+    }
+
+    /// [`Self::sel_range`], or the empty range at the caret.
+    pub(super) fn range(self) -> Range<usize> {
+        self.sel_range().unwrap_or(self.caret..self.caret)
+    }
+}
+
 /// One edit as its parts, before the history decides whether to keep it.
 ///
 /// The text is borrowed — from the buffer for `removed`, from the caller
@@ -60,6 +94,9 @@ pub(super) struct EditParts<'a> {
     pub(super) inserted: &'a str,
     pub(super) before: SelectionState,
     pub(super) after: SelectionState,
+    /// The extra carets either side of the edit, `[before, after]` —
+    /// both empty for an edit made at one caret.
+    pub(super) carets: [&'a [SelectionState]; 2],
 }
 
 /// One undoable buffer edit. Built here — from the borrowed [`EditParts`]
@@ -74,6 +111,16 @@ pub(super) struct EditDelta {
     pub(super) inserted: String,
     pub(super) before: SelectionState,
     pub(super) after: SelectionState,
+    /// The extra carets either side of an edit made at several, so undo
+    /// puts every one of them back. Boxed: the common delta has none.
+    pub(super) carets: Option<Box<CaretSets>>,
+}
+
+/// [`EditDelta::carets`]: the extra carets before and after the edit.
+#[derive(Clone, Debug)]
+pub(super) struct CaretSets {
+    pub(super) before: Vec<SelectionState>,
+    pub(super) after: Vec<SelectionState>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -97,17 +144,40 @@ impl EditDelta {
             inserted: parts.inserted.to_owned(),
             before: parts.before,
             after: parts.after,
+            carets: (parts.carets != [&[], &[]]).then(|| {
+                Box::new(CaretSets {
+                    before: parts.carets[0].to_vec(),
+                    after: parts.carets[1].to_vec(),
+                })
+            }),
         }
     }
 
+    /// The extra carets this delta leaves behind it.
+    fn carets_after(&self) -> &[SelectionState] {
+        self.carets.as_ref().map_or(&[], |sets| &sets.after)
+    }
+
     /// Extend this delta with an edit that abuts it, reporting whether it
     /// did. Takes the parts rather than a built [`EditDelta`] so the caller
     /// pays for the strings only when this says no.
     fn coalesce(&mut self, next: EditParts<'_>, kind: EditKind) -> bool {
-        if self.after != next.before {
+        if self.after != next.before || self.carets_after() != next.carets[0] {
             return false;
         }
+        // An edit made at several carets is one span over all of them, so
+        // the next keystroke's span overlaps this one's rather than
+        // abutting it the way a single caret's does.
+        let several = self.carets.is_some() || !next.carets[0].is_empty();
         let merged = match kind {
+            EditKind::Typing | EditKind::Delete
+                if several
+                    && next.start <= self.start + self.inserted.len()
+                    && next.start + next.removed.len() >= self.start =>
+            {
+                self.absorb(next);
+                true
+            }
             EditKind::Typing
                 if next.removed.is_empty() && next.start == self.start + self.inserted.len() =>
             {
@@ -135,9 +205,41 @@ impl EditDelta {
         };
         if merged {
             self.after = next.after;
+            if let Some(sets) = &mut self.carets {
+                sets.after.clear();
+                sets.after.extend_from_slice(next.carets[1]);
+            }
         }
         merged
     }
+
+    /// Fold in `next`, an edit whose span overlaps or touches the text
+    /// this one inserted. What `next` removed outside that text is buffer
+    /// this delta never touched, so it joins `removed`; what it left of
+    /// that text stays in `inserted` around its own.
+    fn absorb(&mut self, next: EditParts<'_>) {
+        let (start, end) = (self.start, self.start + self.inserted.len());
+        let (next_start, next_end) = (next.start, next.start + next.removed.len());
+        let mut removed = String::with_capacity(self.removed.len() + next.removed.len());
+        if next_start < start {
+            removed.push_str(&next.removed[..start - next_start]);
+        }
+        removed.push_str(&self.removed);
+        if next_end > end {
+            removed.push_str(&next.removed[end - next_start..]);
+        }
+        let mut inserted = String::with_capacity(self.inserted.len() + next.inserted.len());
+        if next_start > start {
+            inserted.push_str(&self.inserted[..next_start - start]);
+        }
+        inserted.push_str(next.inserted);
+        if next_end < end {
+            inserted.push_str(&self.inserted[next_end - start..]);
+        }
+        self.start = start.min(next_start);
+        self.removed = removed;
+        self.inserted = inserted;
+    }
 }
 
 impl EditState {
@@ -184,6 +286,10 @@ impl EditState {
             self.redo.clear();
             self.last_edit_kind = None;
             self.char_count = None;
+            if let Some(log) = &mut self.splices {
+                log.splices.clear();
+                log.broken = true;
+            }
         }
         self.expected_hash = Some(text_hash);
     }
@@ -202,7 +308,7 @@ impl EditState {
     /// A pending local edit means we moved the buffer ourselves and the
     /// hash it settles at is not known until paint, which adopts it —
     /// so there is nothing to reconcile against here.
-    pub(super) fn reconcile_before_edit(&mut self, text: &str) {
+    pub(super) fn reconcile_before_edit(&mut self, text: &dyn TextBuffer) {
         if self.local_edit_pending {
             return;
         }
@@ -217,21 +323,97 @@ impl EditState {
     /// run — so for exactly the content that hashes to zero, a raw hash
     /// here would compare unequal against the probe's for the *same*
     /// buffer and silently wipe the undo stack.
-    pub(super) fn text_hash(text: &str) -> u64 {
-        TextShapeKey::content_hash(hash::hash_str(text))
+    ///
+    /// A `String`'s [`TextBuffer::content_hash`] is the `hash_str` the
+    /// probe takes, so the two agree on it; any other buffer is never
+    /// shaped as one run, and the paint path mints its hash here too.
+    pub(super) fn text_hash(text: &dyn TextBuffer) -> u64 {
+        TextShapeKey::content_hash(text.content_hash())
     }
 
-    pub(super) fn sel_range(&self) -> Option<std::ops::Range<usize>> {
-        let a = self.selection?;
-        Some(a.min(self.caret)..a.max(self.caret))
+    pub(super) fn sel_range(&self) -> Option<Range<usize>> {
+        self.primary().sel_range()
+    }
+
+    /// The primary caret and its anchor.
+    pub(super) fn primary(&self) -> SelectionState {
+        SelectionState {
+            caret: self.caret,
+            selection: self.selection,
+        }
+    }
+
+    /// Every caret, the primary among them, in buffer order — each with
+    /// whether it is the primary.
+    pub(super) fn all_carets(&self) -> Vec<(SelectionState, bool)> {
+        let mut all = Vec::with_capacity(self.carets.len() + 1);
+        all.push((self.primary(), true));
+        all.extend(self.carets.iter().map(|&caret| (caret, false)));
+        all.sort_by_key(|(caret, _)| caret.range().start);
+        all
     }
 
-    pub(super) fn repair_offset(text: &str, offset: usize) -> usize {
-        let mut offset = offset.min(text.len());
-        while !text.is_char_boundary(offset) {
-            offset -= 1;
+    /// Take `all` as every caret: the one flagged primary becomes it, the
+    /// rest the extras, merged where they now overlap.
+    pub(super) fn set_carets(&mut self, all: Vec<(SelectionState, bool)>) {
+        self.adopt_carets(all);
+        self.merge_carets();
+    }
+
+    fn adopt_carets(&mut self, all: Vec<(SelectionState, bool)>) {
+        self.carets.clear();
+        for (caret, primary) in all {
+            if primary {
+                self.caret = caret.caret;
+                self.selection = caret.selection;
+            } else {
+                self.carets.push(caret);
+            }
+        }
+    }
+
+    /// Sort the extra carets and fold together any two that overlap, or
+    /// that coincide — a bare caret at a selection's edge included — so
+    /// no byte is edited twice by one keystroke. A merge that takes in
+    /// the primary stays the primary.
+    pub(super) fn merge_carets(&mut self) {
+        if self.carets.is_empty() {
+            return;
         }
-        offset
+        let mut merged: Vec<(SelectionState, bool)> = Vec::with_capacity(self.carets.len() + 1);
+        for (caret, primary) in self.all_carets() {
+            let Some((last, last_primary)) = merged.last_mut() else {
+                merged.push((caret, primary));
+                continue;
+            };
+            let (a, b) = (last.range(), caret.range());
+            let touching = b.start == a.end && (a.is_empty() || b.is_empty());
+            if b.start < a.end || touching {
+                let union = a.start..a.end.max(b.end);
+                let kept = if primary { caret } else { *last };
+                let backward = kept.selection.is_some_and(|anchor| anchor > kept.caret);
+                *last = if union.is_empty() {
+                    SelectionState {
+                        caret: union.start,
+                        selection: None,
+                    }
+                } else if backward {
+                    SelectionState {
+                        caret: union.start,
+                        selection: Some(union.end),
+                    }
+                } else {
+                    SelectionState {
+                        caret: union.end,
+                        selection: Some(union.start),
+                    }
+                };
+                *last_primary |= primary;
+            } else {
+                merged.push((caret, primary));
+            }
+        }
+        self.adopt_carets(merged);
     }
 
     /// Repair every persisted byte offset against the current host-owned
@@ -239,17 +421,21 @@ impl EditState {
     /// UTF-8 code point walk backward to its start (at most three bytes).
     /// Then collapse an empty selection. Safe both before input, when the
     /// application may have replaced the buffer, and after our mutations.
-    pub(super) fn normalize(&mut self, text: &str) {
-        self.caret = Self::repair_offset(text, self.caret);
-        self.selection = self
-            .selection
-            .map(|offset| Self::repair_offset(text, offset));
-        self.drag_anchor = self
-            .drag_anchor
-            .map(|offset| Self::repair_offset(text, offset));
+    pub(super) fn normalize(&mut self, text: &dyn TextBuffer) {
+        self.caret = repair_offset(text, self.caret);
+        self.selection = self.selection.map(|offset| repair_offset(text, offset));
+        self.drag_anchor = self.drag_anchor.map(|offset| repair_offset(text, offset));
         if self.selection == Some(self.caret) {
             self.selection = None;
         }
+        for caret in &mut self.carets {
+            caret.caret = repair_offset(text, caret.caret);
+            caret.selection = caret
+                .selection
+                .map(|offset| repair_offset(text, offset))
+                .filter(|&anchor| anchor != caret.caret);
+        }
+        self.merge_carets();
     }
 }
 
@@ -275,12 +461,12 @@ mod tests {
         for text in ["", "a", "hello world", "\u{1f600} multi\nline"] {
             let probe = TextShapeKey::unbounded(hash::hash_str(text), GlyphFont::new(16.0));
             assert_eq!(
-                EditState::text_hash(text),
+                EditState::text_hash(&text.to_owned()),
                 probe.text_hash,
                 "input path disagrees with the probe for {text:?}",
             );
             assert_ne!(
-                EditState::text_hash(text),
+                EditState::text_hash(&text.to_owned()),
                 0,
                 "{text:?} minted the invalid tag"
             );
@@ -1,21 +1,41 @@
 //! One frame's semantic editing session over the host-owned buffer.
 
+use crate::widgets::text_edit::buffer::{
+    TextBuffer, next_grapheme, next_word, prev_grapheme, prev_word,
+};
 use crate::widgets::text_edit::edit_state::{
     EditDelta, EditKind, EditParts, EditState, SelectionState,
 };
-use crate::widgets::text_edit::unicode::{
-    next_grapheme_boundary, next_word_boundary, prev_grapheme_boundary, prev_word_boundary,
-    sanitize_single_line,
-};
+use crate::widgets::text_edit::find::Splice;
+use crate::widgets::text_edit::paragraphs::LineEdit;
+use crate::widgets::text_edit::unicode::sanitize_single_line;
+use crate::widgets::text_edit::validate::{Validator, Verdict, caret_from_end, changed_span};
 use std::borrow::Cow;
+use std::ops::Range;
+
+/// What [`Editor::vet`] makes of a pending edit.
+#[derive(Debug)]
+pub(super) enum Vetted {
+    Land,
+    Reject,
+    /// Land `value` as the whole buffer instead, the caret at `caret`.
+    Rewrite {
+        value: String,
+        caret: usize,
+    },
+}
 
 /// One frame's semantic editing session.
 #[derive(Debug)]
 pub(super) struct Editor<'a> {
-    pub(super) text: &'a mut String,
+    pub(super) text: &'a mut dyn TextBuffer,
     pub(super) state: &'a mut EditState,
     pub(super) multiline: bool,
-    max_chars: Option<usize>,
+    /// Code mode: Enter carries indentation, Tab indents.
+    pub(super) code: bool,
+    pub(super) max_chars: Option<usize>,
+    /// Asked about every edit before it lands — see [`Self::vet`].
+    pub(super) validator: Option<&'a dyn Validator>,
     history_checked: bool,
     /// The buffer was mutated this session (typing, delete, paste,
     /// cut, undo/redo). Set by the mutation choke points, so it's
@@ -25,7 +45,7 @@ pub(super) struct Editor<'a> {
 
 impl<'a> Editor<'a> {
     pub(super) fn new(
-        text: &'a mut String,
+        text: &'a mut dyn TextBuffer,
         state: &'a mut EditState,
         multiline: bool,
         max_chars: Option<usize>,
@@ -34,17 +54,16 @@ impl<'a> Editor<'a> {
             text,
             state,
             multiline,
+            code: false,
             max_chars,
+            validator: None,
             history_checked: false,
             edited: false,
         }
     }
 
-    fn selection_state(&self) -> SelectionState {
-        SelectionState {
-            caret: self.state.caret,
-            selection: self.state.selection,
-        }
+    pub(super) fn selection_state(&self) -> SelectionState {
+        self.state.primary()
     }
 
     /// Reconcile the history against the buffer once per editor.
@@ -53,7 +72,7 @@ impl<'a> Editor<'a> {
     /// of the rule: the `Editor` is built per frame and several entry
     /// points below open with this, so the first one pays and the rest
     /// are free. The rule itself is [`EditState::reconcile_before_edit`].
-    fn ensure_history_matches(&mut self) {
+    pub(super) fn ensure_history_matches(&mut self) {
         if self.history_checked {
             return;
         }
@@ -61,29 +80,126 @@ impl<'a> Editor<'a> {
         self.state.reconcile_before_edit(self.text);
     }
 
-    fn mark_local_edit(&mut self) {
+    pub(super) fn mark_local_edit(&mut self) {
         self.state.local_edit_pending = true;
     }
 
-    fn replace_range(&mut self, range: std::ops::Range<usize>, replacement: &str, kind: EditKind) {
+    /// The one place the buffer is spliced, so the paragraph layout's
+    /// line table hears of every edit. Logged only where that layout is
+    /// in use — a multi-line field over a non-contiguous buffer — since
+    /// nothing else drains the log. The find bar's log is the same: kept
+    /// only while the bar is open.
+    pub(super) fn splice(&mut self, range: Range<usize>, replacement: &str) {
+        if let Some(log) = &mut self.state.splices {
+            log.splices.push(Splice {
+                start: range.start,
+                removed: range.len(),
+                inserted: replacement.len(),
+            });
+        }
+        let logged = (self.multiline && self.text.as_str().is_none())
+            .then(|| LineEdit::before(self.text, range.clone()));
+        let inserted_end = range.start + replacement.len();
+        self.text.replace_range(range, replacement);
+        if let Some(edit) = logged {
+            self.state
+                .line_edits
+                .push(edit.after(self.text, inserted_end));
+        }
+    }
+
+    pub(super) fn replace_range(&mut self, range: Range<usize>, replacement: &str, kind: EditKind) {
+        // Where the caret lands, derived rather than read back after the
+        // splice: the history wants it *before* the buffer moves.
+        let after = SelectionState {
+            caret: range.start + replacement.len(),
+            selection: None,
+        };
+        self.replace_range_to(range, replacement, kind, after);
+    }
+
+    /// [`Self::replace_range`], leaving caret and selection at `after`
+    /// rather than collapsed past the replacement.
+    ///
+    /// One range, so one caret: any extra carets are dropped, having been
+    /// placed against text this is about to move.
+    pub(super) fn replace_range_to(
+        &mut self,
+        range: Range<usize>,
+        replacement: &str,
+        kind: EditKind,
+        after: SelectionState,
+    ) {
         debug_assert!(self.text.is_char_boundary(range.start));
         debug_assert!(self.text.is_char_boundary(range.end));
         debug_assert!(range.start <= range.end);
-        if &self.text[range.clone()] == replacement {
-            self.state.caret = range.start + replacement.len();
-            self.state.selection = None;
+        self.state.carets.clear();
+        if self.text.slice(range.clone()) == replacement {
+            self.state.caret = after.caret;
+            self.state.selection = after.selection;
             self.state.last_edit_kind = None;
             return;
         }
-        self.ensure_history_matches();
-        let before = self.selection_state();
-        // Where the caret lands, derived rather than read back after the
-        // splice: the history wants it *before* the buffer moves.
+        match self.vet(range.clone(), replacement, after.caret) {
+            Vetted::Land => self.land(range, replacement, kind, after),
+            Vetted::Reject => self.state.last_edit_kind = None,
+            Vetted::Rewrite { value, caret } => self.land_value(&value, caret, kind),
+        }
+    }
+
+    /// What the validator says about the value splicing `replacement`
+    /// over `range` would leave, `caret` being where the edit leaves the
+    /// caret. Lands as it is without one, or over a buffer that isn't
+    /// contiguous (see [`TextEdit::validator`](crate::TextEdit::validator)).
+    pub(super) fn vet(&self, range: Range<usize>, replacement: &str, caret: usize) -> Vetted {
+        let (Some(validator), Some(all)) = (self.validator, self.text.as_str()) else {
+            return Vetted::Land;
+        };
+        let mut proposed = String::with_capacity(all.len() - range.len() + replacement.len());
+        proposed.push_str(&all[..range.start]);
+        proposed.push_str(replacement);
+        proposed.push_str(&all[range.end..]);
+        match validator.check(&proposed) {
+            Verdict::Accept | Verdict::Invalid(_) => Vetted::Land,
+            Verdict::Reject => Vetted::Reject,
+            Verdict::Replace(value) => Vetted::Rewrite {
+                caret: caret_from_end(&proposed, caret, &value),
+                value,
+            },
+        }
+    }
+
+    /// Land the whole `value` a validator rewrote an edit to, with the
+    /// caret at `caret` — recorded as the one span it changes, so undo
+    /// and the line table see an edit the size of the change.
+    pub(super) fn land_value(&mut self, value: &str, caret: usize, kind: EditKind) {
+        let (old, new) = changed_span(&self.text.slice(0..self.text.len()), value);
+        self.state.carets.clear();
         let after = SelectionState {
-            caret: range.start + replacement.len(),
+            caret,
             selection: None,
         };
-        let removed = &self.text[range.clone()];
+        if old.is_empty() && new.is_empty() {
+            self.state.caret = after.caret;
+            self.state.selection = after.selection;
+            self.state.last_edit_kind = None;
+            return;
+        }
+        self.land(old, &value[new], kind, after);
+    }
+
+    /// Make the edit, past every check: record it, splice it, move the
+    /// caret.
+    fn land(
+        &mut self,
+        range: Range<usize>,
+        replacement: &str,
+        kind: EditKind,
+        after: SelectionState,
+    ) {
+        self.ensure_history_matches();
+        let before = self.selection_state();
+        let removed = self.text.slice(range.clone());
         let counts_chars = self.state.char_count.is_some();
         let removed_chars = counts_chars.then(|| removed.chars().count());
         let inserted_chars = counts_chars.then(|| replacement.chars().count());
@@ -93,14 +209,16 @@ impl<'a> Editor<'a> {
         self.state.record_edit(
             EditParts {
                 start: range.start,
-                removed,
+                removed: &removed,
                 inserted: replacement,
                 before,
                 after,
+                carets: [&[], &[]],
             },
             kind,
         );
-        self.text.replace_range(range, replacement);
+        drop(removed);
+        self.splice(range, replacement);
         self.state.caret = after.caret;
         self.state.selection = after.selection;
         if let Some(count) = &mut self.state.char_count {
@@ -120,11 +238,16 @@ impl<'a> Editor<'a> {
         debug_assert!(end <= self.text.len());
         debug_assert!(self.text.is_char_boundary(delta.start));
         debug_assert!(self.text.is_char_boundary(end));
-        self.text.replace_range(delta.start..end, replacement);
+        self.splice(delta.start..end, replacement);
         self.state.caret = selection.caret;
         self.state.selection = selection.selection;
+        self.state.carets.clear();
+        if let Some(sets) = &delta.carets {
+            let carets = if undo { &sets.before } else { &sets.after };
+            self.state.carets.extend_from_slice(carets);
+        }
         if self.state.char_count.is_some() {
-            self.state.char_count = Some(self.text.chars().count());
+            self.state.char_count = Some(self.text.char_count());
         }
         self.state.last_edit_kind = None;
         self.mark_local_edit();
@@ -140,11 +263,11 @@ impl<'a> Editor<'a> {
                 let selected_chars = self
                     .state
                     .sel_range()
-                    .map_or(0, |range| self.text[range].chars().count());
+                    .map_or(0, |range| self.text.slice(range).chars().count());
                 let current_chars = *self
                     .state
                     .char_count
-                    .get_or_insert_with(|| self.text.chars().count());
+                    .get_or_insert_with(|| self.text.char_count());
                 let chars_after_delete = current_chars - selected_chars;
                 let room = max.saturating_sub(chars_after_delete);
                 match s.char_indices().nth(room) {
@@ -158,8 +281,15 @@ impl<'a> Editor<'a> {
 
     /// Replace the live selection with `s` under one undo unit of
     /// `kind` — the shared choke point for typing, IME text, newline
-    /// insert, and paste.
+    /// insert, and paste. With extra carets, at every one of them.
     pub(super) fn replace_selection(&mut self, s: &str, kind: EditKind) {
+        if !self.state.carets.is_empty() {
+            self.edit_each(kind, |_, _, caret| {
+                let range = caret.range();
+                (!range.is_empty() || !s.is_empty()).then_some((range, s))
+            });
+            return;
+        }
         self.ensure_history_matches();
         let fit_len = self.capped_prefix(s).len();
         let fit = &s[..fit_len];
@@ -186,23 +316,59 @@ impl<'a> Editor<'a> {
     /// Paste at the caret, replacing any live selection; line breaks
     /// are sanitized away for single-line editors. No-op on an empty
     /// clipboard.
+    ///
+    /// With extra carets, a clipboard of exactly one line per caret —
+    /// what copying from as many carets put there — pastes a line at
+    /// each; anything else pastes whole at every one.
     pub(super) fn paste(&mut self, raw: &str) {
         let cleaned = self.sanitized(raw);
-        if !cleaned.is_empty() {
+        if cleaned.is_empty() {
+            return;
+        }
+        let carets = self.state.carets.len() + 1;
+        let lines: Vec<&str> = cleaned
+            .strip_suffix('\n')
+            .unwrap_or(&cleaned)
+            .split('\n')
+            .collect();
+        if carets > 1 && lines.len() == carets {
+            self.edit_each(EditKind::Other, |_, at, caret| {
+                Some((caret.range(), lines[at]))
+            });
+        } else {
             self.replace_selection(&cleaned, EditKind::Other);
         }
     }
 
-    /// Delete the live selection as one bulk edit.
+    /// Delete the live selection as one bulk edit — every caret's, with
+    /// extra carets.
     pub(super) fn cut_selection(&mut self) {
+        if !self.state.carets.is_empty() {
+            self.edit_each(EditKind::Other, |_, _, caret| {
+                Some((caret.sel_range()?, ""))
+            });
+            return;
+        }
         let Some(r) = self.state.sel_range() else {
             return;
         };
         self.replace_range(r, "", EditKind::Other);
     }
 
-    pub(super) fn selected_text(&self) -> Option<&str> {
-        self.state.sel_range().map(|range| &self.text[range])
+    /// The selected text; with extra carets, every selection in buffer
+    /// order, a line each.
+    pub(super) fn selected_text(&self) -> Option<Cow<'_, str>> {
+        if self.state.carets.is_empty() {
+            return self.state.sel_range().map(|range| self.text.slice(range));
+        }
+        let selected: Vec<_> = self
+            .state
+            .all_carets()
+            .into_iter()
+            .filter_map(|(caret, _)| caret.sel_range())
+            .map(|range| self.text.slice(range))
+            .collect();
+        (!selected.is_empty()).then(|| Cow::Owned(selected.join("\n")))
     }
 
     /// Clear the whole buffer (the context menu's Clear).
@@ -216,17 +382,18 @@ impl<'a> Editor<'a> {
         if self.multiline {
             return;
         }
-        let Cow::Owned(cleaned) = sanitize_single_line(self.text) else {
+        let all = 0..self.text.len();
+        let Cow::Owned(cleaned) = sanitize_single_line(&self.text.slice(all.clone())) else {
             return;
         };
         self.ensure_history_matches();
         self.state.undo.clear();
         self.state.redo.clear();
         self.state.last_edit_kind = None;
-        *self.text = cleaned;
+        self.text.replace_range(all, &cleaned);
         self.state.normalize(self.text);
         if self.state.char_count.is_some() {
-            self.state.char_count = Some(self.text.chars().count());
+            self.state.char_count = Some(self.text.char_count());
         }
         self.mark_local_edit();
         self.edited = true;
@@ -234,6 +401,7 @@ impl<'a> Editor<'a> {
 
     /// Select the whole buffer (collapses to no-selection when empty).
     pub(super) fn select_all(&mut self) {
+        self.state.carets.clear();
         self.state.selection = (!self.text.is_empty()).then_some(0);
         self.state.caret = self.text.len();
         self.state.last_edit_kind = None;
@@ -282,26 +450,42 @@ impl<'a> Editor<'a> {
     }
 
     pub(super) fn delete_backward(&mut self) {
+        if !self.state.carets.is_empty() {
+            self.edit_each(EditKind::Delete, |text, _, caret| match caret.sel_range() {
+                Some(range) => Some((range, "")),
+                None if caret.caret == 0 => None,
+                None => Some((prev_grapheme(text, caret.caret)..caret.caret, "")),
+            });
+            return;
+        }
         if self.state.selection.is_none() && self.state.caret == 0 {
             return;
         }
         let range = if let Some(range) = self.state.sel_range() {
             range
         } else {
-            let prev = prev_grapheme_boundary(self.text, self.state.caret);
+            let prev = prev_grapheme(self.text, self.state.caret);
             prev..self.state.caret
         };
         self.replace_range(range, "", EditKind::Delete);
     }
 
     pub(super) fn delete_forward(&mut self) {
+        if !self.state.carets.is_empty() {
+            self.edit_each(EditKind::Delete, |text, _, caret| match caret.sel_range() {
+                Some(range) => Some((range, "")),
+                None if caret.caret == text.len() => None,
+                None => Some((caret.caret..next_grapheme(text, caret.caret), "")),
+            });
+            return;
+        }
         if self.state.selection.is_none() && self.state.caret == self.text.len() {
             return;
         }
         let range = if let Some(range) = self.state.sel_range() {
             range
         } else {
-            let next = next_grapheme_boundary(self.text, self.state.caret);
+            let next = next_grapheme(self.text, self.state.caret);
             self.state.caret..next
         };
         self.replace_range(range, "", EditKind::Delete);
@@ -311,7 +495,7 @@ impl<'a> Editor<'a> {
         let target = if !extend && let Some(range) = self.state.sel_range() {
             range.start
         } else {
-            prev_grapheme_boundary(self.text, self.state.caret)
+            prev_grapheme(self.text, self.state.caret)
         };
         self.move_caret(target, extend);
     }
@@ -320,18 +504,18 @@ impl<'a> Editor<'a> {
         let target = if !extend && let Some(range) = self.state.sel_range() {
             range.end
         } else {
-            next_grapheme_boundary(self.text, self.state.caret)
+            next_grapheme(self.text, self.state.caret)
         };
         self.move_caret(target, extend);
     }
 
     pub(super) fn move_word_left(&mut self, extend: bool) {
-        let target = prev_word_boundary(self.text, self.state.caret);
+        let target = prev_word(self.text, self.state.caret);
         self.move_caret(target, extend);
     }
 
     pub(super) fn move_word_right(&mut self, extend: bool) {
-        let target = next_word_boundary(self.text, self.state.caret);
+        let target = next_word(self.text, self.state.caret);
         self.move_caret(target, extend);
     }
 
@@ -0,0 +1,838 @@
+//! Find and replace for a multi-line [`TextEdit`](crate::TextEdit): the
+//! bar Ctrl+F and Ctrl+H open over the field, the match set it keeps in
+//! step with the buffer, and the washes it paints behind the matches.
+//!
+//! Every query — literal, whole-word or regex — compiles to one
+//! [`Regex`], so there is one search path and one replace path. The match
+//! set is kept current by replaying the editor's [`Splice`] log: an edit
+//! rescans the lines it touched rather than the document, and a pattern
+//! that crosses lines the stretch its longest match could reach.
+
+use crate::input::keyboard::KeyPress;
+use crate::input::shortcut::Shortcut;
+use crate::layout::types::align::{Align, VAlign};
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::rect::Rect;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::Ui;
+use crate::widgets::button::Button;
+use crate::widgets::panel::Panel;
+use crate::widgets::popup::{ClickOutside, Popup};
+use crate::widgets::text::Text;
+use crate::widgets::text_edit::TextEdit;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::edit_state::{EditKind, EditState, SelectionState};
+use crate::widgets::text_edit::editor::Editor;
+use crate::widgets::text_edit::paragraphs::{Paragraphs, paragraph};
+use crate::widgets::text_edit::shape_ctx::ShapeCtx;
+use glam::Vec2;
+use regex::{Regex, RegexBuilder};
+use regex_syntax::ParserBuilder;
+use regex_syntax::hir::{Class, Hir, HirKind, Literal, Look};
+use std::ops::Range;
+
+/// Opens the bar on its find row.
+pub(super) const FIND: Shortcut = Shortcut::ctrl('F');
+/// Opens the bar with its replace row as well.
+pub(super) const REPLACE: Shortcut = Shortcut::ctrl('H');
+
+/// Which of the bar's rows a chord opens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum FindMode {
+    Find,
+    Replace,
+}
+
+impl FindMode {
+    pub(super) fn from_keypress(press: KeyPress) -> Option<Self> {
+        if FIND.matches(press) {
+            Some(Self::Find)
+        } else if REPLACE.matches(press) {
+            Some(Self::Replace)
+        } else {
+            None
+        }
+    }
+}
+
+/// How the query is read. All off is a case-insensitive literal search.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct FindOptions {
+    pub(super) case_sensitive: bool,
+    pub(super) whole_word: bool,
+    pub(super) regex: bool,
+}
+
+/// One splice of the buffer, in the coordinates of the buffer it was made
+/// to: `removed` bytes at `start` became `inserted` bytes.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Splice {
+    pub(super) start: usize,
+    pub(super) removed: usize,
+    pub(super) inserted: usize,
+}
+
+/// The splices made since the find bar last synced its matches.
+#[derive(Clone, Default, Debug)]
+pub(super) struct SpliceLog {
+    pub(super) splices: Vec<Splice>,
+    /// The host replaced the buffer in between: the splices no longer lead
+    /// from the text the matches were found in.
+    pub(super) broken: bool,
+}
+
+/// A compiled query and what it was compiled from.
+#[derive(Clone, Debug)]
+struct Matcher {
+    regex: Regex,
+    query: String,
+    options: FindOptions,
+    /// How much of the buffer decides a match — see [`reach`].
+    reach: Reach,
+}
+
+impl Matcher {
+    fn compile(query: &str, options: FindOptions) -> Result<Self, regex::Error> {
+        let mut pattern = if options.regex {
+            query.to_owned()
+        } else {
+            regex::escape(query)
+        };
+        if options.whole_word {
+            pattern = format!(r"\b(?:{pattern})\b");
+        }
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!options.case_sensitive)
+            .multi_line(true)
+            .build()?;
+        Ok(Self {
+            regex,
+            query: query.to_owned(),
+            options,
+            reach: reach(&pattern, options),
+        })
+    }
+
+    /// Push the non-empty matches in `haystack` onto `out`, offset by
+    /// `base` — the byte `haystack` starts at in the buffer.
+    fn scan(&self, haystack: &str, base: usize, out: &mut Vec<Range<usize>>) {
+        out.extend(
+            self.regex
+                .find_iter(haystack)
+                .filter(|m| !m.is_empty())
+                .map(|m| base + m.start()..base + m.end()),
+        );
+    }
+
+    /// The stretch of `text` a search must see to find `found` again: the
+    /// lines it runs over, or the whole buffer under an anchored pattern.
+    fn window(&self, text: &dyn TextBuffer, found: &Range<usize>) -> Range<usize> {
+        if self.reach == Reach::Buffer {
+            return 0..text.len();
+        }
+        let start = text.line_to_byte(text.byte_to_line(found.start));
+        start..line_end(text, text.byte_to_line(found.end))
+    }
+
+    /// What `found` — a match in `haystack`, which starts at byte `base`
+    /// of the buffer and holds the match's [`Self::window`] — is replaced
+    /// with: `replacement` with its `$1` / `${name}` groups filled in
+    /// under a regex query, `replacement` itself otherwise.
+    fn expand(
+        &self,
+        haystack: &str,
+        base: usize,
+        found: Range<usize>,
+        replacement: &str,
+        out: &mut String,
+    ) {
+        let local = found.start - base..found.end - base;
+        if self.options.regex
+            && let Some(captures) = self.regex.captures_at(haystack, local.start)
+            && captures.get(0).map(|m| m.range()) == Some(local)
+        {
+            captures.expand(replacement, out);
+        } else {
+            out.push_str(replacement);
+        }
+    }
+}
+
+/// How much of the buffer decides the matches of a pattern, compiled as
+/// [`Matcher::compile`] does — and so how little of it an edit makes
+/// [`FindState::sync`] search again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Reach {
+    /// No match holds a `'\n'`: a line searched alone finds what a
+    /// search of the whole buffer finds on it.
+    Line,
+    /// A match may hold a `'\n'` — a literal one, `\s`, `[^,]`,
+    /// `(?s:.)` — but is at most this many bytes long.
+    Lines(usize),
+    /// A match may hold `'\n'`s without bound, as under `\s+`: an edit
+    /// can change a match any distance away.
+    Unbounded,
+    /// The pattern is anchored to where the buffer starts or ends (`\A`,
+    /// `\z`), which a slice of it would move — or doesn't parse.
+    Buffer,
+}
+
+/// The [`Reach`] of `pattern` under `options`.
+pub(super) fn reach(pattern: &str, options: FindOptions) -> Reach {
+    let Ok(hir) = ParserBuilder::new()
+        .case_insensitive(!options.case_sensitive)
+        .multi_line(true)
+        .build()
+        .parse(pattern)
+    else {
+        return Reach::Buffer;
+    };
+    let properties = hir.properties();
+    let looks = properties.look_set();
+    if looks.contains(Look::Start) || looks.contains(Look::End) {
+        Reach::Buffer
+    } else if !holds_newline(&hir) {
+        Reach::Line
+    } else {
+        properties
+            .maximum_len()
+            .map_or(Reach::Unbounded, Reach::Lines)
+    }
+}
+
+/// Whether some match of `hir` holds a `'\n'`.
+fn holds_newline(hir: &Hir) -> bool {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => false,
+        HirKind::Literal(Literal(bytes)) => bytes.contains(&b'\n'),
+        HirKind::Class(Class::Unicode(class)) => class
+            .ranges()
+            .iter()
+            .any(|range| (range.start()..=range.end()).contains(&'\n')),
+        HirKind::Class(Class::Bytes(class)) => class
+            .ranges()
+            .iter()
+            .any(|range| (range.start()..=range.end()).contains(&b'\n')),
+        HirKind::Repetition(repetition) => holds_newline(&repetition.sub),
+        HirKind::Capture(capture) => holds_newline(&capture.sub),
+        HirKind::Concat(subs) | HirKind::Alternation(subs) => subs.iter().any(holds_newline),
+    }
+}
+
+/// What the bar asked for this frame, applied once its popup is done
+/// with the state it reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Command {
+    /// The query or an option changed: go to the first match from the
+    /// selection on.
+    Search,
+    Next,
+    Previous,
+    Replace,
+    ReplaceAll,
+}
+
+/// The find bar's state, retained on the field's state row.
+#[derive(Clone, Default, Debug)]
+pub(super) struct FindState {
+    pub(super) open: bool,
+    pub(super) replace_open: bool,
+    pub(super) query: String,
+    pub(super) replacement: String,
+    pub(super) options: FindOptions,
+    matcher: Option<Matcher>,
+    /// The query doesn't compile as a regex.
+    pub(super) invalid: bool,
+    /// Every match, in order and not overlapping.
+    pub(super) matches: Vec<Range<usize>>,
+    /// Which of `matches` the selection is on.
+    pub(super) current: Option<usize>,
+    /// Identity of the buffer `matches` were found in; `None` until a
+    /// search has run against the current query.
+    synced: Option<u64>,
+    /// This frame's washes, block-local, with whether each belongs to the
+    /// current match.
+    rects: Vec<(Rect, bool)>,
+}
+
+impl FindState {
+    /// Open the bar — on `seed` as the query, if the selection offered
+    /// one — and start the editor logging its splices for it.
+    pub(super) fn open(&mut self, mode: FindMode, edit: &mut EditState, seed: Option<String>) {
+        self.open = true;
+        self.replace_open = mode == FindMode::Replace;
+        if let Some(seed) = seed {
+            self.query = seed;
+        }
+        edit.splices.get_or_insert_with(SpliceLog::default);
+    }
+
+    /// Close the bar, dropping its matches and the editor's log. The query
+    /// and options stay for the next time it opens.
+    pub(super) fn close(&mut self, edit: &mut EditState) {
+        self.open = false;
+        self.matches.clear();
+        self.current = None;
+        self.synced = None;
+        self.rects.clear();
+        edit.splices = None;
+    }
+
+    pub(super) fn rects(&self) -> &[(Rect, bool)] {
+        &self.rects
+    }
+
+    /// Bring `matches` up to date with `text`: recompiled and searched
+    /// afresh if the query changed, else the logged splices replayed. The
+    /// whole buffer is searched again only when nothing less will do — the
+    /// host replaced the text, or the pattern's [`Reach`] has no bound.
+    pub(super) fn sync(&mut self, text: &dyn TextBuffer, log: &mut Option<SpliceLog>) {
+        self.recompile();
+        let log = log.get_or_insert_with(SpliceLog::default);
+        let hash = EditState::text_hash(text);
+        match &self.matcher {
+            None => self.matches.clear(),
+            Some(matcher) => {
+                let replayable = self.synced.is_some()
+                    && !log.broken
+                    && matches!(matcher.reach, Reach::Line | Reach::Lines(_));
+                if replayable && log.splices.is_empty() && self.synced == Some(hash) {
+                    return;
+                }
+                if replayable && !log.splices.is_empty() {
+                    replay(matcher, text, &log.splices, &mut self.matches);
+                } else {
+                    self.matches.clear();
+                    matcher.scan(&text.slice(0..text.len()), 0, &mut self.matches);
+                }
+            }
+        }
+        log.splices.clear();
+        log.broken = false;
+        self.synced = Some(hash);
+    }
+
+    /// Compile the query if it or the options changed since the last
+    /// compile. An empty query, or a regex that doesn't parse, matches
+    /// nothing.
+    fn recompile(&mut self) {
+        if self
+            .matcher
+            .as_ref()
+            .is_some_and(|matcher| matcher.query == self.query && matcher.options == self.options)
+            || (self.matcher.is_none() && self.query.is_empty())
+        {
+            return;
+        }
+        self.synced = None;
+        self.invalid = false;
+        self.matcher = None;
+        if self.query.is_empty() {
+            return;
+        }
+        match Matcher::compile(&self.query, self.options) {
+            Ok(matcher) => self.matcher = Some(matcher),
+            Err(_) => self.invalid = true,
+        }
+    }
+
+    /// Point `current` at the match `selection` covers exactly, if any.
+    pub(super) fn settle(&mut self, selection: Option<Range<usize>>) {
+        self.current = selection.and_then(|selection| {
+            let at = self.matches.partition_point(|m| m.start < selection.start);
+            (self.matches.get(at) == Some(&selection)).then_some(at)
+        });
+    }
+
+    /// Select the first match starting at or after `from`, wrapping round
+    /// to the first in the buffer.
+    fn seek(&mut self, editor: &mut Editor<'_>, from: usize) {
+        let at = self.matches.partition_point(|m| m.start < from);
+        self.select(editor, if at == self.matches.len() { 0 } else { at });
+    }
+
+    /// Select the match after the selection, or before it, wrapping at
+    /// either end of the buffer.
+    pub(super) fn step(&mut self, editor: &mut Editor<'_>, forward: bool) {
+        let caret = editor.state.caret;
+        let selection = editor.state.sel_range().unwrap_or(caret..caret);
+        if forward {
+            self.seek(editor, selection.end);
+            return;
+        }
+        let at = self.matches.partition_point(|m| m.end <= selection.start);
+        let at = if at == 0 { self.matches.len() } else { at };
+        self.select(editor, at.wrapping_sub(1));
+    }
+
+    /// Select match `at`, if there is one. Moving the caret is what makes
+    /// the view follow it into sight.
+    fn select(&mut self, editor: &mut Editor<'_>, at: usize) {
+        let Some(found) = self.matches.get(at).cloned() else {
+            self.current = None;
+            return;
+        };
+        editor.drop_carets();
+        editor.move_caret(found.start, false);
+        editor.move_caret(found.end, true);
+        self.current = Some(at);
+    }
+
+    /// Replace the current match and move on to the next; with no current
+    /// match, only move on.
+    pub(super) fn replace(&mut self, editor: &mut Editor<'_>) {
+        let (Some(matcher), Some(at)) = (&self.matcher, self.current) else {
+            self.step(editor, true);
+            return;
+        };
+        let found = self.matches[at].clone();
+        let window = matcher.window(editor.text, &found);
+        let mut with = String::new();
+        matcher.expand(
+            &editor.text.slice(window.clone()),
+            window.start,
+            found.clone(),
+            &self.replacement,
+            &mut with,
+        );
+        let caret = found.start + with.len();
+        editor.replace_range_to(
+            found,
+            &with,
+            EditKind::Other,
+            SelectionState {
+                caret,
+                selection: None,
+            },
+        );
+        self.sync(editor.text, &mut editor.state.splices);
+        self.seek(editor, caret);
+    }
+
+    /// Replace every match as one edit, so one undo takes them all back.
+    pub(super) fn replace_all(&mut self, editor: &mut Editor<'_>) {
+        let (Some(matcher), Some(first), Some(last)) =
+            (&self.matcher, self.matches.first(), self.matches.last())
+        else {
+            return;
+        };
+        let span = first.start..last.end;
+        let mut with = String::with_capacity(span.len());
+        {
+            let text = &*editor.text;
+            // The window the last match was expanded in, kept while the
+            // next ones fall inside it — a line of many matches is sliced
+            // once.
+            let mut window = 0..0;
+            let mut haystack = text.slice(0..0);
+            let mut at = span.start;
+            for found in &self.matches {
+                with.push_str(&text.slice(at..found.start));
+                if found.start < window.start || found.end > window.end {
+                    window = matcher.window(text, found);
+                    haystack = text.slice(window.clone());
+                }
+                matcher.expand(
+                    &haystack,
+                    window.start,
+                    found.clone(),
+                    &self.replacement,
+                    &mut with,
+                );
+                at = found.end;
+            }
+        }
+        let caret = span.start + with.len();
+        editor.replace_range_to(
+            span,
+            &with,
+            EditKind::Other,
+            SelectionState {
+                caret,
+                selection: None,
+            },
+        );
+        self.sync(editor.text, &mut editor.state.splices);
+        self.current = None;
+    }
+
+    /// The washes for the matches in `view` of `text`, shaped as one run.
+    pub(super) fn rects_flat(&mut self, ui: &mut Ui, ctx: &ShapeCtx, text: &str, view: Range<f32>) {
+        self.rects.clear();
+        if self.matches.is_empty() {
+            return;
+        }
+        let probe = ui.probe_text(ctx.run(text));
+        let first = probe.byte_at(0.0, view.start.max(0.0));
+        let last = probe.byte_at(f32::MAX, view.end);
+        let from = self.matches.partition_point(|m| m.end <= first);
+        for (at, found) in self.matches.iter().enumerate().skip(from) {
+            if found.start > last {
+                break;
+            }
+            let current = self.current == Some(at);
+            probe.selection_rects(found.clone(), |rect| self.rects.push((rect, current)));
+        }
+    }
+
+    /// The washes for the matches on paragraphs `lines`.
+    pub(super) fn rects_paragraphs(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        paragraphs: &mut Paragraphs,
+        lines: Range<usize>,
+    ) {
+        self.rects.clear();
+        for line in lines {
+            let start = text.line_to_byte(line);
+            let paragraph = paragraph(text, line);
+            let end = start + paragraph.len();
+            let from = self.matches.partition_point(|m| m.end <= start);
+            let to = self.matches.partition_point(|m| m.start < end);
+            if from >= to {
+                continue;
+            }
+            let top = paragraphs.top(line);
+            let probe = ui.probe_text(ctx.run(&paragraph));
+            for (at, found) in self.matches[from..to].iter().enumerate() {
+                let current = self.current == Some(from + at);
+                let local =
+                    found.start.saturating_sub(start)..(found.end - start).min(paragraph.len());
+                probe.selection_rects(local, |rect| {
+                    self.rects.push((
+                        Rect {
+                            min: rect.min + Vec2::new(0.0, top),
+                            ..rect
+                        },
+                        current,
+                    ));
+                });
+            }
+        }
+    }
+
+    /// The bar's count: where the selection is among the matches.
+    fn status(&self) -> String {
+        match (self.current, self.matches.len()) {
+            _ if self.invalid => "Invalid pattern".to_owned(),
+            _ if self.query.is_empty() => String::new(),
+            (_, 0) => "No results".to_owned(),
+            (Some(at), total) => format!("{} of {total}", at + 1),
+            (None, 1) => "1 match".to_owned(),
+            (None, total) => format!("{total} matches"),
+        }
+    }
+}
+
+/// Bytes either side of a match its look-arounds read: `\b` weighs the
+/// char next to it, which is at most four.
+const LOOK: usize = 4;
+
+/// Update `matches` across `splices` without searching the document:
+/// matches an edit touched are dropped, the rest shift with the text, and
+/// the stretch around each edit a match could reach is searched again —
+/// its lines under [`Reach::Line`], see [`rescan_reach`] under
+/// [`Reach::Lines`].
+fn replay(
+    matcher: &Matcher,
+    text: &dyn TextBuffer,
+    splices: &[Splice],
+    matches: &mut Vec<Range<usize>>,
+) {
+    // What each splice inserted, in the coordinates of the text after
+    // every splice — carried forward as later splices move it.
+    let mut dirty: Vec<Range<usize>> = Vec::new();
+    for splice in splices {
+        let end = splice.start + splice.removed;
+        let shift = |at: usize| at + splice.inserted - splice.removed;
+        let inserted = splice.start..splice.start + splice.inserted;
+        // A match touching the splice may have grown or shrunk with it.
+        matches.retain(|m| m.end < splice.start || m.start > end);
+        for m in matches.iter_mut().filter(|m| m.start > end) {
+            *m = shift(m.start)..shift(m.end);
+        }
+        let mut merged = inserted.clone();
+        dirty.retain_mut(|range| {
+            if range.start > end {
+                *range = shift(range.start)..shift(range.end);
+                true
+            } else if range.end < splice.start {
+                true
+            } else {
+                merged.start = merged.start.min(range.start);
+                merged.end = merged.end.max(if range.end > end {
+                    shift(range.end)
+                } else {
+                    inserted.end
+                });
+                false
+            }
+        });
+        dirty.push(merged);
+    }
+    dirty.sort_by_key(|range| range.start);
+    if let Reach::Lines(longest) = matcher.reach {
+        rescan_reach(matcher, text, &dirty, longest, matches);
+        return;
+    }
+    // Widened to whole lines, since that is the unit a match lives in.
+    let mut windows: Vec<Range<usize>> = Vec::with_capacity(dirty.len());
+    for range in dirty {
+        let start = text.line_to_byte(text.byte_to_line(range.start));
+        let end = line_end(text, text.byte_to_line(range.end));
+        match windows.last_mut() {
+            Some(previous) if previous.end >= start => previous.end = previous.end.max(end),
+            _ => windows.push(start..end),
+        }
+    }
+    let mut found = Vec::new();
+    for window in windows {
+        found.clear();
+        matcher.scan(&text.slice(window.clone()), window.start, &mut found);
+        let from = matches.partition_point(|m| m.start < window.start);
+        let to = matches.partition_point(|m| m.start <= window.end);
+        matches.splice(from..to, found.drain(..));
+    }
+}
+
+/// Search again around each of `dirty`, under a pattern whose matches
+/// may cross lines but run at most `longest` bytes.
+///
+/// A match starting at some byte is decided by the text from [`LOOK`]
+/// before it to `longest + LOOK` after, so only the matches within that
+/// reach of an edit can have changed. The search starts at a line start
+/// that far back which no kept match runs across, and stops at the first
+/// byte that far on where neither the old matches nor the new ones run
+/// across — from there both searches step through the same text from the
+/// same place, and the old matches stand.
+fn rescan_reach(
+    matcher: &Matcher,
+    text: &dyn TextBuffer,
+    dirty: &[Range<usize>],
+    longest: usize,
+    matches: &mut Vec<Range<usize>>,
+) {
+    let reach = longest + LOOK;
+    let len = text.len();
+    let mut found = Vec::new();
+    let mut dirty = dirty.iter().peekable();
+    while let Some(edit) = dirty.next() {
+        let mut start = text.line_to_byte(text.byte_to_line(edit.start.saturating_sub(reach)));
+        loop {
+            let at = matches.partition_point(|m| m.end <= start);
+            match matches.get(at) {
+                Some(m) if m.start < start => {
+                    start = text.line_to_byte(text.byte_to_line(m.start));
+                }
+                _ => break,
+            }
+        }
+        let mut stop = (edit.end + reach).min(len);
+        let mut searched = start;
+        found.clear();
+        loop {
+            // Edits close enough that their reach meets this one's are
+            // searched with it.
+            while let Some(next) = dirty.next_if(|next| next.start < stop + reach) {
+                stop = stop.max((next.end + reach).min(len));
+            }
+            if stop + reach > searched && searched < len {
+                // The new matches are only settled up to `reach` short of
+                // where the search ends, unless it ends with the buffer.
+                searched = line_end(text, text.byte_to_line((stop + 2 * reach).min(len)));
+                found.clear();
+                matcher.scan(&text.slice(start..searched), start, &mut found);
+            }
+            let inside = |list: &[Range<usize>]| {
+                let at = list.partition_point(|m| m.end <= stop);
+                list.get(at).filter(|m| m.start < stop).map(|m| m.end)
+            };
+            match inside(&found).or_else(|| inside(matches)) {
+                Some(end) => stop = end,
+                None if stop + reach <= searched || searched == len => break,
+                None => {}
+            }
+        }
+        let from = matches.partition_point(|m| m.start < start);
+        let to = matches.partition_point(|m| m.start < stop);
+        let kept = found.partition_point(|m| m.start < stop);
+        matches.splice(from..to, found.drain(..kept));
+    }
+}
+
+/// Where `line` ends, short of its `'\n'`.
+fn line_end(text: &dyn TextBuffer, line: usize) -> usize {
+    let next = text.line_to_byte(line + 1);
+    if text.byte_to_line(next) > line {
+        next - 1
+    } else {
+        next
+    }
+}
+
+/// Show the bar over `rect`, the field's box, and carry out what it's
+/// asked for through `editor`. Closing it hands focus back to `field`.
+pub(super) fn show_bar(
+    ui: &mut Ui,
+    field: WidgetId,
+    rect: Rect,
+    editor: &mut Editor<'_>,
+    find: &mut FindState,
+) {
+    let theme = ui.theme().find_bar.clone();
+    let button = ui.theme().button.clone();
+    let mut option_on = button.clone();
+    option_on.looks.normal.background.fill = theme.option_on.into();
+    option_on.looks.hovered.background.fill = theme.option_on.into();
+    find.sync(editor.text, &mut editor.state.splices);
+    find.settle(editor.state.sel_range());
+
+    let mut command = None;
+    let mut close = false;
+    // The field's box inset by the gap at the top and right, so the bar
+    // doesn't sit on its border.
+    let anchor = Rect::new(
+        rect.min.x,
+        rect.min.y + theme.gap,
+        (rect.size.w - theme.gap).max(0.0),
+        rect.size.h,
+    );
+    let field_size = (Sizing::fixed(theme.field_width), Sizing::HUG);
+    Popup::inside_top_end(anchor)
+        .click_outside(ClickOutside::PassThrough)
+        .id(field.with("find-bar"))
+        .padding(theme.gap)
+        .gap(theme.gap)
+        .show(ui, |ui, _| {
+            Panel::hstack()
+                .id_salt("find")
+                .gap(theme.gap)
+                .child_align(Align::v(VAlign::Center))
+                .show(ui, |ui| {
+                    let query = TextEdit::new(&mut find.query)
+                        .id(field.with("find-query"))
+                        .placeholder("Find")
+                        .select_all_on_focus()
+                        .escape_falls_through()
+                        .size(field_size)
+                        .show(ui);
+                    let (changed, submitted) = (query.changed, query.submitted);
+                    if changed {
+                        command = Some(Command::Search);
+                    }
+                    if submitted {
+                        command = Some(if ui.modifiers().shift {
+                            Command::Previous
+                        } else {
+                            Command::Next
+                        });
+                    }
+                    let options = [
+                        ("case", "Aa", &mut find.options.case_sensitive),
+                        ("word", "W", &mut find.options.whole_word),
+                        ("regex", ".*", &mut find.options.regex),
+                    ];
+                    for (salt, label, on) in options {
+                        let style = if *on { &option_on } else { &button };
+                        if Button::new()
+                            .id_salt(salt)
+                            .label(label)
+                            .style(style)
+                            .show(ui)
+                            .left
+                            .clicked()
+                        {
+                            *on = !*on;
+                            command = Some(Command::Search);
+                        }
+                    }
+                    Text::new(find.status()).show(ui);
+                    if Button::new()
+                        .id_salt("previous")
+                        .label("↑")
+                        .show(ui)
+                        .left
+                        .clicked()
+                    {
+                        command = Some(Command::Previous);
+                    }
+                    if Button::new()
+                        .id_salt("next")
+                        .label("↓")
+                        .show(ui)
+                        .left
+                        .clicked()
+                    {
+                        command = Some(Command::Next);
+                    }
+                    close |= Button::new()
+                        .id_salt("close")
+                        .label("×")
+                        .show(ui)
+                        .left
+                        .clicked();
+                });
+            if find.replace_open {
+                Panel::hstack()
+                    .id_salt("replace")
+                    .gap(theme.gap)
+                    .child_align(Align::v(VAlign::Center))
+                    .show(ui, |ui| {
+                        let replacement = TextEdit::new(&mut find.replacement)
+                            .id(field.with("find-replacement"))
+                            .placeholder("Replace")
+                            .escape_falls_through()
+                            .size(field_size)
+                            .show(ui);
+                        if replacement.submitted {
+                            command = Some(Command::Replace);
+                        }
+                        if Button::new()
+                            .id_salt("replace")
+                            .label("Replace")
+                            .show(ui)
+                            .left
+                            .clicked()
+                        {
+                            command = Some(Command::Replace);
+                        }
+                        if Button::new()
+                            .id_salt("all")
+                            .label("All")
+                            .show(ui)
+                            .left
+                            .clicked()
+                        {
+                            command = Some(Command::ReplaceAll);
+                        }
+                    });
+            }
+            // Both fields let Escape through, so one press closes the bar
+            // even while the query still holds its select-all.
+            close |= ui.escape_pressed();
+        });
+
+    if let Some(command) = command {
+        find.sync(editor.text, &mut editor.state.splices);
+        match command {
+            Command::Search => {
+                let caret = editor.state.caret;
+                let from = editor.state.sel_range().map_or(caret, |range| range.start);
+                find.seek(editor, from);
+            }
+            Command::Next => find.step(editor, true),
+            Command::Previous => find.step(editor, false),
+            Command::Replace => find.replace(editor),
+            Command::ReplaceAll => find.replace_all(editor),
+        }
+        // The count above was drawn from the state before the command.
+        ui.request_repaint();
+    }
+    if close {
+        find.close(editor.state);
+        ui.request_focus(Some(field));
+    }
+}
@@ -0,0 +1,128 @@
+//! Syntax highlighting for [`TextEdit`](crate::TextEdit): a
+//! [`Highlighter`] colours one line at a time, and the field keeps what
+//! it said keyed by the line's *content* — so an edit re-highlights the
+//! line it touched and nothing else, wherever the lines around it moved
+//! to, and a line scrolled out of view is never asked about at all.
+
+use crate::common::hash;
+use crate::primitives::color::Color;
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::ops::Range;
+
+/// Colours a line of a [`TextEdit`](crate::TextEdit)'s buffer.
+///
+/// Line-at-a-time and stateless by design: the field asks only about the
+/// lines it is showing, in any order, and reuses an answer for as long
+/// as the line's text is unchanged. A construct spanning lines — a block
+/// comment, a raw string — has to be recognised from within each line.
+///
+/// ```
+/// # use palantir::{Color, HighlightSpan, Highlighter};
+/// #[derive(Debug)]
+/// struct Comments(Color);
+///
+/// impl Highlighter for Comments {
+///     fn highlight_line(&self, line: &str, spans: &mut Vec<HighlightSpan>) {
+///         if let Some(at) = line.find("//") {
+///             spans.push(HighlightSpan { range: at..line.len(), color: self.0 });
+///         }
+///     }
+/// }
+/// ```
+pub trait Highlighter: fmt::Debug {
+    /// Push the coloured stretches of `line` — one line of the buffer,
+    /// without its terminator — onto `spans` as byte ranges into it, in
+    /// order and not overlapping. Bytes no span covers paint in the
+    /// field's text colour. A span that overlaps the one before it, or
+    /// doesn't fall on char boundaries, is ignored.
+    fn highlight_line(&self, line: &str, spans: &mut Vec<HighlightSpan>);
+
+    /// Changes whenever a line would now highlight differently — another
+    /// colour scheme, another language. The field's cache is keyed by
+    /// line content alone, so this is how it learns to drop it.
+    fn revision(&self) -> u64 {
+        0
+    }
+}
+
+/// One coloured stretch of a line — see [`Highlighter::highlight_line`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighlightSpan {
+    pub range: Range<usize>,
+    pub color: Color,
+}
+
+/// A line cut into the stretches it paints as: the highlighter's spans
+/// with the gaps between them filled in uncoloured.
+pub(super) type Segment = (Range<usize>, Option<Color>);
+
+/// What the highlighter said about each line on show, retained on the
+/// field's state row.
+#[derive(Clone, Default, Debug)]
+pub(super) struct HighlightCache {
+    lines: FxHashMap<u64, CachedLine>,
+    /// [`Highlighter::revision`] the entries were made under.
+    revision: u64,
+    /// Bumped per pass; an entry no pass looked up last time is dropped,
+    /// so the cache holds about a screenful whatever the document's size.
+    pass: u64,
+}
+
+#[derive(Clone, Debug)]
+struct CachedLine {
+    spans: Vec<HighlightSpan>,
+    pass: u64,
+}
+
+impl HighlightCache {
+    /// Open a pass: forget everything if the highlighter changed its
+    /// mind, and whatever the previous pass didn't show.
+    pub(super) fn begin_pass(&mut self, highlighter: &dyn Highlighter) {
+        let revision = highlighter.revision();
+        if revision != self.revision {
+            self.lines.clear();
+            self.revision = revision;
+        }
+        let previous = self.pass;
+        self.lines.retain(|_, line| line.pass == previous);
+        self.pass += 1;
+    }
+
+    /// `line`'s segments onto `out`, offset by `base` — the byte `line`
+    /// starts at in whatever the caller positions against.
+    pub(super) fn segments(
+        &mut self,
+        highlighter: &dyn Highlighter,
+        line: &str,
+        base: usize,
+        out: &mut Vec<Segment>,
+    ) {
+        let pass = self.pass;
+        let cached = self.lines.entry(hash::hash_str(line)).or_insert_with(|| {
+            let mut spans = Vec::new();
+            highlighter.highlight_line(line, &mut spans);
+            CachedLine { spans, pass }
+        });
+        cached.pass = pass;
+        let mut at = 0;
+        for span in &cached.spans {
+            let range = span.range.start..span.range.end.min(line.len());
+            if range.start < at
+                || range.is_empty()
+                || !line.is_char_boundary(range.start)
+                || !line.is_char_boundary(range.end)
+            {
+                continue;
+            }
+            if at < range.start {
+                out.push((base + at..base + range.start, None));
+            }
+            out.push((base + range.start..base + range.end, Some(span.color)));
+            at = range.end;
+        }
+        if at < line.len() {
+            out.push((base + at..base + line.len(), None));
+        }
+    }
+}
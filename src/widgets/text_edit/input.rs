@@ -4,14 +4,21 @@ use crate::common::platform::{PLATFORM, Platform};
 use crate::input::key_class::KeyFilter;
 use crate::input::keyboard::{Key, KeyPress, KeyboardEvent, Modifiers};
 use crate::input::response::ResponseState;
+use crate::text::probe::Caret;
 use crate::ui::Ui;
 use crate::widgets::text_edit::TextEditState;
 use crate::widgets::text_edit::action::EditAction;
+use crate::widgets::text_edit::autocomplete::{Autocomplete, ListKey};
+use crate::widgets::text_edit::buffer::{TextBuffer, word_range};
+use crate::widgets::text_edit::code;
 use crate::widgets::text_edit::edit_state::EditKind;
 use crate::widgets::text_edit::editor::Editor;
+use crate::widgets::text_edit::find::FindMode;
+use crate::widgets::text_edit::paragraphs::{self, Paragraphs};
 use crate::widgets::text_edit::shape_ctx::ShapeCtx;
 use crate::widgets::text_edit::text_layout::TextLayout;
-use crate::widgets::text_edit::unicode::word_range_at;
+use crate::widgets::text_edit::validate::Validator;
+use glam::Vec2;
 
 /// Result of one frame's input pass over a TextEdit: the caret byte,
 /// the (sorted) selection range for the painter, and the edge signals
@@ -27,10 +34,14 @@ pub(super) struct InputResult {
     /// content-accurate — a same-length overwrite still counts, unlike
     /// a length-delta proxy.
     pub(super) edited: bool,
+    /// Ctrl+F or Ctrl+H asked for the find bar.
+    pub(super) find: Option<FindMode>,
+    /// Tab or Enter accepted a suggestion this frame.
+    pub(super) completed: bool,
 }
 
 /// What the builder configured about *accepting* input, as opposed to
-/// rendering it. The three travel together because they are set on the
+/// rendering it. They travel together because they are set on the
 /// same builder and read at the same call, and bundling them is what
 /// keeps [`run_input`]'s parameter list inside its own means.
 ///
@@ -38,7 +49,7 @@ pub(super) struct InputResult {
 /// that one gates whether a frame re-records at all. This is per-widget
 /// and concerns which keystrokes a field takes.
 #[derive(Clone, Copy, Debug)]
-pub(super) struct AcceptPolicy {
+pub(super) struct AcceptPolicy<'a> {
     /// Cap on buffer length; `None` is unbounded.
     pub(super) max_chars: Option<usize>,
     /// Select everything when focus lands without a same-frame press.
@@ -47,6 +58,13 @@ pub(super) struct AcceptPolicy {
     /// declares, so what it tells other readers it takes and what it
     /// acts on cannot drift apart.
     pub(super) filter: KeyFilter,
+    /// Code mode: Enter carries indentation and Tab indents.
+    pub(super) code: bool,
+    /// Asked about every edit before it lands.
+    pub(super) validator: Option<&'a dyn Validator>,
+    /// Typing opens a suggestion list, which takes the keys it is steered
+    /// by while it shows.
+    pub(super) autocomplete: Option<Autocomplete<'a>>,
 }
 
 /// Process this frame's pointer + keyboard input for one TextEdit
@@ -65,9 +83,9 @@ pub(super) fn run_input(
     ui: &mut Ui,
     resp_state: &ResponseState,
     is_focused: bool,
-    text: &mut String,
+    text: &mut dyn TextBuffer,
     layout: &TextLayout,
-    policy: AcceptPolicy,
+    policy: AcceptPolicy<'_>,
     state: &mut TextEditState,
 ) -> InputResult {
     let ctx = &layout.ctx;
@@ -75,17 +93,31 @@ pub(super) fn run_input(
         max_chars,
         select_all_on_focus,
         filter,
+        code,
+        validator,
+        autocomplete,
     } = policy;
     let mut blur = false;
     let mut submitted = false;
+    let mut find = None;
+    let mut completed = false;
     let clipboard = ui.clipboard();
 
     let TextEditState {
         edit,
         view,
+        paragraphs,
         // Filled by the geometry pass after this one, and read only by the
         // painter — the input pass has no business in it.
         selection_rects: _,
+        caret_positions: _,
+        highlight: _,
+        visible: _,
+        find: _,
+        suggest,
+        diagnostics,
+        // Judged after the edits this pass makes have landed.
+        validity: _,
     } = state;
     let was_focused = view.prev_focused;
     // Repair persisted byte offsets before any range/slice operation.
@@ -93,6 +125,8 @@ pub(super) fn run_input(
     // longer string whose UTF-8 boundaries differ from the prior frame.
     edit.normalize(text);
     let mut ed = Editor::new(text, edit, ctx.multiline, max_chars);
+    ed.code = code;
+    ed.validator = validator;
     ed.enforce_single_line();
 
     // Select-all-on-focus: the frame focus lands (and no press this frame — a
@@ -101,6 +135,23 @@ pub(super) fn run_input(
         ed.select_all();
     }
 
+    // A right-click opens the context menu over whatever is under it, and
+    // the diagnostic there is what the menu offers replacements for. Read
+    // focused or not: a right-click doesn't take focus.
+    if resp_state.right.clicked()
+        && let Some(pointer_offset) = resp_state.pointer_local
+    {
+        let (hit, _) = pointer_hit(
+            ui,
+            layout,
+            &mut ed,
+            paragraphs,
+            view.scroll.offset,
+            pointer_offset,
+        );
+        diagnostics.menu = diagnostics.at(hit).cloned();
+    }
+
     // Click + drag-to-select. On the rising edge of the press, latch the
     // hit caret as the drag anchor and clear any prior selection. On
     // subsequent held frames, the active end follows the pointer and
@@ -120,21 +171,15 @@ pub(super) fn run_input(
     if resp_state.left.held()
         && let Some(pointer_offset) = resp_state.pointer_local
     {
-        // Hit-test runs against the *unscrolled* shaped layout, so we
-        // add last frame's scroll back into the pointer's local coords.
-        // Both offsets are last frame's for the same reason: the user
-        // clicked on what they saw, and this frame's block offset and
-        // scroll are computed after this pass returns.
-        let [pad_l, pad_t, _, _] = ctx.padding.as_array();
-        let block = layout.prev_block_offset;
-        let local_x = pointer_offset.x - pad_l - block.x + view.scroll.offset.x;
-        let local_y = pointer_offset.y - pad_t - block.y + view.scroll.offset.y;
-        // `byte_at_xy` handles both axes; single-line probes at
-        // `y=0` (against an unwrapped layout) collapse to cosmic's
-        // 1D `Buffer::hit` walk — one shaped lookup.
-        let hit = ui
-            .probe_text(ctx.run(ed.text))
-            .byte_at(local_x, if ctx.multiline { local_y } else { 0.0 });
+        let (hit, local) = pointer_hit(
+            ui,
+            layout,
+            &mut ed,
+            paragraphs,
+            view.scroll.offset,
+            pointer_offset,
+        );
+        let (local_x, local_y) = (local.x, local.y);
         if resp_state.left.press_count() > 0 {
             // Press rising edge — the input layer counts the
             // multi-press run (`press_count`: 1 = single, 2 = double,
@@ -142,30 +187,56 @@ pub(super) fn run_input(
             // radius, so single/word/all selection dispatches straight
             // off it.
             ed.state.last_edit_kind = None;
-            match resp_state.left.press_count() {
-                2 => {
-                    // Double-click: select the word under the caret.
-                    let r = word_range_at(ed.text, hit);
-                    if r.is_empty() {
+            ed.state.column_anchor = None;
+            let count = resp_state.left.press_count();
+            // Alt+press adds a caret rather than moving the one there is,
+            // and latches where a column drag would start from. Any other
+            // press starts over with one caret.
+            if count == 1 && ui.modifiers().alt {
+                ed.state.drag_anchor = None;
+                ed.state.column_anchor = Some(Vec2::new(local_x, local_y));
+                ed.add_caret(hit);
+            } else {
+                ed.state.carets.clear();
+                match count {
+                    2 => {
+                        // Double-click: select the word under the caret.
+                        let r = word_range(ed.text, hit);
+                        if r.is_empty() {
+                            ed.state.drag_anchor = Some(hit);
+                            ed.state.selection = None;
+                            ed.state.caret = hit;
+                        } else {
+                            ed.state.drag_anchor = None;
+                            ed.state.selection = Some(r.start);
+                            ed.state.caret = r.end;
+                        }
+                    }
+                    3.. => {
+                        // Triple-click and beyond: select everything.
+                        ed.state.drag_anchor = None;
+                        ed.select_all();
+                    }
+                    _ => {
                         ed.state.drag_anchor = Some(hit);
                         ed.state.selection = None;
                         ed.state.caret = hit;
-                    } else {
-                        ed.state.drag_anchor = None;
-                        ed.state.selection = Some(r.start);
-                        ed.state.caret = r.end;
                     }
                 }
-                3.. => {
-                    // Triple-click and beyond: select everything.
-                    ed.state.drag_anchor = None;
-                    ed.select_all();
-                }
-                _ => {
-                    ed.state.drag_anchor = Some(hit);
-                    ed.state.selection = None;
-                    ed.state.caret = hit;
-                }
+            }
+        } else if let Some(from) = ed.state.column_anchor {
+            // Held Alt+drag: a selection per row between the press and the
+            // pointer, once the box is more than the caret it added.
+            let rows = column_rows(
+                ui,
+                ctx,
+                &mut ed,
+                paragraphs,
+                from,
+                Vec2::new(local_x, local_y),
+            );
+            if rows.len() > 1 || rows.first().is_some_and(|(anchor, caret)| anchor != caret) {
+                ed.set_column(rows);
             }
         } else if let Some(anchor) = ed.state.drag_anchor {
             // Held drag from a single-click press — caret follows
@@ -178,6 +249,7 @@ pub(super) fn run_input(
         }
     } else if !resp_state.left.held() {
         ed.state.drag_anchor = None;
+        ed.state.column_anchor = None;
     }
 
     if !is_focused {
@@ -186,6 +258,8 @@ pub(super) fn run_input(
             blur,
             submitted,
             edited: ed.edited,
+            find,
+            completed,
         };
     }
 
@@ -204,9 +278,40 @@ pub(super) fn run_input(
                 let to_insert = ed.sanitized(chunk.as_str());
                 if !to_insert.is_empty() {
                     ed.replace_selection(&to_insert, EditKind::Typing);
+                    if autocomplete.is_some() {
+                        suggest.arm();
+                    }
                 }
             }
             KeyboardEvent::Down(kp) => {
+                // An open suggestion list is steered by the keys the field
+                // already takes, so focus never has to leave it.
+                if let Some(autocomplete) = autocomplete {
+                    match ListKey::of(kp) {
+                        Some(key) if suggest.showing() => {
+                            match key {
+                                ListKey::Step(by) => suggest.step(by),
+                                ListKey::Accept => {
+                                    let at = suggest.selected;
+                                    completed |= suggest.accept(&mut ed, autocomplete.provider, at);
+                                }
+                                ListKey::Dismiss => suggest.disarm(),
+                            }
+                            return;
+                        }
+                        // ArrowDown has no line to go to in a single-line
+                        // field, so it opens the list instead.
+                        Some(ListKey::Step(1)) if !ed.multiline => {
+                            suggest.arm();
+                            return;
+                        }
+                        // Typing opens it.
+                        None if matches!(kp.key, Key::Char(_)) && !kp.mods.any_command() => {
+                            suggest.arm();
+                        }
+                        _ => {}
+                    }
+                }
                 // Single-line Enter is a *submit* signal, not an edit: the buffer
                 // is left untouched (multi-line handles `\n` in `apply_key`), but
                 // the caller learns the user accepted the value.
@@ -214,14 +319,23 @@ pub(super) fn run_input(
                     submitted = true;
                     return;
                 }
+                if ed.multiline
+                    && let Some(mode) = FindMode::from_keypress(kp)
+                {
+                    find = Some(mode);
+                    return;
+                }
                 if let Some(action) = EditAction::from_keypress(kp) {
                     action.execute(&mut ed, &clipboard);
                     return;
                 }
+                if ed.code && code::apply_key(&mut ed, kp) {
+                    return;
+                }
                 match apply_key(&mut ed, kp) {
                     KeyOutcome::Blur => blur = true,
                     KeyOutcome::Vertical { up, extend } => {
-                        resolve_vertical(&mut ed, ui, ctx, up, extend);
+                        ed.each_caret(|ed| resolve_vertical(ed, ui, ctx, paragraphs, up, extend));
                     }
                     KeyOutcome::None => {}
                 }
@@ -234,19 +348,58 @@ pub(super) fn run_input(
         blur,
         submitted,
         edited: ed.edited,
+        find,
+        completed,
     }
 }
 
+/// The byte under `pointer`, in the field's local coordinates, and the
+/// block-local point it was hit-tested at.
+fn pointer_hit(
+    ui: &mut Ui,
+    layout: &TextLayout,
+    ed: &mut Editor<'_>,
+    paragraphs: &mut Paragraphs,
+    scroll: Vec2,
+    pointer: Vec2,
+) -> (usize, Vec2) {
+    let ctx = &layout.ctx;
+    // Hit-test runs against the *unscrolled* shaped layout, so we
+    // add last frame's scroll back into the pointer's local coords.
+    // Both offsets are last frame's for the same reason: the user
+    // clicked on what they saw, and this frame's block offset and
+    // scroll are computed after this pass returns.
+    let [pad_l, pad_t, _, _] = ctx.padding.as_array();
+    let local = pointer - Vec2::new(pad_l, pad_t) - layout.prev_block_offset + scroll;
+    // `byte_at_xy` handles both axes; single-line probes at
+    // `y=0` (against an unwrapped layout) collapse to cosmic's
+    // 1D `Buffer::hit` walk — one shaped lookup.
+    let hit = paragraphs::byte_at(
+        ui,
+        ctx,
+        ed.text,
+        paragraphs,
+        &mut ed.state.line_edits,
+        local.x,
+        if ctx.multiline { local.y } else { 0.0 },
+    );
+    (hit, local)
+}
+
 pub(super) fn apply_key(editor: &mut Editor<'_>, keypress: KeyPress) -> KeyOutcome {
     let extend = keypress.mods.shift;
     match keypress.key {
         Key::Char(c) if !keypress.mods.any_command() => editor.insert_char(c),
         Key::Backspace => editor.delete_backward(),
         Key::Delete => editor.delete_forward(),
-        Key::ArrowLeft if is_word_nav(keypress.mods) => editor.move_word_left(extend),
-        Key::ArrowRight if is_word_nav(keypress.mods) => editor.move_word_right(extend),
-        Key::ArrowLeft => editor.move_grapheme_left(extend),
-        Key::ArrowRight => editor.move_grapheme_right(extend),
+        Key::ArrowLeft if is_word_nav(keypress.mods) => {
+            editor.each_caret(|ed| ed.move_word_left(extend));
+        }
+        Key::ArrowRight if is_word_nav(keypress.mods) => {
+            editor.each_caret(|ed| ed.move_word_right(extend));
+        }
+        Key::ArrowLeft => editor.each_caret(|ed| ed.move_grapheme_left(extend)),
+        Key::ArrowRight => editor.each_caret(|ed| ed.move_grapheme_right(extend)),
         Key::ArrowUp if editor.multiline => {
             return KeyOutcome::Vertical { up: true, extend };
         }
@@ -254,34 +407,104 @@ pub(super) fn apply_key(editor: &mut Editor<'_>, keypress: KeyPress) -> KeyOutco
             return KeyOutcome::Vertical { up: false, extend };
         }
         Key::Enter if editor.multiline => editor.replace_selection("\n", EditKind::Other),
-        Key::Home => editor.move_caret(0, extend),
-        Key::End => editor.move_caret(editor.text.len(), extend),
-        Key::Escape if !editor.collapse_selection() => return KeyOutcome::Blur,
+        Key::Home => editor.each_caret(|ed| ed.move_caret(0, extend)),
+        Key::End => editor.each_caret(|ed| ed.move_caret(ed.text.len(), extend)),
+        // Escape drops the extra carets, then the selection, then focus.
+        Key::Escape if !editor.drop_carets() && !editor.collapse_selection() => {
+            return KeyOutcome::Blur;
+        }
         Key::Escape => {}
         _ => {}
     }
     KeyOutcome::None
 }
 
-fn resolve_vertical(editor: &mut Editor<'_>, ui: &mut Ui, ctx: &ShapeCtx, up: bool, extend: bool) {
-    // Both queries sit in one `probe_text` closure: the caret position
-    // and the adjacent-line hit resolve under a single shaper borrow and
-    // one cache dispatch, which is exactly what the scoped probe is for.
-    let target = {
-        let probe = ui.probe_text(ctx.run(editor.text));
+fn resolve_vertical(
+    editor: &mut Editor<'_>,
+    ui: &mut Ui,
+    ctx: &ShapeCtx,
+    paragraphs: &mut Paragraphs,
+    up: bool,
+    extend: bool,
+) {
+    let target = if Paragraphs::applies(ctx, editor.text) {
+        // Two lookups rather than one probe: the line above or below may
+        // be another paragraph, which is another run.
+        paragraphs.sync(ctx, editor.text, &mut editor.state.line_edits);
+        let pos = paragraphs.caret_at(ui, ctx, editor.text, editor.state.caret);
+        vertical_probe_y(pos, up).map_or(0, |y| paragraphs.byte_at(ui, ctx, editor.text, pos.x, y))
+    } else {
+        // Both queries sit in one `probe_text` closure: the caret position
+        // and the adjacent-line hit resolve under a single shaper borrow and
+        // one cache dispatch, which is exactly what the scoped probe is for.
+        let flat = editor.text.slice(0..editor.text.len());
+        let probe = ui.probe_text(ctx.run(&flat));
         let pos = probe.caret_at(editor.state.caret);
-        if up && pos.y_top <= 0.5 {
-            0
+        vertical_probe_y(pos, up).map_or(0, |y| probe.byte_at(pos.x, y))
+    };
+    editor.move_caret(target, extend);
+}
+
+/// The rows an Alt+drag from `from` to `to` spans, as the byte under each
+/// end on each row — `(anchor, caret)`, starting from the press's row so
+/// the pointer's row comes last. A row shorter than the box gets a caret
+/// at its end, as the hit-test clamps it there.
+fn column_rows(
+    ui: &mut Ui,
+    ctx: &ShapeCtx,
+    editor: &mut Editor<'_>,
+    paragraphs: &mut Paragraphs,
+    from: Vec2,
+    to: Vec2,
+) -> Vec<(usize, usize)> {
+    let line = ctx.font.line_height_px;
+    let row = |y: f32| {
+        if ctx.multiline {
+            (y.max(0.0) / line) as usize
         } else {
-            let probe_y = if up {
-                pos.y_top - 1.0
-            } else {
-                pos.y_top + pos.line_height + 1.0
-            };
-            probe.byte_at(pos.x, probe_y)
+            0
         }
     };
-    editor.move_caret(target, extend);
+    let (first, last) = (row(from.y), row(to.y));
+    let rows: Vec<usize> = if first <= last {
+        (first..=last).collect()
+    } else {
+        (last..=first).rev().collect()
+    };
+    rows.into_iter()
+        .map(|row| {
+            let y = if ctx.multiline {
+                (row as f32 + 0.5) * line
+            } else {
+                0.0
+            };
+            let mut at = |x| {
+                paragraphs::byte_at(
+                    ui,
+                    ctx,
+                    editor.text,
+                    paragraphs,
+                    &mut editor.state.line_edits,
+                    x,
+                    y,
+                )
+            };
+            (at(from.x), at(to.x))
+        })
+        .collect()
+}
+
+/// Where a step up or down from `pos` hit-tests: just past the edge of
+/// its line. `None` for a step up off the first line, which lands on
+/// byte zero.
+fn vertical_probe_y(pos: Caret, up: bool) -> Option<f32> {
+    if up && pos.y_top <= 0.5 {
+        None
+    } else if up {
+        Some(pos.y_top - 1.0)
+    } else {
+        Some(pos.y_top + pos.line_height + 1.0)
+    }
 }
 
 fn is_word_nav(modifiers: Modifiers) -> bool {
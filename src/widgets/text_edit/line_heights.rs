@@ -0,0 +1,321 @@
+//! The per-line heights behind [`Paragraphs`](super::paragraphs::Paragraphs)
+//! for a rope: every query a frame makes of them — a line's top, the
+//! line at a height, the total — and every splice an edit makes, in
+//! `O(log lines)`.
+//!
+//! The lines are the in-order nodes of a treap: a binary tree balanced
+//! by a pseudo-random priority per node, each node carrying its
+//! subtree's line count and height sum. A splice splits the tree at the
+//! edit's lines and merges the new ones in between; a query walks one
+//! path down, adding up the sums it passes.
+
+use std::ops::Range;
+
+/// No node: an empty subtree.
+const NIL: u32 = u32::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    /// Shaped height, or the estimate the line stands in at until then.
+    height: f32,
+    measured: bool,
+    /// Heap-ordered down the tree, which is what keeps it balanced.
+    priority: u32,
+    left: u32,
+    right: u32,
+    /// Lines in this subtree, this one included.
+    lines: u32,
+    /// Sum of the subtree's heights.
+    total: f32,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct LineHeights {
+    nodes: Vec<Node>,
+    /// Slots of `nodes` a splice removed, reused before `nodes` grows.
+    free: Vec<u32>,
+    root: u32,
+    /// Priorities drawn so far — the seed of the next.
+    drawn: u32,
+}
+
+impl Default for LineHeights {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            drawn: 0,
+        }
+    }
+}
+
+impl LineHeights {
+    /// Start over with `lines` unmeasured lines at `estimate`.
+    pub(super) fn reset(&mut self, lines: usize, estimate: f32) {
+        self.nodes.clear();
+        self.free.clear();
+        self.root = self.build(lines, estimate);
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.lines(self.root)
+    }
+
+    /// Replace `range` of the lines with `inserted` unmeasured ones at
+    /// `estimate`.
+    pub(super) fn splice(&mut self, range: Range<usize>, inserted: usize, estimate: f32) {
+        let (before, rest) = self.split(self.root, range.start);
+        let (removed, after) = self.split(rest, range.len());
+        self.release(removed);
+        let inserted = self.build(inserted, estimate);
+        let head = self.merge(before, inserted);
+        self.root = self.merge(head, after);
+    }
+
+    /// `line`'s shaped height; `None` until [`Self::measure`] records one.
+    pub(super) fn measured(&self, line: usize) -> Option<f32> {
+        let node = &self.nodes[self.find(line) as usize];
+        node.measured.then_some(node.height)
+    }
+
+    /// Record `line`'s shaped height.
+    pub(super) fn measure(&mut self, line: usize, height: f32) {
+        let mut path = Vec::new();
+        let mut at = self.root;
+        let mut line = line;
+        loop {
+            path.push(at);
+            let left = self.nodes[at as usize].left;
+            let before = self.lines(left);
+            if line < before {
+                at = left;
+            } else if line == before {
+                break;
+            } else {
+                line -= before + 1;
+                at = self.nodes[at as usize].right;
+            }
+        }
+        let node = &mut self.nodes[at as usize];
+        node.height = height;
+        node.measured = true;
+        for &at in path.iter().rev() {
+            self.update(at);
+        }
+    }
+
+    /// Sum of the heights above `line`; the total for `len()`.
+    pub(super) fn top(&self, line: usize) -> f32 {
+        let mut top = 0.0;
+        let mut at = self.root;
+        let mut line = line;
+        while at != NIL {
+            let node = &self.nodes[at as usize];
+            let before = self.lines(node.left);
+            if line < before {
+                at = node.left;
+                continue;
+            }
+            top += self.total(node.left);
+            if line == before {
+                break;
+            }
+            top += node.height;
+            line -= before + 1;
+            at = node.right;
+        }
+        top
+    }
+
+    fn total(&self, at: u32) -> f32 {
+        if at == NIL {
+            0.0
+        } else {
+            self.nodes[at as usize].total
+        }
+    }
+
+    /// Height of every line.
+    pub(super) fn total_height(&self) -> f32 {
+        self.total(self.root)
+    }
+
+    /// The last line whose top is at or above `y`, clamped to the lines
+    /// there are.
+    pub(super) fn line_at(&self, y: f32) -> usize {
+        let mut y = y;
+        let mut line = 0;
+        let mut at = self.root;
+        while at != NIL {
+            let node = &self.nodes[at as usize];
+            let above = self.total(node.left);
+            if node.left != NIL && y < above {
+                at = node.left;
+                continue;
+            }
+            y -= above;
+            line += self.lines(node.left);
+            if y < node.height || node.right == NIL {
+                break;
+            }
+            y -= node.height;
+            line += 1;
+            at = node.right;
+        }
+        line
+    }
+
+    fn lines(&self, at: u32) -> usize {
+        if at == NIL {
+            0
+        } else {
+            self.nodes[at as usize].lines as usize
+        }
+    }
+
+    /// The node holding `line`.
+    fn find(&self, mut line: usize) -> u32 {
+        let mut at = self.root;
+        loop {
+            let node = &self.nodes[at as usize];
+            let before = self.lines(node.left);
+            if line < before {
+                at = node.left;
+            } else if line == before {
+                return at;
+            } else {
+                line -= before + 1;
+                at = node.right;
+            }
+        }
+    }
+
+    /// Recount `at` from its children.
+    fn update(&mut self, at: u32) {
+        let node = self.nodes[at as usize];
+        let lines = self.lines(node.left) + 1 + self.lines(node.right);
+        let total = self.total(node.left) + node.height + self.total(node.right);
+        let node = &mut self.nodes[at as usize];
+        node.lines = lines as u32;
+        node.total = total;
+    }
+
+    /// Split the subtree at `at` into its first `lines` lines and the rest.
+    fn split(&mut self, at: u32, lines: usize) -> (u32, u32) {
+        if at == NIL {
+            return (NIL, NIL);
+        }
+        let node = self.nodes[at as usize];
+        let before = self.lines(node.left);
+        if lines <= before {
+            let (head, tail) = self.split(node.left, lines);
+            self.nodes[at as usize].left = tail;
+            self.update(at);
+            (head, at)
+        } else {
+            let (head, tail) = self.split(node.right, lines - before - 1);
+            self.nodes[at as usize].right = head;
+            self.update(at);
+            (at, tail)
+        }
+    }
+
+    /// Join two subtrees, every line of `head` before every line of `tail`.
+    fn merge(&mut self, head: u32, tail: u32) -> u32 {
+        if head == NIL {
+            return tail;
+        }
+        if tail == NIL {
+            return head;
+        }
+        if self.nodes[head as usize].priority > self.nodes[tail as usize].priority {
+            let right = self.nodes[head as usize].right;
+            self.nodes[head as usize].right = self.merge(right, tail);
+            self.update(head);
+            head
+        } else {
+            let left = self.nodes[tail as usize].left;
+            self.nodes[tail as usize].left = self.merge(head, left);
+            self.update(tail);
+            tail
+        }
+    }
+
+    /// A subtree of `lines` unmeasured lines at `estimate`, built in one
+    /// pass: each new node goes on the tree's right spine, below the first
+    /// node there that outranks it.
+    fn build(&mut self, lines: usize, estimate: f32) -> u32 {
+        let mut spine: Vec<u32> = Vec::new();
+        for _ in 0..lines {
+            let at = self.alloc(estimate);
+            let priority = self.nodes[at as usize].priority;
+            let mut below = NIL;
+            while let Some(&top) = spine.last()
+                && self.nodes[top as usize].priority < priority
+            {
+                spine.pop();
+                self.update(top);
+                below = top;
+            }
+            self.nodes[at as usize].left = below;
+            if let Some(&top) = spine.last() {
+                self.nodes[top as usize].right = at;
+            }
+            spine.push(at);
+        }
+        let mut root = NIL;
+        while let Some(top) = spine.pop() {
+            self.update(top);
+            root = top;
+        }
+        root
+    }
+
+    fn alloc(&mut self, height: f32) -> u32 {
+        let node = Node {
+            height,
+            measured: false,
+            priority: self.draw(),
+            left: NIL,
+            right: NIL,
+            lines: 1,
+            total: height,
+        };
+        match self.free.pop() {
+            Some(at) => {
+                self.nodes[at as usize] = node;
+                at
+            }
+            None => {
+                self.nodes.push(node);
+                (self.nodes.len() - 1) as u32
+            }
+        }
+    }
+
+    /// Hand the subtree at `at` back to `free`.
+    fn release(&mut self, at: u32) {
+        let mut pending = vec![at];
+        while let Some(at) = pending.pop() {
+            if at == NIL {
+                continue;
+            }
+            let node = self.nodes[at as usize];
+            pending.extend([node.left, node.right]);
+            self.free.push(at);
+        }
+    }
+
+    /// The next priority: a counter run through a 32-bit hash finalizer,
+    /// spread enough to balance the tree and the same from run to run.
+    fn draw(&mut self) -> u32 {
+        self.drawn = self.drawn.wrapping_add(1);
+        let mut x = self.drawn.wrapping_mul(0x9e37_79b9);
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x85eb_ca6b);
+        x ^= x >> 13;
+        x = x.wrapping_mul(0xc2b2_ae35);
+        x ^ (x >> 16)
+    }
+}
@@ -7,6 +7,8 @@ use crate::widgets::context_menu::ContextMenu;
 use crate::widgets::context_menu::menu_item::MenuItem;
 use crate::widgets::response::ResponseSnapshot;
 use crate::widgets::text_edit::action::{ActionAvailability, EditAction};
+use crate::widgets::text_edit::diagnostics::Diagnostic;
+use crate::widgets::text_edit::edit_state::EditKind;
 use crate::widgets::text_edit::editor::Editor;
 
 /// Run the default context menu, returning whether it edited the
@@ -24,14 +26,19 @@ use crate::widgets::text_edit::editor::Editor;
 /// `filter` is the field's own — the menu drains the same layer-wide
 /// stream `run_input` does, so it owes the same
 /// [`KeyFilter::accepts`] gate against double dispatch.
+///
+/// `diagnostic` is the one the menu was opened over: its message heads
+/// the menu, followed by a row per suggested replacement.
 pub(super) fn show(
     ui: &mut Ui,
     snapshot: &ResponseSnapshot,
     editor: &mut Editor<'_>,
     filter: KeyFilter,
+    diagnostic: Option<&Diagnostic>,
 ) -> bool {
     let clipboard = ui.clipboard();
     let mut clicked_action = None;
+    let mut replacement = None;
     ContextMenu::attach(ui, snapshot).show(ui, |ui, popup| {
         ui.each_keyboard_event(|_, event| {
             let Some(KeyboardEvent::Down(keypress)) = filter.accepts(event) else {
@@ -45,6 +52,18 @@ pub(super) fn show(
             }
         });
 
+        if let Some(diagnostic) = diagnostic {
+            let message = ui.intern(&diagnostic.message);
+            MenuItem::new(message).enabled(false).show(ui, popup);
+            for suggestion in &diagnostic.suggestions {
+                let label = ui.intern(suggestion);
+                if MenuItem::new(label).show(ui, popup).left.clicked() {
+                    replacement = Some(suggestion);
+                }
+            }
+            MenuItem::separator().show(ui);
+        }
+
         let has_selection = editor.state.sel_range().is_some();
         let has_text = !editor.text.is_empty();
         for item in EditAction::MENU {
@@ -65,6 +84,17 @@ pub(super) fn show(
             }
         }
     });
+    // The buffer may have changed since the menu opened; a range that no
+    // longer lands on it is left alone.
+    if let (Some(diagnostic), Some(with)) = (diagnostic, replacement) {
+        let range = diagnostic.range.clone();
+        if range.end <= editor.text.len()
+            && editor.text.is_char_boundary(range.start)
+            && editor.text.is_char_boundary(range.end)
+        {
+            editor.replace_range(range, with, EditKind::Other);
+        }
+    }
     if let Some(action) = clicked_action {
         action.execute(editor, &clipboard);
     }
@@ -1,17 +1,28 @@
 mod action;
+pub(crate) mod autocomplete;
 #[cfg(feature = "bench")]
 pub(crate) mod bench;
+pub(crate) mod buffer;
 mod caret_paint;
+mod carets;
+mod code;
+pub(crate) mod diagnostics;
 mod edit_state;
 mod editor;
+mod find;
+pub(crate) mod highlight;
 mod input;
+mod line_heights;
 mod menu;
 mod paint_input;
+mod paragraphs;
 mod shape_ctx;
 mod text_geometry;
 mod text_layout;
-mod unicode;
+pub(crate) mod unicode;
+pub(crate) mod validate;
 mod view_state;
+mod visible;
 
 use crate::input::key_class::KeyFilter;
 use crate::input::response::ResponseState;
@@ -23,18 +34,32 @@ use crate::primitives::approx::noop_f32;
 use crate::primitives::rect::Rect;
 use crate::primitives::spacing::Spacing;
 use crate::scene::node::Node;
+use crate::text::FontFamily;
+use crate::text::probe::Caret;
 use crate::ui::Ui;
 use crate::widgets::response::{Response, ResponseSnapshot};
+use crate::widgets::text_edit::autocomplete::{Autocomplete, SuggestAnchor, SuggestState};
+use crate::widgets::text_edit::buffer::TextBuffer;
 use crate::widgets::text_edit::caret_paint::CaretPaint;
-use crate::widgets::text_edit::edit_state::EditState;
+use crate::widgets::text_edit::code::Gutter;
+use crate::widgets::text_edit::diagnostics::{DiagnosticProvider, DiagnosticState};
+use crate::widgets::text_edit::edit_state::{EditState, SelectionState};
 use crate::widgets::text_edit::editor::Editor;
+use crate::widgets::text_edit::find::FindState;
+use crate::widgets::text_edit::highlight::{HighlightCache, Highlighter};
 use crate::widgets::text_edit::input::{AcceptPolicy, InputResult, run_input};
-use crate::widgets::text_edit::paint_input::PaintInput;
+use crate::widgets::text_edit::paint_input::{
+    CodePaint, GhostPaint, MatchPaint, PaintInput, PaintText, SquigglePaint,
+};
+use crate::widgets::text_edit::paragraphs::Paragraphs;
 use crate::widgets::text_edit::text_geometry::{GeometryInput, TextGeometry};
 use crate::widgets::text_edit::text_layout::{LayoutInput, TextLayout};
+use crate::widgets::text_edit::validate::{Validator, Validity};
 use crate::widgets::text_edit::view_state::{ViewState, ViewUpdateInput};
+use crate::widgets::text_edit::visible::VisibleText;
 use crate::widgets::theme::text_edit::TextEditTheme;
 use crate::widgets::theme::widget_look::look_plan::LookPlan;
+use crate::widgets::tooltip::Tooltip;
 use crate::widgets::widget::Widget;
 use glam::Vec2;
 use std::borrow::Cow;
@@ -52,6 +77,26 @@ struct TextEditState {
     /// disjoint, where a field of `view` would have to be moved out and put
     /// back around every call that touches the rest of the view.
     selection_rects: Vec<Rect>,
+    /// Where the extra carets paint, refilled beside `selection_rects` and
+    /// retained for the same reason.
+    caret_positions: Vec<Caret>,
+    /// Line table for a buffer laid out a paragraph at a time; idle (and
+    /// empty) for a contiguous one.
+    paragraphs: Paragraphs,
+    /// What the highlighter said about the lines on show; empty without
+    /// one.
+    highlight: HighlightCache,
+    /// The lines and runs painted this frame, where the buffer doesn't
+    /// paint as one run.
+    visible: VisibleText,
+    /// The find bar, its matches and their washes; idle while closed.
+    find: FindState,
+    /// The suggestion list; idle (and empty) while closed.
+    suggest: SuggestState,
+    /// What the diagnostic provider flagged; empty without one.
+    diagnostics: DiagnosticState,
+    /// The validator's last verdict; valid without one.
+    validity: Validity,
 }
 
 /// Editable text leaf. Supports typing (`KeyDown` printable chars or
@@ -59,14 +104,20 @@ struct TextEditState {
 /// end), drag-select, multi-line, cut/copy/paste, undo+redo
 /// (Cmd/Ctrl+Z, Cmd/Ctrl+Shift+Z), escape-to-blur, click-to-place-caret.
 ///
-/// Borrows `&'a mut String` for the buffer — host owns the storage and
-/// the widget retains only semantic and view state. Host-side buffer
-/// mutations between frames are visible immediately; persisted offsets
-/// are repaired before each input pass.
+/// Several carets at once: Alt+click adds one, Cmd/Ctrl+D selects the
+/// word at the caret and then adds the next occurrence of the selection,
+/// and Alt+drag selects a column. Typing, deleting, cut and paste apply
+/// at every caret as one undo step; Escape drops back to one caret.
+///
+/// Borrows the buffer as a [`TextBuffer`] — a `String` for an ordinary
+/// field, a [`TextRope`](crate::TextRope) for a large document. The host
+/// owns the storage and the widget retains only semantic and view state.
+/// Host-side buffer mutations between frames are visible immediately;
+/// persisted offsets are repaired before each input pass.
 #[derive(Debug)]
 pub struct TextEdit<'a> {
     node: Node,
-    text: &'a mut String,
+    text: &'a mut dyn TextBuffer,
     style: Option<&'a TextEditTheme>,
     placeholder: Cow<'static, str>,
     /// When `true`, Enter inserts `\n`, paste/IME preserve newlines,
@@ -92,11 +143,20 @@ pub struct TextEdit<'a> {
     /// Whether Escape belongs to the container rather than to this field —
     /// see [`TextEdit::escape_falls_through`].
     escape_falls_through: bool,
+    /// Code-editor mode — see [`TextEdit::code`].
+    code: bool,
+    highlighter: Option<&'a dyn Highlighter>,
+    validator: Option<&'a dyn Validator>,
+    /// Explain an invalid value in a tooltip — see
+    /// [`TextEdit::invalid_tooltip`].
+    invalid_tooltip: bool,
+    autocomplete: Option<Autocomplete<'a>>,
+    diagnostics: Option<&'a dyn DiagnosticProvider>,
 }
 
 impl<'a> TextEdit<'a> {
     #[track_caller]
-    pub fn new(text: &'a mut String) -> Self {
+    pub fn new(text: &'a mut dyn TextBuffer) -> Self {
         // **A scrolling viewport over one child**, which is what a field is.
         //
         // A child, because where the text sits inside the inner rect is an
@@ -138,6 +198,12 @@ impl<'a> TextEdit<'a> {
             max_chars: None,
             select_all_on_focus: false,
             escape_falls_through: false,
+            code: false,
+            highlighter: None,
+            validator: None,
+            invalid_tooltip: false,
+            autocomplete: None,
+            diagnostics: None,
         }
     }
 
@@ -190,12 +256,80 @@ impl<'a> TextEdit<'a> {
     /// Switch to multi-line mode. Enter inserts `\n` (instead of
     /// blurring), paste / IME-text preserve newlines, text soft-wraps
     /// to the editor's inner width, and click/caret/selection all
-    /// route through cosmic-text's 2D layout.
+    /// route through cosmic-text's 2D layout. Ctrl+F opens a find bar
+    /// over the field — case, whole-word and regex options, every match
+    /// marked — and Ctrl+H adds a replace row to it; its colours come
+    /// from [`crate::Theme::find_bar`].
     pub fn multiline(mut self, on: bool) -> Self {
         self.multiline = on;
         self
     }
 
+    /// Switch to code-editor mode: multi-line without soft wrap, in the
+    /// monospace family, with a line-number gutter, the caret's line and
+    /// its matching bracket marked, Enter carrying the line's indentation
+    /// (and adding a level after an opening bracket), and Tab / Shift+Tab
+    /// indenting the caret's line or every selected one. Colours come from
+    /// [`crate::Theme::code_edit`].
+    pub fn code(mut self, on: bool) -> Self {
+        self.code = on;
+        self
+    }
+
+    /// Colour the text with `highlighter`, one visible line at a time.
+    /// What it says about a line is kept until the line's text changes,
+    /// so an edit re-highlights only the line it touched. A highlighted
+    /// field doesn't soft-wrap: a multi-line one scrolls sideways.
+    pub fn highlighter(mut self, highlighter: &'a dyn Highlighter) -> Self {
+        self.highlighter = Some(highlighter);
+        self
+    }
+
+    /// Run every edit past `validator` before it lands — it may refuse
+    /// it, or rewrite the value, as an input mask or a formatter does —
+    /// and judge the buffer with it every frame. A value it flags paints
+    /// the field's stroke in [`TextEditTheme::invalid`] and is reported
+    /// on [`TextEditResponse::invalid`].
+    ///
+    /// Only over a contiguous buffer — a `String`. Every edit is judged
+    /// as the whole value it leaves, and building that out of a rope per
+    /// keystroke costs the document; a buffer without
+    /// [`TextBuffer::as_str`] ignores the validator.
+    pub fn validator(mut self, validator: &'a dyn Validator) -> Self {
+        self.validator = self.text.as_str().is_some().then_some(validator);
+        self
+    }
+
+    /// Show the validator's reason for an invalid value in a
+    /// [`Tooltip`] over the field. Off by default, for forms that say
+    /// it in a label of their own.
+    pub fn invalid_tooltip(mut self) -> Self {
+        self.invalid_tooltip = true;
+        self
+    }
+
+    /// Offer suggestions as the user types, in a list below the field
+    /// (or the caret — see [`Autocomplete::anchor`]) with the characters
+    /// that matched highlighted. ArrowUp/ArrowDown move the selection and
+    /// Tab or Enter accepts it, as does a click; Escape closes the list.
+    /// Where the selected suggestion extends what is typed, the rest of it
+    /// shows after the caret. Focus stays in the field throughout. The
+    /// list is [`crate::Theme::context_menu`]'s, and its own colours are
+    /// [`crate::Theme::autocomplete`]'s.
+    pub fn autocomplete(mut self, autocomplete: Autocomplete<'a>) -> Self {
+        self.autocomplete = Some(autocomplete);
+        self
+    }
+
+    /// Underline what `provider` flags with a squiggle in its severity's
+    /// colour from [`crate::Theme::diagnostics`], and offer its suggested
+    /// replacements at the top of the right-click menu opened over it.
+    /// The provider is asked again whenever the buffer changes.
+    pub fn diagnostics(mut self, provider: &'a dyn DiagnosticProvider) -> Self {
+        self.diagnostics = Some(provider);
+        self
+    }
+
     pub fn placeholder(mut self, s: impl Into<Cow<'static, str>>) -> Self {
         self.placeholder = s.into();
         self
@@ -241,6 +375,9 @@ impl<'a> TextEdit<'a> {
             size: _,
             min_size: _,
             max_size: _,
+            min_units: _,
+            max_units: _,
+            aspect_ratio: _,
             // `TextEdit::new` pins `ClipMode::Rect` so glyphs cannot
             // spill past the field; a caller's clip choice must not
             // relax that.
@@ -259,6 +396,7 @@ impl<'a> TextEdit<'a> {
             // child.
             gaps: _,
             justify: _,
+            align_content: _,
             child_align: _,
             // The editor senses as a text field, not as a click/drag
             // chip.
@@ -272,6 +410,7 @@ impl<'a> TextEdit<'a> {
             margin,
             align,
             position,
+            anchor,
             grid,
             visibility,
         } = from;
@@ -283,6 +422,7 @@ impl<'a> TextEdit<'a> {
         }
         self.node.align = align;
         self.node.position = position;
+        self.node.anchor = anchor;
         self.node.grid = grid;
         self.node.visibility = visibility;
         self
@@ -291,6 +431,7 @@ impl<'a> TextEdit<'a> {
     pub fn show(self, ui: &mut Ui) -> TextEditResponse<'_> {
         let widget = ui.widget(self.node);
         let id = widget.id();
+        let invalid_tooltip = self.invalid_tooltip;
         // **The state row is moved out for the whole pass and moved back
         // after.** Every stage of the pass wants it, and the stages are
         // separated by `&mut Ui` calls — the keyboard drain, the shape
@@ -304,8 +445,23 @@ impl<'a> TextEdit<'a> {
         // write-back only runs on *some* paths silently resets the caret.
         let mut state = std::mem::take(ui.state_mut::<TextEditState>(id));
         let signals = self.pass(ui, widget, &mut state);
+        let reason = state.validity.reason().filter(|_| signals.invalid);
+        if invalid_tooltip && let Some(reason) = reason.filter(|r| !r.is_empty()) {
+            let snapshot = ResponseSnapshot {
+                id,
+                state: signals.state,
+            };
+            Tooltip::on(&snapshot).text(reason).show(ui);
+        }
         *ui.state_mut::<TextEditState>(id) = state;
 
+        // The reason is lent off the row rather than cloned out of it, so
+        // an owned one costs nothing while it stands.
+        let ui: &Ui = ui;
+        let invalid = signals
+            .invalid
+            .then(|| ui.try_state::<TextEditState>(id)?.validity.reason())
+            .flatten();
         TextEditResponse {
             // The pass already probed this id and tracked the one field that
             // can move under it mid-pass (focus), so it hands the state back
@@ -316,6 +472,8 @@ impl<'a> TextEdit<'a> {
             cancelled: signals.cancelled,
             gained_focus: signals.gained_focus,
             lost_focus: signals.lost_focus,
+            invalid,
+            completed: signals.completed,
         }
     }
 
@@ -327,6 +485,15 @@ impl<'a> TextEdit<'a> {
     /// is one copy and no point at which it can be recorded stale.
     fn pass(self, ui: &mut Ui, mut widget: Widget, state: &mut TextEditState) -> EditSignals {
         let id = widget.id();
+        // A press on a suggestion row lands on no focusable widget, so it
+        // clears focus; it is handed straight back, before it is read, so
+        // the field never reads as blurred.
+        let picked = self
+            .autocomplete
+            .and_then(|_| state.suggest.pressed(ui, id));
+        if picked.is_some() {
+            ui.request_focus(Some(id));
+        }
         let mut is_focused = ui.focused_id() == Some(id);
         // The pass's one probe, and what `show` hands back at the end.
         // Nothing below can move a cascade or layout answer — both are frozen
@@ -368,6 +535,11 @@ impl<'a> TextEdit<'a> {
         // fact rather than two that can disagree.
         let mut filter = KeyFilter::TEXT_FIELD;
         filter.set(KeyFilter::ESCAPE, !self.escape_falls_through);
+        // An open suggestion list is something of the field's own to
+        // cancel, whatever the field does with Escape otherwise.
+        if self.autocomplete.is_some() && state.suggest.showing() {
+            filter.insert(KeyFilter::ESCAPE);
+        }
         if is_focused {
             widget.node.flags.set_key_filter(filter);
         }
@@ -379,10 +551,12 @@ impl<'a> TextEdit<'a> {
         // caret hit-test reads it back below, so both see the resolved value.
         let theme = ui.theme();
         let slot = self.slot(theme);
+        let code_theme = self.code.then(|| theme.code_edit.clone());
         let caret_color = slot.caret;
         let caret_width = slot.caret_width;
         let selection_color = slot.selection;
         let placeholder_color = slot.placeholder;
+        let slot_invalid = slot.invalid;
         let look = LookPlan {
             target: slot.pick(&response).to_animated(&theme.text),
             padding: slot.padding,
@@ -400,10 +574,16 @@ impl<'a> TextEdit<'a> {
                 cancelled: false,
                 gained_focus: is_focused && !was_focused,
                 lost_focus: was_focused && !is_focused,
+                invalid: false,
+                completed: false,
                 state: probed,
             };
         }
-        let font = look.text.font();
+        let mut font = look.text.font();
+        if self.code {
+            font.family = FontFamily::Mono;
+        }
+        let multiline = self.multiline || self.code;
         // `Tree::open_node` folds chrome stroke width into the stored
         // padding so children sit inside the painted stroke ring (see
         // `scene/tree/mod.rs::open_node`). Encoder's clip mask is
@@ -417,7 +597,7 @@ impl<'a> TextEdit<'a> {
         } else {
             look.background.stroke.width
         };
-        let padding = Spacing::from_array(
+        let mut padding = Spacing::from_array(
             widget
                 .node
                 .padding
@@ -425,13 +605,24 @@ impl<'a> TextEdit<'a> {
                 .as_array()
                 .map(|v| v + stroke_w),
         );
+        // The gutter sits inside the inner rect, so the text is shaped and
+        // hit-tested as if the padding ran past it; the block's margin in
+        // `PaintInput` is the same width.
+        let gutter = code_theme
+            .as_ref()
+            .map(|theme| Gutter::measure(ui, font, self.text.line_count(), theme.gutter_padding));
+        if let Some(gutter) = gutter {
+            let [left, top, right, bottom] = padding.as_array();
+            padding = Spacing::new(left + gutter.width, top, right, bottom);
+        }
         let previous_block_offset = state.view.block_offset;
         let layout = TextLayout::resolve(LayoutInput {
             response_rect: response.layout_rect,
             padding,
             caret_width,
             font,
-            multiline: self.multiline,
+            multiline,
+            wrap: !self.code && self.highlighter.is_none(),
             text_align: self.text_align,
             previous_block_offset,
         });
@@ -447,7 +638,9 @@ impl<'a> TextEdit<'a> {
         let InputResult {
             blur: blur_after,
             submitted,
-            edited,
+            mut edited,
+            find: open_find,
+            mut completed,
         } = run_input(
             ui,
             &response,
@@ -458,6 +651,9 @@ impl<'a> TextEdit<'a> {
                 max_chars: self.max_chars,
                 select_all_on_focus: self.select_all_on_focus,
                 filter,
+                code: self.code,
+                validator: self.validator,
+                autocomplete: self.autocomplete,
             },
             state,
         );
@@ -466,6 +662,12 @@ impl<'a> TextEdit<'a> {
             is_focused = false;
             probed.focused = false;
         }
+        if let (Some(autocomplete), Some(at)) = (self.autocomplete, picked) {
+            let mut editor = Editor::new(self.text, &mut state.edit, ctx.multiline, self.max_chars);
+            editor.validator = self.validator;
+            completed |= state.suggest.accept(&mut editor, autocomplete.provider, at);
+            edited |= editor.edited;
+        }
         let gained_focus = is_focused && !was_focused;
         let lost_focus = was_focused && !is_focused;
 
@@ -476,12 +678,40 @@ impl<'a> TextEdit<'a> {
         // reconciles the undo history against the buffer once.
         let menu_edited = {
             let mut editor = Editor::new(self.text, &mut state.edit, ctx.multiline, self.max_chars);
-            menu::show(ui, &snapshot, &mut editor, filter)
+            editor.validator = self.validator;
+            let diagnostic = self.diagnostics.and(state.diagnostics.menu.as_ref());
+            menu::show(ui, &snapshot, &mut editor, filter, diagnostic)
+        };
+        // The find bar, shown over the field while open. Like the menu it
+        // edits through a session of its own, and it searches after every
+        // edit above has landed. Opening it seeds the query from a
+        // selection within one line and hands the query field the focus.
+        if let Some(mode) = open_find {
+            let seed = state
+                .edit
+                .sel_range()
+                .map(|range| self.text.slice(range).into_owned())
+                .filter(|selected| !selected.contains('\n'));
+            state.find.open(mode, &mut state.edit, seed);
+            ui.request_focus(Some(id.with("find-query")));
+        }
+        let find_edited = match response.rect {
+            Some(rect) if state.find.open => {
+                let mut editor =
+                    Editor::new(self.text, &mut state.edit, ctx.multiline, self.max_chars);
+                editor.validator = self.validator;
+                find::show_bar(ui, id, rect, &mut editor, &mut state.find);
+                editor.edited
+            }
+            _ => false,
         };
-        let changed = edited || menu_edited;
+        let changed = edited || menu_edited || find_edited;
         let caret_moved = caret_before != state.edit.caret || sel_before != state.edit.selection;
         let caret_byte = state.edit.caret;
-        let selection = state.edit.sel_range();
+        let lone_caret =
+            is_focused && state.edit.selection.is_none() && state.edit.carets.is_empty();
+        let selection = is_focused.then(|| state.edit.sel_range()).flatten();
+        let carets: &[SelectionState] = if is_focused { &state.edit.carets } else { &[] };
 
         // Same pixel + line fold `Scroll` applies, against the editor's
         // own line height rather than the theme's default text size:
@@ -499,12 +729,49 @@ impl<'a> TextEdit<'a> {
                 text: self.text,
                 placeholder: &self.placeholder,
                 caret: caret_byte,
-                selection: is_focused.then_some(selection).flatten(),
+                selection: selection.clone(),
+                carets,
+                scroll_y: state.view.scroll.offset.y,
             },
             &mut state.selection_rects,
+            &mut state.caret_positions,
+            &mut state.paragraphs,
+            &mut state.edit.line_edits,
         );
         let caret_pos = geometry.caret_pos;
         state.edit.observe_text_hash(geometry.text_hash);
+        if let Some(provider) = self.diagnostics {
+            state
+                .diagnostics
+                .refresh(provider, self.text, geometry.text_hash);
+        }
+        let invalid = match self.validator {
+            Some(validator) => {
+                state
+                    .validity
+                    .refresh(validator, self.text, geometry.text_hash);
+                state.validity.reason().is_some()
+            }
+            None => false,
+        };
+        let mut chrome = look.background;
+        if invalid {
+            chrome.stroke.color = slot_invalid;
+        }
+        // Asked after every edit has landed, so the list answers the text
+        // as it is painted.
+        if let Some(autocomplete) = self.autocomplete {
+            if is_focused && state.suggest.armed {
+                state.suggest.refresh(
+                    autocomplete.provider,
+                    self.text,
+                    caret_byte,
+                    geometry.text_hash,
+                );
+            } else {
+                state.suggest.disarm();
+            }
+        }
         let now = ui.now();
         let view = state.view.update(ViewUpdateInput {
             response_rect: response.layout_rect,
@@ -521,12 +788,145 @@ impl<'a> TextEdit<'a> {
             now,
             block_offset: geometry.block_offset,
         });
+        let suggestions = match (self.autocomplete, response.rect) {
+            (Some(autocomplete), Some(rect)) if is_focused && state.suggest.showing() => {
+                Some(match autocomplete.anchor {
+                    SuggestAnchor::Field => (rect, rect.size.w),
+                    SuggestAnchor::Caret => {
+                        let [pad_l, pad_t, _, _] = ctx.padding.as_array();
+                        let at = rect.min + Vec2::new(pad_l, pad_t) + geometry.block_offset
+                            - view.scroll.offset
+                            + Vec2::new(caret_pos.x, caret_pos.y_top);
+                        (Rect::new(at.x, at.y, 0.0, caret_pos.line_height), 0.0)
+                    }
+                })
+            }
+            _ => None,
+        };
+        let brackets = (is_focused && self.code)
+            .then(|| code::bracket_rects(ui, &ctx, self.text, &mut state.paragraphs, caret_byte))
+            .flatten();
+        let highlight = self.highlighter.map(|highlighter| {
+            state.highlight.begin_pass(highlighter);
+            (highlighter, &mut state.highlight)
+        });
+        // A paragraph-laid buffer shapes what the view settled on showing,
+        // now that it has. The measuring can only grow the document past
+        // the estimate the view was bounded with, so a frame that grew it
+        // asks for one more to bound the scroll by the real height.
+        let flat;
+        let text = if Paragraphs::applies(&ctx, self.text) {
+            let lines = state.paragraphs.measure_visible(
+                ui,
+                &ctx,
+                self.text,
+                view.scroll.offset.y,
+                layout.inner_size.h,
+            );
+            state.paragraphs.selection_rects(
+                ui,
+                &ctx,
+                self.text,
+                lines.clone(),
+                selection.into_iter().chain(
+                    state
+                        .edit
+                        .carets
+                        .iter()
+                        .filter(|_| is_focused)
+                        .filter_map(|caret| caret.sel_range()),
+                ),
+                &mut state.selection_rects,
+            );
+            if state.find.open {
+                state.find.rects_paragraphs(
+                    ui,
+                    &ctx,
+                    self.text,
+                    &mut state.paragraphs,
+                    lines.clone(),
+                );
+            }
+            if self.diagnostics.is_some() {
+                state.diagnostics.rects_paragraphs(
+                    ui,
+                    &ctx,
+                    self.text,
+                    &mut state.paragraphs,
+                    lines.clone(),
+                );
+            }
+            if state.paragraphs.total_height() != geometry.content_size.h {
+                ui.request_repaint();
+            }
+            state
+                .visible
+                .paragraphs(ui, &ctx, self.text, &mut state.paragraphs, lines, highlight);
+            PaintText::Runs(state.visible.runs())
+        } else {
+            flat = self.text.slice(0..self.text.len());
+            let top = view.scroll.offset.y;
+            let view = top..top + layout.inner_size.h;
+            if self.code || highlight.is_some() {
+                state.visible.flat(ui, &ctx, &flat, view.clone(), highlight);
+            }
+            if state.find.open {
+                state.find.rects_flat(ui, &ctx, &flat, view.clone());
+            }
+            if self.diagnostics.is_some() {
+                state.diagnostics.rects_flat(ui, &ctx, &flat, view);
+            }
+            if self.highlighter.is_some() && !flat.is_empty() {
+                PaintText::Runs(state.visible.runs())
+            } else {
+                PaintText::Run(&flat)
+            }
+        };
+        let code = code_theme.zip(gutter).map(|(theme, gutter)| CodePaint {
+            theme,
+            gutter_id: id.with("gutter"),
+            gutter,
+            lines: state.visible.lines(),
+            current_line: is_focused.then(|| {
+                let width = geometry
+                    .display_size
+                    .w
+                    .max(layout.inner_size.w + view.scroll.offset.x);
+                let band = Rect::new(0.0, caret_pos.y_top, width, caret_pos.line_height);
+                (self.text.byte_to_line(caret_byte), band)
+            }),
+            brackets,
+        });
+        let matches = state.find.open.then(|| {
+            let theme = &ui.theme().find_bar;
+            MatchPaint {
+                rects: state.find.rects(),
+                color: theme.matches,
+                current: theme.current_match,
+            }
+        });
+        let squiggles = self.diagnostics.map(|_| SquigglePaint {
+            rects: state.diagnostics.rects(),
+            theme: ui.theme().diagnostics.clone(),
+        });
+        let ghost = if lone_caret && state.suggest.showing() {
+            state
+                .suggest
+                .ghost(self.text.len(), caret_byte)
+                .map(|rest| GhostPaint {
+                    text: rest,
+                    at: Vec2::new(caret_pos.x, caret_pos.y_top),
+                    color: ui.theme().autocomplete.ghost,
+                })
+        } else {
+            None
+        };
         let text_color = look.text.color;
         let placeholder = self.placeholder;
         PaintInput {
-            chrome: look.background,
+            chrome,
             block_id: id.with("text-block"),
-            text: self.text,
+            text,
             placeholder: &placeholder,
             geometry,
             selection_rects: &state.selection_rects,
@@ -539,15 +939,25 @@ impl<'a> TextEdit<'a> {
                 width: caret_width,
                 color: caret_color,
                 anim: view.caret_anim,
+                extra: &state.caret_positions,
             }),
+            code,
+            matches,
+            squiggles,
+            ghost,
         }
         .record(ui, widget);
+        if let Some((anchor, width)) = suggestions {
+            state.suggest.show(ui, id, anchor, width);
+        }
         EditSignals {
             changed,
             submitted,
             cancelled: blur_after,
             gained_focus,
             lost_focus,
+            invalid,
+            completed,
             state: probed,
         }
     }
@@ -558,13 +968,16 @@ impl_configure!(TextEdit<'_>);
 /// [`TextEditResponse`] minus its `Response` — what one pass can report
 /// while the state row is still out on loan. `show` reunites the two
 /// once the row is home and it can borrow `ui` again.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 struct EditSignals {
     changed: bool,
     submitted: bool,
     cancelled: bool,
     gained_focus: bool,
     lost_focus: bool,
+    /// The validator flags the value; its reason is on the row.
+    invalid: bool,
+    completed: bool,
     /// The response the pass probed, with `focused` as the pass left it —
     /// what `show` hands to [`Response::eager`] instead of re-probing.
     /// Every other field is frozen for the pass, so this is the same answer
@@ -598,6 +1011,14 @@ pub struct TextEditResponse<'a> {
     /// The editor lost focus this frame (clicked away, another widget focused,
     /// or Escape) — the conventional "commit on blur" signal.
     pub lost_focus: bool,
+    /// Why the [`validator`](TextEdit::validator) flags the value, or
+    /// `None` while it's valid (and always without a validator). Empty
+    /// for a value the validator would have refused or rewritten had it
+    /// been typed — one the host put in the buffer itself.
+    pub invalid: Option<&'a str>,
+    /// A suggestion from the [`autocomplete`](TextEdit::autocomplete)
+    /// list was accepted this frame. [`Self::changed`] fires with it.
+    pub completed: bool,
 }
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use crate::layout::types::sizing::Sizing;
 use crate::primitives::background::Background;
 use crate::primitives::color::Color;
 use crate::primitives::rect::Rect;
-use crate::primitives::size::Size;
+use crate::primitives::spacing::Spacing;
+use crate::primitives::translate_scale::TranslateScale;
 use crate::primitives::widget_id::WidgetId;
 use crate::scene::node::Node;
 use crate::shape::Shape;
@@ -13,12 +14,68 @@ use crate::text::wrap::TextWrap;
 use crate::ui::Ui;
 use crate::widgets::scroll::state::ScrollState;
 use crate::widgets::text_edit::caret_paint::CaretPaint;
+use crate::widgets::text_edit::code::{self, Gutter};
+use crate::widgets::text_edit::diagnostics::{self, Severity};
 use crate::widgets::text_edit::shape_ctx::ShapeCtx;
 use crate::widgets::text_edit::text_geometry::TextGeometry;
 use crate::widgets::text_edit::text_layout::TextLayout;
+use crate::widgets::text_edit::visible::{PaintRun, VisibleLine};
+use crate::widgets::theme::code_edit::CodeEditTheme;
+use crate::widgets::theme::diagnostics::DiagnosticsTheme;
 use crate::widgets::widget::Widget;
 use glam::Vec2;
 
+/// The buffer's text as the painter records it.
+#[derive(Debug)]
+pub(super) enum PaintText<'a> {
+    /// The whole buffer as one run, or the placeholder when it's empty.
+    Run(&'a str),
+    /// The text in view as runs of its own — a paragraph each, or a
+    /// highlighted stretch each.
+    Runs(&'a [PaintRun]),
+}
+
+/// What code mode paints besides the text.
+#[derive(Debug)]
+pub(super) struct CodePaint<'a> {
+    pub(super) theme: CodeEditTheme,
+    /// Identity of the gutter's node, a sibling after the text block so it
+    /// paints over text scrolled under it.
+    pub(super) gutter_id: WidgetId,
+    pub(super) gutter: Gutter,
+    pub(super) lines: &'a [VisibleLine],
+    /// The caret's line, by index and block-local band, when focused.
+    pub(super) current_line: Option<(usize, Rect)>,
+    /// The bracket by the caret and its partner, block-local.
+    pub(super) brackets: Option<[Rect; 2]>,
+}
+
+/// The find bar's washes behind its matches.
+#[derive(Debug)]
+pub(super) struct MatchPaint<'a> {
+    /// Block-local, each with whether it belongs to the current match.
+    pub(super) rects: &'a [(Rect, bool)],
+    pub(super) color: Color,
+    pub(super) current: Color,
+}
+
+/// The squiggles under what the diagnostics flag.
+#[derive(Debug)]
+pub(super) struct SquigglePaint<'a> {
+    /// Block-local, each with the severity that colours it.
+    pub(super) rects: &'a [(Rect, Severity)],
+    pub(super) theme: DiagnosticsTheme,
+}
+
+/// The rest of the selected suggestion, painted after the caret.
+#[derive(Debug)]
+pub(super) struct GhostPaint<'a> {
+    pub(super) text: &'a str,
+    /// Block-local: the caret's top.
+    pub(super) at: Vec2,
+    pub(super) color: Color,
+}
+
 #[derive(Debug)]
 pub(super) struct PaintInput<'a> {
     pub(super) chrome: Background,
@@ -26,7 +83,7 @@ pub(super) struct PaintInput<'a> {
     /// field, so the layout engine is what places it. Derived from the
     /// field's own id, so it is as stable across frames as the field is.
     pub(super) block_id: WidgetId,
-    pub(super) text: &'a str,
+    pub(super) text: PaintText<'a>,
     pub(super) placeholder: &'a str,
     pub(super) geometry: TextGeometry,
     pub(super) selection_rects: &'a [Rect],
@@ -34,7 +91,11 @@ pub(super) struct PaintInput<'a> {
     pub(super) text_color: Color,
     pub(super) placeholder_color: Color,
     pub(super) scroll: ScrollState,
-    pub(super) caret: Option<CaretPaint>,
+    pub(super) caret: Option<CaretPaint<'a>>,
+    pub(super) code: Option<CodePaint<'a>>,
+    pub(super) matches: Option<MatchPaint<'a>>,
+    pub(super) squiggles: Option<SquigglePaint<'a>>,
+    pub(super) ghost: Option<GhostPaint<'a>>,
 }
 
 impl PaintInput<'_> {
@@ -45,8 +106,9 @@ impl PaintInput<'_> {
         let layout = self.geometry.layout;
         let ctx = layout.ctx;
         if !ctx.multiline {
+            let viewport = ui.display().logical_size();
             let node = &mut widget.node;
-            let min_size = node.min_size.get_or_insert(Size::ZERO);
+            let min_size = node.min_size_px(viewport);
             // The block's own height rather than the theme's leading, because a
             // panned axis contributes no max-content — so this floor is what the
             // field's height *is*, and a floor a thousandth under what the shaper
@@ -55,7 +117,7 @@ impl PaintInput<'_> {
             if node.size.unwrap_or_default().w().is_hug() {
                 let reserved =
                     self.geometry.display_size.w + ctx.padding.horiz() + 2.0 * layout.caret_room;
-                let min_size = node.min_size.get_or_insert(Size::ZERO);
+                let min_size = node.min_size_px(viewport);
                 min_size.w = min_size.w.max(reserved);
             }
         }
@@ -63,26 +125,89 @@ impl PaintInput<'_> {
         let block = Widget::new(self.block_id, self.block_node(ctx, layout));
         widget.record(ui, Some(&self.chrome), |ui| {
             block.record(ui, None, |ui| {
+                if let Some(code) = &self.code {
+                    let marks = code
+                        .current_line
+                        .map(|(_, band)| (band, code.theme.current_line));
+                    let brackets = code
+                        .brackets
+                        .into_iter()
+                        .flatten()
+                        .map(|rect| (rect, code.theme.bracket_match));
+                    for (rect, color) in marks.into_iter().chain(brackets) {
+                        ui.add_shape(Shape::rect(rect).fill(color));
+                    }
+                }
+                if let Some(matches) = &self.matches {
+                    for &(rect, current) in matches.rects {
+                        let color = if current {
+                            matches.current
+                        } else {
+                            matches.color
+                        };
+                        ui.add_shape(Shape::rect(rect).fill(color));
+                    }
+                }
                 for rect in self.selection_rects {
                     ui.add_shape(Shape::rect(*rect).fill(self.selection_color));
                 }
 
-                let (display, color) = if self.text.is_empty() {
-                    (ui.intern(self.placeholder), self.placeholder_color)
+                let wrap = if ctx.multiline {
+                    TextWrap::Wrap
                 } else {
-                    (ui.intern(self.text), self.text_color)
+                    TextWrap::Scroll
                 };
-                if !display.is_empty() {
-                    ui.add_shape(
-                        Shape::text(display, ctx.font)
-                            .at(Vec2::ZERO)
-                            .color(color)
-                            .wrap(if ctx.multiline {
-                                TextWrap::Wrap
+                match self.text {
+                    PaintText::Run(text) => {
+                        let (display, color) = if text.is_empty() {
+                            (ui.intern(self.placeholder), self.placeholder_color)
+                        } else {
+                            (ui.intern(text), self.text_color)
+                        };
+                        if !display.is_empty() {
+                            ui.add_shape(
+                                Shape::text(display, ctx.font)
+                                    .at(Vec2::ZERO)
+                                    .color(color)
+                                    .wrap(wrap)
+                                    .align(layout.text_align),
+                            );
+                        }
+                    }
+                    PaintText::Runs(runs) => {
+                        for run in runs {
+                            // A highlighted stretch was placed by the probe
+                            // of its whole line; it paints exactly there.
+                            let (wrap, align) = if run.whole {
+                                (wrap, layout.text_align)
                             } else {
-                                TextWrap::Scroll
-                            })
-                            .align(layout.text_align),
+                                (TextWrap::Scroll, Align::TOP_LEFT)
+                            };
+                            ui.add_shape(
+                                Shape::text(run.text, ctx.font)
+                                    .at(run.at)
+                                    .color(run.color.unwrap_or(self.text_color))
+                                    .wrap(wrap)
+                                    .align(align),
+                            );
+                        }
+                    }
+                }
+
+                if let Some(squiggles) = &self.squiggles {
+                    for &(rect, severity) in squiggles.rects {
+                        let color = severity.color(&squiggles.theme);
+                        diagnostics::squiggle(ui, rect, color, &squiggles.theme);
+                    }
+                }
+                if let Some(ghost) = &self.ghost {
+                    let rest = ui.intern(ghost.text);
+                    ui.add_shape(
+                        Shape::text(rest, ctx.font)
+                            .at(ghost.at)
+                            .color(ghost.color)
+                            .wrap(TextWrap::Scroll)
+                            .align(Align::TOP_LEFT),
                     );
                 }
 
@@ -91,19 +216,57 @@ impl PaintInput<'_> {
                     // inside was the *widget's* box, which is the one thing here that is
                     // still a frame stale. The block carries the caret with it now, and
                     // the field's own clip is what keeps it from painting outside.
-                    let rect = Rect::new(
-                        caret.pos.x,
-                        caret.pos.y_top,
-                        caret.width,
-                        caret.pos.line_height,
-                    );
-                    let shape = Shape::rect(rect).fill(caret.color);
-                    match caret.anim {
-                        Some(anim) => ui.add_shape_animated(shape, anim),
-                        None => ui.add_shape(shape),
+                    for pos in std::iter::once(&caret.pos).chain(caret.extra) {
+                        let rect = Rect::new(pos.x, pos.y_top, caret.width, pos.line_height);
+                        let shape = Shape::rect(rect).fill(caret.color);
+                        match caret.anim {
+                            Some(anim) => ui.add_shape_animated(shape, anim),
+                            None => ui.add_shape(shape),
+                        }
                     }
                 }
             });
+            if let Some(code) = &self.code {
+                self.record_gutter(ui, code);
+            }
+        });
+    }
+
+    /// The line-number strip down the field's leading edge. Scrolls with
+    /// the text vertically but not sideways, and is opaque, so a line
+    /// panned left slides under it rather than through it.
+    fn record_gutter(&self, ui: &mut Ui, code: &CodePaint<'_>) {
+        let ctx = self.geometry.layout.ctx;
+        let height = self
+            .block_height(ctx)
+            .max(self.geometry.layout.inner_size.h);
+        let mut node = Node::leaf();
+        node.size = Some((Sizing::fixed(code.gutter.width), Sizing::fixed(height)).into());
+        node.transform = TranslateScale::new(Vec2::new(0.0, -self.scroll.offset.y), 1.0);
+        let top = self.geometry.block_offset.y;
+        let current = code.current_line.map(|(line, _)| line);
+        Widget::new(code.gutter_id, node).record(ui, None, |ui| {
+            ui.add_shape(
+                Shape::rect(Rect::new(0.0, 0.0, code.gutter.width, height)).fill(code.theme.gutter),
+            );
+            let mut buf = [0u8; 20];
+            for line in code.lines {
+                let number = code::decimal(line.index + 1, &mut buf);
+                let x = code.gutter.number_x(number.len());
+                let color = if current == Some(line.index) {
+                    code.theme.current_line_number
+                } else {
+                    code.theme.line_number
+                };
+                let number = ui.intern(number);
+                ui.add_shape(
+                    Shape::text(number, ctx.font)
+                        .at(Vec2::new(x, top + line.top))
+                        .color(color)
+                        .wrap(TextWrap::Scroll)
+                        .align(Align::TOP_LEFT),
+                );
+            }
         });
     }
 
@@ -165,6 +328,11 @@ impl PaintInput<'_> {
             layout.text_align
         };
         block.transform = self.scroll.transform();
+        // Code mode's gutter takes the leading edge of the inner rect; the
+        // text starts past it, where the shaping padding already put it.
+        if let Some(code) = &self.code {
+            block.margin = Some(Spacing::new(code.gutter.width, 0.0, 0.0, 0.0));
+        }
         block
     }
 }
@@ -0,0 +1,291 @@
+//! Paragraph-at-a-time layout for a multi-line field over a buffer that
+//! is not one `&str` — a [`TextRope`](crate::TextRope).
+//!
+//! A contiguous buffer is shaped as one run and every query goes to that
+//! run's probe. A rope can't be: the run would be the whole document, so
+//! each `'\n'`-separated paragraph is a run of its own instead, stacked
+//! top to bottom. Only the paragraphs the viewport shows, plus the
+//! caret's, are ever shaped; the rest stand in at one line's height until
+//! they scroll into view. Shaping is cached by each run's text, so an edit
+//! re-shapes the paragraph it touched and nothing else.
+
+use crate::primitives::rect::Rect;
+use crate::text::probe::Caret;
+use crate::ui::Ui;
+use crate::widgets::text_edit::buffer::{TextBuffer, line_range};
+use crate::widgets::text_edit::line_heights::LineHeights;
+use crate::widgets::text_edit::shape_ctx::ShapeCtx;
+use glam::Vec2;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// One splice of the line table, logged by the editor as it edits so the
+/// cache can follow it rather than forget everything: lines
+/// `first..first + removed` became `inserted` lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct LineEdit {
+    /// [`TextBuffer::content_hash`] before the edit — what ties the log
+    /// to the state the cache was last synced at.
+    pub(super) before: u64,
+    pub(super) first: usize,
+    pub(super) removed: usize,
+    pub(super) inserted: usize,
+}
+
+impl LineEdit {
+    /// The splice replacing `range` with `with` makes of `text`'s lines.
+    /// Call before the edit; `after` finishes it once the text has moved.
+    pub(super) fn before(text: &dyn TextBuffer, range: Range<usize>) -> Self {
+        let first = text.byte_to_line(range.start);
+        Self {
+            before: text.content_hash(),
+            first,
+            removed: text.byte_to_line(range.end) - first + 1,
+            inserted: 0,
+        }
+    }
+
+    /// Fill in the lines the edit left, given where its inserted text ends.
+    pub(super) fn after(mut self, text: &dyn TextBuffer, inserted_end: usize) -> Self {
+        self.inserted = text.byte_to_line(inserted_end) - self.first + 1;
+        self
+    }
+}
+
+/// Per-line heights and the tops derived from them, retained on the
+/// field's state row across frames.
+#[derive(Clone, Default, Debug)]
+pub(super) struct Paragraphs {
+    /// Height of each line — shaped once it has been in view, the
+    /// one-line estimate until then — kept so a splice, a line's top and
+    /// the line at a height all cost `O(log lines)`.
+    heights: LineHeights,
+    /// The buffer state `heights` describes.
+    synced: Option<u64>,
+    /// The parameters `heights` were shaped under; any change re-measures.
+    ctx: Option<ShapeCtx>,
+    /// Widest paragraph measured since the last reset — the document's
+    /// width as far as anyone has looked, which is what an unwrapped
+    /// field scrolls sideways across.
+    widest: f32,
+}
+
+impl Paragraphs {
+    /// Whether `text` takes this layout in a field shaped with `ctx`: a
+    /// multi-line field over a non-contiguous buffer with something in it.
+    /// An empty one has nothing to split and shows its placeholder the
+    /// ordinary way.
+    pub(super) fn applies(ctx: &ShapeCtx, text: &dyn TextBuffer) -> bool {
+        ctx.multiline && text.as_str().is_none() && !text.is_empty()
+    }
+
+    /// Bring the line table in step with `text`: replay the editor's
+    /// `edits` if they pick up where the table left off, otherwise start
+    /// over. Cheap when nothing moved — one identity compare.
+    pub(super) fn sync(
+        &mut self,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        edits: &mut Vec<LineEdit>,
+    ) {
+        let hash = text.content_hash();
+        if self.ctx != Some(*ctx) {
+            self.ctx = Some(*ctx);
+            self.reset(text);
+        } else if self.synced != Some(hash) {
+            if edits
+                .first()
+                .is_some_and(|edit| Some(edit.before) == self.synced)
+            {
+                let estimate = self.estimate();
+                for edit in edits.iter() {
+                    let end = (edit.first + edit.removed).min(self.heights.len());
+                    let first = edit.first.min(end);
+                    self.heights.splice(first..end, edit.inserted, estimate);
+                }
+            } else {
+                self.reset(text);
+            }
+            // A log that doesn't add up — a host edit between the editor's
+            // own — is caught here rather than trusted.
+            if self.heights.len() != text.line_count() {
+                self.reset(text);
+            }
+        }
+        edits.clear();
+        self.synced = Some(hash);
+    }
+
+    fn reset(&mut self, text: &dyn TextBuffer) {
+        self.heights.reset(text.line_count(), self.estimate());
+        self.widest = 0.0;
+    }
+
+    fn estimate(&self) -> f32 {
+        self.ctx.map_or(0.0, |ctx| ctx.font.line_height_px)
+    }
+
+    pub(super) fn top(&self, line: usize) -> f32 {
+        self.heights.top(line)
+    }
+
+    pub(super) fn widest(&self) -> f32 {
+        self.widest
+    }
+
+    /// Height of everything, unmeasured lines at their estimate.
+    pub(super) fn total_height(&self) -> f32 {
+        self.heights.total_height()
+    }
+
+    /// The line whose band holds `y`, clamped to the document.
+    fn line_at(&self, y: f32) -> usize {
+        self.heights.line_at(y)
+    }
+
+    /// Shape `line` if it hasn't been, recording its height.
+    fn measure(&mut self, ui: &mut Ui, ctx: &ShapeCtx, text: &dyn TextBuffer, line: usize) {
+        if self.heights.measured(line).is_some() {
+            return;
+        }
+        let paragraph = paragraph(text, line);
+        // The bottom of its last line rather than the probe's size, which
+        // is rounded up for layout: stacked, the rounding would add up to a
+        // drift from where the one-run layout puts the same lines. It also
+        // gives an empty paragraph the one line it occupies.
+        let probe = ui.probe_text(ctx.run(&paragraph));
+        let end = probe.caret_at(paragraph.len());
+        self.widest = self.widest.max(probe.size().w);
+        self.heights.measure(line, end.y_top + end.line_height);
+    }
+
+    /// Measure the lines a viewport `height` tall scrolled to `offset`
+    /// shows, returning them. Measuring only ever grows a line past its
+    /// one-line estimate, so walking down from the first visible line
+    /// until the viewport is full is exact — nothing above it moves.
+    pub(super) fn measure_visible(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        offset: f32,
+        height: f32,
+    ) -> Range<usize> {
+        let first = self.line_at(offset);
+        let mut bottom = self.top(first);
+        let mut line = first;
+        while line < self.heights.len() {
+            self.measure(ui, ctx, text, line);
+            bottom += self.heights.measured(line).unwrap_or_default();
+            line += 1;
+            if bottom >= offset + height {
+                break;
+            }
+        }
+        first..line
+    }
+
+    /// Where the caret at `byte` sits, block-local.
+    pub(super) fn caret_at(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        byte: usize,
+    ) -> Caret {
+        let line = text.byte_to_line(byte);
+        self.measure(ui, ctx, text, line);
+        let top = self.top(line);
+        let start = text.line_to_byte(line);
+        let paragraph = paragraph(text, line);
+        let mut caret = ui
+            .probe_text(ctx.run(&paragraph))
+            .caret_at((byte - start).min(paragraph.len()));
+        caret.y_top += top;
+        caret
+    }
+
+    /// The byte under block-local `(x, y)`.
+    pub(super) fn byte_at(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        x: f32,
+        y: f32,
+    ) -> usize {
+        let line = self.line_at(y);
+        self.measure(ui, ctx, text, line);
+        let top = self.top(line);
+        let start = text.line_to_byte(line);
+        let paragraph = paragraph(text, line);
+        start + ui.probe_text(ctx.run(&paragraph)).byte_at(x, y - top)
+    }
+
+    /// Fill `selection_rects` with the washes `selections` paint across
+    /// `lines`. Only the visible lines are walked: a selection reaching
+    /// off-screen has nothing to show there.
+    pub(super) fn selection_rects(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        lines: Range<usize>,
+        selections: impl Iterator<Item = Range<usize>> + Clone,
+        selection_rects: &mut Vec<Rect>,
+    ) {
+        selection_rects.clear();
+        if selections.clone().next().is_none() {
+            return;
+        }
+        for line in lines {
+            let top = self.top(line);
+            let start = text.line_to_byte(line);
+            let paragraph = paragraph(text, line);
+            let probe = ui.probe_text(ctx.run(&paragraph));
+            for selection in selections.clone() {
+                let local = selection.start.saturating_sub(start).min(paragraph.len())
+                    ..selection.end.saturating_sub(start).min(paragraph.len());
+                probe.selection_rects(local, |rect| {
+                    selection_rects.push(Rect {
+                        min: rect.min + Vec2::new(0.0, top),
+                        ..rect
+                    });
+                });
+            }
+        }
+    }
+}
+
+/// `line`'s text without its terminator — what is shaped as its run.
+pub(super) fn paragraph(text: &dyn TextBuffer, line: usize) -> Cow<'_, str> {
+    let range = line_range(text, line);
+    let mut paragraph = text.slice(range);
+    let trimmed = paragraph.trim_end_matches(['\n', '\r']).len();
+    match &mut paragraph {
+        Cow::Borrowed(s) => *s = &s[..trimmed],
+        Cow::Owned(s) => s.truncate(trimmed),
+    }
+    paragraph
+}
+
+/// The byte under block-local `(x, y)`, through whichever layout `text`
+/// takes — the one paragraph-aware entry for hit-testing, so the click
+/// and vertical-motion paths can't disagree about which that is.
+pub(super) fn byte_at(
+    ui: &mut Ui,
+    ctx: &ShapeCtx,
+    text: &dyn TextBuffer,
+    paragraphs: &mut Paragraphs,
+    edits: &mut Vec<LineEdit>,
+    x: f32,
+    y: f32,
+) -> usize {
+    if Paragraphs::applies(ctx, text) {
+        paragraphs.sync(ctx, text, edits);
+        paragraphs.byte_at(ui, ctx, text, x, y)
+    } else {
+        ui.probe_text(ctx.run(&text.slice(0..text.len())))
+            .byte_at(x, y)
+    }
+}
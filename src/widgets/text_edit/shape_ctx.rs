@@ -15,7 +15,7 @@ use crate::text::wrap::TextWrap;
 /// mean last frame's offset before the probe and this frame's after it.
 /// The two now live apart — [`TextLayout::prev_block_offset`] and
 /// [`TextGeometry::block_offset`].
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(super) struct ShapeCtx {
     pub(super) font: GlyphFont,
     pub(super) padding: Spacing,
@@ -49,6 +49,13 @@ impl ShapeCtx {
         }
     }
 
+    /// Whether lines soft-wrap to the field's width — a multi-line field
+    /// outside code mode, once it has a rect. One that doesn't scrolls
+    /// sideways instead.
+    pub(super) fn wraps(&self) -> bool {
+        self.wrap_target.is_some()
+    }
+
     /// This editor's shaping parameters as the public run description.
     ///
     /// `TextEdit` probes through [`Ui::probe_text`](crate::Ui::probe_text)
@@ -0,0 +1,304 @@
+//! Suggestions: a list opened by typing, steered and accepted by keys the
+//! field keeps, or by a click that never takes focus away.
+
+use crate::primitives::color::{Color, ColorF16};
+use crate::scene::shapes::record::ShapeRecord;
+use crate::widgets::text_edit::TextEditResponse;
+use crate::widgets::text_edit::autocomplete::{Autocomplete, SuggestAnchor, SuggestionProvider};
+use crate::widgets::text_edit::tests::*;
+use glam::UVec2;
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+
+const FRUIT: [&str; 4] = ["apple", "apricot", "banana", "grape"];
+
+/// Room below the field for the list, which would flip above it otherwise.
+const TALL: UVec2 = UVec2::new(400, 300);
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("autocomplete-ed")
+}
+
+fn row(at: usize) -> WidgetId {
+    ed_id().with(("suggestion", at))
+}
+
+/// One frame of a field over `buf`, completing from `provider`.
+fn field(
+    h: &mut UiHarness,
+    buf: &mut String,
+    provider: &dyn SuggestionProvider,
+    anchor: SuggestAnchor,
+) -> (bool, bool, bool, bool) {
+    let mut seen = (false, false, false, false);
+    h.frame(|ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            let TextEditResponse {
+                changed,
+                submitted,
+                lost_focus,
+                completed,
+                ..
+            } = TextEdit::new(buf)
+                .id(ed_id())
+                .autocomplete(Autocomplete::new(provider).anchor(anchor))
+                .size((Sizing::fixed(160.0), Sizing::fixed(30.0)))
+                .show(ui);
+            // Or'd: a frame can record more than once.
+            seen.0 |= changed;
+            seen.1 |= submitted;
+            seen.2 |= lost_focus;
+            seen.3 |= completed;
+        });
+    });
+    seen
+}
+
+fn listed(h: &UiHarness, at: usize) -> bool {
+    h.ui.tree(Layer::Popup)
+        .records
+        .widget_id()
+        .contains(&row(at))
+}
+
+/// Typing opens the list, ranked best first; the arrows move through it
+/// and Tab takes the selected one, all without the field losing focus.
+#[test]
+fn typing_opens_the_list_and_tab_accepts() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(!listed(&h, 0), "nothing typed, nothing listed");
+
+    h.type_text("ap");
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(listed(&h, 0) && listed(&h, 1));
+    assert!(listed(&h, 2), "grape has an a then a p");
+    assert!(!listed(&h, 3), "banana doesn't");
+    let list = h.rect(ed_id().with("suggestions")).unwrap();
+    let rect = h.rect(ed_id()).unwrap();
+    assert!(list.min.y >= rect.max().y, "below the field");
+    assert!(list.size.w >= rect.size.w, "as wide as the field");
+
+    h.key(Key::ArrowDown);
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.key(Key::Tab);
+    let (changed, _, lost_focus, completed) = field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert_eq!(buf, "apricot");
+    assert!(changed && completed && !lost_focus);
+    assert_eq!(h.focused_id(), Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(!listed(&h, 0), "accepting closes the list");
+}
+
+/// Enter accepts instead of submitting while the list shows; Escape
+/// closes the list first and only blurs once it's gone.
+#[test]
+fn enter_accepts_and_escape_closes_the_list_first() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.type_text("gr");
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.key(Key::Enter);
+    let (_, submitted, _, completed) = field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert_eq!(buf, "grape");
+    assert!(completed && !submitted);
+
+    h.type_text("s");
+    field(
+        &mut h,
+        &mut buf,
+        &["grapes!", "grapefruit"],
+        SuggestAnchor::Field,
+    );
+    assert!(listed(&h, 0));
+    h.key(Key::Escape);
+    field(&mut h, &mut buf, &["grapes!"], SuggestAnchor::Field);
+    assert!(!listed(&h, 0));
+    assert_eq!(
+        h.focused_id(),
+        Some(ed_id()),
+        "the first Escape is the list's"
+    );
+    h.key(Key::Escape);
+    field(&mut h, &mut buf, &["grapes!"], SuggestAnchor::Field);
+    assert_eq!(h.focused_id(), None);
+}
+
+/// ArrowDown opens the list in a single-line field, even empty.
+#[test]
+fn arrow_down_opens_the_list() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.key(Key::ArrowDown);
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(
+        (0..4).all(|at| listed(&h, at)),
+        "an empty query matches all"
+    );
+}
+
+/// A press on a row accepts it, and focus — which a press on no focusable
+/// widget clears — comes straight back, with no blur reported.
+#[test]
+fn clicking_a_row_accepts_it_and_keeps_focus() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.type_text("a");
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(listed(&h, 2), "banana has an a");
+
+    h.press_on(row(1));
+    let (changed, _, lost_focus, completed) = field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    h.release();
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert_eq!(buf, "apricot");
+    assert!(changed && completed && !lost_focus);
+    assert_eq!(h.focused_id(), Some(ed_id()));
+}
+
+/// The rest of the selected suggestion paints after the caret, in the
+/// theme's ghost colour, and goes with the list.
+#[test]
+fn the_selected_suggestion_ghosts_after_the_caret() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    let runs = |h: &UiHarness| -> Vec<(String, Color)> {
+        let payloads = h.ui.payloads();
+        let interned = payloads.interned_text();
+        let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+        h.ui.tree(Layer::Main)
+            .shapes_of(block)
+            .filter_map(|s| match s {
+                ShapeRecord::Text { text, color, .. } => Some((
+                    text.source.resolve(&interned).to_owned(),
+                    Color::from(*color),
+                )),
+                _ => None,
+            })
+            .collect()
+    };
+    let ghost = Color::from(ColorF16::from(h.ui.theme().autocomplete.ghost));
+    h.type_text("ap");
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(runs(&h).contains(&("ple".to_owned(), ghost)));
+    h.key(Key::ArrowDown);
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(runs(&h).contains(&("ricot".to_owned(), ghost)));
+    h.key(Key::Escape);
+    field(&mut h, &mut buf, &FRUIT, SuggestAnchor::Field);
+    assert!(runs(&h).iter().all(|(_, color)| *color != ghost));
+}
+
+/// A provider that completes the tag at the caret, answering from a list
+/// that is filled in later — as a lookup elsewhere would.
+#[derive(Debug, Default)]
+struct LateTags {
+    tags: RefCell<Vec<String>>,
+    revision: Cell<u64>,
+    asked: RefCell<Vec<String>>,
+}
+
+impl SuggestionProvider for LateTags {
+    fn suggest(&self, query: &str, out: &mut Vec<String>) {
+        self.asked.borrow_mut().push(query.to_owned());
+        let tags = self.tags.borrow();
+        out.extend(tags.iter().filter(|t| t.starts_with(query)).cloned());
+    }
+
+    fn token(&self, line: &str, caret: usize) -> Range<usize> {
+        let start = line[..caret].rfind(' ').map_or(0, |at| at + 1);
+        start..caret
+    }
+
+    fn revision(&self) -> u64 {
+        self.revision.get()
+    }
+}
+
+/// The provider is asked once per token, again when its revision says the
+/// answer changed, and accepting replaces only the token — with the list
+/// hung at the caret.
+#[test]
+fn a_late_provider_completes_the_token_at_the_caret() {
+    let mut h = UiHarness::with_text(TALL);
+    let tags = LateTags::default();
+    let mut buf = String::from("red ");
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    h.key(Key::End);
+    h.type_text("bl");
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    assert_eq!(*tags.asked.borrow(), ["bl"], "asked about the token, once");
+    assert!(!listed(&h, 0), "nothing yet");
+
+    *tags.tags.borrow_mut() = vec!["blue".into(), "black".into()];
+    tags.revision.set(1);
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    assert_eq!(tags.asked.borrow().len(), 2);
+    assert!(listed(&h, 1));
+    let list = h.rect(ed_id().with("suggestions")).unwrap();
+    let rect = h.rect(ed_id()).unwrap();
+    assert!(
+        list.min.x > rect.min.x + 20.0,
+        "hung at the caret, past `red `"
+    );
+
+    h.key(Key::ArrowUp);
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    h.key(Key::Tab);
+    field(&mut h, &mut buf, &tags, SuggestAnchor::Caret);
+    assert_eq!(buf, "red black");
+}
+
+/// In a multi-line field the token is cut from the caret's line: by
+/// default all of that line, and nothing of the lines around it.
+#[test]
+fn the_default_token_is_the_carets_line() {
+    let mut h = UiHarness::with_text(TALL);
+    let mut buf = String::from("grape\n\nbanana");
+    let frame = |h: &mut UiHarness, buf: &mut String| {
+        h.frame(|ui| {
+            Panel::vstack().auto_id().show(ui, |ui| {
+                TextEdit::new(buf)
+                    .id(ed_id())
+                    .multiline(true)
+                    .autocomplete(Autocomplete::new(&FRUIT))
+                    .size((Sizing::fixed(160.0), Sizing::fixed(90.0)))
+                    .show(ui);
+            });
+        });
+    };
+    h.request_focus(Some(ed_id()));
+    frame(&mut h, &mut buf);
+    h.key(Key::ArrowDown);
+    frame(&mut h, &mut buf);
+    h.type_text("apr");
+    frame(&mut h, &mut buf);
+    assert!(listed(&h, 0), "asked about `apr`, not the whole value");
+    h.key(Key::Tab);
+    frame(&mut h, &mut buf);
+    assert_eq!(buf, "grape\napricot\nbanana");
+}
+
+/// A list of strings ranks by fuzzy match, and leaves out the entry that
+/// is the query already.
+#[test]
+fn a_list_ranks_by_fuzzy_match() {
+    let mut out = Vec::new();
+    vec!["src/main.rs", "src/lib.rs", "lib"].suggest("lib", &mut out);
+    assert_eq!(out, ["src/lib.rs"]);
+    out.clear();
+    ["Go to Type Definition", "Quit", "Go to Definition"].suggest("gtd", &mut out);
+    assert_eq!(out, ["Go to Definition", "Go to Type Definition"]);
+}
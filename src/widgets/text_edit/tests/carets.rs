@@ -0,0 +1,280 @@
+//! Several carets: adding them from the keyboard and the pointer, moving
+//! and editing at every one, and the whole edit undone in one step.
+
+use crate::common::clipboard::Clipboard;
+use crate::primitives::color::ColorF16;
+use crate::primitives::rect::Rect;
+use crate::scene::shapes::paint::{QuadShape, ShapeBrush};
+use crate::scene::shapes::record::ShapeRecord;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::edit_state::SelectionState;
+use crate::widgets::text_edit::tests::*;
+
+fn at(caret: usize) -> SelectionState {
+    SelectionState {
+        caret,
+        selection: None,
+    }
+}
+
+fn selecting(range: std::ops::Range<usize>) -> SelectionState {
+    SelectionState {
+        caret: range.end,
+        selection: Some(range.start),
+    }
+}
+
+/// Carets at the end of each of three lines, the last the primary.
+fn three_line_ends() -> (String, EditState) {
+    let state = EditState {
+        caret: 8,
+        carets: vec![at(2), at(5)],
+        ..Default::default()
+    };
+    (String::from("ab\nab\nab"), state)
+}
+
+#[test]
+fn typing_and_deleting_land_at_every_caret_and_undo_as_one() {
+    let (mut s, mut state) = three_line_ends();
+    for c in ['x', 'y'] {
+        apply_key(&mut s, &mut state, press(Key::Char(c)));
+    }
+    assert_eq!(s, "abxy\nabxy\nabxy");
+    assert_eq!(state.caret, 14);
+    assert_eq!(state.carets, [at(4), at(9)]);
+    assert_eq!(
+        state.undo.len(),
+        1,
+        "both keystrokes at all three carets coalesce"
+    );
+
+    apply_key(&mut s, &mut state, press(Key::Backspace));
+    apply_key(&mut s, &mut state, press(Key::Backspace));
+    assert_eq!(s, "abx\nabx\nabx".replace('x', ""));
+    assert_eq!(state.undo.len(), 2, "deleting opens one group of its own");
+
+    apply_key(&mut s, &mut state, ctrl_press(Key::Char('z')));
+    assert_eq!(s, "abxy\nabxy\nabxy");
+    apply_key(&mut s, &mut state, ctrl_press(Key::Char('z')));
+    assert_eq!(s, "ab\nab\nab");
+    assert_eq!(
+        (state.caret, state.carets.clone()),
+        (8, vec![at(2), at(5)]),
+        "undo puts every caret back"
+    );
+    apply_key(&mut s, &mut state, ctrl_shift_press(Key::Char('z')));
+    assert_eq!(s, "abxy\nabxy\nabxy");
+    assert_eq!(state.carets, [at(4), at(9)]);
+}
+
+/// Motion moves every caret, and carets that meet become one.
+#[test]
+fn carets_move_together_and_merge_where_they_meet() {
+    let mut s = String::from("abc");
+    let mut state = EditState {
+        caret: 2,
+        carets: vec![at(1)],
+        ..Default::default()
+    };
+    apply_key(&mut s, &mut state, press(Key::ArrowLeft));
+    assert_eq!((state.caret, state.carets.clone()), (1, vec![at(0)]));
+    apply_key(&mut s, &mut state, press(Key::ArrowLeft));
+    assert_eq!(state.caret, 0);
+    assert!(state.carets.is_empty(), "two carets on one byte merge");
+}
+
+/// Ctrl+D selects the word, then adds each next occurrence after the
+/// primary, wrapping, until every one is taken; copy then gives a line
+/// per selection and typing replaces them all.
+#[test]
+fn ctrl_d_adds_the_next_occurrence_until_all_are_taken() {
+    let mut s = String::from("foo bar foo baz foo");
+    let mut state = EditState {
+        caret: 9,
+        ..Default::default()
+    };
+    let clipboard = Clipboard::default();
+    let ctrl_d = ctrl_press(Key::Char('d'));
+    apply_key(&mut s, &mut state, ctrl_d);
+    assert_eq!(state.primary(), selecting(8..11), "the word at the caret");
+    assert!(state.carets.is_empty());
+
+    apply_key(&mut s, &mut state, ctrl_d);
+    assert_eq!(state.primary(), selecting(16..19));
+    apply_key(&mut s, &mut state, ctrl_d);
+    assert_eq!(state.primary(), selecting(0..3), "wrapped past the end");
+    assert_eq!(state.carets, [selecting(8..11), selecting(16..19)]);
+    apply_key(&mut s, &mut state, ctrl_d);
+    assert_eq!(state.carets.len(), 2, "nothing left to add");
+
+    apply_key_with_clipboard(&mut s, &mut state, ctrl_press(Key::Char('c')), &clipboard);
+    assert_eq!(clipboard.get(), "foo\nfoo\nfoo");
+    apply_key(&mut s, &mut state, press(Key::Char('x')));
+    assert_eq!(s, "x bar x baz x");
+}
+
+/// A selection running over a line break finds its next occurrence the
+/// same way, in a rope too: searched from the primary's line, round past
+/// the end.
+#[test]
+fn ctrl_d_finds_a_multi_line_selection_past_the_end() {
+    let mut rope = TextRope::from("ab\ncd ab\ncd ab");
+    let mut state = EditState {
+        caret: 11,
+        selection: Some(6),
+        ..Default::default()
+    };
+    Editor::new(&mut rope, &mut state, true, None).add_next_occurrence();
+    assert_eq!(
+        state.primary(),
+        selecting(0..5),
+        "wrapped to the first line"
+    );
+    assert_eq!(state.carets, [selecting(6..11)]);
+    Editor::new(&mut rope, &mut state, true, None).add_next_occurrence();
+    assert_eq!(
+        state.carets.len(),
+        1,
+        "the trailing `ab` lacks the line after it"
+    );
+}
+
+/// A clipboard of a line per caret pastes a line at each; any other
+/// pastes whole at every one.
+#[test]
+fn paste_hands_each_caret_its_own_line() {
+    let (mut s, mut state) = three_line_ends();
+    Editor::new(&mut s, &mut state, true, None).paste("1\n2\n3\n");
+    assert_eq!(s, "ab1\nab2\nab3");
+
+    let (mut s, mut state) = three_line_ends();
+    Editor::new(&mut s, &mut state, true, None).paste("1\n2");
+    assert_eq!(s, "ab1\n2\nab1\n2\nab1\n2");
+}
+
+/// The cap holds for an edit at several carets as a whole: one that
+/// would overflow at any of them is made at none.
+#[test]
+fn an_edit_that_overflows_the_cap_is_made_nowhere() {
+    let (mut s, mut state) = three_line_ends();
+    let mut ed = Editor::new(&mut s, &mut state, true, Some(11));
+    ed.insert_char('x');
+    assert_eq!(s, "abx\nabx\nabx");
+    let mut ed = Editor::new(&mut s, &mut state, true, Some(11));
+    ed.insert_char('y');
+    assert_eq!(s, "abx\nabx\nabx", "three more would make fourteen");
+}
+
+/// Escape drops the extra carets first, then the selection, then focus.
+#[test]
+fn escape_drops_the_extra_carets_first() {
+    let (mut s, mut state) = three_line_ends();
+    assert!(!apply_key(&mut s, &mut state, press(Key::Escape)));
+    assert!(state.carets.is_empty());
+    assert_eq!(state.caret, 8, "the primary stays");
+    assert!(apply_key(&mut s, &mut state, press(Key::Escape)));
+}
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("carets-ed")
+}
+
+fn field(buf: &mut String) -> impl FnMut(&mut Ui) + '_ {
+    |ui: &mut Ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            TextEdit::new(buf)
+                .id(ed_id())
+                .multiline(true)
+                .padding(Spacing::ZERO)
+                .size((Sizing::fixed(200.0), Sizing::fixed(100.0)))
+                .show(ui);
+        });
+    }
+}
+
+/// Press and release at `pos`, a frame apart: the caret lands while the
+/// button is held.
+fn click(h: &mut UiHarness, buf: &mut String, pos: Vec2) {
+    h.press_at(pos);
+    h.frame(field(buf));
+    h.release();
+    h.frame(field(buf));
+}
+
+fn alt(h: &mut UiHarness, on: bool) {
+    h.set_modifiers(Modifiers {
+        alt: on,
+        ..Modifiers::NONE
+    });
+}
+
+/// The caret-coloured rects the field painted, block-local.
+fn painted_carets(h: &UiHarness) -> Vec<Rect> {
+    let caret = ColorF16::from(h.ui.theme().text_edit.caret);
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    h.ui.tree(Layer::Main)
+        .shapes_of(block)
+        .filter_map(|s| match s {
+            ShapeRecord::Quad(QuadShape::Rect {
+                local_rect: Some(rect),
+                fill: ShapeBrush::Solid(fill),
+                ..
+            }) if *fill == caret => Some(*rect),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Where on screen a caret at `byte` stands, mid-line — read off the field
+/// itself, so the points aimed at below are the ones it would hit-test.
+fn point_of(h: &mut UiHarness, buf: &mut String, byte: usize) -> Vec2 {
+    h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = byte;
+    h.frame(field(buf));
+    let block = h.rect(ed_id().with("text-block")).unwrap().min;
+    let rect = painted_carets(h)[0];
+    block + rect.min + Vec2::new(0.0, rect.size.h * 0.5)
+}
+
+/// Alt+click adds a caret and a plain click goes back to one; Alt+drag
+/// selects a column, a selection per row, and each caret is painted.
+#[test]
+fn alt_click_adds_carets_and_alt_drag_selects_a_column() {
+    let mut h = UiHarness::with_text(UVec2::new(240, 120));
+    let mut buf = String::from("abcd\nabcd\nabcd");
+    h.request_focus(Some(ed_id()));
+    h.frame(field(&mut buf));
+    let [first, second, third] = [1, 7, 13].map(|byte| point_of(&mut h, &mut buf, byte));
+
+    click(&mut h, &mut buf, first);
+    alt(&mut h, true);
+    h.advance_past_double_click(field(&mut buf));
+    click(&mut h, &mut buf, second);
+    alt(&mut h, false);
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).edit.clone();
+    assert_eq!((state.caret, state.carets.clone()), (7, vec![at(1)]));
+    assert_eq!(painted_carets(&h).len(), 2);
+
+    h.advance_past_double_click(field(&mut buf));
+    click(&mut h, &mut buf, third);
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).edit.clone();
+    assert_eq!((state.caret, state.carets.len()), (13, 0), "a plain click");
+
+    h.advance_past_double_click(field(&mut buf));
+    alt(&mut h, true);
+    h.press_at(first);
+    h.frame(field(&mut buf));
+    h.drag_to(third);
+    h.frame(field(&mut buf));
+    h.release();
+    alt(&mut h, false);
+    h.frame(field(&mut buf));
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).edit.clone();
+    assert_eq!(state.carets, [selecting(1..3), selecting(6..8)]);
+    assert_eq!(state.primary(), selecting(11..13), "the pointer's row");
+    assert_eq!(painted_carets(&h).len(), 3);
+
+    h.type_text("-");
+    h.frame(field(&mut buf));
+    assert_eq!(buf, "a-d\na-d\na-d");
+}
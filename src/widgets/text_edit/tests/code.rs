@@ -0,0 +1,256 @@
+//! Code mode and highlighting: indentation-aware editing, bracket
+//! matching, the gutter, and a highlighter asked only about lines it
+//! hasn't answered for.
+
+use crate::primitives::color::Color;
+use crate::scene::shapes::record::ShapeRecord;
+use crate::text::FontFamily;
+use crate::ui::harness::UiHarness;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::code::{apply_key as apply_code_key, matching_bracket};
+use crate::widgets::text_edit::highlight::{HighlightSpan, Highlighter};
+use crate::widgets::text_edit::tests::*;
+use std::cell::Cell;
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("code-ed")
+}
+
+fn code_field<'a>(
+    buf: &'a mut dyn TextBuffer,
+    highlighter: Option<&'a dyn Highlighter>,
+) -> impl FnMut(&mut Ui) + 'a {
+    move |ui: &mut Ui| {
+        Panel::hstack().auto_id().show(ui, |ui| {
+            let mut field = TextEdit::new(&mut *buf)
+                .id(ed_id())
+                .code(true)
+                .size((Sizing::fixed(240.0), Sizing::fixed(120.0)));
+            if let Some(highlighter) = highlighter {
+                field = field.highlighter(highlighter);
+            }
+            field.show(ui);
+        });
+    }
+}
+
+/// Colours every `fn` in `color`, counting the lines it was asked about.
+#[derive(Debug)]
+struct Keywords {
+    color: Color,
+    calls: Cell<usize>,
+}
+
+impl Keywords {
+    fn new() -> Self {
+        Self {
+            color: Color::rgb(1.0, 0.0, 0.0),
+            calls: Cell::new(0),
+        }
+    }
+}
+
+impl Highlighter for Keywords {
+    fn highlight_line(&self, line: &str, spans: &mut Vec<HighlightSpan>) {
+        self.calls.set(self.calls.get() + 1);
+        for (at, _) in line.match_indices("fn") {
+            spans.push(HighlightSpan {
+                range: at..at + 2,
+                color: self.color,
+            });
+        }
+    }
+}
+
+/// One code-mode key through a fresh editor over `text`.
+fn code_key(text: &mut String, state: &mut EditState, kp: KeyPress) {
+    let mut ed = Editor::new(text, state, true, None);
+    ed.code = true;
+    assert!(apply_code_key(&mut ed, kp), "{kp:?} is a code-mode key");
+}
+
+/// The text runs the field's block records, with their colours.
+fn block_runs(h: &UiHarness) -> Vec<(String, Color)> {
+    let payloads = h.ui.payloads();
+    let interned = payloads.interned_text();
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    h.ui.tree(Layer::Main)
+        .shapes_of(block)
+        .filter_map(|s| match s {
+            ShapeRecord::Text { text, color, .. } => Some((
+                text.source.resolve(&interned).to_owned(),
+                Color::from(*color),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn enter_carries_indent_and_opens_a_level_after_a_bracket() {
+    let mut text = String::from("    fn f() {");
+    let mut state = EditState {
+        caret: text.len(),
+        ..EditState::default()
+    };
+    code_key(&mut text, &mut state, press(Key::Enter));
+    assert_eq!(text, "    fn f() {\n        ");
+    code_key(&mut text, &mut state, press(Key::Enter));
+    assert_eq!(text, "    fn f() {\n        \n        ");
+    assert_eq!(state.caret, text.len());
+}
+
+/// Tab pads to the next stop; over a multi-line selection it indents each
+/// line, Shift+Tab takes it back off, and each is one undo step.
+#[test]
+fn tab_indents_and_shift_tab_dedents_selected_lines() {
+    let mut text = String::from("ab");
+    let mut state = EditState {
+        caret: 1,
+        ..EditState::default()
+    };
+    code_key(&mut text, &mut state, press(Key::Tab));
+    assert_eq!(text, "a   b", "padded to column 4");
+
+    let mut text = String::from("one\ntwo\nthree");
+    let mut state = EditState {
+        caret: 5,
+        selection: Some(1),
+        ..EditState::default()
+    };
+    code_key(&mut text, &mut state, press(Key::Tab));
+    assert_eq!(text, "    one\n    two\nthree");
+    assert_eq!(
+        (state.selection, state.caret),
+        (Some(5), 13),
+        "the selection moves with its text"
+    );
+    code_key(&mut text, &mut state, shift(Key::Tab));
+    assert_eq!(text, "one\ntwo\nthree");
+
+    let mut ed = Editor::new(&mut text, &mut state, true, None);
+    ed.undo();
+    assert_eq!(text, "    one\n    two\nthree");
+}
+
+#[test]
+fn brackets_match_across_nesting_and_lines() {
+    let text = String::from("f(a[b]c)");
+    assert_eq!(
+        matching_bracket(&text, 8),
+        Some((7, 1)),
+        "the one before the caret"
+    );
+    assert_eq!(
+        matching_bracket(&text, 3),
+        Some((3, 5)),
+        "else the one after"
+    );
+    assert_eq!(matching_bracket(&text, 1), Some((1, 7)));
+    assert_eq!(matching_bracket(&String::from("(a"), 0), None, "unclosed");
+    let rope = TextRope::from("{\n  x()\n}");
+    assert_eq!(matching_bracket(&rope, 0), Some((0, rope.len() - 1)));
+}
+
+/// Only lines the cache hasn't seen are highlighted: a frame that changes
+/// nothing asks about nothing, and an edit asks about the line it touched.
+#[test]
+fn highlighter_runs_only_for_changed_lines() {
+    let mut h = UiHarness::with_text(UVec2::new(300, 160));
+    let keywords = Keywords::new();
+    let mut buf = String::from("fn a\nlet b\nlet c");
+    h.request_focus(Some(ed_id()));
+    // The first frame has no viewport yet and shows one line; the second
+    // shows the rest.
+    h.frame(code_field(&mut buf, Some(&keywords)));
+    h.frame(code_field(&mut buf, Some(&keywords)));
+    assert_eq!(keywords.calls.get(), 3);
+    h.frame(code_field(&mut buf, Some(&keywords)));
+    assert_eq!(
+        keywords.calls.get(),
+        3,
+        "an unchanged frame re-highlights nothing"
+    );
+
+    h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = 0;
+    h.type_text("x");
+    h.frame(code_field(&mut buf, Some(&keywords)));
+    assert_eq!(buf, "xfn a\nlet b\nlet c");
+    assert_eq!(keywords.calls.get(), 4, "only the edited line");
+}
+
+/// Highlighted stretches paint in their colour, whether the buffer is
+/// shaped as one run or a paragraph at a time.
+#[test]
+fn highlighted_stretches_paint_in_their_colour() {
+    let keywords = Keywords::new();
+    let buffers: [Box<dyn TextBuffer>; 2] = [
+        Box::new(String::from("pub fn main")),
+        Box::new(TextRope::from("pub fn main")),
+    ];
+    for mut buf in buffers {
+        let mut h = UiHarness::with_text(UVec2::new(300, 160));
+        h.frame(code_field(&mut *buf, Some(&keywords)));
+        h.frame(code_field(&mut *buf, Some(&keywords)));
+        let runs = block_runs(&h);
+        let texts: Vec<&str> = runs.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(texts, ["pub ", "fn", " main"], "{buf:?}");
+        assert_eq!(runs[1].1, keywords.color);
+        assert_ne!(runs[0].1, keywords.color);
+    }
+}
+
+/// Code mode shapes in the monospace family and numbers its lines in a
+/// gutter beside the block.
+#[test]
+fn code_field_is_mono_with_numbered_gutter() {
+    let mut h = UiHarness::with_text(UVec2::new(300, 160));
+    let mut buf = String::from("a\nb\nc");
+    h.frame(code_field(&mut buf, None));
+    h.frame(code_field(&mut buf, None));
+    let field = h.node_for_widget_id(ed_id());
+    let block = block_of(&h.ui, field);
+    let tree = h.ui.tree(Layer::Main);
+    let families: Vec<FontFamily> = tree
+        .shapes_of(block)
+        .filter_map(|s| match s {
+            ShapeRecord::Text { font, .. } => Some(font.family),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(families, [FontFamily::Mono]);
+
+    let gutter = tree
+        .children(field)
+        .nth(1)
+        .expect("code mode records a gutter after the block")
+        .id;
+    let payloads = h.ui.payloads();
+    let interned = payloads.interned_text();
+    let numbers: Vec<String> = tree
+        .shapes_of(gutter)
+        .filter_map(|s| match s {
+            ShapeRecord::Text { text, .. } => Some(text.source.resolve(&interned).to_owned()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(numbers, ["1", "2", "3"]);
+}
+
+/// An unwrapped line longer than the field scrolls sideways to keep the
+/// caret in view, as a single-line field does.
+#[test]
+fn code_field_scrolls_sideways_to_the_caret() {
+    let mut h = UiHarness::with_text(UVec2::new(300, 160));
+    let mut buf = format!("short\n{}", "x".repeat(200));
+    h.request_focus(Some(ed_id()));
+    h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = buf.len();
+    h.frame(code_field(&mut buf, None));
+    h.frame(code_field(&mut buf, None));
+    let offset = h.ui.state_mut::<TextEditState>(ed_id()).view.scroll.offset;
+    assert!(
+        offset.x > 0.0,
+        "the view followed the caret right: {offset:?}"
+    );
+}
@@ -0,0 +1,233 @@
+//! Diagnostics: squiggles under what a provider flags, and its suggested
+//! replacements offered in the right-click menu.
+
+use crate::primitives::color::{Color, ColorF16};
+use crate::primitives::rect::Rect;
+use crate::scene::shapes::paint::ShapeBrush;
+use crate::scene::shapes::record::ShapeRecord;
+use crate::widgets::context_menu::ContextMenu;
+use crate::widgets::text_edit::TextEditState;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::diagnostics::{
+    Diagnostic, DiagnosticProvider, DiagnosticState, Severity,
+};
+use crate::widgets::text_edit::tests::*;
+use std::cell::Cell;
+use std::ops::Range;
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("diagnostics-ed")
+}
+
+/// Flags every occurrence of its words, counting how often it is asked.
+#[derive(Debug, Default)]
+struct Typos {
+    words: Vec<(&'static str, Severity, &'static str)>,
+    revision: Cell<u64>,
+    asked: Cell<usize>,
+}
+
+impl Typos {
+    fn new(words: &[(&'static str, Severity, &'static str)]) -> Self {
+        Self {
+            words: words.to_vec(),
+            ..Self::default()
+        }
+    }
+}
+
+impl DiagnosticProvider for Typos {
+    fn check(&self, text: &str, out: &mut Vec<Diagnostic>) {
+        self.asked.set(self.asked.get() + 1);
+        for &(word, severity, fix) in &self.words {
+            for (at, _) in text.match_indices(word) {
+                out.push(Diagnostic {
+                    range: at..at + word.len(),
+                    severity,
+                    message: format!("Did you mean “{fix}”?").into(),
+                    suggestions: vec![fix.to_owned()],
+                });
+            }
+        }
+    }
+
+    fn revision(&self) -> u64 {
+        self.revision.get()
+    }
+}
+
+fn field(h: &mut UiHarness, buf: &mut String, provider: &dyn DiagnosticProvider) {
+    h.frame(|ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            TextEdit::new(buf)
+                .id(ed_id())
+                .diagnostics(provider)
+                .size((Sizing::fixed(200.0), Sizing::fixed(30.0)))
+                .show(ui);
+        });
+    });
+}
+
+/// The curves painted in the field's text block, block-local, with their
+/// colour.
+fn squiggles(h: &UiHarness) -> Vec<(Rect, Color)> {
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    h.ui.tree(Layer::Main)
+        .shapes_of(block)
+        .filter_map(|s| match s {
+            ShapeRecord::Curve {
+                fill: ShapeBrush::Solid(color),
+                bbox,
+                ..
+            } => Some((*bbox, Color::from(*color))),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Each flagged range gets a wave under it in its severity's colour, and
+/// nothing else in the field is underlined.
+#[test]
+fn flagged_ranges_are_squiggled_in_their_severitys_colour() {
+    let mut h = UiHarness::with_text(SMALL);
+    let typos = Typos::new(&[
+        ("teh", Severity::Error, "the"),
+        ("sat", Severity::Warning, "sits"),
+    ]);
+    let mut buf = String::from("teh cat sat");
+    field(&mut h, &mut buf, &typos);
+
+    let theme = h.ui.theme().diagnostics.clone();
+    let error = Color::from(ColorF16::from(theme.error));
+    let warning = Color::from(ColorF16::from(theme.warning));
+    let waves = squiggles(&h);
+    let of = |color| -> Vec<Rect> {
+        waves
+            .iter()
+            .filter(|(_, c)| *c == color)
+            .map(|(r, _)| *r)
+            .collect()
+    };
+    let (errors, warnings) = (of(error), of(warning));
+    assert_eq!(errors.len() + warnings.len(), waves.len());
+    assert!(errors.len() > 1, "a wave is more than one curve");
+    assert!(!warnings.is_empty());
+    let error_end = errors.iter().map(|r| r.max().x).fold(0.0, f32::max);
+    let warning_start = warnings.iter().map(|r| r.min.x).fold(f32::MAX, f32::min);
+    assert!(error_end < warning_start, "`teh` is squiggled, `cat` isn't");
+    assert!(errors.iter().all(|r| r.min.y > 5.0), "under the text");
+}
+
+/// The provider is asked once per buffer, and again when its revision
+/// moves; a squiggle goes when its text does.
+#[test]
+fn the_provider_is_asked_again_on_edit_or_revision() {
+    let mut h = UiHarness::with_text(SMALL);
+    let typos = Typos::new(&[("teh", Severity::Error, "the")]);
+    let mut buf = String::from("teh");
+    h.request_focus(Some(ed_id()));
+    field(&mut h, &mut buf, &typos);
+    field(&mut h, &mut buf, &typos);
+    assert_eq!(typos.asked.get(), 1);
+
+    typos.revision.set(1);
+    field(&mut h, &mut buf, &typos);
+    assert_eq!(typos.asked.get(), 2);
+
+    h.key(Key::End);
+    h.key(Key::Backspace);
+    field(&mut h, &mut buf, &typos);
+    field(&mut h, &mut buf, &typos);
+    assert_eq!(buf, "te");
+    assert_eq!(typos.asked.get(), 3);
+    assert!(squiggles(&h).is_empty());
+}
+
+/// Right-clicking a flagged word heads the menu with its message and
+/// offers its fix, which replaces just the flagged range.
+#[test]
+fn a_suggestion_in_the_menu_replaces_the_range() {
+    let mut h = UiHarness::with_text(SMALL);
+    let typos = Typos::new(&[("teh", Severity::Error, "the")]);
+    let mut buf = String::from("teh cat");
+    field(&mut h, &mut buf, &typos);
+    let rect = h.rect(ed_id()).unwrap();
+    h.right_click_at(rect.min + Vec2::new(10.0, rect.size.h * 0.5));
+    field(&mut h, &mut buf, &typos);
+    field(&mut h, &mut buf, &typos);
+    assert!(ContextMenu::is_open(&h.ui, ed_id()));
+
+    // Rows in record order: the message, the fix, a separator, then the
+    // standard items.
+    let body_id = ed_id().with("body");
+    let tree = h.ui.tree(Layer::Popup);
+    let body = tree
+        .records
+        .widget_id()
+        .iter()
+        .position(|id| *id == body_id)
+        .expect("menu body recorded");
+    let fix = tree.records.subtree_end()[body + 1].end() as usize;
+    h.click_at(h.ui.layout(Layer::Popup).rect[fix].center());
+    field(&mut h, &mut buf, &typos);
+    assert_eq!(buf, "the cat");
+    assert!(!ContextMenu::is_open(&h.ui, ed_id()));
+}
+
+/// Right-clicking clean text offers no fixes: the menu is the standard
+/// one.
+#[test]
+fn clean_text_opens_the_standard_menu() {
+    let mut h = UiHarness::with_text(SMALL);
+    let typos = Typos::new(&[("teh", Severity::Error, "the")]);
+    let mut buf = String::from("cat teh");
+    field(&mut h, &mut buf, &typos);
+    let rect = h.rect(ed_id()).unwrap();
+    h.right_click_at(rect.min + Vec2::new(8.0, rect.size.h * 0.5));
+    field(&mut h, &mut buf, &typos);
+    field(&mut h, &mut buf, &typos);
+    assert!(ContextMenu::is_open(&h.ui, ed_id()));
+    assert_eq!(
+        h.ui.state_mut::<TextEditState>(ed_id()).diagnostics.menu,
+        None
+    );
+}
+
+/// Ranges past the end, backwards, or splitting a char are dropped; of
+/// overlapping ones, the narrowest is the one under a byte.
+#[test]
+fn bad_ranges_are_dropped_and_the_narrowest_wins() {
+    #[derive(Debug)]
+    struct Raw(Vec<Range<usize>>);
+    impl DiagnosticProvider for Raw {
+        fn check(&self, _: &str, out: &mut Vec<Diagnostic>) {
+            out.extend(self.0.iter().map(|range| Diagnostic {
+                range: range.clone(),
+                severity: Severity::Info,
+                message: "".into(),
+                suggestions: Vec::new(),
+            }));
+        }
+    }
+    #[allow(clippy::reversed_empty_ranges)]
+    let raw = Raw(vec![0..20, 4..2, 1..2, 4..8, 0..8]);
+    let string = String::from("né word");
+    let rope = TextRope::from("né word");
+    for text in [&string as &dyn TextBuffer, &rope] {
+        let mut state = DiagnosticState::default();
+        state.refresh(&raw, text, 1);
+        assert_eq!(state.at(1), Some(&raw_diag(0..8)), "1..2 splits `é`");
+        assert_eq!(state.at(5), Some(&raw_diag(4..8)));
+        assert_eq!(state.at(8), Some(&raw_diag(4..8)), "the end is on it");
+    }
+
+    fn raw_diag(range: Range<usize>) -> Diagnostic {
+        Diagnostic {
+            range,
+            severity: Severity::Info,
+            message: "".into(),
+            suggestions: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! Find and replace: the match set each option produces, kept in step
+//! with the buffer as it is edited, replace-all as one undo step, and the
+//! bar driven from the keyboard.
+
+use crate::KeyClass;
+use crate::primitives::color::ColorF16;
+use crate::scene::shapes::paint::{QuadShape, ShapeBrush};
+use crate::scene::shapes::record::ShapeRecord;
+use crate::ui::harness::UiHarness;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::edit_state::{EditKind, SelectionState};
+use crate::widgets::text_edit::find::{
+    FIND, FindMode, FindOptions, FindState, REPLACE, Reach, reach,
+};
+use crate::widgets::text_edit::tests::*;
+use std::ops::Range;
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("find-ed")
+}
+
+fn field(buf: &mut dyn TextBuffer) -> impl FnMut(&mut Ui) + '_ {
+    move |ui: &mut Ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            TextEdit::new(&mut *buf)
+                .id(ed_id())
+                .multiline(true)
+                .size((Sizing::fixed(360.0), Sizing::fixed(100.0)))
+                .show(ui);
+        });
+    }
+}
+
+/// A fresh search of `text` for `query`.
+fn search(text: &dyn TextBuffer, query: &str, options: FindOptions) -> FindState {
+    let mut find = FindState::default();
+    find.query = query.to_owned();
+    find.options = options;
+    find.sync(text, &mut None);
+    find
+}
+
+fn find_state(h: &mut UiHarness) -> FindState {
+    h.ui.state_mut::<TextEditState>(ed_id()).find.clone()
+}
+
+fn chord(h: &mut UiHarness, c: char) {
+    h.set_modifiers(Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    });
+    h.key(Key::Char(c));
+    h.set_modifiers(Modifiers::NONE);
+}
+
+/// Ctrl+F and Ctrl+H are the field's, not the app's: they classify with
+/// the edit chords a focused field's scope takes.
+#[test]
+fn find_chords_are_edit_class() {
+    for shortcut in [FIND, REPLACE] {
+        let press = KeyPress {
+            key: shortcut.key,
+            mods: Modifiers {
+                ctrl: true,
+                ..Modifiers::NONE
+            },
+            repeat: false,
+            physical: Key::Other,
+        };
+        assert_eq!(KeyClass::of(press), KeyClass::Edit, "{shortcut:?}");
+    }
+}
+
+#[test]
+fn options_shape_the_match_set() {
+    let text = String::from("Foo foo food\nfoo");
+    let cases: [(&str, FindOptions, &[Range<usize>]); 4] = [
+        ("foo", FindOptions::default(), &[0..3, 4..7, 8..11, 13..16]),
+        (
+            "foo",
+            FindOptions {
+                case_sensitive: true,
+                ..FindOptions::default()
+            },
+            &[4..7, 8..11, 13..16],
+        ),
+        (
+            "foo",
+            FindOptions {
+                whole_word: true,
+                ..FindOptions::default()
+            },
+            &[0..3, 4..7, 13..16],
+        ),
+        (
+            "fo+d?$",
+            FindOptions {
+                regex: true,
+                ..FindOptions::default()
+            },
+            &[8..12, 13..16],
+        ),
+    ];
+    for (query, options, want) in cases {
+        let find = search(&text, query, options);
+        assert_eq!(find.matches, want, "{query:?} under {options:?}");
+        assert!(!find.invalid);
+    }
+    let find = search(
+        &text,
+        "(",
+        FindOptions {
+            regex: true,
+            ..FindOptions::default()
+        },
+    );
+    assert!(find.invalid, "an unbalanced group doesn't compile");
+    assert!(find.matches.is_empty());
+    assert_eq!(
+        search(&text, "(", FindOptions::default()).matches,
+        [] as [Range<usize>; 0],
+        "as a literal it is only a bracket"
+    );
+}
+
+/// Edits replayed from the splice log land on the same matches a fresh
+/// search finds, on a `String` and on a rope alike — several edits
+/// between syncs, ones that make, break and move matches, and a buffer
+/// the host replaced.
+#[test]
+fn matches_follow_edits_incrementally() {
+    let regex = FindOptions {
+        regex: true,
+        ..FindOptions::default()
+    };
+    // A literal and a one-line regex replay line by line, the bounded
+    // multi-line regexes around each edit; the last can't, and searches
+    // afresh.
+    let queries = [
+        ("foo", FindOptions::default()),
+        (r"\bf[aeiou]+\b", regex),
+        (r"o\sb", regex),
+        (r"(?s)o.{1,3}f\b", regex),
+        (r"o\s+b", regex),
+    ];
+    for (query, options) in queries {
+        follow_edits(query, options);
+    }
+}
+
+fn follow_edits(query: &str, options: FindOptions) {
+    let buffers: [Box<dyn TextBuffer>; 2] = [
+        Box::new(String::from("foo bar\nbaz foo\nfoo")),
+        Box::new(TextRope::from("foo bar\nbaz foo\nfoo")),
+    ];
+    for mut text in buffers {
+        let mut state = EditState::default();
+        let mut find = FindState::default();
+        find.query = query.to_owned();
+        find.options = options;
+        find.open(FindMode::Find, &mut state, None);
+        find.sync(&*text, &mut state.splices);
+        assert_eq!(find.matches, search(&*text, query, options).matches);
+
+        let rounds: [&[(Range<usize>, &str)]; 3] = [
+            &[(5..5, "fo"), (0..3, ""), (16..16, "o")],
+            &[(0..0, "x\nfoo"), (9..11, "")],
+            &[(3..3, "\n"), (10..13, "FOO foo")],
+        ];
+        for edits in rounds {
+            let mut ed = Editor::new(&mut *text, &mut state, true, None);
+            for (range, with) in edits {
+                let caret = range.start + with.len();
+                ed.replace_range_to(
+                    range.clone(),
+                    with,
+                    EditKind::Other,
+                    SelectionState {
+                        caret,
+                        selection: None,
+                    },
+                );
+            }
+            find.sync(&*text, &mut state.splices);
+            let want = search(&*text, query, options).matches;
+            assert_eq!(find.matches, want, "{query:?} in {text:?} after {edits:?}");
+        }
+
+        text.replace_range(0..0, "foo ");
+        find.sync(&*text, &mut state.splices);
+        let want = search(&*text, query, options).matches;
+        assert_eq!(
+            find.matches, want,
+            "{query:?} in {text:?} replaced by the host"
+        );
+    }
+}
+
+/// A regex that can't match a line break is searched line by line, one
+/// that can as far as its longest match; only an unbounded one, or one
+/// anchored to the buffer's ends, needs the whole buffer.
+#[test]
+fn a_patterns_reach_follows_what_its_matches_can_hold() {
+    let regex = FindOptions {
+        regex: true,
+        ..FindOptions::default()
+    };
+    let cases = [
+        (r"fo+d?$", Reach::Line),
+        (r"^\w+\b", Reach::Line),
+        (r"[a-z]+\d", Reach::Line),
+        (r"a.b", Reach::Line),
+        (r"\S+", Reach::Line),
+        (r"a\nb", Reach::Lines(3)),
+        (r"(?s)a.b", Reach::Lines(6)),
+        (r"\x0A", Reach::Lines(1)),
+        (r"a\s+b", Reach::Unbounded),
+        (r"[^,]+", Reach::Unbounded),
+        (r"\Afoo", Reach::Buffer),
+        (r"foo\z", Reach::Buffer),
+    ];
+    for (pattern, want) in cases {
+        assert_eq!(reach(pattern, regex), want, "{pattern:?}");
+    }
+    assert!(matches!(reach(r"\W", regex), Reach::Lines(_)));
+    assert_eq!(
+        reach(&regex::escape("a.b"), FindOptions::default()),
+        Reach::Line
+    );
+    assert_eq!(
+        reach(&regex::escape("a\nb"), FindOptions::default()),
+        Reach::Lines(3)
+    );
+}
+
+/// Replace-all is one edit: a regex replacement fills in its groups, and
+/// one undo takes every replacement back.
+#[test]
+fn replace_all_is_one_undo_step() {
+    let mut text = String::from("a1 b2 a3");
+    let mut state = EditState::default();
+    let mut find = FindState::default();
+    find.query = r"a(\d)".to_owned();
+    find.replacement = "x$1".to_owned();
+    find.options.regex = true;
+    find.open(FindMode::Replace, &mut state, None);
+    find.sync(&text, &mut state.splices);
+    let mut ed = Editor::new(&mut text, &mut state, true, None);
+    find.replace_all(&mut ed);
+    assert_eq!(text, "x1 b2 x3");
+    assert!(find.matches.is_empty());
+    assert_eq!(state.undo.len(), 1);
+
+    let mut ed = Editor::new(&mut text, &mut state, true, None);
+    ed.undo();
+    assert_eq!(text, "a1 b2 a3");
+}
+
+/// A replacement reads its groups from the lines its match runs over, so
+/// a rope fills them in as a `String` does — a match crossing a line, and
+/// ones whose `^` / `$` lean on the line around them.
+#[test]
+fn replacements_fill_in_groups_across_lines() {
+    let cases = [
+        (r"(\w+)\n(\w+)", "$2 $1", "ab\ncd ef\ngh", "cd ab gh ef"),
+        (r"^(\w)|(\w)$", "<$1$2>", "ab cd\nef", "<a>b c<d>\n<e><f>"),
+    ];
+    for (query, replacement, before, after) in cases {
+        let buffers: [Box<dyn TextBuffer>; 2] = [
+            Box::new(String::from(before)),
+            Box::new(TextRope::from(before)),
+        ];
+        for mut text in buffers {
+            let mut state = EditState::default();
+            let mut find = FindState::default();
+            find.query = query.to_owned();
+            find.replacement = replacement.to_owned();
+            find.options.regex = true;
+            find.open(FindMode::Replace, &mut state, None);
+            find.sync(&*text, &mut state.splices);
+            let mut ed = Editor::new(&mut *text, &mut state, true, None);
+            find.replace_all(&mut ed);
+            assert_eq!(text.slice(0..text.len()), after, "{query:?} in {before:?}");
+        }
+    }
+}
+
+/// Ctrl+F opens the bar on the selected word with its query focused;
+/// Enter and Shift+Enter step through the matches, scrolling each into
+/// view, and Escape hands focus back to the field.
+#[test]
+fn the_bar_steps_through_matches_from_the_keyboard() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 240));
+    let mut lines = vec!["hay"; 30];
+    lines[1] = "needle";
+    lines[25] = "needle";
+    let mut buf = lines.join("\n");
+    let first = buf.find("needle").unwrap();
+    let second = buf.rfind("needle").unwrap();
+    h.request_focus(Some(ed_id()));
+    h.frame(field(&mut buf));
+    h.frame(field(&mut buf));
+
+    {
+        let edit = &mut h.ui.state_mut::<TextEditState>(ed_id()).edit;
+        edit.selection = Some(first);
+        edit.caret = first + 6;
+    }
+    chord(&mut h, 'f');
+    h.frame(field(&mut buf));
+    h.frame(field(&mut buf));
+    assert_eq!(h.ui.focused_id(), Some(ed_id().with("find-query")));
+    let find = find_state(&mut h);
+    assert!(find.open && !find.replace_open);
+    assert_eq!(find.query, "needle", "seeded from the selection");
+    assert_eq!(find.matches, [first..first + 6, second..second + 6]);
+    assert_eq!(find.current, Some(0));
+
+    h.key(Key::Enter);
+    h.frame(field(&mut buf));
+    h.frame(field(&mut buf));
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).clone();
+    assert_eq!(state.edit.sel_range(), Some(second..second + 6));
+    assert_eq!(state.find.current, Some(1));
+    assert!(
+        state.view.scroll.offset.y > 0.0,
+        "the view followed the match down"
+    );
+
+    h.set_modifiers(Modifiers {
+        shift: true,
+        ..Modifiers::NONE
+    });
+    h.key(Key::Enter);
+    h.set_modifiers(Modifiers::NONE);
+    h.frame(field(&mut buf));
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).clone();
+    assert_eq!(state.edit.sel_range(), Some(first..first + 6));
+
+    h.key(Key::Escape);
+    h.frame(field(&mut buf));
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).clone();
+    assert!(!state.find.open);
+    assert!(
+        state.edit.splices.is_none(),
+        "nothing logs for a closed bar"
+    );
+    assert_eq!(h.ui.focused_id(), Some(ed_id()));
+}
+
+/// Typing a query selects the first match from the caret on, and Enter
+/// in the replace row replaces it and moves to the next. Every match is
+/// washed, the current one in its own colour.
+#[test]
+fn the_replace_row_replaces_the_current_match() {
+    let mut h = UiHarness::with_text(UVec2::new(400, 240));
+    let mut buf = String::from("cat dog cat\ncat");
+    h.request_focus(Some(ed_id()));
+    h.frame(field(&mut buf));
+    h.frame(field(&mut buf));
+    h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = 2;
+
+    chord(&mut h, 'h');
+    h.frame(field(&mut buf));
+    h.type_text("cat");
+    h.frame(field(&mut buf));
+    h.frame(field(&mut buf));
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).clone();
+    assert!(state.find.replace_open);
+    assert_eq!(
+        state.edit.sel_range(),
+        Some(8..11),
+        "the first from the caret"
+    );
+
+    let theme = h.ui.theme().find_bar.clone();
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    let fills: Vec<ColorF16> =
+        h.ui.tree(Layer::Main)
+            .shapes_of(block)
+            .filter_map(|s| match s {
+                ShapeRecord::Quad(QuadShape::Rect {
+                    fill: ShapeBrush::Solid(fill),
+                    ..
+                }) => Some(*fill),
+                _ => None,
+            })
+            .collect();
+    let count = |color| {
+        fills
+            .iter()
+            .filter(|&&f| f == ColorF16::from(color))
+            .count()
+    };
+    assert_eq!(count(theme.matches), 2);
+    assert_eq!(count(theme.current_match), 1);
+
+    h.request_focus(Some(ed_id().with("find-replacement")));
+    h.frame(field(&mut buf));
+    h.type_text("cow");
+    h.key(Key::Enter);
+    h.frame(field(&mut buf));
+    assert_eq!(buf, "cat dog cow\ncat");
+    let state = h.ui.state_mut::<TextEditState>(ed_id()).clone();
+    assert_eq!(state.edit.sel_range(), Some(12..15), "on to the next");
+    assert_eq!(state.find.matches, [0..3, 12..15]);
+}
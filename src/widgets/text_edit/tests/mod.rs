@@ -1,4 +1,3 @@
-use crate::common::hash;
 use crate::widgets::text_edit::TextEditState;
 use crate::widgets::text_edit::action::EditAction;
 use crate::widgets::text_edit::edit_state::EditState;
@@ -28,7 +27,7 @@ fn apply_key_with_clipboard(
         }
         None => apply_editor_key(&mut ed, kp) == KeyOutcome::Blur,
     };
-    let text_hash = hash::hash_str(ed.text);
+    let text_hash = ed.text.content_hash();
     ed.state.observe_text_hash(text_hash);
     blur
 }
@@ -197,16 +196,23 @@ fn multiline_editor(buf: &mut String) -> impl FnMut(&mut Ui) + '_ {
 mod align;
 mod align_per_line;
 mod apply_key;
+mod autocomplete;
 mod blink;
+mod carets;
 mod click;
+mod code;
 mod context_menu;
+mod diagnostics;
+mod find;
 mod grapheme;
 mod measure;
 mod multi_click;
 mod multiline;
 mod response;
+mod rope;
 mod scroll;
 mod selection;
 mod theme;
 mod undo;
+mod validate;
 mod word_nav;
@@ -0,0 +1,216 @@
+//! A [`TextRope`]-backed field: the buffer helpers agree with a `String`'s,
+//! the editor's edits and undo run through the trait, and a multi-line
+//! field lays the rope out one visible paragraph at a time.
+
+use crate::scene::layer::Layer;
+use crate::scene::shapes::paint::QuadShape;
+use crate::scene::shapes::record::ShapeRecord;
+use crate::shape::rect::RectKind;
+use crate::ui::harness::UiHarness;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::buffer::{
+    TextBuffer, next_grapheme, next_word, prev_grapheme, prev_word, word_range,
+};
+use crate::widgets::text_edit::line_heights::LineHeights;
+use crate::widgets::text_edit::tests::*;
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("rope-ed")
+}
+
+fn field(buf: &mut dyn TextBuffer) -> impl FnMut(&mut Ui) + '_ {
+    move |ui: &mut Ui| {
+        Panel::hstack().auto_id().show(ui, |ui| {
+            TextEdit::new(&mut *buf)
+                .id(ed_id())
+                .multiline(true)
+                .size((Sizing::fixed(200.0), Sizing::fixed(120.0)))
+                .show(ui);
+        });
+    }
+}
+
+/// The caret's rect, block-local — the last rounded rect the block records.
+fn caret_rect(h: &UiHarness) -> crate::primitives::rect::Rect {
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    h.ui.tree(Layer::Main)
+        .shapes_of(block)
+        .filter_map(|s| match s {
+            ShapeRecord::Quad(QuadShape::Rect {
+                kind: RectKind::Rounded,
+                local_rect: Some(r),
+                ..
+            }) => Some(*r),
+            _ => None,
+        })
+        .last()
+        .expect("a focused field paints its caret")
+}
+
+/// Per-paragraph motion on a rope lands where whole-text motion on a
+/// `String` does, across blank lines, CRLF and combining marks.
+#[test]
+fn rope_motion_matches_string() {
+    for text in [
+        "one two\n\n  three\nfour",
+        "e\u{301}x\r\ny  z\n",
+        "\n\n",
+        "",
+    ] {
+        let string = text.to_owned();
+        let rope = TextRope::from(text);
+        for at in (0..=text.len()).filter(|&i| text.is_char_boundary(i)) {
+            assert_eq!(
+                next_grapheme(&rope, at),
+                next_grapheme(&string, at),
+                "next_grapheme({text:?}, {at})"
+            );
+            assert_eq!(
+                prev_grapheme(&rope, at),
+                prev_grapheme(&string, at),
+                "prev_grapheme({text:?}, {at})"
+            );
+            assert_eq!(
+                next_word(&rope, at),
+                next_word(&string, at),
+                "next_word({text:?}, {at})"
+            );
+            assert_eq!(
+                prev_word(&rope, at),
+                prev_word(&string, at),
+                "prev_word({text:?}, {at})"
+            );
+            assert_eq!(
+                word_range(&rope, at),
+                word_range(&string, at),
+                "word_range({text:?}, {at})"
+            );
+        }
+    }
+}
+
+#[test]
+fn rope_line_index_matches_string() {
+    for text in ["a\nbc\n\nd", "\n", "no newline", ""] {
+        let string = text.to_owned();
+        let rope = TextRope::from(text);
+        assert_eq!(rope.line_count(), string.line_count(), "{text:?}");
+        for line in 0..=string.line_count() {
+            assert_eq!(rope.line_to_byte(line), string.line_to_byte(line));
+        }
+        for byte in 0..=text.len() {
+            assert_eq!(rope.byte_to_line(byte), string.byte_to_line(byte));
+        }
+    }
+}
+
+/// Typing into a rope edits it in place, and the editor's own edits don't
+/// read as a host replacement: the undo history survives to undo them.
+#[test]
+fn rope_field_edits_and_undoes() {
+    let mut h = UiHarness::with_text(UVec2::new(300, 160));
+    let mut doc = TextRope::from("abc\ndef");
+    h.request_focus(Some(ed_id()));
+    h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = 3;
+    h.frame(field(&mut doc));
+    h.key(Key::Enter);
+    h.type_text("x");
+    h.frame(field(&mut doc));
+    assert_eq!(doc, "abc\nx\ndef");
+
+    h.set_modifiers(Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    });
+    while doc != "abc\ndef" {
+        let before = doc.revision();
+        h.key(Key::Char('z'));
+        h.frame(field(&mut doc));
+        assert_ne!(doc.revision(), before, "undo history was dropped");
+    }
+}
+
+/// The paragraph layout puts the caret where the one-run layout of the
+/// same text does.
+#[test]
+fn rope_caret_matches_string_caret() {
+    let text = "first\nsecond line\n\nlast";
+    let caret = text.len() - 2;
+    let at = |buf: &mut dyn TextBuffer| {
+        let mut h = UiHarness::with_text(UVec2::new(300, 160));
+        h.request_focus(Some(ed_id()));
+        h.ui.state_mut::<TextEditState>(ed_id()).edit.caret = caret;
+        h.frame(field(buf));
+        h.frame(field(buf));
+        caret_rect(&h)
+    };
+    let from_string = at(&mut text.to_owned());
+    let from_rope = at(&mut TextRope::from(text));
+    assert_eq!(from_rope, from_string);
+}
+
+/// A document far taller than the field records only the paragraphs the
+/// viewport shows, each as a run of its own.
+#[test]
+fn large_rope_records_only_visible_paragraphs() {
+    let mut h = UiHarness::with_text(UVec2::new(300, 160));
+    let mut doc = TextRope::from("a line of the log\n".repeat(100_000));
+    h.frame(field(&mut doc));
+    h.frame(field(&mut doc));
+    let block = block_of(&h.ui, h.node_for_widget_id(ed_id()));
+    let runs =
+        h.ui.tree(Layer::Main)
+            .shapes_of(block)
+            .filter(|s| matches!(s, ShapeRecord::Text { .. }))
+            .count();
+    assert!(
+        (1..=12).contains(&runs),
+        "a 120 px field shows a handful of lines, recorded {runs}"
+    );
+}
+
+/// The line-height tree answers as a flat table of heights does — tops,
+/// the line at a height, the total — through splices that grow, shrink
+/// and empty it, and lines measured in between.
+#[test]
+fn line_heights_agree_with_a_flat_table() {
+    const ESTIMATE: f32 = 10.0;
+    let mut tree = LineHeights::default();
+    let mut flat: Vec<Option<f32>> = vec![None; 40];
+    tree.reset(flat.len(), ESTIMATE);
+    for step in 0..400_usize {
+        let len = flat.len();
+        if step % 3 == 0 && len > 0 {
+            let line = step * 7 % len;
+            let height = (10 + step % 5 * 7) as f32;
+            tree.measure(line, height);
+            flat[line] = Some(height);
+        } else {
+            let start = step * 13 % (len + 1);
+            let end = (start + step % 4).min(len);
+            let inserted = (step * 5 + 1) % 6;
+            tree.splice(start..end, inserted, ESTIMATE);
+            flat.splice(start..end, std::iter::repeat_n(None, inserted));
+        }
+        assert_eq!(tree.len(), flat.len(), "step {step}");
+        let mut top = 0.0;
+        for (line, height) in flat.iter().enumerate() {
+            assert_eq!(tree.top(line), top, "top of {line} at step {step}");
+            assert_eq!(tree.measured(line), *height, "{line} at step {step}");
+            assert_eq!(tree.line_at(top), line, "line at {top} at step {step}");
+            top += height.unwrap_or(ESTIMATE);
+            assert_eq!(tree.line_at(top - 0.5), line);
+        }
+        assert_eq!(tree.total_height(), top, "step {step}");
+        assert_eq!(tree.top(flat.len()), top);
+        assert_eq!(tree.line_at(-5.0), 0);
+        assert_eq!(tree.line_at(top + 5.0), flat.len().saturating_sub(1));
+    }
+    tree.splice(0..flat.len(), 0, ESTIMATE);
+    assert_eq!(
+        (tree.len(), tree.total_height(), tree.line_at(5.0)),
+        (0, 0.0, 0)
+    );
+    tree.splice(0..0, 2, ESTIMATE);
+    assert_eq!((tree.len(), tree.top(1)), (2, ESTIMATE));
+}
@@ -0,0 +1,288 @@
+//! Validators: edits refused or rewritten before they land, masks that
+//! put their literals in as the user types, and the validity a field
+//! reports, paints and explains.
+
+use crate::primitives::color::ColorF16;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::buffer::rope::TextRope;
+use crate::widgets::text_edit::tests::*;
+use crate::widgets::text_edit::validate::{
+    IntRange, Mask, Numeric, RegexValidator, ValidateFn, Validator, Verdict,
+};
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// Type `typed` into `text` a char at a time, under `validator`.
+fn type_into(text: &mut String, state: &mut EditState, validator: &dyn Validator, typed: &str) {
+    for c in typed.chars() {
+        let mut ed = Editor::new(text, state, false, None);
+        ed.validator = Some(validator);
+        ed.insert_char(c);
+    }
+}
+
+fn backspace(text: &mut String, state: &mut EditState, validator: &dyn Validator) {
+    let mut ed = Editor::new(text, state, false, None);
+    ed.validator = Some(validator);
+    ed.delete_backward();
+}
+
+/// Typing fills the slots and the literals between them go in on their
+/// own; what fits no slot, or comes past the last one, is refused.
+#[test]
+fn mask_puts_in_its_literals_as_the_user_types() {
+    let phone = Mask::new("(###) ###-####");
+    let (mut s, mut state) = (String::new(), EditState::default());
+    type_into(&mut s, &mut state, &phone, "555");
+    assert_eq!(s, "(555");
+    type_into(&mut s, &mut state, &phone, "x1234567");
+    assert_eq!(s, "(555) 123-4567", "the letter is refused");
+    assert_eq!(state.caret, s.len());
+    type_into(&mut s, &mut state, &phone, "8");
+    assert_eq!(s, "(555) 123-4567", "past the last slot");
+
+    backspace(&mut s, &mut state, &phone);
+    backspace(&mut s, &mut state, &phone);
+    backspace(&mut s, &mut state, &phone);
+    backspace(&mut s, &mut state, &phone);
+    assert_eq!(s, "(555) 123-");
+
+    // Mid-value: the digits after the caret shift along, over the
+    // literal, and the caret stays just past what was typed.
+    state.caret = 7;
+    type_into(&mut s, &mut state, &phone, "9");
+    assert_eq!(s, "(555) 192-3");
+    assert_eq!(state.caret, 8);
+}
+
+#[test]
+fn mask_verdicts() {
+    let time = Mask::new("##:##");
+    let cases: &[(&str, Verdict)] = &[
+        ("", Verdict::Accept),
+        ("12:34", Verdict::Accept),
+        ("1234", Verdict::Replace("12:34".into())),
+        ("12:", Verdict::Invalid("Expected __:__".into())),
+        ("12:3a", Verdict::Reject),
+        ("12:345", Verdict::Reject),
+        // A literal typed a step early is stepped over.
+        ("1:23", Verdict::Replace("12:3".into())),
+    ];
+    for (proposed, verdict) in cases {
+        assert_eq!(&time.check(proposed), verdict, "{proposed:?}");
+    }
+    let escaped = Mask::new(r"\#### \A");
+    assert_eq!(escaped.check("12"), Verdict::Replace("#12".into()));
+    assert_eq!(escaped.check("#123"), Verdict::Replace("#123 A".into()));
+    let named = Mask::new("##").reason("Two digits");
+    assert_eq!(named.check("1"), Verdict::Invalid("Two digits".into()));
+}
+
+#[test]
+fn numeric_and_int_range_verdicts() {
+    let invalid = |reason: &'static str| Verdict::Invalid(Cow::Borrowed(reason));
+    for (proposed, verdict) in [
+        ("-12.5", Verdict::Accept),
+        ("", Verdict::Accept),
+        ("1.2.3", Verdict::Reject),
+        ("1e3", Verdict::Reject),
+        ("-", invalid("Enter a number")),
+        (".", invalid("Enter a number")),
+    ] {
+        assert_eq!(Numeric.check(proposed), verdict, "{proposed:?}");
+    }
+
+    let percent = IntRange(0..=100);
+    let out_of_range = invalid("Enter a whole number from 0 to 100");
+    for (proposed, verdict) in [
+        ("42", Verdict::Accept),
+        ("", Verdict::Accept),
+        ("-1", Verdict::Reject),
+        ("4.2", Verdict::Reject),
+        ("101", out_of_range.clone()),
+        ("99999999999999999999", out_of_range),
+    ] {
+        assert_eq!(percent.check(proposed), verdict, "{proposed:?}");
+    }
+    let signed = IntRange(-10..=10);
+    assert_eq!(
+        signed.check("-"),
+        invalid("Enter a whole number from -10 to 10")
+    );
+    assert_eq!(signed.check("-10"), Verdict::Accept);
+}
+
+/// A regex flags but never refuses: the value has to be typed through
+/// ones that don't match yet.
+#[test]
+fn regex_flags_without_refusing() {
+    let hex = RegexValidator::new("#[0-9a-f]{6}", "A colour like #ff8800").unwrap();
+    let (mut s, mut state) = (String::new(), EditState::default());
+    type_into(&mut s, &mut state, &hex, "#ff8");
+    assert_eq!(s, "#ff8");
+    assert_eq!(
+        hex.check(&s),
+        Verdict::Invalid("A colour like #ff8800".into())
+    );
+    type_into(&mut s, &mut state, &hex, "800");
+    assert_eq!(hex.check(&s), Verdict::Accept, "anchored, and matched");
+    assert_eq!(
+        hex.check("x#ff8800"),
+        Verdict::Invalid("A colour like #ff8800".into())
+    );
+    assert!(RegexValidator::new("(", "").is_err());
+}
+
+/// A formatter's rewrite lands as the change it makes, one undo step,
+/// with the caret as far from the end as the edit left it.
+#[test]
+fn a_formatter_rewrites_and_undoes_as_one_edit() {
+    let upper = ValidateFn(|proposed: &str| {
+        if proposed.chars().any(char::is_lowercase) {
+            Verdict::Replace(proposed.to_uppercase())
+        } else {
+            Verdict::Accept
+        }
+    });
+    let mut s = String::from("AC");
+    let mut state = EditState {
+        caret: 1,
+        ..Default::default()
+    };
+    type_into(&mut s, &mut state, &upper, "b");
+    assert_eq!((s.as_str(), state.caret), ("ABC", 2));
+
+    let mut ed = Editor::new(&mut s, &mut state, false, None);
+    ed.validator = Some(&upper);
+    ed.paste("xyz");
+    assert_eq!((s.as_str(), state.caret), ("ABXYZC", 5));
+    apply_key(&mut s, &mut state, ctrl_press(Key::Char('z')));
+    assert_eq!(s, "ABC");
+}
+
+/// Several carets are vetted as one edit: refused at every one, or made
+/// at every one.
+#[test]
+fn an_edit_at_several_carets_is_vetted_whole() {
+    let digits = ValidateFn(|proposed: &str| {
+        if proposed.chars().all(|c| c.is_ascii_digit() || c == '\n') {
+            Verdict::Accept
+        } else {
+            Verdict::Reject
+        }
+    });
+    let mut s = String::from("1\n2");
+    let mut state = EditState {
+        caret: 3,
+        carets: vec![crate::widgets::text_edit::edit_state::SelectionState {
+            caret: 1,
+            selection: None,
+        }],
+        ..Default::default()
+    };
+    for c in ['x', '0'] {
+        let mut ed = Editor::new(&mut s, &mut state, true, None);
+        ed.validator = Some(&digits);
+        ed.insert_char(c);
+    }
+    assert_eq!(s, "10\n20");
+}
+
+/// A rope isn't validated: judging every edit as the whole value would
+/// copy the document per keystroke.
+#[test]
+fn a_rope_buffer_skips_the_validator() {
+    let refuse = ValidateFn(|_: &str| Verdict::Reject);
+    let mut rope = TextRope::from("ab");
+    let mut state = EditState {
+        caret: 2,
+        ..Default::default()
+    };
+    let mut ed = Editor::new(&mut rope, &mut state, true, None);
+    ed.validator = Some(&refuse);
+    ed.insert_char('c');
+    assert_eq!(rope.slice(0..rope.len()), "abc");
+}
+
+fn ed_id() -> WidgetId {
+    WidgetId::from_hash("validate-ed")
+}
+
+/// One frame of a percent field, returning what it reported invalid.
+fn percent_field(h: &mut UiHarness, buf: &mut String) -> Option<String> {
+    ranged_field(h, buf, 100)
+}
+
+/// One frame of a field taking `0..=max`, its validator rebuilt each
+/// frame as a caller's would be.
+fn ranged_field(h: &mut UiHarness, buf: &mut String, max: i64) -> Option<String> {
+    let percent = IntRange(0..=max);
+    let mut invalid = None;
+    h.frame(|ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            invalid = TextEdit::new(buf)
+                .id(ed_id())
+                .validator(&percent)
+                .invalid_tooltip()
+                .size((Sizing::fixed(120.0), Sizing::fixed(30.0)))
+                .show(ui)
+                .invalid
+                .map(str::to_owned);
+        });
+    });
+    invalid
+}
+
+/// The field reports its validity, strokes itself in the theme's
+/// `invalid` colour while flagged, and explains why in a tooltip.
+#[test]
+fn an_invalid_value_is_reported_painted_and_explained() {
+    let mut h = UiHarness::with_text(SMALL);
+    let mut buf = String::new();
+    h.request_focus(Some(ed_id()));
+    percent_field(&mut h, &mut buf);
+    h.type_text("1x50");
+    let invalid = percent_field(&mut h, &mut buf);
+    assert_eq!(buf, "150", "the letter was refused");
+    assert_eq!(
+        invalid.as_deref(),
+        Some("Enter a whole number from 0 to 100")
+    );
+    let stroke = |h: &UiHarness| {
+        let node = h.node_for_widget_id(ed_id());
+        h.ui.tree(Layer::Main).chrome(node).unwrap().stroke.color
+    };
+    let flagged = ColorF16::from(h.ui.theme().text_edit.invalid);
+    assert_eq!(stroke(&h), flagged);
+
+    let rect = h.rect(ed_id()).unwrap();
+    h.move_to(rect.min + Vec2::new(10.0, 10.0));
+    for secs in [0.0, 1.0, 2.0] {
+        h.at(Duration::from_secs_f32(secs));
+        percent_field(&mut h, &mut buf);
+    }
+    let bubble = ed_id().with("bubble");
+    let tooltips = h.ui.tree(Layer::Tooltip);
+    assert!(tooltips.records.widget_id().contains(&bubble));
+
+    h.key(Key::Backspace);
+    assert_eq!(percent_field(&mut h, &mut buf), None);
+    assert_eq!(buf, "15");
+    assert_ne!(stroke(&h), flagged);
+}
+
+/// The verdict is kept while the value and the validator hold still, so
+/// a validator whose range moves has to be judged again — even rebuilt at
+/// the same address, which is what its revision is for.
+#[test]
+fn a_moved_range_is_judged_again() {
+    let mut h = UiHarness::with_text(SMALL);
+    let mut buf = String::from("150");
+    assert!(ranged_field(&mut h, &mut buf, 100).is_some());
+    assert!(ranged_field(&mut h, &mut buf, 100).is_some());
+    assert_eq!(ranged_field(&mut h, &mut buf, 200), None);
+    assert_eq!(
+        ranged_field(&mut h, &mut buf, 120).as_deref(),
+        Some("Enter a whole number from 0 to 120"),
+    );
+}
@@ -5,6 +5,9 @@ use crate::primitives::rect::Rect;
 use crate::primitives::size::Size;
 use crate::text::probe::Caret;
 use crate::ui::Ui;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::edit_state::{EditState, SelectionState};
+use crate::widgets::text_edit::paragraphs::{LineEdit, Paragraphs};
 use crate::widgets::text_edit::text_layout::TextLayout;
 use glam::Vec2;
 use std::ops::Range;
@@ -22,10 +25,17 @@ struct Probed {
 #[derive(Debug)]
 pub(super) struct GeometryInput<'a> {
     pub(super) layout: TextLayout,
-    pub(super) text: &'a str,
+    pub(super) text: &'a dyn TextBuffer,
     pub(super) placeholder: &'a str,
     pub(super) caret: usize,
     pub(super) selection: Option<Range<usize>>,
+    /// The extra carets, washed and placed like the primary; empty when
+    /// there are none or the field isn't focused.
+    pub(super) carets: &'a [SelectionState],
+    /// Last frame's scroll offset down the block — where a paragraph-laid
+    /// buffer measures from before it places the caret, so the lines above
+    /// it on screen stand at their shaped heights rather than estimates.
+    pub(super) scroll_y: f32,
 }
 
 /// The layout plus everything only the shape probe could answer. Paint
@@ -62,15 +72,49 @@ pub(super) struct TextGeometry {
 
 impl TextGeometry {
     /// Measure the run and fill `selection_rects` with the wash for
-    /// `input.selection` — an out-parameter so the caller's retained buffer
-    /// is refilled in place instead of a fresh one being handed back each
-    /// frame.
+    /// `input.selection` and the extra carets' selections, and
+    /// `caret_positions` with where the extra carets stand —
+    /// out-parameters so the caller's retained buffers are refilled in
+    /// place instead of fresh ones being handed back each frame.
+    ///
+    /// A buffer on the paragraph layout measures as the stack of its
+    /// paragraphs and leaves `selection_rects` empty: which paragraphs are
+    /// on show isn't settled until the view has scrolled, so the painter
+    /// fills the wash for them then.
     pub(super) fn resolve(
         ui: &mut Ui,
         input: GeometryInput<'_>,
         selection_rects: &mut Vec<Rect>,
+        caret_positions: &mut Vec<Caret>,
+        paragraphs: &mut Paragraphs,
+        edits: &mut Vec<LineEdit>,
     ) -> Self {
+        caret_positions.clear();
         let layout = input.layout;
+        let ctx = &layout.ctx;
+        if Paragraphs::applies(ctx, input.text) {
+            paragraphs.sync(ctx, input.text, edits);
+            selection_rects.clear();
+            paragraphs.measure_visible(ui, ctx, input.text, input.scroll_y, layout.inner_size.h);
+            for caret in input.carets {
+                caret_positions.push(paragraphs.caret_at(ui, ctx, input.text, caret.caret));
+            }
+            // As wide as the widest paragraph shaped so far: nothing when
+            // wrapped, which fits the field, and the sideways scroll when not.
+            let measured = Size::new(
+                layout.inner_size.w.max(paragraphs.widest()),
+                paragraphs.total_height(),
+            );
+            return TextGeometry {
+                layout,
+                block_offset: Vec2::ZERO,
+                content_size: measured,
+                display_size: measured,
+                caret_pos: paragraphs.caret_at(ui, ctx, input.text, input.caret),
+                text_hash: EditState::text_hash(input.text),
+            };
+        }
+        let text = input.text.slice(0..input.text.len());
         // The block is load-bearing: the content probe holds the shaper's
         // exclusive borrow, so the placeholder measurement below cannot be
         // taken until this one has dropped. Overlapping them is E0499, not a
@@ -80,18 +124,26 @@ impl TextGeometry {
             caret_pos,
             text_hash,
         } = {
-            let probe = ui.probe_text(layout.ctx.run(input.text));
+            let probe = ui.probe_text(layout.ctx.run(&text));
             selection_rects.clear();
-            if let Some(selection) = input.selection {
+            let extra = input.carets.iter().filter_map(|caret| caret.sel_range());
+            for selection in input.selection.into_iter().chain(extra) {
                 probe.selection_rects(selection, |rect| selection_rects.push(rect));
             }
+            caret_positions.extend(input.carets.iter().map(|caret| probe.caret_at(caret.caret)));
             Probed {
                 measured: probe.size(),
                 caret_pos: probe.caret_at(input.caret),
-                text_hash: probe.text_hash(),
+                // The probe's hash is the buffer's identity only for a
+                // buffer that *is* its flattened text.
+                text_hash: if input.text.as_str().is_some() {
+                    probe.text_hash()
+                } else {
+                    EditState::text_hash(input.text)
+                },
             }
         };
-        let placeholder_measured = if input.text.is_empty() && !input.placeholder.is_empty() {
+        let placeholder_measured = if text.is_empty() && !input.placeholder.is_empty() {
             ui.probe_text(layout.ctx.run(input.placeholder)).size()
         } else {
             measured
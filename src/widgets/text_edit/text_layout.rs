@@ -15,6 +15,9 @@ pub(super) struct LayoutInput {
     pub(super) caret_width: f32,
     pub(super) font: GlyphFont,
     pub(super) multiline: bool,
+    /// Soft-wrap a multi-line field to its inner width. Off in code mode,
+    /// where a long line scrolls sideways instead.
+    pub(super) wrap: bool,
     pub(super) text_align: Option<Align>,
     pub(super) previous_block_offset: Vec2,
 }
@@ -45,7 +48,7 @@ impl TextLayout {
     pub(super) fn resolve(input: LayoutInput) -> Self {
         let caret_room = input.caret_width.max(0.0);
         // Raw inner width; `WrapBound::new` owns the canonical rounding.
-        let wrap_target = if input.multiline {
+        let wrap_target = if input.multiline && input.wrap {
             input
                 .response_rect
                 .map(|rect| rect.size.w - input.padding.horiz())
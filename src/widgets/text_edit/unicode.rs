@@ -140,8 +140,9 @@ pub(super) fn prev_word_boundary(text: &str, from: usize) -> usize {
 /// such that every char in it shares one `CharKind` and `byte` lies on
 /// or just past a boundary inside the run. Whitespace runs collapse to
 /// `byte..byte` so a double-click on a space doesn't select the gap.
-/// Used by double-click word selection.
-pub(super) fn word_range_at(text: &str, byte: usize) -> std::ops::Range<usize> {
+/// Used by double-click word selection, here and in a selectable
+/// [`Text`](crate::Text).
+pub(crate) fn word_range_at(text: &str, byte: usize) -> std::ops::Range<usize> {
     if text.is_empty() {
         return 0..0;
     }
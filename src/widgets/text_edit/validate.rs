@@ -0,0 +1,386 @@
+//! Input validation for [`TextEdit`](crate::TextEdit): a [`Validator`]
+//! sees every edit as the whole value it would leave behind, before it
+//! lands, and may let it through, flag it, refuse it or rewrite it. The
+//! same call, made against the buffer as it stands, is the field's
+//! validity — what the theme's `invalid` stroke and the response report.
+
+use crate::common::hash::Hasher;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use regex::Regex;
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::Hasher as _;
+use std::ops::{Range, RangeInclusive};
+
+/// Judges a [`TextEdit`](crate::TextEdit)'s value.
+///
+/// Asked about every edit — typing, paste, cut, delete, a find-bar
+/// replace — with the value the edit would leave, and about the buffer
+/// for the validity it reports whenever the buffer, the validator or its
+/// [`revision`](Self::revision) changes. Undo and redo aren't asked: they
+/// restore values that were let in once already.
+///
+/// ```
+/// # use palantir::{ValidateFn, Verdict};
+/// // A formatter: whatever is typed lands upper-cased.
+/// let upper = ValidateFn(|proposed: &str| {
+///     if proposed.chars().any(char::is_lowercase) {
+///         Verdict::Replace(proposed.to_uppercase())
+///     } else {
+///         Verdict::Accept
+///     }
+/// });
+/// ```
+pub trait Validator: fmt::Debug {
+    /// Judge `proposed`, the whole value as the pending edit would leave
+    /// it. Should be idempotent: a value this replaced is asked about
+    /// again, and ought to be accepted (or flagged) as it stands.
+    fn check(&self, proposed: &str) -> Verdict;
+
+    /// Changes whenever the same value would now be judged differently —
+    /// a range or a pattern that moved. The field keeps its last verdict
+    /// while the value, the validator and this stay put.
+    fn revision(&self) -> u64 {
+        0
+    }
+}
+
+/// What a [`Validator`] says about a value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    /// Let the edit land; the value is valid.
+    Accept,
+    /// Let the edit land, but flag the value — a number out of range, a
+    /// mask still being filled. The reason is what the field's tooltip
+    /// says, so it's written for the user.
+    Invalid(Cow<'static, str>),
+    /// Refuse the edit: the buffer and caret stay as they were.
+    Reject,
+    /// Land this value instead. The caret keeps its distance, in
+    /// characters, from the end of the value — so typing at the end stays
+    /// at the end however the formatting moved the text before it.
+    Replace(String),
+}
+
+/// A closure as a [`Validator`].
+pub struct ValidateFn<F>(pub F);
+
+impl<F: Fn(&str) -> Verdict> Validator for ValidateFn<F> {
+    fn check(&self, proposed: &str) -> Verdict {
+        (self.0)(proposed)
+    }
+}
+
+impl<F> fmt::Debug for ValidateFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ValidateFn(..)")
+    }
+}
+
+/// Decimal numbers: digits, a leading `-` and at most one `.`. Anything
+/// else is refused as it's typed; a value that is only a sign or a point
+/// so far is flagged. An empty field is accepted.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Numeric;
+
+impl Validator for Numeric {
+    fn check(&self, proposed: &str) -> Verdict {
+        let digits = proposed.strip_prefix('-').unwrap_or(proposed);
+        let mut points = 0;
+        for c in digits.chars() {
+            match c {
+                '0'..='9' => {}
+                '.' if points == 0 => points += 1,
+                _ => return Verdict::Reject,
+            }
+        }
+        if !proposed.is_empty() && !digits.contains(|c: char| c.is_ascii_digit()) {
+            return Verdict::Invalid(Cow::Borrowed("Enter a number"));
+        }
+        Verdict::Accept
+    }
+}
+
+/// Whole numbers within a range. Anything that couldn't begin one is
+/// refused as it's typed — a `-` only where the range goes below zero;
+/// one outside the range lands, flagged. An empty field is accepted.
+#[derive(Clone, Debug)]
+pub struct IntRange(pub RangeInclusive<i64>);
+
+impl Validator for IntRange {
+    fn check(&self, proposed: &str) -> Verdict {
+        let (min, max) = (*self.0.start(), *self.0.end());
+        let digits = match proposed.strip_prefix('-') {
+            Some(_) if min >= 0 => return Verdict::Reject,
+            Some(digits) => digits,
+            None => proposed,
+        };
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Verdict::Reject;
+        }
+        if proposed.is_empty() {
+            return Verdict::Accept;
+        }
+        // Past `i64` is past either end, on the side its sign says.
+        let in_range = proposed.parse::<i64>().is_ok_and(|n| self.0.contains(&n));
+        if digits.is_empty() || !in_range {
+            return Verdict::Invalid(Cow::Owned(format!(
+                "Enter a whole number from {min} to {max}"
+            )));
+        }
+        Verdict::Accept
+    }
+
+    fn revision(&self) -> u64 {
+        let mut h = Hasher::new();
+        h.write_i64(*self.0.start());
+        h.write_i64(*self.0.end());
+        h.finish()
+    }
+}
+
+/// Flags a value the regex doesn't match in full. Never refuses an edit:
+/// a value on its way to matching has to be typed through ones that
+/// don't. An empty field is accepted.
+#[derive(Clone, Debug)]
+pub struct RegexValidator {
+    regex: Regex,
+    reason: Cow<'static, str>,
+}
+
+impl RegexValidator {
+    /// `reason` is what the field's tooltip says while the value doesn't
+    /// match. The regex is anchored at both ends on its own.
+    pub fn new(regex: &str, reason: impl Into<Cow<'static, str>>) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: Regex::new(&format!("^(?:{regex})$"))?,
+            reason: reason.into(),
+        })
+    }
+}
+
+impl Validator for RegexValidator {
+    fn check(&self, proposed: &str) -> Verdict {
+        if proposed.is_empty() || self.regex.is_match(proposed) {
+            Verdict::Accept
+        } else {
+            Verdict::Invalid(self.reason.clone())
+        }
+    }
+
+    fn revision(&self) -> u64 {
+        let mut h = Hasher::new();
+        h.write(self.regex.as_str().as_bytes());
+        h.write(self.reason.as_bytes());
+        h.finish()
+    }
+}
+
+/// An input mask: a fixed shape of slots and literal characters, such as
+/// `(###) ###-####` for a phone number or `##:##` for a time.
+///
+/// In the pattern, `#` is a slot for a digit, `A` for a letter and `*`
+/// for any character; `\` makes the character after it a literal, and
+/// every other character is one. Typing fills the slots in order and
+/// the literals between them are put in for the user — typing `1234`
+/// into `##:##` gives `12:34` — while a literal typed where it belongs
+/// is taken as that literal. A character that fits no slot is refused,
+/// as is one past the last slot. A value that stops short of the last
+/// slot is flagged; an empty field is accepted.
+#[derive(Clone, Debug)]
+pub struct Mask {
+    tokens: Vec<MaskToken>,
+    reason: Cow<'static, str>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MaskToken {
+    Digit,
+    Letter,
+    Any,
+    Literal(char),
+}
+
+impl MaskToken {
+    fn fits(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Any => true,
+            Self::Literal(l) => c == l,
+        }
+    }
+}
+
+impl Mask {
+    /// A mask of `pattern`, flagged while incomplete with a reason naming
+    /// the shape it wants — the pattern with its slots shown as `_`.
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '#' => MaskToken::Digit,
+                'A' => MaskToken::Letter,
+                '*' => MaskToken::Any,
+                '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+                c => MaskToken::Literal(c),
+            });
+        }
+        let shape: String = tokens
+            .iter()
+            .map(|token| match token {
+                MaskToken::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect();
+        Self {
+            tokens,
+            reason: Cow::Owned(format!("Expected {shape}")),
+        }
+    }
+
+    /// Replace the reason an incomplete value is flagged with.
+    pub fn reason(mut self, reason: impl Into<Cow<'static, str>>) -> Self {
+        self.reason = reason.into();
+        self
+    }
+
+    fn is_literal(&self, c: char) -> bool {
+        self.tokens.contains(&MaskToken::Literal(c))
+    }
+}
+
+impl Validator for Mask {
+    fn check(&self, proposed: &str) -> Verdict {
+        if proposed.is_empty() {
+            return Verdict::Accept;
+        }
+        let mut input = proposed.chars().peekable();
+        let mut filled = String::with_capacity(proposed.len());
+        let mut complete = true;
+        for (at, &token) in self.tokens.iter().enumerate() {
+            if let MaskToken::Literal(literal) = token {
+                // Taken from the input where typed, and put in where there's
+                // more to come after it — more input, or no slot left to
+                // wait for.
+                match input.peek() {
+                    Some(&c) if c == literal => _ = input.next(),
+                    Some(_) => {}
+                    None if self.tokens[at..]
+                        .iter()
+                        .any(|token| !matches!(token, MaskToken::Literal(_))) =>
+                    {
+                        complete = false;
+                        break;
+                    }
+                    None => {}
+                }
+                filled.push(literal);
+                continue;
+            }
+            // A literal typed where a slot is due is one the user put in
+            // by hand, a step early or for the wrong place: step over it.
+            let found = loop {
+                match input.next() {
+                    Some(c) if token.fits(c) => break Some(c),
+                    Some(c) if self.is_literal(c) => {}
+                    Some(_) => return Verdict::Reject,
+                    None => break None,
+                }
+            };
+            let Some(c) = found else {
+                complete = false;
+                break;
+            };
+            filled.push(c);
+        }
+        if input.any(|c| !self.is_literal(c)) {
+            return Verdict::Reject;
+        }
+        if filled != proposed {
+            Verdict::Replace(filled)
+        } else if complete {
+            Verdict::Accept
+        } else {
+            Verdict::Invalid(self.reason.clone())
+        }
+    }
+
+    fn revision(&self) -> u64 {
+        let mut h = Hasher::new();
+        for token in &self.tokens {
+            match token {
+                MaskToken::Digit => h.write_u8(0),
+                MaskToken::Letter => h.write_u8(1),
+                MaskToken::Any => h.write_u8(2),
+                MaskToken::Literal(c) => h.write_u32(u32::from(*c)),
+            }
+        }
+        h.write(self.reason.as_bytes());
+        h.finish()
+    }
+}
+
+/// The validity [`TextEdit`](crate::TextEdit) reports, on its state row.
+#[derive(Clone, Default, Debug)]
+pub(super) struct Validity {
+    /// What `reason` answers: the buffer's hash, the validator's address
+    /// and its revision. Judging flattens the buffer and may run a regex,
+    /// so it waits for one of them to move rather than running every
+    /// frame.
+    asked: Option<(u64, usize, u64)>,
+    /// `None` when the validator accepts the value, its reason when it
+    /// flags it, and an empty reason when it would refuse or rewrite it —
+    /// a value the host put there itself, which no edit could have made.
+    reason: Option<Cow<'static, str>>,
+}
+
+impl Validity {
+    pub(super) fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Judge `text` if it, the validator or its revision changed since it
+    /// was last judged. `hash` is the buffer's.
+    pub(super) fn refresh(&mut self, validator: &dyn Validator, text: &dyn TextBuffer, hash: u64) {
+        let at = std::ptr::from_ref(validator).cast::<()>().addr();
+        let asked = (hash, at, validator.revision());
+        if self.asked == Some(asked) {
+            return;
+        }
+        self.asked = Some(asked);
+        self.reason = match validator.check(&text.slice(0..text.len())) {
+            Verdict::Accept => None,
+            Verdict::Invalid(reason) => Some(reason),
+            Verdict::Reject | Verdict::Replace(_) => Some(Cow::Borrowed("")),
+        };
+    }
+}
+
+/// The bytes of `old` and of `new` that differ, as the smallest single
+/// splice turning one into the other — a rewritten value recorded as the
+/// change it makes rather than as the whole value gone and back.
+pub(super) fn changed_span(old: &str, new: &str) -> (Range<usize>, Range<usize>) {
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(old.len().min(new.len()), |((at, _), _)| at);
+    let suffix = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum::<usize>();
+    (prefix..old.len() - suffix, prefix..new.len() - suffix)
+}
+
+/// Where a caret `at` bytes into `from` lands in `to`: as many characters
+/// from the end as it was.
+pub(super) fn caret_from_end(from: &str, at: usize, to: &str) -> usize {
+    let from_end = from[at..].chars().count();
+    let kept = to.chars().count().saturating_sub(from_end);
+    to.char_indices().nth(kept).map_or(to.len(), |(at, _)| at)
+}
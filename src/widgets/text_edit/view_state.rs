@@ -39,10 +39,12 @@ impl ViewState {
     /// Fold this frame's wheel delta into the offset, then keep the caret
     /// visible.
     ///
-    /// **The field pans exactly one axis**: a multi-line editor wraps to
-    /// its own width, and a single-line one has one line to slide along.
-    /// The other axis is pinned by handing the solver no content on it,
-    /// so the clamp does the pinning rather than an assignment beside it.
+    /// **A field pans only the axes its text can overflow**: a wrapped
+    /// multi-line editor fits its own width, and a single-line one has one
+    /// line to slide along; only an unwrapped multi-line one — code mode —
+    /// pans both. A pinned axis is pinned by handing the solver no content
+    /// on it, so the clamp does the pinning rather than an assignment
+    /// beside it.
     ///
     /// The caret only pulls the view when it has *moved* since the view
     /// last followed it, or the buffer changed under it, or focus just
@@ -63,28 +65,38 @@ impl ViewState {
         let follow_caret =
             input.caret_byte != self.last_followed_caret || input.edited || input.gained_focus;
         self.last_followed_caret = input.caret_byte;
+        let pan_x = !ctx.multiline || !ctx.wraps();
         let bounds = ScrollBounds {
-            // A single line reserves room for the caret past its last
+            // An unwrapped line reserves room for the caret past its last
             // glyph, on both ends; a wrapped block has a next line to
             // fall to and reserves none.
-            content: if ctx.multiline {
-                Size::new(0.0, input.content_size.h)
-            } else {
-                Size::new(input.content_size.w + 2.0 * input.caret_width, 0.0)
-            },
+            content: Size::new(
+                if pan_x {
+                    input.content_size.w + 2.0 * input.caret_width
+                } else {
+                    0.0
+                },
+                if ctx.multiline {
+                    input.content_size.h
+                } else {
+                    0.0
+                },
+            ),
             viewport,
             content_margin: Spacing::ZERO,
         };
         // One line, so both wheel axes pan a single-line field
         // horizontally — a plain vertical wheel over one is the common
         // gesture, and there is nothing vertical to spend it on.
-        let wheel = if ctx.multiline {
-            Vec2::new(0.0, input.wheel.y)
-        } else {
+        let wheel = if !ctx.multiline {
             Vec2::new(input.wheel.x + input.wheel.y, 0.0)
+        } else if pan_x {
+            input.wheel
+        } else {
+            Vec2::new(0.0, input.wheel.y)
         };
         self.scroll
-            .apply_wheel_pan(bounds, !ctx.multiline, ctx.multiline, wheel, false);
+            .apply_wheel_pan(bounds, pan_x, ctx.multiline, wheel, false);
         if follow_caret {
             let offset = &mut self.scroll.offset;
             if ctx.multiline {
@@ -95,7 +107,8 @@ impl ViewState {
                 } else if caret_bottom > offset.y + trailing {
                     offset.y = caret_bottom - trailing;
                 }
-            } else {
+            }
+            if pan_x {
                 let trailing = (viewport.w - input.caret_width).max(0.0);
                 let caret_right = input.caret_pos.x + input.caret_width;
                 if input.caret_pos.x < offset.x {
@@ -0,0 +1,166 @@
+//! What a field shows of its buffer line by line: the lines in view with
+//! their tops, which the code gutter numbers, and the runs they paint as
+//! when one run for the whole buffer won't do — a paragraph-laid rope,
+//! or highlighted text cut into one run per coloured stretch.
+
+use crate::primitives::color::Color;
+use crate::primitives::interned_str::InternedStr;
+use crate::ui::Ui;
+use crate::widgets::text_edit::buffer::TextBuffer;
+use crate::widgets::text_edit::highlight::{HighlightCache, Highlighter, Segment};
+use crate::widgets::text_edit::paragraphs::{Paragraphs, paragraph};
+use crate::widgets::text_edit::shape_ctx::ShapeCtx;
+use glam::Vec2;
+use std::ops::Range;
+
+/// A highlighter together with the field's cache of what it said.
+pub(super) type Highlight<'h> = (&'h dyn Highlighter, &'h mut HighlightCache);
+
+/// One line in view, block-local.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct VisibleLine {
+    pub(super) index: usize,
+    pub(super) top: f32,
+}
+
+/// One run as the painter records it.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct PaintRun {
+    pub(super) text: InternedStr,
+    pub(super) at: Vec2,
+    /// `None` paints in the field's text colour.
+    pub(super) color: Option<Color>,
+    /// A whole paragraph, laid out like the field's own run — wrapped and
+    /// aligned. `false` for a highlighted stretch, which sits exactly at
+    /// `at` on one line.
+    pub(super) whole: bool,
+}
+
+/// This frame's lines and runs, retained on the field's state row so a
+/// scrolling document records without allocating.
+#[derive(Clone, Default, Debug)]
+pub(super) struct VisibleText {
+    lines: Vec<VisibleLine>,
+    runs: Vec<PaintRun>,
+    /// Scratch: one line's segments, then each placed, between the probe
+    /// that positions them and the interning that needs `ui` back.
+    segments: Vec<Segment>,
+    placed: Vec<(Range<usize>, Option<Color>, Vec2)>,
+}
+
+impl VisibleText {
+    pub(super) fn lines(&self) -> &[VisibleLine] {
+        &self.lines
+    }
+
+    pub(super) fn runs(&self) -> &[PaintRun] {
+        &self.runs
+    }
+
+    /// The lines of `text` — shaped as one unwrapped run — that fall in
+    /// `view`, and their highlighted runs if there is a highlighter.
+    /// Without one, `runs` stays empty: the buffer paints as one run.
+    pub(super) fn flat(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &str,
+        view: Range<f32>,
+        mut highlight: Option<Highlight<'_>>,
+    ) {
+        self.lines.clear();
+        self.runs.clear();
+        // Scoped: the probe holds the shaper's borrow of `ui`, which the
+        // interning below needs back.
+        {
+            let probe = ui.probe_text(ctx.run(text));
+            let first = probe.byte_at(0.0, view.start.max(0.0));
+            let mut start = text[..first].rfind('\n').map_or(0, |newline| newline + 1);
+            let mut index = memchr::memchr_iter(b'\n', &text.as_bytes()[..start]).count();
+            loop {
+                let top = probe.caret_at(start).y_top;
+                if top >= view.end && !self.lines.is_empty() {
+                    break;
+                }
+                let end = memchr::memchr(b'\n', &text.as_bytes()[start..])
+                    .map_or(text.len(), |at| start + at);
+                self.lines.push(VisibleLine { index, top });
+                if let Some((highlighter, cache)) = &mut highlight {
+                    let line = &text[start..end];
+                    cache.segments(
+                        *highlighter,
+                        line.strip_suffix('\r').unwrap_or(line),
+                        start,
+                        &mut self.segments,
+                    );
+                    for (range, color) in self.segments.drain(..) {
+                        let at = probe.caret_at(range.start);
+                        self.placed.push((range, color, Vec2::new(at.x, at.y_top)));
+                    }
+                }
+                if end == text.len() {
+                    break;
+                }
+                start = end + 1;
+                index += 1;
+            }
+        }
+        for (range, color, at) in self.placed.drain(..) {
+            self.runs.push(PaintRun {
+                text: ui.intern(&text[range]),
+                at,
+                color,
+                whole: false,
+            });
+        }
+    }
+
+    /// The paragraphs `lines` of `text`, each a run of its own or, under
+    /// a highlighter, one per coloured stretch.
+    pub(super) fn paragraphs(
+        &mut self,
+        ui: &mut Ui,
+        ctx: &ShapeCtx,
+        text: &dyn TextBuffer,
+        paragraphs: &mut Paragraphs,
+        lines: Range<usize>,
+        mut highlight: Option<Highlight<'_>>,
+    ) {
+        self.lines.clear();
+        self.runs.clear();
+        for index in lines {
+            let top = paragraphs.top(index);
+            self.lines.push(VisibleLine { index, top });
+            let paragraph = paragraph(text, index);
+            if paragraph.is_empty() {
+                continue;
+            }
+            let Some((highlighter, cache)) = &mut highlight else {
+                self.runs.push(PaintRun {
+                    text: ui.intern(paragraph.as_ref()),
+                    at: Vec2::new(0.0, top),
+                    color: None,
+                    whole: true,
+                });
+                continue;
+            };
+            cache.segments(*highlighter, &paragraph, 0, &mut self.segments);
+            {
+                let probe = ui.probe_text(ctx.run(&paragraph));
+                for (range, color) in self.segments.drain(..) {
+                    let at = probe.caret_at(range.start);
+                    self.placed
+                        .push((range, color, Vec2::new(at.x, top + at.y_top)));
+                }
+            }
+            for (range, color, at) in self.placed.drain(..) {
+                self.runs.push(PaintRun {
+                    text: ui.intern(&paragraph[range]),
+                    at,
+                    color,
+                    whole: false,
+                });
+            }
+        }
+    }
+}
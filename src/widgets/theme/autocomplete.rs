@@ -0,0 +1,45 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+
+/// Geometry and the suggestion-only colours for a
+/// [`TextEdit`](crate::TextEdit)'s [`Autocomplete`](crate::Autocomplete)
+/// list. The list itself is borrowed: its panel is a context menu's
+/// ([`crate::Theme::context_menu`]) and its rows are menu rows.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AutocompleteTheme {
+    /// Most suggestions shown at once; the list keeps the selection
+    /// inside this window as arrows move it.
+    pub max_rows: u16,
+    /// Gutter between the field (or the caret's line) and the list.
+    pub gap: f32,
+    /// Colour of the suggestion characters the typed text matched.
+    pub highlight: Color,
+    /// Colour of the inline completion painted after the caret.
+    pub ghost: Color,
+}
+
+impl AutocompleteTheme {
+    pub fn from_palette(p: &Palette) -> Self {
+        Self {
+            max_rows: 8,
+            gap: 2.0,
+            highlight: p.accent,
+            ghost: p.text_disabled,
+        }
+    }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            max_rows: _,
+            gap: _,
+            highlight,
+            ghost,
+        } = self;
+        f(highlight, from.highlight);
+        f(ghost, from.ghost);
+    }
+}
+
+palette_default!(AutocompleteTheme);
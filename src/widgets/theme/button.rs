@@ -136,6 +136,18 @@ impl ButtonTheme {
     pub fn pick(&self, state: &ResponseState) -> &WidgetLook {
         self.looks.pick(state, state.pressed())
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            looks,
+            padding: _,
+            margin: _,
+            anim: _,
+        } = self;
+        looks.zip_colors(&from.looks, f);
+    }
 }
 
 palette_default!(ButtonTheme);
@@ -0,0 +1,56 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+
+/// What a [`crate::TextEdit`] in code mode paints beyond an ordinary
+/// field: the line-number gutter, the current line's wash and the
+/// matched-bracket marks. The field itself — chrome, caret, selection,
+/// text colour — still comes from [`crate::Theme::text_edit`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CodeEditTheme {
+    /// Gutter fill. Opaque in the stock recipe, because text scrolled
+    /// sideways slides underneath it.
+    pub gutter: Color,
+    /// Line numbers.
+    pub line_number: Color,
+    /// The caret line's number.
+    pub current_line_number: Color,
+    /// Space either side of the line numbers, in logical px.
+    pub gutter_padding: f32,
+    /// Wash behind the caret's line while the field is focused.
+    pub current_line: Color,
+    /// Wash behind the bracket at the caret and its partner.
+    pub bracket_match: Color,
+}
+
+impl CodeEditTheme {
+    pub fn from_palette(p: &Palette) -> Self {
+        Self {
+            gutter: p.elem,
+            line_number: p.text_disabled,
+            current_line_number: p.text_muted,
+            gutter_padding: 8.0,
+            current_line: p.text.with_alpha(0.06),
+            bracket_match: p.accent.with_alpha(0.3),
+        }
+    }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            gutter,
+            line_number,
+            current_line_number,
+            gutter_padding: _,
+            current_line,
+            bracket_match,
+        } = self;
+        f(gutter, from.gutter);
+        f(line_number, from.line_number);
+        f(current_line_number, from.current_line_number);
+        f(current_line, from.current_line);
+        f(bracket_match, from.bracket_match);
+    }
+}
+
+palette_default!(CodeEditTheme);
@@ -0,0 +1,56 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+
+/// Geometry and the two palette-only colours for
+/// [`crate::CommandPalette`]. Everything else is borrowed: the card is a
+/// modal's ([`crate::Theme::modal`]) or a context menu's panel
+/// ([`crate::Theme::context_menu`]) depending on placement, the search
+/// field is a [`crate::TextEdit`], and the result rows are menu rows.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CommandPaletteTheme {
+    /// Width of the palette in logical px. Fixed rather than hugging, so
+    /// the card doesn't resize under the pointer as the results narrow.
+    pub width: f32,
+    /// Most result rows shown at once; the list keeps the selection
+    /// inside this window as arrows move it.
+    pub max_rows: u16,
+    /// Gap between the surface's top edge and a
+    /// [`PalettePlacement::Top`](crate::PalettePlacement::Top) palette.
+    pub top_margin: f32,
+    /// Gutter between the search field and the first row.
+    pub gap: f32,
+    /// Colour of the label characters the query matched.
+    pub highlight: Color,
+    /// Colour of the "no matches" line.
+    pub empty: Color,
+}
+
+impl CommandPaletteTheme {
+    pub fn from_palette(p: &Palette) -> Self {
+        Self {
+            width: 480.0,
+            max_rows: 10,
+            top_margin: 64.0,
+            gap: 6.0,
+            highlight: p.accent,
+            empty: p.text_muted,
+        }
+    }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            width: _,
+            max_rows: _,
+            top_margin: _,
+            gap: _,
+            highlight,
+            empty,
+        } = self;
+        f(highlight, from.highlight);
+        f(empty, from.empty);
+    }
+}
+
+palette_default!(CommandPaletteTheme);
@@ -99,6 +99,21 @@ impl MenuItemTheme {
             anim: None,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            looks,
+            shortcut,
+            padding: _,
+            margin: _,
+            gap: _,
+            anim: _,
+        } = self;
+        looks.zip_colors(&from.looks, f);
+        f(shortcut, from.shortcut);
+    }
 }
 
 palette_default!(MenuItemTheme);
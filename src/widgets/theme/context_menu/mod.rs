@@ -14,6 +14,7 @@ use crate::widgets::theme::context_menu::menu_item::MenuItemTheme;
 use crate::widgets::theme::palette::Palette;
 use crate::widgets::theme::separator::SeparatorTheme;
 use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::theme::transition::zip_background;
 use crate::widgets::theme::widget_look::stateful_look::StatefulLook;
 use glam::Vec2;
 
@@ -112,6 +113,22 @@ impl ContextMenuTheme {
             separator: SeparatorTheme::menu_separator(p),
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            panel,
+            padding: _,
+            min_width: _,
+            gap: _,
+            item,
+            separator,
+        } = self;
+        zip_background(panel, &from.panel, f);
+        item.zip_colors(&from.item, f);
+        separator.zip_colors(&from.separator, f);
+    }
 }
 
 palette_default!(ContextMenuTheme);
@@ -0,0 +1,51 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+
+/// The squiggles a [`crate::TextEdit`] draws under what its
+/// [`DiagnosticProvider`](crate::DiagnosticProvider) flags — one colour
+/// per [`Severity`](crate::Severity), and the wave's shape.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DiagnosticsTheme {
+    pub error: Color,
+    pub warning: Color,
+    pub info: Color,
+    /// Stroke width of the wave, in logical px.
+    pub thickness: f32,
+    /// Height of the wave from its midline to a crest, in logical px.
+    pub amplitude: f32,
+    /// Length of one full wave, crest to crest, in logical px.
+    pub wavelength: f32,
+}
+
+impl DiagnosticsTheme {
+    pub fn from_palette(p: &Palette) -> Self {
+        Self {
+            // Not palette roles: nothing in a recipe is a red or an amber,
+            // and these read the same on any background.
+            error: Color::rgb(1.0, 0.35, 0.35),
+            warning: Color::rgb(0.95, 0.75, 0.25),
+            info: p.accent,
+            thickness: 1.0,
+            amplitude: 1.5,
+            wavelength: 6.0,
+        }
+    }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            error,
+            warning,
+            info,
+            thickness: _,
+            amplitude: _,
+            wavelength: _,
+        } = self;
+        f(error, from.error);
+        f(warning, from.warning);
+        f(info, from.info);
+    }
+}
+
+palette_default!(DiagnosticsTheme);
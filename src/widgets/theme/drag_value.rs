@@ -1,3 +1,4 @@
+use crate::primitives::color::Color;
 use crate::widgets::theme::button::ButtonTheme;
 use crate::widgets::theme::palette::Palette;
 use crate::widgets::theme::text_edit::TextEditTheme;
@@ -23,8 +24,8 @@ pub struct DragValueTheme {
 impl DragValueTheme {
     /// Derive from a `chip` look: the editor inherits the chip's box (padding /
     /// margin / per-state backgrounds) so the two modes are pixel-identical,
-    /// while caret / selection / placeholder come from `text_edit` so they match
-    /// the app's other fields. The editor's `active` (= focused) maps to the
+    /// while caret / selection / placeholder / invalid come from `text_edit`
+    /// so they match the app's other fields. The editor's `active` (= focused) maps to the
     /// chip's `hovered` look — the chip is already hovered under the pointer
     /// that clicked it.
     pub fn from_chip(chip: ButtonTheme, text_edit: &TextEditTheme) -> Self {
@@ -41,6 +42,7 @@ impl DragValueTheme {
             caret: text_edit.caret,
             caret_width: text_edit.caret_width,
             selection: text_edit.selection,
+            invalid: text_edit.invalid,
             placeholder: text_edit.placeholder,
         };
         Self { chip, editor }
@@ -62,6 +64,14 @@ impl DragValueTheme {
             &TextEditTheme::from_palette(p),
         )
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self { chip, editor } = self;
+        chip.zip_colors(&from.chip, f);
+        editor.zip_colors(&from.editor, f);
+    }
 }
 
 palette_default!(DragValueTheme);
@@ -0,0 +1,51 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+
+/// A [`crate::TextEdit`]'s find bar and the matches it marks in the
+/// buffer. The bar's own fields and buttons still come from
+/// [`crate::Theme::text_edit`] and [`crate::Theme::button`], and its
+/// panel from [`crate::Theme::panel_background`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct FindBarTheme {
+    /// Wash behind every match in view.
+    pub matches: Color,
+    /// Wash behind the match next/previous last landed on, painted over
+    /// [`Self::matches`].
+    pub current_match: Color,
+    /// Fill behind a search option — case, whole word, regex — while
+    /// it's on.
+    pub option_on: Color,
+    /// Width of the query and replacement fields, in logical px.
+    pub field_width: f32,
+    /// Space between the bar's controls, in logical px.
+    pub gap: f32,
+}
+
+impl FindBarTheme {
+    pub fn from_palette(p: &Palette) -> Self {
+        Self {
+            matches: p.accent.with_alpha(0.25),
+            current_match: p.accent.with_alpha(0.5),
+            option_on: p.accent.with_alpha(0.35),
+            field_width: 180.0,
+            gap: 4.0,
+        }
+    }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            matches,
+            current_match,
+            option_on,
+            field_width: _,
+            gap: _,
+        } = self;
+        f(matches, from.matches);
+        f(current_match, from.current_match);
+        f(option_on, from.option_on);
+    }
+}
+
+palette_default!(FindBarTheme);
@@ -15,13 +15,20 @@ macro_rules! palette_default {
     };
 }
 
+pub(crate) mod autocomplete;
 pub(crate) mod button;
+pub(crate) mod code_edit;
 pub(crate) mod combo_box;
+pub(crate) mod command_palette;
 pub(crate) mod context_menu;
+pub(crate) mod diagnostics;
 pub(crate) mod drag_value;
+pub(crate) mod find_bar;
 pub(crate) mod modal;
 pub(crate) mod palette;
 pub(crate) mod progress_bar;
+#[cfg(feature = "theme-reload")]
+pub(crate) mod reload;
 pub(crate) mod scrollbar;
 pub(crate) mod separator;
 mod serde;
@@ -32,6 +39,7 @@ pub(crate) mod text_edit;
 pub(crate) mod text_style;
 pub(crate) mod toggle;
 pub(crate) mod tooltip;
+pub(crate) mod transition;
 pub(crate) mod widget_look;
 
 #[cfg(test)]
@@ -41,10 +49,15 @@ use crate::layout::types::clip_mode::ClipMode;
 use crate::primitives::background::Background;
 use crate::primitives::color::Color;
 use crate::text::glyph_font::GlyphFont;
+use crate::widgets::theme::autocomplete::AutocompleteTheme;
 use crate::widgets::theme::button::ButtonTheme;
+use crate::widgets::theme::code_edit::CodeEditTheme;
 use crate::widgets::theme::combo_box::ComboBoxTheme;
+use crate::widgets::theme::command_palette::CommandPaletteTheme;
 use crate::widgets::theme::context_menu::ContextMenuTheme;
+use crate::widgets::theme::diagnostics::DiagnosticsTheme;
 use crate::widgets::theme::drag_value::DragValueTheme;
+use crate::widgets::theme::find_bar::FindBarTheme;
 use crate::widgets::theme::modal::ModalTheme;
 use crate::widgets::theme::palette::Palette;
 use crate::widgets::theme::progress_bar::ProgressBarTheme;
@@ -104,6 +117,17 @@ pub struct Theme {
     pub switch: ToggleTheme,
     pub scrollbar: ScrollbarTheme,
     pub text_edit: TextEditTheme,
+    /// What a [`crate::TextEdit`] in code mode adds to
+    /// [`Self::text_edit`]: gutter, current line and bracket marks.
+    pub code_edit: CodeEditTheme,
+    /// A multi-line [`crate::TextEdit`]'s find bar and the matches it
+    /// marks.
+    pub find_bar: FindBarTheme,
+    /// Geometry and colours for a [`crate::TextEdit`]'s suggestion list;
+    /// its panel and rows come from [`Self::context_menu`].
+    pub autocomplete: AutocompleteTheme,
+    /// The squiggles under what a [`crate::TextEdit`]'s diagnostics flag.
+    pub diagnostics: DiagnosticsTheme,
     /// Theme for [`crate::DragValue`] — the scrub chip plus its inline
     /// editor. Both modes resolve from this bundle (`chip` at rest,
     /// `editor` while editing), so restyling it moves them together.
@@ -116,6 +140,10 @@ pub struct Theme {
     /// [`Self::button`] and [`Self::context_menu`].
     pub combo_box: ComboBoxTheme,
     pub modal: ModalTheme,
+    /// Geometry for [`crate::CommandPalette`]; its card, field and rows
+    /// come from [`Self::modal`], [`Self::text_edit`] and
+    /// [`Self::context_menu`].
+    pub command_palette: CommandPaletteTheme,
     pub tooltip: TooltipTheme,
     pub progress_bar: ProgressBarTheme,
     pub separator: SeparatorTheme,
@@ -218,6 +246,11 @@ impl Theme {
             scrollbar: _,
             combo_box: _,
             modal: _,
+            command_palette: _,
+            code_edit: _,
+            find_bar: _,
+            autocomplete: _,
+            diagnostics: _,
             progress_bar: _,
             separator: _,
             slider: _,
@@ -239,6 +272,75 @@ impl Theme {
         tooltip.for_each_text(f);
     }
 
+    /// Pair every colour in the theme with its counterpart in `from`:
+    /// `f(mine, theirs)`. [`Self::blend`] drives the walk to ease a theme
+    /// switch; each sub-theme owns its own pairing (see each
+    /// `zip_colors`).
+    ///
+    /// Destructured on the same terms as [`Self::for_each_text`]: a new
+    /// field anywhere in the tree fails to compile until it is classified
+    /// as colour-bearing or not — an unwalked colour would snap while
+    /// everything around it eased. An `Option` pairs only when both sides
+    /// are `Some`; otherwise there is nothing to blend from and the field
+    /// keeps `self`'s value.
+    fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            button,
+            checkbox,
+            radio,
+            switch,
+            scrollbar,
+            text_edit,
+            code_edit,
+            find_bar,
+            autocomplete,
+            diagnostics,
+            drag_value,
+            context_menu,
+            modal,
+            command_palette,
+            tooltip,
+            progress_bar,
+            separator,
+            slider,
+            spinner,
+            splitter,
+            text,
+            window_clear,
+            panel_background,
+            // Geometry only.
+            combo_box: _,
+            panel_clip: _,
+        } = self;
+        button.zip_colors(&from.button, f);
+        checkbox.zip_colors(&from.checkbox, f);
+        radio.zip_colors(&from.radio, f);
+        switch.zip_colors(&from.switch, f);
+        scrollbar.zip_colors(&from.scrollbar, f);
+        text_edit.zip_colors(&from.text_edit, f);
+        code_edit.zip_colors(&from.code_edit, f);
+        find_bar.zip_colors(&from.find_bar, f);
+        autocomplete.zip_colors(&from.autocomplete, f);
+        diagnostics.zip_colors(&from.diagnostics, f);
+        drag_value.zip_colors(&from.drag_value, f);
+        context_menu.zip_colors(&from.context_menu, f);
+        modal.zip_colors(&from.modal, f);
+        command_palette.zip_colors(&from.command_palette, f);
+        tooltip.zip_colors(&from.tooltip, f);
+        progress_bar.zip_colors(&from.progress_bar, f);
+        separator.zip_colors(&from.separator, f);
+        slider.zip_colors(&from.slider, f);
+        spinner.zip_colors(&from.spinner, f);
+        splitter.zip_colors(&from.splitter, f);
+        text.zip_colors(&from.text, f);
+        f(window_clear, from.window_clear);
+        if let (Some(background), Some(from_background)) =
+            (panel_background, &from.panel_background)
+        {
+            transition::zip_background(background, from_background, f);
+        }
+    }
+
     /// Assemble a full theme from a [`Palette`] — every widget recipe
     /// recolored from one roster. This is the single source of the
     /// recipes: `Theme::default()` is `from_palette(&Palette::DEFAULT)`,
@@ -252,10 +354,15 @@ impl Theme {
             switch: ToggleTheme::switch(p),
             scrollbar: ScrollbarTheme::from_palette(p),
             text_edit: TextEditTheme::from_palette(p),
+            code_edit: CodeEditTheme::from_palette(p),
+            find_bar: FindBarTheme::from_palette(p),
+            autocomplete: AutocompleteTheme::from_palette(p),
+            diagnostics: DiagnosticsTheme::from_palette(p),
             drag_value: DragValueTheme::from_palette(p),
             context_menu: ContextMenuTheme::from_palette(p),
             combo_box: ComboBoxTheme::from_palette(p),
             modal: ModalTheme::from_palette(p),
+            command_palette: CommandPaletteTheme::from_palette(p),
             tooltip: TooltipTheme::from_palette(p),
             progress_bar: ProgressBarTheme::from_palette(p),
             separator: SeparatorTheme::from_palette(p),
@@ -4,6 +4,7 @@ use crate::primitives::corners::Corners;
 use crate::primitives::spacing::Spacing;
 use crate::primitives::stroke::Stroke;
 use crate::widgets::theme::palette::Palette;
+use crate::widgets::theme::transition::zip_background;
 
 /// Visuals for [`crate::widgets::modal::Modal`]: the centered dialog
 /// card plus the dimming backdrop behind it. Builder overrides
@@ -37,6 +38,19 @@ impl ModalTheme {
             min_width: 280.0,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            card,
+            backdrop,
+            padding: _,
+            min_width: _,
+        } = self;
+        zip_background(card, &from.card, f);
+        f(backdrop, from.backdrop);
+    }
 }
 
 palette_default!(ModalTheme);
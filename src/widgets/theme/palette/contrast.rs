@@ -0,0 +1,195 @@
+//! WCAG contrast checks over a [`Palette`]'s role pairs: the pairs the
+//! stock recipes actually draw one on the other, each with the minimum
+//! its use calls for. [`Palette::from_seed`] derives to these; any other
+//! palette can be audited against them with [`Palette::contrast_report`].
+
+use crate::primitives::color::Color;
+use crate::widgets::theme::palette::Palette;
+use std::fmt;
+
+/// One colour role of a [`Palette`], named like its field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PaletteRole {
+    Text,
+    TextMuted,
+    TextDisabled,
+    TerminalBg,
+    Elem,
+    ElemHover,
+    ElemActive,
+    BorderFocused,
+    Accent,
+}
+
+impl PaletteRole {
+    /// The field this role reads, e.g. `"text_muted"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::TextMuted => "text_muted",
+            Self::TextDisabled => "text_disabled",
+            Self::TerminalBg => "terminal_bg",
+            Self::Elem => "elem",
+            Self::ElemHover => "elem_hover",
+            Self::ElemActive => "elem_active",
+            Self::BorderFocused => "border_focused",
+            Self::Accent => "accent",
+        }
+    }
+
+    /// This role's colour in `palette`.
+    pub fn of(self, palette: &Palette) -> Color {
+        match self {
+            Self::Text => palette.text,
+            Self::TextMuted => palette.text_muted,
+            Self::TextDisabled => palette.text_disabled,
+            Self::TerminalBg => palette.terminal_bg,
+            Self::Elem => palette.elem,
+            Self::ElemHover => palette.elem_hover,
+            Self::ElemActive => palette.elem_active,
+            Self::BorderFocused => palette.border_focused,
+            Self::Accent => palette.accent,
+        }
+    }
+
+    pub(super) fn of_mut(self, palette: &mut Palette) -> &mut Color {
+        match self {
+            Self::Text => &mut palette.text,
+            Self::TextMuted => &mut palette.text_muted,
+            Self::TextDisabled => &mut palette.text_disabled,
+            Self::TerminalBg => &mut palette.terminal_bg,
+            Self::Elem => &mut palette.elem,
+            Self::ElemHover => &mut palette.elem_hover,
+            Self::ElemActive => &mut palette.elem_active,
+            Self::BorderFocused => &mut palette.border_focused,
+            Self::Accent => &mut palette.accent,
+        }
+    }
+}
+
+impl fmt::Display for PaletteRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// WCAG AA for body text.
+const TEXT: f32 = 4.5;
+/// WCAG AA for UI components and graphics (1.4.11).
+const NON_TEXT: f32 = 3.0;
+
+/// `(foreground, background, minimum)` for every pair checked. Labels and
+/// hints are held to the text minimum on each surface they sit on; the
+/// accent (progress fill on its track, a checkbox's tick drawn in
+/// `terminal_bg` on it) and the focus ring (around a field's
+/// `elem_hover`) to the non-text one. `text_disabled` is absent on
+/// purpose: WCAG exempts inactive controls, and a disabled label that
+/// read as clearly as a live one would stop saying "disabled".
+pub(super) const PAIRS: [(PaletteRole, PaletteRole, f32); 11] = {
+    use PaletteRole::*;
+    [
+        (Text, TerminalBg, TEXT),
+        (Text, Elem, TEXT),
+        (Text, ElemHover, TEXT),
+        (Text, ElemActive, TEXT),
+        (TextMuted, TerminalBg, TEXT),
+        (TextMuted, Elem, TEXT),
+        (TextMuted, ElemHover, TEXT),
+        (Accent, TerminalBg, NON_TEXT),
+        (Accent, Elem, NON_TEXT),
+        (Accent, ElemHover, NON_TEXT),
+        (BorderFocused, ElemHover, NON_TEXT),
+    ]
+};
+
+/// One role pair's measured contrast against what it needs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContrastCheck {
+    pub foreground: PaletteRole,
+    pub background: PaletteRole,
+    /// [`Color::contrast_ratio`] of the pair, `1.0..=21.0`.
+    pub ratio: f32,
+    pub minimum: f32,
+}
+
+impl ContrastCheck {
+    pub fn passes(&self) -> bool {
+        self.ratio >= self.minimum
+    }
+}
+
+impl fmt::Display for ContrastCheck {
+    /// `text_muted on elem_hover: 3.21:1 (needs 4.5:1)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on {}: {:.2}:1 (needs {}:1)",
+            self.foreground, self.background, self.ratio, self.minimum,
+        )
+    }
+}
+
+/// Every checked pair of one palette — see [`Palette::contrast_report`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastReport {
+    checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Every pair checked, passing or not, in a fixed order.
+    pub fn checks(&self) -> &[ContrastCheck] {
+        &self.checks
+    }
+
+    /// The pairs below their minimum.
+    pub fn failures(&self) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(|c| !c.passes())
+    }
+
+    pub fn passes(&self) -> bool {
+        self.checks.iter().all(ContrastCheck::passes)
+    }
+}
+
+impl fmt::Display for ContrastReport {
+    /// One line per failing pair, or a one-line all-clear.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passes() {
+            return write!(f, "all {} role pairs pass", self.checks.len());
+        }
+        for (i, check) in self.failures().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{check}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Palette {
+    /// Measure every role pair the stock recipes draw on each other
+    /// against its WCAG AA minimum — 4.5:1 for text on the surfaces it
+    /// sits on, 3:1 for the accent and the focus ring. Run it on a
+    /// hand-picked palette to find the pairs that won't read;
+    /// [`Self::from_seed`] palettes pass by construction.
+    ///
+    /// ```
+    /// # use palantir::Palette;
+    /// for check in Palette::DEFAULT.contrast_report().failures() {
+    ///     eprintln!("{check}");
+    /// }
+    /// ```
+    pub fn contrast_report(&self) -> ContrastReport {
+        let checks = PAIRS
+            .iter()
+            .map(|&(foreground, background, minimum)| ContrastCheck {
+                foreground,
+                background,
+                ratio: foreground.of(self).contrast_ratio(background.of(self)),
+                minimum,
+            })
+            .collect();
+        ContrastReport { checks }
+    }
+}
@@ -2,10 +2,15 @@
 //! public input to [`crate::Theme::from_palette`] — apps hand in their
 //! own swatches and every widget recolors from one source instead of
 //! re-deriving palantir's recipes per widget. [`Palette::DEFAULT`] is
-//! the built-in neutral dark grayscale with a single blue accent.
+//! the built-in neutral dark grayscale with a single blue accent;
+//! [`Palette::from_seed`] derives a light or dark roster from one accent,
+//! and [`Palette::contrast_report`] audits any palette's role pairs.
 
 use crate::primitives::color::Color;
 
+pub(crate) mod contrast;
+pub(crate) mod seed;
+
 /// Semantic color roster for theme assembly. Fields are the roles the
 /// widget recipes key on; derived tints (the border ladder) live as
 /// methods so a palette swap moves them automatically.
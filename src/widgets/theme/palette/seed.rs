@@ -0,0 +1,194 @@
+//! [`Palette::from_seed`]: a whole roster from one accent colour. Every
+//! role is placed in OKLCH — Oklab's lightness / chroma / hue — so the
+//! surface and text ladders are evenly spaced to the eye whatever the
+//! seed's hue, then the foreground roles are walked away from the
+//! surfaces until each pair in the contrast table clears its minimum.
+
+use crate::primitives::color::{Color, linear_to_oklab, oklab_to_linear};
+use crate::widgets::theme::palette::Palette;
+use crate::widgets::theme::palette::contrast::{PAIRS, PaletteRole};
+
+/// Which end of the lightness range the surfaces sit at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Light surfaces, dark text.
+    Light,
+    /// Dark surfaces, light text — what [`Palette::DEFAULT`] is.
+    Dark,
+}
+
+/// A colour in OKLCH. `h` in radians; meaningless when `c` is ~0.
+#[derive(Clone, Copy, Debug)]
+struct Lch {
+    l: f32,
+    c: f32,
+    h: f32,
+}
+
+impl Lch {
+    fn of(color: Color) -> Self {
+        let [l, a, b] = linear_to_oklab(color.r, color.g, color.b);
+        Self {
+            l,
+            c: a.hypot(b),
+            h: b.atan2(a),
+        }
+    }
+
+    fn with(self, l: f32, c: f32) -> Self {
+        Self { l, c, ..self }
+    }
+
+    /// Back to linear RGB, shedding chroma until the colour fits the sRGB
+    /// gamut. Lightness and hue hold, so a role keeps its place on the
+    /// ladder; only its saturation gives. A dozen bisection steps land
+    /// well under one 8-bit code value.
+    fn to_color(self) -> Color {
+        let rgb = |c: f32| oklab_to_linear([self.l, c * self.h.cos(), c * self.h.sin()]);
+        let fits = |rgb: [f32; 3]| rgb.iter().all(|v| (-1e-4..=1.0 + 1e-4).contains(v));
+        let mut best = rgb(self.c);
+        if !fits(best) {
+            let (mut lo, mut hi) = (0.0, self.c);
+            best = rgb(0.0);
+            for _ in 0..12 {
+                let mid = (lo + hi) * 0.5;
+                let candidate = rgb(mid);
+                if fits(candidate) {
+                    (lo, best) = (mid, candidate);
+                } else {
+                    hi = mid;
+                }
+            }
+        }
+        let [r, g, b] = best.map(|v| v.clamp(0.0, 1.0));
+        Color::linear_rgb(r, g, b)
+    }
+}
+
+/// Starting lightness per role, before the contrast walk. Surfaces are
+/// spaced evenly in L so each tier reads as one step; text tiers sit far
+/// enough off the surfaces that the walk rarely has to move them.
+struct Ladder {
+    terminal_bg: f32,
+    elem: f32,
+    elem_hover: f32,
+    elem_active: f32,
+    text: f32,
+    text_muted: f32,
+    text_disabled: f32,
+    accent: f32,
+    border_focused: f32,
+    /// Ceiling on the accent's chroma: a dark theme's light accent gets
+    /// less room before it reads as neon.
+    accent_chroma: f32,
+}
+
+const DARK: Ladder = Ladder {
+    terminal_bg: 0.20,
+    elem: 0.29,
+    elem_hover: 0.33,
+    elem_active: 0.38,
+    text: 0.97,
+    text_muted: 0.74,
+    text_disabled: 0.58,
+    accent: 0.78,
+    border_focused: 0.62,
+    accent_chroma: 0.16,
+};
+
+const LIGHT: Ladder = Ladder {
+    terminal_bg: 0.985,
+    elem: 0.955,
+    elem_hover: 0.93,
+    elem_active: 0.89,
+    text: 0.22,
+    text_muted: 0.47,
+    text_disabled: 0.66,
+    accent: 0.52,
+    border_focused: 0.50,
+    accent_chroma: 0.20,
+};
+
+/// Lightness step of the contrast walk — about a quarter of one 8-bit
+/// grey step mid-range, so a role moves no further than it must.
+const WALK_STEP: f32 = 0.005;
+
+impl Palette {
+    /// Derive a full palette from one accent colour. Surfaces and text
+    /// are near-neutral, tinted toward the seed's hue; the accent and
+    /// focus ring carry the hue at full strength, at a lightness chosen
+    /// for `mode`. A grey seed gives a grey palette.
+    ///
+    /// Text, muted text, the accent and the focus ring are then pushed
+    /// away from the surfaces until every pair [`Self::contrast_report`]
+    /// checks passes, so the result reads at WCAG AA whatever the seed.
+    /// The seed's alpha is ignored.
+    ///
+    /// ```
+    /// # use palantir::{Color, Mode, Palette, Theme};
+    /// let palette = Palette::from_seed(Color::hex(0x7c3aed), Mode::Light);
+    /// assert!(palette.contrast_report().passes());
+    /// let theme = Theme::from_palette(&palette);
+    /// ```
+    pub fn from_seed(accent: Color, mode: Mode) -> Self {
+        let seed = Lch::of(accent);
+        let ladder = match mode {
+            Mode::Dark => &DARK,
+            Mode::Light => &LIGHT,
+        };
+        let surface_c = seed.c.min(0.1) * 0.12;
+        let text_c = seed.c.min(0.1) * 0.05;
+        let surface = |l: f32| seed.with(l, surface_c).to_color();
+        let ink = |l: f32| seed.with(l, text_c).to_color();
+
+        let mut palette = Self {
+            text: ink(ladder.text),
+            text_muted: ink(ladder.text_muted),
+            text_disabled: ink(ladder.text_disabled),
+            terminal_bg: surface(ladder.terminal_bg),
+            elem: surface(ladder.elem),
+            elem_hover: surface(ladder.elem_hover),
+            elem_active: surface(ladder.elem_active),
+            border_focused: seed
+                .with(ladder.border_focused, seed.c.min(ladder.accent_chroma))
+                .to_color(),
+            accent: seed
+                .with(ladder.accent, seed.c.min(ladder.accent_chroma))
+                .to_color(),
+        };
+
+        // Light text on dark surfaces walks up, dark on light walks down.
+        let step = match mode {
+            Mode::Dark => WALK_STEP,
+            Mode::Light => -WALK_STEP,
+        };
+        for role in [
+            PaletteRole::Text,
+            PaletteRole::TextMuted,
+            PaletteRole::Accent,
+            PaletteRole::BorderFocused,
+        ] {
+            let mut lch = Lch::of(role.of(&palette));
+            // `Lch::of` of a gamut-mapped colour keeps its (possibly
+            // reduced) chroma; restore the intended one each step so a
+            // role that walks back into gamut regains saturation.
+            let chroma = match role {
+                PaletteRole::Text | PaletteRole::TextMuted => text_c,
+                _ => seed.c.min(ladder.accent_chroma),
+            };
+            while !passes(&palette, role) && (0.0..=1.0).contains(&(lch.l + step)) {
+                lch = seed.with(lch.l + step, chroma);
+                *role.of_mut(&mut palette) = lch.to_color();
+            }
+        }
+        palette
+    }
+}
+
+/// Every table pair with `role` in front clears its minimum.
+fn passes(palette: &Palette, role: PaletteRole) -> bool {
+    PAIRS
+        .iter()
+        .filter(|(fg, _, _)| *fg == role)
+        .all(|&(fg, bg, minimum)| fg.of(palette).contrast_ratio(bg.of(palette)) >= minimum)
+}
@@ -22,6 +22,18 @@ impl ProgressBarTheme {
             height: 6.0,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            track,
+            fill,
+            height: _,
+        } = self;
+        f(track, from.track);
+        f(fill, from.fill);
+    }
 }
 
 palette_default!(ProgressBarTheme);
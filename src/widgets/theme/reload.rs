@@ -0,0 +1,243 @@
+//! Live theme reloading: poll a TOML theme file and swap it in while the
+//! app runs. Behind the `theme-reload` feature.
+
+use crate::layout::types::justify::Justify;
+use crate::layout::types::sizing::Sizing;
+use crate::primitives::background::Background;
+use crate::primitives::color::Color;
+use crate::primitives::spacing::Spacing;
+use crate::scene::layer::Layer;
+use crate::scene::node::Configure;
+use crate::text::{FontFamily, FontWeight};
+use crate::ui::Ui;
+use crate::widgets::panel::Panel;
+use crate::widgets::text::Text;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::text_style::TextStyle;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Why a theme file didn't load: unreadable, not TOML, or a value the
+/// theme's validation rejected. Carries the dotted path of the offending
+/// field when the failure is in one (`button.padding`, `text.font_size_px`)
+/// and the line it sits on when the parser knows it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeLoadError {
+    field: Option<String>,
+    line: Option<usize>,
+    message: String,
+}
+
+impl ThemeLoadError {
+    /// Dotted path of the field that failed, e.g. `"slider.thumb_radius"`.
+    /// `None` for errors that aren't about one field — an unreadable
+    /// file, a syntax error.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// 1-based line in the file, when known.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// What went wrong, without the field or line.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(field) = &self.field {
+            write!(f, "{field}: ")?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ThemeLoadError {}
+
+impl Theme {
+    /// Parse a theme from TOML — the format `examples/dump_theme.rs`
+    /// writes. Runs the same validation as any other deserialize, and
+    /// reports a rejected value by its field path rather than by position
+    /// alone.
+    pub fn from_toml(text: &str) -> Result<Self, ThemeLoadError> {
+        // Newlines before the error, plus one: `lines()` would drop the
+        // empty line an error at column 0 opens.
+        let line_of = |error: &toml::de::Error| {
+            error.span().map(|span| {
+                let before = &text.as_bytes()[..span.start.min(text.len())];
+                before.iter().filter(|&&b| b == b'\n').count() + 1
+            })
+        };
+        let deserializer = toml::Deserializer::parse(text).map_err(|error| ThemeLoadError {
+            field: None,
+            line: line_of(&error),
+            message: error.message().to_owned(),
+        })?;
+        serde_path_to_error::deserialize(deserializer).map_err(|error| {
+            let path = error.path().to_string();
+            let inner = error.inner();
+            ThemeLoadError {
+                // The root path prints as `.`; a whole-document error is not
+                // about a field.
+                field: (path != ".").then_some(path),
+                line: line_of(inner),
+                message: inner.message().to_owned(),
+            }
+        })
+    }
+}
+
+/// Watches a theme file and applies it through [`Ui::set_theme`] whenever
+/// it changes on disk — for tuning colours and spacing in the running app
+/// instead of a rebuild per tweak.
+///
+/// Polls the file's modification time every [`Self::interval`] (not an OS
+/// watch: no thread, no platform backend, and a theme file is one stat).
+/// A file that fails to load leaves the current theme in place and shows
+/// the error, field path included, in a strip along the bottom of the
+/// window until a later save loads cleanly.
+///
+/// Call [`Self::update`] every frame, first thing in the record closure —
+/// a theme applied partway through the record restyles only what comes
+/// after it:
+///
+/// ```no_run
+/// # use palantir::{ThemeWatcher, Ui};
+/// # fn demo(ui: &mut Ui, watcher: &mut ThemeWatcher) {
+/// watcher.update(ui);
+/// // ...the rest of the frame...
+/// # }
+/// ```
+///
+/// The watcher keeps the host waking once per interval to poll, so it is
+/// a development tool: an idle app holding one never sleeps for long.
+#[derive(Debug)]
+pub struct ThemeWatcher {
+    path: PathBuf,
+    interval: Duration,
+    /// [`Ui::now`] at which the next poll is due.
+    next_poll: Duration,
+    /// Modification time of the version last read, good or bad — a broken
+    /// save is parsed once, not every poll until the next one. `None` on a
+    /// fresh watcher, so the first poll always reads.
+    seen: Option<Option<SystemTime>>,
+    error: Option<ThemeLoadError>,
+}
+
+impl ThemeWatcher {
+    /// How often [`Self::new`] polls.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Watch `path`. Nothing is read until the first [`Self::update`],
+    /// which loads the file whatever its age — the app's starting theme
+    /// is replaced by the file's as soon as the watcher runs.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: Self::DEFAULT_INTERVAL,
+            next_poll: Duration::ZERO,
+            seen: None,
+            error: None,
+        }
+    }
+
+    /// Poll every `interval` instead of [`Self::DEFAULT_INTERVAL`].
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Why the last read failed, while the file stays broken.
+    pub fn error(&self) -> Option<&ThemeLoadError> {
+        self.error.as_ref()
+    }
+
+    /// Poll if due, apply the file if it changed and loads, and draw the
+    /// error strip if it doesn't. Returns `true` on the frame a new theme
+    /// was applied.
+    pub fn update(&mut self, ui: &mut Ui) -> bool {
+        let now = ui.now();
+        let mut applied = false;
+        if now >= self.next_poll {
+            self.next_poll = now.saturating_add(self.interval);
+            applied = self.poll(ui);
+        }
+        ui.request_repaint_after(self.next_poll.saturating_sub(now));
+        if let Some(error) = &self.error {
+            record_error(ui, &self.path, error);
+        }
+        applied
+    }
+
+    fn poll(&mut self, ui: &mut Ui) -> bool {
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|meta| meta.modified())
+            .ok();
+        if self.seen == Some(modified) {
+            return false;
+        }
+        self.seen = Some(modified);
+        let loaded = std::fs::read_to_string(&self.path)
+            .map_err(|error| ThemeLoadError {
+                field: None,
+                line: None,
+                message: format!("cannot read {}: {error}", self.path.display()),
+            })
+            .and_then(|text| Theme::from_toml(&text));
+        match loaded {
+            Ok(theme) => {
+                self.error = None;
+                ui.set_theme(theme);
+                true
+            }
+            Err(error) => {
+                tracing::warn!(path = %self.path.display(), %error, "theme reload failed");
+                self.error = Some(error);
+                false
+            }
+        }
+    }
+}
+
+/// The error strip, recorded into `Layer::Debug` along the bottom edge —
+/// the same layer and the same hardcoded look as the frame-stats readout,
+/// so it stays legible whatever the broken theme would have done.
+fn record_error(ui: &mut Ui, path: &Path, error: &ThemeLoadError) {
+    let label = ui.fmt(format_args!("{}: {error}", path.display()));
+    let style = TextStyle {
+        family: FontFamily::Mono,
+        weight: FontWeight::Regular,
+        color: Color::rgb(1.0, 0.35, 0.35),
+        font_size_px: 12.0,
+        ..ui.theme().text.clone()
+    };
+    let chrome = Background::fill(Color::linear_rgba(0.0, 0.0, 0.0, 0.85));
+    ui.layer(Layer::Debug).show(|ui| {
+        Panel::vstack()
+            .id_salt("theme-reload-error")
+            .size((Sizing::FILL, Sizing::FILL))
+            .justify(Justify::End)
+            .show(ui, |ui| {
+                Panel::hstack()
+                    .id_salt("strip")
+                    .background(chrome)
+                    .size((Sizing::FILL, Sizing::HUG))
+                    .padding(Spacing::xy(6.0, 3.0))
+                    .show(ui, |ui| {
+                        Text::new(label).id_salt("message").style(&style).show(ui);
+                    });
+            });
+    });
+}
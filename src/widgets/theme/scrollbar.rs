@@ -51,6 +51,25 @@ impl ScrollbarTheme {
             radius: 4.0,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            width: _,
+            gap: _,
+            min_thumb_px: _,
+            track,
+            thumb,
+            thumb_hover,
+            thumb_active,
+            radius: _,
+        } = self;
+        f(track, from.track);
+        f(thumb, from.thumb);
+        f(thumb_hover, from.thumb_hover);
+        f(thumb_active, from.thumb_active);
+    }
 }
 
 palette_default!(ScrollbarTheme);
@@ -45,6 +45,17 @@ impl SeparatorTheme {
             ..Self::from_palette(p)
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            color,
+            thickness: _,
+            margin: _,
+        } = self;
+        f(color, from.color);
+    }
 }
 
 palette_default!(SeparatorTheme);
@@ -29,6 +29,21 @@ impl SliderTheme {
             rail_thickness: 4.0,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            rail,
+            fill,
+            knob,
+            knob_size: _,
+            rail_thickness: _,
+        } = self;
+        f(rail, from.rail);
+        f(fill, from.fill);
+        f(knob, from.knob);
+    }
 }
 
 palette_default!(SliderTheme);
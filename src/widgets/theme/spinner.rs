@@ -34,6 +34,20 @@ impl SpinnerTheme {
             min_thickness: 1.5,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            color,
+            diameter: _,
+            sweep: _,
+            speed: _,
+            thickness_ratio: _,
+            min_thickness: _,
+        } = self;
+        f(color, from.color);
+    }
 }
 
 palette_default!(SpinnerTheme);
@@ -30,6 +30,21 @@ impl SplitterTheme {
             drag: p.accent.with_alpha(0.6),
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            thickness: _,
+            rule,
+            rule_thickness: _,
+            hover,
+            drag,
+        } = self;
+        f(rule, from.rule);
+        f(hover, from.hover);
+        f(drag, from.drag);
+    }
 }
 
 palette_default!(SplitterTheme);
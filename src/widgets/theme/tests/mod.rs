@@ -1,8 +1,13 @@
+mod palette;
+#[cfg(feature = "theme-reload")]
+mod reload;
+mod scope;
 mod serialization;
 mod state_looks;
 mod style_override;
 mod text_scale;
 mod tooltip;
+mod transition;
 
 use crate::widgets::theme::Theme;
 use crate::widgets::theme::button::ButtonTheme;
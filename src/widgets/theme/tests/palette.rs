@@ -0,0 +1,89 @@
+use crate::primitives::color::Color;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::palette::Palette;
+use crate::widgets::theme::palette::contrast::PaletteRole;
+use crate::widgets::theme::palette::seed::Mode;
+
+const SEEDS: [u32; 10] = [
+    0x9adbfb, // the default accent
+    0x7c3aed, // violet
+    0xe11d48, // rose
+    0x16a34a, // green
+    0xfacc15, // yellow — light, so the light-mode accent must walk down
+    0x0000ff, // saturated primary, far out of reach at most lightnesses
+    0x00ffff, 0x808080, // grey: no hue at all
+    0x000000, 0xffffff,
+];
+
+#[test]
+fn from_seed_passes_its_own_contrast_report() {
+    for seed in SEEDS {
+        for mode in [Mode::Light, Mode::Dark] {
+            let palette = Palette::from_seed(Color::hex(seed), mode);
+            let report = palette.contrast_report();
+            assert!(report.passes(), "{seed:06x} {mode:?}:\n{report}");
+            for role in [
+                PaletteRole::Text,
+                PaletteRole::TerminalBg,
+                PaletteRole::Accent,
+            ] {
+                let c = role.of(&palette);
+                assert!(
+                    [c.r, c.g, c.b].iter().all(|v| (0.0..=1.0).contains(v)) && c.a == 1.0,
+                    "{seed:06x} {mode:?} {role}: {c:?} out of gamut",
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn from_seed_orders_surfaces_and_text_by_mode() {
+    let lum = |c: Color| c.relative_luminance();
+    let dark = Palette::from_seed(Color::hex(0x7c3aed), Mode::Dark);
+    assert!(lum(dark.terminal_bg) < lum(dark.elem));
+    assert!(lum(dark.elem) < lum(dark.elem_hover));
+    assert!(lum(dark.elem_hover) < lum(dark.elem_active));
+    assert!(lum(dark.text) > lum(dark.text_muted));
+    assert!(lum(dark.text_muted) > lum(dark.text_disabled));
+
+    let light = Palette::from_seed(Color::hex(0x7c3aed), Mode::Light);
+    assert!(lum(light.terminal_bg) > lum(light.elem));
+    assert!(lum(light.elem) > lum(light.elem_hover));
+    assert!(lum(light.elem_hover) > lum(light.elem_active));
+    assert!(lum(light.text) < lum(light.text_muted));
+    assert!(lum(light.text_muted) < lum(light.text_disabled));
+
+    // The accent keeps the seed's hue: violet stays blue-heavy.
+    assert!(light.accent.b > light.accent.g && dark.accent.b > dark.accent.g);
+    // And the palette assembles into a theme like any other.
+    assert_eq!(Theme::from_palette(&light).window_clear, light.terminal_bg);
+}
+
+#[test]
+fn contrast_report_flags_the_default_focus_ring() {
+    let report = Palette::DEFAULT.contrast_report();
+    let failures: Vec<_> = report
+        .failures()
+        .map(|c| (c.foreground, c.background))
+        .collect();
+    assert_eq!(
+        failures,
+        [(PaletteRole::BorderFocused, PaletteRole::ElemHover)]
+    );
+    let line = report.to_string();
+    assert!(
+        line.starts_with("border_focused on elem_hover: 1.") && line.ends_with("(needs 3:1)"),
+        "{line}",
+    );
+
+    let fixed = Palette {
+        border_focused: Color::hex(0x4aa8d8),
+        ..Palette::DEFAULT
+    };
+    assert!(fixed.contrast_report().passes());
+    assert_eq!(
+        fixed.contrast_report().to_string(),
+        "all 11 role pairs pass"
+    );
+}
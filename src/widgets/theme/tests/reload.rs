@@ -0,0 +1,101 @@
+use crate::scene::layer::Layer;
+use crate::ui::harness::UiHarness;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::reload::ThemeWatcher;
+use glam::UVec2;
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+const SURFACE: UVec2 = UVec2::new(400, 300);
+
+/// Write `text` to `path` with an explicit mtime, so the test doesn't
+/// depend on the filesystem's timestamp granularity to see a change.
+fn write(path: &PathBuf, text: &str, mtime_secs: u64) {
+    std::fs::write(path, text).unwrap();
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(mtime_secs))
+        .unwrap();
+}
+
+fn theme_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("palantir-theme-reload-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn from_toml_names_the_rejected_field() {
+    let mut table: toml::Table =
+        toml::from_str(&toml::to_string(&Theme::default()).unwrap()).unwrap();
+    table["slider"]["knob_size"] = toml::Value::from("big");
+    let Err(error) = Theme::from_toml(&toml::to_string(&table).unwrap()) else {
+        panic!("a string knob size must not load");
+    };
+    assert_eq!(error.field(), Some("slider.knob_size"), "{error}");
+
+    let Err(error) = Theme::from_toml("window_clear = 3") else {
+        panic!("a number is not a colour");
+    };
+    assert_eq!(error.field(), Some("window_clear"), "{error}");
+    assert_eq!(error.line(), Some(1));
+
+    let Err(error) = Theme::from_toml("[button\n") else {
+        panic!("unterminated table header");
+    };
+    assert_eq!(error.field(), None, "a syntax error is not about a field");
+}
+
+/// An error at column 0 sits on the line that starts there, not on the
+/// one the preceding newline ends.
+#[test]
+fn from_toml_counts_a_line_start_error_on_its_own_line() {
+    let Err(error) = Theme::from_toml("# theme\n= 3\n") else {
+        panic!("a bare value has no key");
+    };
+    assert_eq!(error.line(), Some(2), "{error}");
+}
+
+#[test]
+fn watcher_applies_changes_and_keeps_the_last_good_theme_on_error() {
+    let path = theme_file("watched.toml");
+    let mut good = Theme::default();
+    good.command_palette.width = 321.0;
+    write(&path, &toml::to_string_pretty(&good).unwrap(), 1);
+
+    let mut h = UiHarness::new(SURFACE);
+    let mut watcher = ThemeWatcher::new(&path).interval(Duration::from_millis(100));
+    assert!(h.frame_value(|ui| watcher.update(ui)), "first update loads");
+    assert_eq!(h.ui.theme().command_palette.width, 321.0);
+    assert!(
+        !h.frame_value(|ui| watcher.update(ui)),
+        "not due again until the interval passes",
+    );
+
+    write(&path, "window_clear = 3", 2);
+    h.advance(Duration::from_millis(150));
+    assert!(!h.frame_value(|ui| watcher.update(ui)));
+    assert_eq!(
+        h.ui.theme().command_palette.width,
+        321.0,
+        "a broken save keeps the previous theme",
+    );
+    assert_eq!(watcher.error().unwrap().field(), Some("window_clear"));
+    assert!(
+        !h.ui.tree(Layer::Debug).records.is_empty(),
+        "the error shows in the debug layer",
+    );
+
+    good.command_palette.width = 456.0;
+    write(&path, &toml::to_string_pretty(&good).unwrap(), 3);
+    h.advance(Duration::from_millis(150));
+    assert!(h.frame_value(|ui| watcher.update(ui)));
+    assert_eq!(h.ui.theme().command_palette.width, 456.0);
+    assert!(watcher.error().is_none());
+    assert!(h.ui.tree(Layer::Debug).records.is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,162 @@
+//! `Ui::with_theme`: what a scope's widgets read, what they leave behind
+//! when it closes, and that swapping a scope's theme re-lays out the
+//! subtree without any invalidation by hand.
+
+use crate::primitives::color::Color;
+use crate::primitives::spacing::Spacing;
+use crate::primitives::widget_id::WidgetId;
+use crate::scene::node::Configure;
+use crate::ui::Ui;
+use crate::ui::harness::UiHarness;
+use crate::widgets::button::Button;
+use crate::widgets::panel::Panel;
+use crate::widgets::theme::Theme;
+use crate::widgets::theme::palette::Palette;
+use glam::UVec2;
+use std::rc::Rc;
+
+const SURFACE: UVec2 = UVec2::new(400, 200);
+
+fn padded(px: f32) -> Rc<Theme> {
+    let mut theme = Theme::default();
+    theme.button.padding = Spacing::all(px);
+    Rc::new(theme)
+}
+
+fn button(ui: &mut Ui, name: &str) {
+    Button::new()
+        .id(WidgetId::from_hash(name))
+        .label("x")
+        .show(ui);
+}
+
+fn width(h: &UiHarness, name: &str) -> f32 {
+    h.rect(WidgetId::from_hash(name)).unwrap().size.w
+}
+
+#[test]
+fn widgets_read_the_innermost_scope_and_scopes_restore_on_close() {
+    let mut h = UiHarness::new(SURFACE);
+    h.ui.set_theme(padded(4.0));
+    let (outer, inner) = (padded(10.0), padded(20.0));
+    let forwarded = h.frame_value(|ui| {
+        Panel::vstack()
+            .id_salt("col")
+            .show(ui, |ui| {
+                button(ui, "before");
+                let value = ui.with_theme(outer.clone(), |ui| {
+                    button(ui, "outer");
+                    ui.with_theme(inner.clone(), |ui| button(ui, "inner"));
+                    button(ui, "outer-again");
+                    7
+                });
+                button(ui, "after");
+                value
+            })
+            .inner
+    });
+    assert_eq!(forwarded, 7, "the body's value comes back out");
+
+    let base = width(&h, "before");
+    assert_eq!(
+        width(&h, "after"),
+        base,
+        "the root theme is back after the scope"
+    );
+    assert_eq!(width(&h, "outer") - base, 2.0 * (10.0 - 4.0));
+    assert_eq!(width(&h, "inner") - base, 2.0 * (20.0 - 4.0));
+    assert_eq!(
+        width(&h, "outer-again"),
+        width(&h, "outer"),
+        "a nested scope restores its parent's theme, not the root",
+    );
+}
+
+#[test]
+fn swapping_a_scopes_theme_relays_out_the_cached_subtree() {
+    let mut h = UiHarness::new(SURFACE);
+    let mut scoped = padded(4.0);
+    let record = |h: &mut UiHarness, theme: &Rc<Theme>| {
+        h.frame(|ui| {
+            Panel::vstack().id_salt("col").show(ui, |ui| {
+                button(ui, "outside");
+                ui.with_theme(theme.clone(), |ui| {
+                    Panel::hstack()
+                        .id_salt("sidebar")
+                        .show(ui, |ui| button(ui, "inside"));
+                });
+            });
+        });
+    };
+    record(&mut h, &scoped);
+    record(&mut h, &scoped);
+    let (outside, narrow) = (width(&h, "outside"), width(&h, "inside"));
+
+    scoped = padded(30.0);
+    record(&mut h, &scoped);
+    assert_eq!(
+        width(&h, "inside") - narrow,
+        2.0 * (30.0 - 4.0),
+        "the new padding reaches layout through the subtree hash",
+    );
+    assert_eq!(width(&h, "outside"), outside);
+}
+
+#[test]
+fn set_theme_inside_a_scope_replaces_the_app_theme() {
+    let mut h = UiHarness::new(SURFACE);
+    let (scoped, replacement) = (padded(20.0), padded(8.0));
+    h.frame(|ui| {
+        ui.with_theme(scoped.clone(), |ui| {
+            ui.set_theme(replacement.clone());
+            assert!(
+                Rc::ptr_eq(ui.theme(), &scoped),
+                "the open scope keeps shadowing"
+            );
+        });
+        assert!(Rc::ptr_eq(ui.theme(), &replacement));
+    });
+    assert!(Rc::ptr_eq(h.ui.theme(), &replacement));
+}
+
+#[test]
+fn a_panicking_body_still_closes_its_scope() {
+    let mut h = UiHarness::new(SURFACE);
+    let root = h.ui.theme().clone();
+    let caught = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        h.ui.with_theme(padded(20.0), |ui| {
+            ui.with_theme(padded(30.0), |_| panic!("body failed"));
+        });
+    }));
+    assert!(caught.is_err());
+    assert!(
+        Rc::ptr_eq(h.ui.theme(), &root),
+        "both scopes closed on the way out"
+    );
+}
+
+#[test]
+fn with_palette_builds_each_palette_theme_once_while_in_use() {
+    let mut h = UiHarness::new(SURFACE);
+    let mut light = Palette::DEFAULT;
+    light.terminal_bg = Color::rgb(0.95, 0.95, 0.92);
+    let scoped = |h: &mut UiHarness, palette: Option<&Palette>| {
+        h.frame_value(|ui| match palette {
+            Some(palette) => ui.with_palette(palette, |ui| ui.theme().clone()),
+            None => ui.theme().clone(),
+        })
+    };
+
+    let first = scoped(&mut h, Some(&light));
+    assert_eq!(first.window_clear, light.terminal_bg);
+    assert!(
+        Rc::ptr_eq(&scoped(&mut h, Some(&light)), &first),
+        "the same palette reuses the theme it built"
+    );
+
+    scoped(&mut h, None);
+    assert!(
+        !Rc::ptr_eq(&scoped(&mut h, Some(&light)), &first),
+        "a frame without the palette drops its theme"
+    );
+}
@@ -0,0 +1,128 @@
+use crate::animation::anim_spec::AnimSpec;
+use crate::animation::easing::Easing;
+use crate::primitives::brush::Brush;
+use crate::primitives::color::Color;
+use crate::primitives::spacing::Spacing;
+use crate::ui::harness::UiHarness;
+use crate::widgets::theme::Theme;
+use glam::UVec2;
+use std::rc::Rc;
+use std::time::Duration;
+
+const SURFACE: UVec2 = UVec2::new(200, 100);
+const STEP: Duration = Duration::from_millis(20);
+const LINEAR: AnimSpec = AnimSpec::duration(0.2, Easing::Linear);
+
+fn dark() -> Rc<Theme> {
+    Rc::new(Theme {
+        window_clear: Color::BLACK,
+        ..Theme::default()
+    })
+}
+
+fn light() -> Rc<Theme> {
+    let mut theme = Theme {
+        window_clear: Color::WHITE,
+        ..Theme::default()
+    };
+    theme.button.looks.normal.background.fill = Brush::Solid(Color::WHITE);
+    theme.button.padding = Spacing::all(20.0);
+    Rc::new(theme)
+}
+
+fn started(spec: AnimSpec) -> (UiHarness, Rc<Theme>) {
+    let mut h = UiHarness::new(SURFACE);
+    h.ui().set_theme(dark());
+    h.frame(|_| {});
+    let to = light();
+    h.frame(|ui| ui.set_theme_animated(to.clone(), spec));
+    (h, to)
+}
+
+#[test]
+fn colours_ease_in_oklab_while_geometry_snaps() {
+    let (mut h, to) = started(LINEAR);
+    let theme = h.ui().theme().clone();
+    assert_eq!(
+        theme.window_clear,
+        Color::BLACK,
+        "starts from the old colours"
+    );
+    assert_eq!(
+        theme.button.padding, to.button.padding,
+        "geometry is the new theme's at once"
+    );
+
+    h.advance_frames(5, STEP, |_| {});
+    let theme = h.ui().theme().clone();
+    let clear = theme.window_clear;
+    assert!(
+        clear.r > 0.0 && clear.r < 1.0,
+        "midway the clear colour is between the two: {clear:?}"
+    );
+    let Brush::Solid(fill) = theme.button.looks.normal.background.fill else {
+        panic!("solid fills stay solid");
+    };
+    let Brush::Solid(from) = dark().button.looks.normal.background.fill.clone() else {
+        unreachable!("the default button fill is solid")
+    };
+    let t = 5.0 * STEP.as_secs_f32() / 0.2;
+    let expected = from.lerp_oklab(Color::WHITE, t);
+    assert!(
+        (fill.r - expected.r).abs() < 1e-3,
+        "nested looks blend through Oklab like the top level: {fill:?} vs {expected:?}"
+    );
+
+    h.advance_frames(6, STEP, |_| {});
+    assert!(
+        Rc::ptr_eq(h.ui().theme(), &to),
+        "lands on the target theme itself"
+    );
+    h.advance(STEP);
+    assert!(
+        !h.frame(|_| {}).repaint_requested,
+        "a landed switch stops repainting"
+    );
+}
+
+#[test]
+fn a_switch_restarted_midway_eases_on_from_the_blend() {
+    let (mut h, _) = started(LINEAR);
+    h.advance_frames(5, STEP, |_| {});
+    let midway = h.ui().theme().window_clear;
+    h.frame(|ui| ui.set_theme_animated(dark(), LINEAR));
+    assert_eq!(
+        h.ui().theme().window_clear,
+        midway,
+        "no jump back to either end"
+    );
+    h.advance_frames(12, STEP, |_| {});
+    assert_eq!(h.ui().theme().window_clear, Color::BLACK);
+}
+
+#[test]
+fn reduced_motion_and_instant_specs_snap() {
+    let mut h = UiHarness::new(SURFACE);
+    h.ui().set_reduced_motion(true);
+    let to = light();
+    h.frame(|ui| ui.set_theme_animated(to.clone(), AnimSpec::MEDIUM));
+    assert!(Rc::ptr_eq(h.ui().theme(), &to));
+
+    let (mut h, to) = started(AnimSpec::duration(0.0, Easing::Linear));
+    assert!(Rc::ptr_eq(h.ui().theme(), &to));
+
+    let (mut h, to) = started(AnimSpec::SPRING);
+    h.advance_frames(2, STEP, |_| {});
+    assert!(
+        !Rc::ptr_eq(h.ui().theme(), &to),
+        "a spring switch is still easing"
+    );
+    h.ui().set_reduced_motion(true);
+    assert!(
+        Rc::ptr_eq(h.ui().theme(), &to),
+        "turning reduced motion on finishes a switch in flight"
+    );
+    h.advance(STEP);
+    h.frame(|_| {});
+    assert!(Rc::ptr_eq(h.ui().theme(), &to));
+}
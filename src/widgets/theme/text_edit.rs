@@ -19,7 +19,7 @@ use glam::Vec2;
 /// `hovered` look equals `normal`, so hover feedback is opt-in.
 ///
 /// State-independent fields (`caret`, `caret_width`, `placeholder`,
-/// `selection`, `padding`, `margin`) live flat on the theme — they
+/// `selection`, `invalid`, `padding`, `margin`) live flat on the theme — they
 /// aren't state-varying in any plausible v1.x design.
 ///
 /// `padding`/`margin` apply when the user didn't call
@@ -41,6 +41,11 @@ pub struct TextEditTheme {
     /// Selection highlight fill, painted as a wash behind the selected
     /// glyphs (see `TextEdit::show`).
     pub selection: Color,
+    /// Stroke colour of a field whose
+    /// [`validator`](crate::TextEdit::validator) flags its value, in
+    /// place of the state look's — at that look's width, so a field
+    /// with no stroke shows nothing.
+    pub invalid: Color,
     /// Default padding inside the editor (around the buffer text).
     /// Applied at `show()` time when the builder hasn't set padding.
     pub padding: Spacing,
@@ -65,6 +70,7 @@ impl TextEditTheme {
             caret: _,
             caret_width: _,
             selection: _,
+            invalid: _,
             padding: _,
             margin: _,
             anim: _,
@@ -163,11 +169,34 @@ impl TextEditTheme {
             caret: p.text,
             caret_width: 1.5,
             selection,
+            // Not a palette role: no recipe but this one wants a red.
+            invalid: Color::rgb(1.0, 0.35, 0.35),
             padding: Spacing::xy(5.0, 3.0),
             margin: Spacing::ZERO,
             anim: None,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            looks,
+            placeholder,
+            caret,
+            caret_width: _,
+            selection,
+            invalid,
+            padding: _,
+            margin: _,
+            anim: _,
+        } = self;
+        looks.zip_colors(&from.looks, f);
+        f(placeholder, from.placeholder);
+        f(caret, from.caret);
+        f(selection, from.selection);
+        f(invalid, from.invalid);
+    }
 }
 
 palette_default!(TextEditTheme);
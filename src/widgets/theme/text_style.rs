@@ -137,4 +137,17 @@ impl TextStyle {
     pub const fn bold(self) -> Self {
         self.with_weight(FontWeight::Bold)
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            font_size_px: _,
+            color,
+            line_height_mult: _,
+            family: _,
+            weight: _,
+        } = self;
+        f(color, from.color);
+    }
 }
@@ -192,4 +192,26 @@ impl ToggleTheme {
             anim: None,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            unchecked,
+            checked,
+            indicator,
+            box_size: _,
+            indicator_stroke: _,
+            check_pts: _,
+            indicator_inset: _,
+            row_gap: _,
+            track_aspect: _,
+            padding: _,
+            margin: _,
+            anim: _,
+        } = self;
+        unchecked.zip_colors(&from.unchecked, f);
+        checked.zip_colors(&from.checked, f);
+        f(indicator, from.indicator);
+    }
 }
@@ -7,6 +7,7 @@ use crate::primitives::spacing::Spacing;
 use crate::primitives::stroke::Stroke;
 use crate::widgets::theme::palette::Palette;
 use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::theme::transition::zip_background;
 use glam::Vec2;
 use std::time::Duration;
 
@@ -75,6 +76,22 @@ impl TooltipTheme {
             gap: 6.0,
         }
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(super) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            panel,
+            text,
+            padding: _,
+            max_size: _,
+            delay: _,
+            warmup: _,
+            gap: _,
+        } = self;
+        zip_background(panel, &from.panel, f);
+        text.zip_colors(&from.text, f);
+    }
 }
 
 palette_default!(TooltipTheme);
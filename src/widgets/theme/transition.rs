@@ -0,0 +1,79 @@
+//! Animated whole-theme switches. [`Ui::set_theme_animated`] hands the
+//! `Ui` a [`ThemeTransition`]; once per frame, before anything records,
+//! the `Ui` advances its progress and installs [`Theme::blend`] of the
+//! two ends as *the* theme. Widgets keep reading [`Ui::theme`] and never
+//! learn a transition is running, which is what lets every colour move
+//! without a widget opting in.
+//!
+//! [`Ui::set_theme_animated`]: crate::Ui::set_theme_animated
+//! [`Ui::theme`]: crate::Ui::theme
+
+use crate::animation::anim_slot::AnimSlot;
+use crate::animation::anim_spec::AnimSpec;
+use crate::primitives::background::Background;
+use crate::primitives::brush::Brush;
+use crate::primitives::color::Color;
+use crate::primitives::shadow::Shadow;
+use crate::primitives::stroke::Stroke;
+use crate::primitives::widget_id::WidgetId;
+use crate::widgets::theme::Theme;
+use std::rc::Rc;
+
+/// One theme switch in flight: the theme on screen when it started and
+/// the one it lands on. Restarting mid-flight takes the current blend as
+/// the new `from`, so a quick light → dark → light never jumps.
+#[derive(Debug)]
+pub(crate) struct ThemeTransition {
+    pub(crate) from: Rc<Theme>,
+    pub(crate) to: Rc<Theme>,
+    pub(crate) spec: AnimSpec,
+}
+
+impl ThemeTransition {
+    /// Where the 0 → 1 progress scalar lives in the animation map. Keyed
+    /// on the viewport root, which every frame records and no sweep
+    /// removes, so the row lives exactly as long as the `Ui` keeps
+    /// ticking it.
+    pub(crate) const ID: WidgetId = WidgetId::VIEWPORT;
+    pub(crate) const SLOT: AnimSlot = AnimSlot::new("theme-transition");
+}
+
+impl Theme {
+    /// `to` with every colour moved a fraction `t` of the way from its
+    /// counterpart in `from`, through Oklab ([`Color::lerp_oklab`]).
+    ///
+    /// Only colours blend. Sizes, spacing, fonts and motion specs are
+    /// `to`'s from the first frame — a font size easing through
+    /// fractional pixels would re-wrap every paragraph each frame — and
+    /// so is anything with no counterpart: a gradient (gradients snap,
+    /// as they do under `Ui::animate`), a look's text override present
+    /// on one side only. `t` is clamped, so a spring's overshoot cannot
+    /// push colours past the target and out of gamut.
+    pub(crate) fn blend(from: &Theme, to: &Theme, t: f32) -> Theme {
+        let t = t.clamp(0.0, 1.0);
+        let mut out = to.clone();
+        out.zip_colors(from, &mut |color, from| *color = from.lerp_oklab(*color, t));
+        out
+    }
+}
+
+/// [`Background`]'s colours: a solid fill, the stroke, the shadow.
+/// Plain data from `primitives`, so the walk lives here with its one
+/// caller rather than on the type.
+pub(crate) fn zip_background<F: FnMut(&mut Color, Color)>(
+    background: &mut Background,
+    from: &Background,
+    f: &mut F,
+) {
+    let Background {
+        fill,
+        stroke: Stroke { color: stroke, .. },
+        corners: _,
+        shadow: Shadow { color: shadow, .. },
+    } = background;
+    if let (Brush::Solid(fill), Brush::Solid(from_fill)) = (fill, &from.fill) {
+        f(fill, *from_fill);
+    }
+    f(stroke, from.stroke.color);
+    f(shadow, from.shadow.color);
+}
@@ -19,7 +19,9 @@ pub(crate) mod stateful_look;
 
 use crate::animation::anim_slot::AnimSlot;
 use crate::primitives::background::Background;
+use crate::primitives::color::Color;
 use crate::widgets::theme::text_style::TextStyle;
+use crate::widgets::theme::transition::zip_background;
 use crate::widgets::theme::widget_look::animated_look::AnimatedLook;
 
 /// Paint settings for one widget state — the same shape that Button
@@ -83,4 +85,18 @@ impl WidgetLook {
             f(t);
         }
     }
+
+    /// Pair this look's colours with `from`'s. A text override present
+    /// on only one side has no counterpart to blend with; it takes this
+    /// look's value at once, as every non-colour field does.
+    ///
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(crate) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self { background, text } = self;
+        zip_background(background, &from.background, f);
+        if let (Some(text), Some(from_text)) = (text, &from.text) {
+            text.zip_colors(from_text, f);
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::input::response::ResponseState;
+use crate::primitives::color::Color;
 use crate::widgets::theme::text_style::TextStyle;
 use crate::widgets::theme::widget_look::WidgetLook;
 
@@ -51,4 +52,19 @@ impl StatefulLook {
         active.for_each_text(f);
         disabled.for_each_text(f);
     }
+
+    /// Destructured so a new field fails to compile here — see
+    /// [`Theme::zip_colors`](crate::Theme).
+    pub(crate) fn zip_colors<F: FnMut(&mut Color, Color)>(&mut self, from: &Self, f: &mut F) {
+        let Self {
+            normal,
+            hovered,
+            active,
+            disabled,
+        } = self;
+        normal.zip_colors(&from.normal, f);
+        hovered.zip_colors(&from.hovered, f);
+        active.zip_colors(&from.active, f);
+        disabled.zip_colors(&from.disabled, f);
+    }
 }
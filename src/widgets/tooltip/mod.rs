@@ -1,3 +1,5 @@
+use crate::input::command::CommandRegistry;
+use crate::input::command::binding::KeyBinding;
 use crate::input::sense::Sense;
 use crate::layout::types::overlay::OverlayPosition;
 use crate::primitives::background::Background;
@@ -59,6 +61,10 @@ fn global_state_id() -> WidgetId {
 /// triggers by default. Pass `.show_when_disabled(true)` to opt in for
 /// "why is this disabled?" hints.
 ///
+/// [`Self::binding`] adds a key binding as a second line under the text;
+/// [`Self::command`] fills both from a registered
+/// [`Command`](crate::Command), so the hint follows the user's rebinding.
+///
 /// Implements [`Configure`](crate::Configure), so the bubble takes `.padding(...)`,
 /// `.max_size(...)`, `.size(...)`, `.margin(...)` and the rest like any
 /// other widget. Identity defaults to the trigger's id — a tooltip has
@@ -68,6 +74,7 @@ fn global_state_id() -> WidgetId {
 pub struct Tooltip<'r, 'a> {
     snapshot: &'r ResponseSnapshot,
     text: TextInput<'a>,
+    binding: Option<KeyBinding>,
     delay: Option<Duration>,
     show_when_disabled: bool,
     node: Node,
@@ -92,6 +99,7 @@ impl<'r> Tooltip<'r, 'static> {
         Self {
             snapshot,
             text: TextInput::default(),
+            binding: None,
             delay: None,
             show_when_disabled: false,
             node,
@@ -113,6 +121,7 @@ impl<'r, 'a> Tooltip<'r, 'a> {
         Tooltip {
             snapshot: self.snapshot,
             text: text.into(),
+            binding: self.binding,
             delay: self.delay,
             show_when_disabled: self.show_when_disabled,
             node: self.node,
@@ -121,6 +130,24 @@ impl<'r, 'a> Tooltip<'r, 'a> {
         }
     }
 
+    /// Show `binding` on its own line under the text, in the platform's
+    /// native form (`⌘S` / `Ctrl+S`). `None` shows nothing, so a
+    /// possibly-unbound [`CommandRegistry::binding`] passes straight in.
+    pub fn binding(mut self, binding: Option<KeyBinding>) -> Self {
+        self.binding = binding;
+        self
+    }
+
+    /// Text and binding from the registered command `id`: its label and
+    /// its current binding. Panics on an unknown id.
+    #[track_caller]
+    pub fn command<C>(self, registry: &CommandRegistry<C>, id: &str) -> Tooltip<'r, 'static> {
+        let Some(command) = registry.command(id) else {
+            panic!("no command `{id}` is registered");
+        };
+        self.text(command.label).binding(registry.binding(id))
+    }
+
     /// Override the per-tooltip delay. Falls back to
     /// [`crate::widgets::theme::tooltip::TooltipTheme::delay`] when unset.
     pub fn delay(mut self, delay: Duration) -> Self {
@@ -200,6 +227,7 @@ impl<'r, 'a> Tooltip<'r, 'a> {
                 .last_visible_at = Some(now);
             let position = OverlayPosition::below(trigger_rect, gap);
             let text = self.text;
+            let binding = self.binding.map(|b| ui.fmt(format_args!("{b}")));
             let chrome = self.chrome.as_ref().unwrap_or(&theme.panel);
             // Theme fills in whatever the caller left alone. Identity
             // derives from the trigger, because that is the only thing a
@@ -216,6 +244,9 @@ impl<'r, 'a> Tooltip<'r, 'a> {
                         .style(&theme.text)
                         .text_wrap(TextWrap::Wrap)
                         .show(ui);
+                    if let Some(binding) = binding {
+                        Text::new(binding).style(&theme.text).show(ui);
+                    }
                 });
             });
         }
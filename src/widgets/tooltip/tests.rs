@@ -3,7 +3,10 @@
 //! Multi-frame integration tests drive fake pointer hover at advancing
 //! the `Ui` frame-runtime clock to assert visibility, placement, and sizing behavior.
 
+use crate::input::command::binding::KeyBinding;
+use crate::input::command::{Command, CommandRegistry};
 use crate::input::response::ResponseState;
+use crate::input::shortcut::Shortcut;
 use crate::layout::types::sizing::Sizing;
 use crate::primitives::background::Background;
 use crate::primitives::rect::Rect;
@@ -538,3 +541,39 @@ fn layer_below_current_scope_panics() {
         });
     });
 }
+
+#[test]
+fn command_tooltip_adds_binding_line_under_label() {
+    let mut registry = CommandRegistry::<()>::new();
+    registry.register(
+        Command::new("file.save", "Save").binding(KeyBinding::single(Shortcut::ctrl('S'))),
+    );
+    registry.register(Command::new("file.revert", "Save"));
+    let trigger_id = WidgetId::from_hash("command-trigger");
+    let snapshot = ResponseSnapshot {
+        id: trigger_id,
+        state: ResponseState {
+            rect: Some(Rect::new(20.0, 20.0, 40.0, 24.0)),
+            hovered: true,
+            ..ResponseState::default()
+        },
+    };
+    let bubble = |command: &str| {
+        let mut h = UiHarness::new(SURFACE);
+        h.frame(|ui| {
+            Tooltip::on(&snapshot)
+                .command(&registry, command)
+                .delay(Duration::ZERO)
+                .show(ui);
+        });
+        h.rect(trigger_id.with("bubble"))
+            .expect("tooltip bubble arranged")
+    };
+
+    let bound = bubble("file.save");
+    let unbound = bubble("file.revert");
+    assert!(
+        bound.size.h > unbound.size.h,
+        "binding line stacks under the label: {bound:?} vs {unbound:?}",
+    );
+}
@@ -1,8 +1,10 @@
 use crate::harness::{audit_steady_state, audit_text_steady_state};
 use palantir::{
-    Background, Button, Color, Configure, ContextMenu, Frame, Grid, MenuItem, Panel, Scroll,
-    Shortcut, Sizing, Splitter, Text, TextEdit, Track, Ui, Vec2, WidgetId,
+    Autocomplete, Background, Button, Color, Configure, ContextMenu, Frame, Grid, InputEvent,
+    IntRange, MenuItem, Panel, RegexValidator, Scroll, Shortcut, Sizing, Splitter,
+    SuggestionProvider, Text, TextChunk, TextEdit, TextRope, Track, Ui, Vec2, WidgetId,
 };
+use std::ops::Range;
 
 #[test]
 fn empty_frame_alloc_free() {
@@ -154,6 +156,88 @@ fn long_multiline_selection_alloc_free() {
     });
 }
 
+/// Completes the word at the caret, so the list opens partway into a
+/// document rather than over all of it.
+#[derive(Debug)]
+struct Words(&'static [&'static str]);
+
+impl SuggestionProvider for Words {
+    fn suggest(&self, query: &str, out: &mut Vec<String>) {
+        out.extend(
+            self.0
+                .iter()
+                .filter(|w| w.starts_with(query) && **w != query)
+                .map(|w| (*w).to_owned()),
+        );
+    }
+
+    fn token(&self, line: &str, caret: usize) -> Range<usize> {
+        let start = line[..caret]
+            .rfind(char::is_whitespace)
+            .map_or(0, |at| at + 1);
+        let end = line[caret..]
+            .find(char::is_whitespace)
+            .map_or(line.len(), |at| caret + at);
+        start..end
+    }
+}
+
+/// A rope document with its suggestion list open must record alloc-free
+/// once nothing moves. Pins the list's refresh waiting on the buffer's
+/// hash and the caret: re-cutting the token flattens the whole rope, an
+/// allocation the size of the document every frame the list showed.
+#[test]
+fn rope_with_open_suggestions_alloc_free() {
+    let editor_id = WidgetId::from_hash("alloc-rope-suggestions");
+    let mut document = TextRope::from(format!("p{}", " lorem ipsum\n".repeat(400)));
+    let words = Words(&["apple", "apricot", "lorem"]);
+    let mut frame = 0u32;
+    audit_text_steady_state(0, move |ui| {
+        ui.request_focus(Some(editor_id));
+        // Typing is what opens the list; once, then the scene holds still.
+        if frame == 1 {
+            ui.on_input(InputEvent::Text(TextChunk::new("a").unwrap()));
+        }
+        frame += 1;
+        TextEdit::new(&mut document)
+            .id(editor_id)
+            .multiline(true)
+            .autocomplete(Autocomplete::new(&words))
+            .size((Sizing::fixed(360.0), Sizing::fixed(300.0)))
+            .show(ui);
+    });
+}
+
+/// Fields whose validators flag their values must record alloc-free once
+/// nothing moves. Pins the verdict cache: judging flattens the buffer,
+/// re-runs the regex and formats the range's reason, none of which may
+/// happen again until the value or the validator changes.
+#[test]
+fn invalid_fields_alloc_free() {
+    let percent = IntRange(0..=100);
+    let hex = RegexValidator::new("#[0-9a-f]{6}", "A colour like #ff8800").unwrap();
+    let mut amount = String::from("150");
+    let mut colour = String::from("#ff88");
+    audit_steady_state(0, move |ui| {
+        Panel::vstack().auto_id().show(ui, |ui| {
+            let flagged = TextEdit::new(&mut amount)
+                .id_salt("percent")
+                .validator(&percent)
+                .invalid_tooltip()
+                .size((Sizing::FILL, Sizing::fixed(28.0)))
+                .show(ui)
+                .invalid
+                .is_some();
+            assert!(flagged, "the fixture must hold an invalid value");
+            TextEdit::new(&mut colour)
+                .id_salt("colour")
+                .validator(&hex)
+                .size((Sizing::FILL, Sizing::fixed(28.0)))
+                .show(ui);
+        });
+    });
+}
+
 #[test]
 fn state_map_counter_alloc_free() {
     let id = WidgetId::from_hash("counter");